@@ -1,6 +1,8 @@
 use crate::Primitive;
-use crate::core::renderer::Quad;
-use crate::core::{Background, Color, Gradient, Rectangle, Size, Transformation, Vector};
+use crate::core::gradient::ColorStop;
+use crate::core::renderer::{BlendMode, LayerStyle, Quad};
+use crate::core::{Background, Color, Gradient, Point, Rectangle, Size, Transformation, Vector};
+use crate::graphics::gradient;
 use crate::graphics::{Image, Text};
 use crate::text;
 
@@ -63,17 +65,21 @@ impl Engine {
 
         let shadow = quad.shadow;
 
-        if shadow.color.a > 0.0 {
+        if shadow.color.a > 0.0 && !shadow.inset {
+            let extent = shadow.blur_radius + shadow.spread;
+
             let shadow_bounds = Rectangle {
-                x: quad.bounds.x + shadow.offset.x - shadow.blur_radius,
-                y: quad.bounds.y + shadow.offset.y - shadow.blur_radius,
-                width: quad.bounds.width + shadow.blur_radius * 2.0,
-                height: quad.bounds.height + shadow.blur_radius * 2.0,
+                x: quad.bounds.x + shadow.offset.x - extent,
+                y: quad.bounds.y + shadow.offset.y - extent,
+                width: (quad.bounds.width + extent * 2.0).max(0.0),
+                height: (quad.bounds.height + extent * 2.0).max(0.0),
             } * transformation;
 
+            let spread = shadow.spread * transformation.scale_factor();
+
             let radii = fill_border_radius
                 .into_iter()
-                .map(|radius| radius * transformation.scale_factor())
+                .map(|radius| spread_radius(radius * transformation.scale_factor(), spread))
                 .collect::<Vec<_>>();
             let (x, y, width, height) = (
                 shadow_bounds.x as u32,
@@ -81,8 +87,8 @@ impl Engine {
                 shadow_bounds.width as u32,
                 shadow_bounds.height as u32,
             );
-            let half_width = physical_bounds.width / 2.0;
-            let half_height = physical_bounds.height / 2.0;
+            let half_width = physical_bounds.width / 2.0 + spread;
+            let half_height = physical_bounds.height / 2.0 + spread;
 
             let colors = (y..y + height)
                 .flat_map(|y| (x..x + width).map(move |x| (x as f32, y as f32)))
@@ -130,6 +136,25 @@ impl Engine {
             }
         }
 
+        // tiny-skia has no conic shader, so we rasterize the visible part of
+        // the gradient and use it as a pattern instead
+        let conic = match background {
+            Background::Gradient(Gradient::Conic(conic)) => physical_bounds
+                .intersection(&clip_bounds)
+                .and_then(|bounds| {
+                    rasterize_conic(
+                        &gradient::Conic {
+                            center: conic.to_absolute(&quad.bounds),
+                            angle: conic.angle,
+                            stops: conic.stops,
+                        },
+                        bounds,
+                        transform,
+                    )
+                }),
+            _ => None,
+        };
+
         pixels.fill_path(
             &path,
             &tiny_skia::Paint {
@@ -138,40 +163,27 @@ impl Engine {
                     Background::Gradient(Gradient::Linear(linear)) => {
                         let (start, end) = linear.angle.to_distance(&quad.bounds);
 
-                        let stops: Vec<tiny_skia::GradientStop> = linear
-                            .stops
-                            .into_iter()
-                            .flatten()
-                            .map(|stop| {
-                                tiny_skia::GradientStop::new(
-                                    stop.offset,
-                                    tiny_skia::Color::from_rgba(
-                                        stop.color.b,
-                                        stop.color.g,
-                                        stop.color.r,
-                                        stop.color.a,
-                                    )
-                                    .expect("Create color"),
-                                )
-                            })
-                            .collect();
-
                         tiny_skia::LinearGradient::new(
                             tiny_skia::Point {
                                 x: start.x,
                                 y: start.y,
                             },
                             tiny_skia::Point { x: end.x, y: end.y },
-                            if stops.is_empty() {
-                                vec![tiny_skia::GradientStop::new(0.0, tiny_skia::Color::BLACK)]
-                            } else {
-                                stops
-                            },
+                            into_gradient_stops(&linear.stops),
                             tiny_skia::SpreadMode::Pad,
                             tiny_skia::Transform::identity(),
                         )
                         .expect("Create linear gradient")
                     }
+                    Background::Gradient(Gradient::Radial(radial)) => {
+                        let (center, radius) = radial.to_absolute(&quad.bounds);
+
+                        into_radial_gradient(center, radius, &radial.stops)
+                    }
+                    Background::Gradient(Gradient::Conic(_)) => match &conic {
+                        Some((pixmap, transform)) => into_pattern(pixmap, *transform),
+                        None => tiny_skia::Shader::SolidColor(tiny_skia::Color::TRANSPARENT),
+                    },
                 },
                 anti_alias: true,
                 ..tiny_skia::Paint::default()
@@ -181,6 +193,19 @@ impl Engine {
             clip_mask,
         );
 
+        if shadow.color.a > 0.0 && shadow.inset {
+            draw_inset_shadow(
+                pixels,
+                &shadow,
+                physical_bounds,
+                clip_bounds,
+                fill_border_radius.map(|radius| radius * transformation.scale_factor()),
+                border_width * transformation.scale_factor(),
+                transformation.scale_factor(),
+                clip_mask,
+            );
+        }
+
         if border_width > 0.0 {
             // Border path is offset by half the border width
             let border_bounds = Rectangle {
@@ -376,6 +401,7 @@ impl Engine {
                 align_x,
                 align_y,
                 shaping,
+                truncation,
                 clip_bounds: local_clip_bounds,
             } => {
                 let physical_bounds = *local_clip_bounds * transformation;
@@ -402,6 +428,7 @@ impl Engine {
                     *align_x,
                     *align_y,
                     *shaping,
+                    *truncation,
                     pixels,
                     clip_mask,
                     transformation,
@@ -454,7 +481,12 @@ impl Engine {
         clip_bounds: Rectangle,
     ) {
         match primitive {
-            Primitive::Fill { path, paint, rule } => {
+            Primitive::Fill {
+                path,
+                paint,
+                rule,
+                conic,
+            } => {
                 let physical_bounds = {
                     let bounds = path.bounds();
 
@@ -473,11 +505,26 @@ impl Engine {
                 let clip_mask =
                     (!physical_bounds.is_within(&clip_bounds)).then_some(clip_mask as &_);
 
+                let transform = into_transform(transformation);
+
+                let conic = conic.as_ref().and_then(|conic| {
+                    rasterize_conic(
+                        conic,
+                        physical_bounds.intersection(&clip_bounds)?,
+                        transform,
+                    )
+                });
+
+                let conic_paint = conic.as_ref().map(|(pixmap, pattern)| tiny_skia::Paint {
+                    shader: into_pattern(pixmap, *pattern),
+                    ..paint.clone()
+                });
+
                 pixels.fill_path(
                     path,
-                    paint,
+                    conic_paint.as_ref().unwrap_or(paint),
                     *rule,
-                    into_transform(transformation),
+                    transform,
                     clip_mask,
                 );
             }
@@ -485,6 +532,7 @@ impl Engine {
                 path,
                 paint,
                 stroke,
+                conic,
             } => {
                 let physical_bounds = {
                     let bounds = path.bounds();
@@ -504,11 +552,26 @@ impl Engine {
                 let clip_mask =
                     (!physical_bounds.is_within(&clip_bounds)).then_some(clip_mask as &_);
 
+                let transform = into_transform(transformation);
+
+                let conic = conic.as_ref().and_then(|conic| {
+                    rasterize_conic(
+                        conic,
+                        physical_bounds.intersection(&clip_bounds)?,
+                        transform,
+                    )
+                });
+
+                let conic_paint = conic.as_ref().map(|(pixmap, pattern)| tiny_skia::Paint {
+                    shader: into_pattern(pixmap, *pattern),
+                    ..paint.clone()
+                });
+
                 pixels.stroke_path(
                     path,
-                    paint,
+                    conic_paint.as_ref().unwrap_or(paint),
                     stroke,
-                    into_transform(transformation),
+                    transform,
                     clip_mask,
                 );
             }
@@ -595,6 +658,127 @@ impl Engine {
         }
     }
 
+    /// Blurs and tints whatever was drawn behind the given [`Backdrop`].
+    ///
+    /// The blurred pixels are read from `behind` (or from `pixels` if `None`)
+    /// and drawn into `pixels`. Both pixmaps are placed at their origin in the
+    /// window. Only the pixels inside of the `clip_bounds` are changed, and
+    /// only the pixels inside of the `source_bounds` are blurred; both in
+    /// physical pixels of the window.
+    ///
+    /// [`Backdrop`]: crate::layer::Backdrop
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_backdrop(
+        &mut self,
+        backdrop: &crate::layer::Backdrop,
+        transformation: Transformation,
+        behind: Option<(tiny_skia::PixmapRef<'_>, Vector)>,
+        pixels: &mut tiny_skia::PixmapMut<'_>,
+        origin: Vector,
+        clip_bounds: Rectangle,
+        source_bounds: Rectangle,
+    ) {
+        let bounds = backdrop.bounds * transformation;
+        let radii: [f32; 4] = (backdrop.border_radius * transformation.scale_factor()).into();
+        let sigma = backdrop.blur_radius * transformation.scale_factor();
+
+        let screen = |width: u32, height: u32, origin: Vector| {
+            Rectangle::new(
+                Point::ORIGIN + origin,
+                Size::new(width as f32, height as f32),
+            )
+        };
+
+        let Some(area) = bounds
+            .intersection(&clip_bounds)
+            .and_then(|area| area.intersection(&screen(pixels.width(), pixels.height(), origin)))
+            .and_then(Rectangle::snap)
+        else {
+            return;
+        };
+
+        let (behind, behind_origin) = behind.unwrap_or((pixels.as_ref(), origin));
+
+        let Some(source) = Rectangle::from(area)
+            .expand((sigma * 3.0).ceil().max(0.0))
+            .intersection(&source_bounds)
+            .and_then(|source| {
+                source.intersection(&screen(behind.width(), behind.height(), behind_origin))
+            })
+            .and_then(Rectangle::snap)
+        else {
+            return;
+        };
+
+        let (width, height) = (source.width as usize, source.height as usize);
+
+        let mut blurred: Vec<[f32; 4]> = Vec::with_capacity(width * height);
+
+        {
+            let stride = behind.width() as usize;
+            let (x, y) = (
+                (source.x - behind_origin.x as u32) as usize,
+                (source.y - behind_origin.y as u32) as usize,
+            );
+
+            for y in y..y + height {
+                let row = &behind.pixels()[y * stride + x..][..width];
+
+                blurred.extend(row.iter().map(|pixel| {
+                    [pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()].map(f32::from)
+                }));
+            }
+        }
+
+        gaussian_blur(&mut blurred, width, height, sigma);
+
+        let tint = into_color(backdrop.tint).premultiply();
+        let tint = [tint.red(), tint.green(), tint.blue(), tint.alpha()].map(|c| c * 255.0);
+
+        let half_size = tiny_skia::Size::from_wh(bounds.width / 2.0, bounds.height / 2.0);
+        let center = bounds.center();
+
+        let stride = pixels.width() as usize;
+        let output = pixels.pixels_mut();
+
+        for y in area.y..area.y + area.height {
+            for x in area.x..area.x + area.width {
+                let to_center = Vector::new(x as f32 + 0.5 - center.x, y as f32 + 0.5 - center.y);
+
+                let coverage = half_size.map_or(0.0, |size| {
+                    (0.5 - rounded_box_sdf(to_center, size, &radii)).clamp(0.0, 1.0)
+                });
+
+                if coverage <= 0.0 {
+                    continue;
+                }
+
+                let blurred = blurred[(y - source.y) as usize * width + (x - source.x) as usize];
+
+                let color: [f32; 4] = std::array::from_fn(|i| {
+                    (tint[i] + blurred[i] * (1.0 - tint[3] / 255.0)) * coverage
+                });
+
+                let pixel = &mut output
+                    [(y - origin.y as u32) as usize * stride + (x - origin.x as u32) as usize];
+                let previous =
+                    [pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()].map(f32::from);
+
+                let [r, g, b, a] = std::array::from_fn(|i| {
+                    (color[i] + previous[i] * (1.0 - color[3] / 255.0))
+                        .round()
+                        .clamp(0.0, 255.0) as u8
+                });
+
+                if let Some(color) =
+                    tiny_skia::PremultipliedColorU8::from_rgba(r.min(a), g.min(a), b.min(a), a)
+                {
+                    *pixel = color;
+                }
+            }
+        }
+    }
+
     pub fn trim(&mut self) {
         self.text_pipeline.trim_cache();
 
@@ -611,6 +795,96 @@ pub fn into_color(color: Color) -> tiny_skia::Color {
         .expect("Convert color from iced to tiny_skia")
 }
 
+pub fn into_gradient_stops(stops: &[Option<ColorStop>; 8]) -> Vec<tiny_skia::GradientStop> {
+    let stops: Vec<tiny_skia::GradientStop> = stops
+        .iter()
+        .flatten()
+        .map(|stop| tiny_skia::GradientStop::new(stop.offset, into_color(stop.color)))
+        .collect();
+
+    if stops.is_empty() {
+        vec![tiny_skia::GradientStop::new(0.0, tiny_skia::Color::BLACK)]
+    } else {
+        stops
+    }
+}
+
+pub fn into_radial_gradient(
+    center: Point,
+    radius: f32,
+    stops: &[Option<ColorStop>; 8],
+) -> tiny_skia::Shader<'static> {
+    let center = tiny_skia::Point {
+        x: center.x,
+        y: center.y,
+    };
+
+    tiny_skia::RadialGradient::new(
+        center,
+        center,
+        radius,
+        into_gradient_stops(stops),
+        tiny_skia::SpreadMode::Pad,
+        tiny_skia::Transform::identity(),
+    )
+    .unwrap_or(tiny_skia::Shader::SolidColor(tiny_skia::Color::TRANSPARENT))
+}
+
+/// Rasterizes the given physical `bounds` of a [`gradient::Conic`] living in the
+/// user space of `transform`.
+///
+/// Returns the [`tiny_skia::Pixmap`] and the transform that places it back in
+/// user space, ready to be used as a pattern.
+///
+/// The gradient is evaluated for every pixel of `bounds` each time it is
+/// drawn. Callers clip `bounds` to the damaged region first, so only
+/// redrawn pixels pay for it.
+fn rasterize_conic(
+    conic: &gradient::Conic,
+    bounds: Rectangle,
+    transform: tiny_skia::Transform,
+) -> Option<(tiny_skia::Pixmap, tiny_skia::Transform)> {
+    let inverse = transform.invert()?;
+
+    let x = bounds.x.floor();
+    let y = bounds.y.floor();
+    let width = ((bounds.x + bounds.width).ceil() - x) as u32;
+    let height = ((bounds.y + bounds.height).ceil() - y) as u32;
+
+    let mut pixmap = tiny_skia::Pixmap::new(width, height)?;
+
+    for (index, pixel) in pixmap.pixels_mut().iter_mut().enumerate() {
+        let index = index as u32;
+
+        let mut point = tiny_skia::Point {
+            x: x + (index % width) as f32 + 0.5,
+            y: y + (index / width) as f32 + 0.5,
+        };
+
+        inverse.map_point(&mut point);
+
+        let offset = conic.offset(Point::new(point.x, point.y));
+        let color = gradient::color_at(&conic.stops, offset);
+
+        *pixel = into_color(color).premultiply().to_color_u8();
+    }
+
+    Some((pixmap, inverse.pre_translate(x, y)))
+}
+
+fn into_pattern(
+    pixmap: &tiny_skia::Pixmap,
+    transform: tiny_skia::Transform,
+) -> tiny_skia::Shader<'_> {
+    tiny_skia::Pattern::new(
+        pixmap.as_ref(),
+        tiny_skia::SpreadMode::Pad,
+        tiny_skia::FilterQuality::Nearest,
+        1.0,
+        transform,
+    )
+}
+
 fn into_transform(transformation: Transformation) -> tiny_skia::Transform {
     let translation = transformation.translation();
 
@@ -756,6 +1030,176 @@ fn arc_to(
     }
 }
 
+/// Draws an inset [`Shadow`] inside the border of a quad, with every argument
+/// in physical pixels except for the `shadow` itself.
+///
+/// The shadow is computed for every pixel of the quad within `clip_bounds`
+/// each time it is drawn, so its cost grows with the damaged area.
+///
+/// [`Shadow`]: crate::core::Shadow
+#[allow(clippy::too_many_arguments)]
+fn draw_inset_shadow(
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    shadow: &crate::core::Shadow,
+    bounds: Rectangle,
+    clip_bounds: Rectangle,
+    radii: [f32; 4],
+    border_width: f32,
+    scale_factor: f32,
+    clip_mask: Option<&tiny_skia::Mask>,
+) {
+    let Some(region) = bounds.intersection(&clip_bounds) else {
+        return;
+    };
+
+    let x = region.x.max(0.0).floor();
+    let y = region.y.max(0.0).floor();
+
+    let (x, y, width, height) = (
+        x as u32,
+        y as u32,
+        ((region.x + region.width).ceil() - x).max(0.0) as u32,
+        ((region.y + region.height).ceil() - y).max(0.0) as u32,
+    );
+
+    let Some(size) = tiny_skia::IntSize::from_wh(width, height) else {
+        return;
+    };
+
+    let offset = shadow.offset * scale_factor;
+    let spread = shadow.spread * scale_factor;
+    let blur_radius = shadow.blur_radius * scale_factor;
+
+    let half_size = tiny_skia::Size::from_wh(bounds.width / 2.0, bounds.height / 2.0);
+    let shadow_half_size = tiny_skia::Size::from_wh(
+        bounds.width / 2.0 - border_width - spread,
+        bounds.height / 2.0 - border_width - spread,
+    );
+    let shadow_radii = radii.map(|radius| spread_radius(radius - border_width, -spread));
+    let center = bounds.center();
+
+    let colors = (y..y + height)
+        .flat_map(|y| (x..x + width).map(move |x| (x as f32 + 0.5, y as f32 + 0.5)))
+        .map(|(x, y)| {
+            let to_center = Vector::new(x - center.x, y - center.y);
+
+            let inner_alpha = half_size.map_or(0.0, |size| {
+                (0.5 - rounded_box_sdf(to_center, size, &radii) - border_width).clamp(0.0, 1.0)
+            });
+
+            // The shadow covers everything outside of its (offset and spread) box
+            let shadow_alpha = shadow_half_size.map_or(1.0, |size| {
+                let distance = rounded_box_sdf(to_center - offset, size, &shadow_radii);
+
+                if blur_radius > 0.0 {
+                    smoothstep(-blur_radius, blur_radius, distance)
+                } else {
+                    (0.5 + distance).clamp(0.0, 1.0)
+                }
+            });
+
+            let mut color = into_color(shadow.color);
+            color.apply_opacity(inner_alpha * shadow_alpha);
+
+            color.to_color_u8().premultiply()
+        })
+        .collect();
+
+    if let Some(pixmap) = tiny_skia::Pixmap::from_vec(bytemuck::cast_vec(colors), size) {
+        pixels.draw_pixmap(
+            x as i32,
+            y as i32,
+            pixmap.as_ref(),
+            &tiny_skia::PixmapPaint::default(),
+            tiny_skia::Transform::default(),
+            clip_mask,
+        );
+    }
+}
+
+/// Approximates a gaussian blur with the given standard deviation by applying
+/// three successive box blurs to the given premultiplied pixels.
+fn gaussian_blur(pixels: &mut [[f32; 4]], width: usize, height: usize, sigma: f32) {
+    if sigma <= 0.0 || width == 0 || height == 0 {
+        return;
+    }
+
+    // See "Fast Almost-Gaussian Filtering" by Peter Kovesi
+    let ideal = (12.0 * sigma * sigma / 3.0 + 1.0).sqrt();
+    let lower = {
+        let lower = ideal.floor() as usize;
+
+        if lower.is_multiple_of(2) {
+            lower - 1
+        } else {
+            lower
+        }
+    };
+    let upper = lower + 2;
+    let lower_passes =
+        ((12.0 * sigma * sigma - (3 * lower * lower) as f32 - (12 * lower) as f32 - 9.0)
+            / (-4.0 * lower as f32 - 4.0))
+            .round() as usize;
+
+    let mut buffer = vec![[0.0; 4]; pixels.len()];
+
+    for pass in 0..3 {
+        let size = if pass < lower_passes { lower } else { upper };
+        let radius = (size - 1) / 2;
+
+        box_blur(pixels, &mut buffer, width, height, radius);
+        box_blur(&buffer, pixels, height, width, radius);
+    }
+}
+
+/// Blurs the given `lines` of pixels with a box of the given `radius`,
+/// repeating the edge pixels as needed.
+///
+/// The `output` is written column by column, so two successive passes blur
+/// in both directions and restore the original layout.
+fn box_blur(
+    input: &[[f32; 4]],
+    output: &mut [[f32; 4]],
+    length: usize,
+    lines: usize,
+    radius: usize,
+) {
+    let window = (2 * radius + 1) as f32;
+
+    for line in 0..lines {
+        let pixel = |i: isize| input[line * length + i.clamp(0, length as isize - 1) as usize];
+
+        let mut sum = [0.0; 4];
+
+        for i in -(radius as isize)..=radius as isize {
+            for (sum, channel) in sum.iter_mut().zip(pixel(i)) {
+                *sum += channel;
+            }
+        }
+
+        for i in 0..length {
+            output[i * lines + line] = sum.map(|channel| channel / window);
+
+            let incoming = pixel((i + radius + 1) as isize);
+            let outgoing = pixel(i as isize - radius as isize);
+
+            for ((sum, incoming), outgoing) in sum.iter_mut().zip(incoming).zip(outgoing) {
+                *sum += incoming - outgoing;
+            }
+        }
+    }
+}
+
+/// Grows (or shrinks) a border radius by the given spread, keeping sharp
+/// corners sharp.
+fn spread_radius(radius: f32, spread: f32) -> f32 {
+    if radius > 0.0 {
+        (radius + spread).max(0.0)
+    } else {
+        0.0
+    }
+}
+
 fn smoothstep(a: f32, b: f32, x: f32) -> f32 {
     let x = ((x - a) / (b - a)).clamp(0.0, 1.0);
 
@@ -770,10 +1214,37 @@ fn rounded_box_sdf(to_center: Vector, size: tiny_skia::Size, radii: &[f32]) -> f
         (false, false) => radii[0],
     };
 
-    let x = (to_center.x.abs() - size.width() + radius).max(0.0);
-    let y = (to_center.y.abs() - size.height() + radius).max(0.0);
+    let x = to_center.x.abs() - size.width() + radius;
+    let y = to_center.y.abs() - size.height() + radius;
+
+    (x.max(0.0).powf(2.0) + y.max(0.0).powf(2.0)).sqrt() + x.max(y).min(0.0) - radius
+}
 
-    (x.powf(2.0) + y.powf(2.0)).sqrt() - radius
+/// Blends the pixels of a group of layers into the given `pixels`.
+pub fn draw_group(
+    group_pixels: &tiny_skia::Pixmap,
+    offset: Vector,
+    style: LayerStyle,
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    clip_mask: &tiny_skia::Mask,
+) {
+    pixels.draw_pixmap(
+        offset.x as i32,
+        offset.y as i32,
+        group_pixels.as_ref(),
+        &tiny_skia::PixmapPaint {
+            opacity: style.opacity.clamp(0.0, 1.0),
+            blend_mode: match style.blend_mode {
+                BlendMode::Normal => tiny_skia::BlendMode::SourceOver,
+                BlendMode::Multiply => tiny_skia::BlendMode::Multiply,
+                BlendMode::Screen => tiny_skia::BlendMode::Screen,
+                BlendMode::Overlay => tiny_skia::BlendMode::Overlay,
+            },
+            quality: tiny_skia::FilterQuality::Nearest,
+        },
+        tiny_skia::Transform::identity(),
+        Some(clip_mask),
+    );
 }
 
 pub fn adjust_clip_mask(clip_mask: &mut tiny_skia::Mask, bounds: Rectangle) {
@@ -795,3 +1266,177 @@ pub fn adjust_clip_mask(clip_mask: &mut tiny_skia::Mask, bounds: Rectangle) {
         tiny_skia::Transform::default(),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Shadow;
+
+    #[test]
+    fn rounded_box_sdf_is_unchanged_outside_the_box() {
+        // The signed distance used to be clamped to `-radius` inside the box
+        fn unsigned_sdf(to_center: Vector, size: tiny_skia::Size, radii: &[f32]) -> f32 {
+            let radius = match (to_center.x > 0.0, to_center.y > 0.0) {
+                (true, true) => radii[2],
+                (true, false) => radii[1],
+                (false, true) => radii[3],
+                (false, false) => radii[0],
+            };
+
+            let x = (to_center.x.abs() - size.width() + radius).max(0.0);
+            let y = (to_center.y.abs() - size.height() + radius).max(0.0);
+
+            (x.powf(2.0) + y.powf(2.0)).sqrt() - radius
+        }
+
+        let size = tiny_skia::Size::from_wh(20.0, 10.0).unwrap();
+
+        for radii in [[0.0; 4], [4.0, 0.0, 8.0, 10.0]] {
+            for x in -30..=30 {
+                for y in -20..=20 {
+                    let to_center = Vector::new(x as f32 + 0.5, y as f32 + 0.5);
+
+                    // Outer shadows only use the distance outside of the box
+                    assert_eq!(
+                        rounded_box_sdf(to_center, size, &radii).max(0.0),
+                        unsigned_sdf(to_center, size, &radii).max(0.0),
+                    );
+                }
+            }
+        }
+
+        let center = rounded_box_sdf(Vector::new(0.0, 0.0), size, &[4.0; 4]);
+
+        assert_eq!(center, -10.0);
+    }
+
+    #[test]
+    fn inset_shadow_is_only_drawn_within_the_clip_bounds() {
+        let shadow = Shadow {
+            color: Color::BLACK,
+            offset: Vector::new(2.0, 1.0),
+            blur_radius: 3.0,
+            spread: 1.0,
+            inset: true,
+        };
+
+        let bounds = Rectangle::new(Point::new(2.0, 2.0), Size::new(20.0, 16.0));
+        let clip_bounds = Rectangle::new(Point::new(0.0, 10.0), Size::new(12.0, 14.0));
+
+        let draw = |clip_bounds| {
+            let mut pixmap = tiny_skia::Pixmap::new(24, 24).unwrap();
+
+            draw_inset_shadow(
+                &mut pixmap.as_mut(),
+                &shadow,
+                bounds,
+                clip_bounds,
+                [4.0; 4],
+                1.0,
+                1.0,
+                None,
+            );
+
+            pixmap
+        };
+
+        let full = draw(Rectangle::new(Point::ORIGIN, Size::new(24.0, 24.0)));
+        let clipped = draw(clip_bounds);
+
+        for y in 0..24 {
+            for x in 0..24 {
+                let pixel = clipped.pixel(x, y).unwrap();
+
+                if clip_bounds.contains(Point::new(x as f32 + 0.5, y as f32 + 0.5)) {
+                    assert_eq!(pixel, full.pixel(x, y).unwrap());
+                } else {
+                    assert_eq!(pixel.alpha(), 0);
+                }
+            }
+        }
+
+        assert!(full.pixels().iter().any(|pixel| pixel.alpha() > 0));
+    }
+
+    #[test]
+    fn box_blur_averages_and_transposes() {
+        let input: Vec<[f32; 4]> = [0.0, 0.0, 3.0, 0.0, 0.0, 6.0]
+            .into_iter()
+            .map(|value| [value; 4])
+            .collect();
+
+        let mut output = vec![[0.0; 4]; input.len()];
+
+        // Two lines of three pixels become three lines of two pixels, with
+        // the edge pixels repeated
+        box_blur(&input, &mut output, 3, 2, 1);
+
+        assert_eq!(
+            output.iter().map(|pixel| pixel[0]).collect::<Vec<_>>(),
+            [0.0, 0.0, 1.0, 2.0, 2.0, 4.0]
+        );
+    }
+
+    #[test]
+    fn gaussian_blur_keeps_uniform_colors() {
+        let mut pixels = vec![[0.25, 0.5, 0.75, 1.0]; 12 * 7];
+
+        gaussian_blur(&mut pixels, 12, 7, 3.0);
+
+        for pixel in pixels {
+            for (channel, expected) in pixel.into_iter().zip([0.25, 0.5, 0.75, 1.0]) {
+                assert!((channel - expected).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn gaussian_blur_spreads_by_its_deviation() {
+        const SIZE: usize = 61;
+
+        for sigma in [1.0, 2.5, 4.0, 7.0] {
+            let mut pixels = vec![[0.0; 4]; SIZE * SIZE];
+            pixels[SIZE * SIZE / 2] = [1.0; 4];
+
+            gaussian_blur(&mut pixels, SIZE, SIZE, sigma);
+
+            let center = (SIZE / 2) as f32;
+            let row = &pixels[SIZE * (SIZE / 2)..SIZE * (SIZE / 2 + 1)];
+            let column: Vec<_> = (0..SIZE).map(|y| pixels[y * SIZE + SIZE / 2]).collect();
+
+            let total: f32 = pixels.iter().map(|pixel| pixel[3]).sum();
+            let variance: f32 = pixels
+                .iter()
+                .enumerate()
+                .map(|(index, pixel)| {
+                    let x = (index % SIZE) as f32 - center;
+
+                    pixel[3] * x * x
+                })
+                .sum();
+
+            // The blur neither gains nor loses color away from the edges
+            assert!((total - 1.0).abs() < 1e-4);
+
+            // The three box blurs approximate the requested deviation
+            assert!((variance.sqrt() - sigma).abs() < 0.5, "{sigma}: {variance}");
+
+            // The blur is symmetric
+            for (a, b) in row.iter().zip(&column) {
+                assert!((a[3] - b[3]).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn gaussian_blur_without_deviation_does_nothing() {
+        let mut pixels = vec![[0.0; 4], [1.0; 4], [0.5; 4]];
+        let original = pixels.clone();
+
+        gaussian_blur(&mut pixels, 3, 1, 0.0);
+        gaussian_blur(&mut pixels, 3, 1, -2.0);
+
+        assert_eq!(pixels, original);
+    }
+}
@@ -1,10 +1,12 @@
 use crate::Primitive;
 use crate::core::text::LineHeight;
 use crate::core::{self, Pixels, Point, Radians, Rectangle, Size, Svg, Vector};
+use crate::engine;
 use crate::graphics::cache::{self, Cached};
 use crate::graphics::geometry::fill::{self, Fill};
 use crate::graphics::geometry::stroke::{self, Stroke};
 use crate::graphics::geometry::{self, Path, Style};
+use crate::graphics::gradient;
 use crate::graphics::{self, Gradient, Image, Text};
 
 use std::sync::Arc;
@@ -109,6 +111,7 @@ impl geometry::frame::Backend for Frame {
             path,
             paint,
             rule: into_fill_rule(fill.rule),
+            conic: into_conic(fill.style, self.transform),
         });
     }
 
@@ -131,6 +134,7 @@ impl geometry::frame::Backend for Frame {
             path,
             paint,
             rule: into_fill_rule(fill.rule),
+            conic: into_conic(fill.style, self.transform),
         });
     }
 
@@ -149,6 +153,7 @@ impl geometry::frame::Backend for Frame {
             path,
             paint,
             stroke: skia_stroke,
+            conic: into_conic(stroke.style, self.transform),
         });
     }
 
@@ -206,6 +211,7 @@ impl geometry::frame::Backend for Frame {
                 align_x: text.align_x,
                 align_y: text.align_y,
                 shaping: text.shaping,
+                truncation: core::text::Truncation::None,
                 clip_bounds: Rectangle::with_size(Size::INFINITE),
             });
         } else {
@@ -399,44 +405,25 @@ pub fn into_paint(style: Style) -> tiny_skia::Paint<'static> {
                     .expect("Create color"),
             ),
             Style::Gradient(gradient) => match gradient {
-                Gradient::Linear(linear) => {
-                    let stops: Vec<tiny_skia::GradientStop> = linear
-                        .stops
-                        .into_iter()
-                        .flatten()
-                        .map(|stop| {
-                            tiny_skia::GradientStop::new(
-                                stop.offset,
-                                tiny_skia::Color::from_rgba(
-                                    stop.color.b,
-                                    stop.color.g,
-                                    stop.color.r,
-                                    stop.color.a,
-                                )
-                                .expect("Create color"),
-                            )
-                        })
-                        .collect();
-
-                    tiny_skia::LinearGradient::new(
-                        tiny_skia::Point {
-                            x: linear.start.x,
-                            y: linear.start.y,
-                        },
-                        tiny_skia::Point {
-                            x: linear.end.x,
-                            y: linear.end.y,
-                        },
-                        if stops.is_empty() {
-                            vec![tiny_skia::GradientStop::new(0.0, tiny_skia::Color::BLACK)]
-                        } else {
-                            stops
-                        },
-                        tiny_skia::SpreadMode::Pad,
-                        tiny_skia::Transform::identity(),
-                    )
-                    .expect("Create linear gradient")
+                Gradient::Linear(linear) => tiny_skia::LinearGradient::new(
+                    tiny_skia::Point {
+                        x: linear.start.x,
+                        y: linear.start.y,
+                    },
+                    tiny_skia::Point {
+                        x: linear.end.x,
+                        y: linear.end.y,
+                    },
+                    engine::into_gradient_stops(&linear.stops),
+                    tiny_skia::SpreadMode::Pad,
+                    tiny_skia::Transform::identity(),
+                )
+                .expect("Create linear gradient"),
+                Gradient::Radial(radial) => {
+                    engine::into_radial_gradient(radial.center, radial.radius, &radial.stops)
                 }
+                // Conic gradients are rasterized when drawing; see `into_conic`
+                Gradient::Conic(_) => tiny_skia::Shader::SolidColor(tiny_skia::Color::TRANSPARENT),
             },
         },
         anti_alias: true,
@@ -444,6 +431,26 @@ pub fn into_paint(style: Style) -> tiny_skia::Paint<'static> {
     }
 }
 
+/// Returns the conic gradient of the given [`Style`], if any, transformed
+/// into the space of its primitive.
+pub fn into_conic(style: Style, transform: tiny_skia::Transform) -> Option<gradient::Conic> {
+    let Style::Gradient(Gradient::Conic(mut conic)) = style else {
+        return None;
+    };
+
+    let mut center = tiny_skia::Point {
+        x: conic.center.x,
+        y: conic.center.y,
+    };
+
+    transform.map_point(&mut center);
+
+    conic.center = Point::new(center.x, center.y);
+    conic.angle += Radians(transform.ky.atan2(transform.sx));
+
+    Some(conic)
+}
+
 pub fn into_fill_rule(rule: fill::Rule) -> tiny_skia::FillRule {
     match rule {
         fill::Rule::EvenOdd => tiny_skia::FillRule::EvenOdd,
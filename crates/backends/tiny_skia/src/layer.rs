@@ -1,6 +1,6 @@
 use crate::Primitive;
-use crate::core::renderer::Quad;
-use crate::core::{self, Background, Color, Point, Rectangle, Svg, Transformation};
+use crate::core::renderer::{LayerStyle, Quad};
+use crate::core::{self, Background, Color, Point, Rectangle, Svg, Transformation, border};
 use crate::graphics::damage;
 use crate::graphics::layer;
 use crate::graphics::text::{Editor, Paragraph, Text};
@@ -13,6 +13,8 @@ pub type Stack = layer::Stack<Layer>;
 #[derive(Debug, Clone)]
 pub struct Layer {
     pub bounds: Rectangle,
+    pub backdrop: Option<Backdrop>,
+    pub style: Option<LayerStyle>,
     pub quads: Vec<(Quad, Background)>,
     pub primitives: Vec<Item<Primitive>>,
     pub images: Vec<Image>,
@@ -86,6 +88,7 @@ impl Layer {
             align_x: text.align_x,
             align_y: text.align_y,
             shaping: text.shaping,
+            truncation: text.truncation,
             clip_bounds: clip_bounds * transformation,
         };
 
@@ -202,7 +205,10 @@ impl Layer {
     }
 
     pub fn damage(previous: &Self, current: &Self) -> Vec<Rectangle> {
-        if previous.bounds != current.bounds {
+        if previous.bounds != current.bounds
+            || previous.backdrop != current.backdrop
+            || previous.style != current.style
+        {
             return vec![previous.bounds, current.bounds];
         }
 
@@ -293,6 +299,8 @@ impl Default for Layer {
     fn default() -> Self {
         Self {
             bounds: Rectangle::INFINITE,
+            backdrop: None,
+            style: None,
             quads: Vec::new(),
             primitives: Vec::new(),
             text: Vec::new(),
@@ -321,6 +329,8 @@ impl graphics::Layer for Layer {
 
     fn reset(&mut self) {
         self.bounds = Rectangle::INFINITE;
+        self.backdrop = None;
+        self.style = None;
 
         self.quads.clear();
         self.primitives.clear();
@@ -374,6 +384,77 @@ impl graphics::Layer for Layer {
         self.text.append(&mut layer.text);
         self.images.append(&mut layer.images);
     }
+
+    fn is_mergeable(&self) -> bool {
+        self.backdrop.is_none()
+    }
+}
+
+/// A backdrop that blurs and tints whatever was drawn behind a [`Layer`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Backdrop {
+    /// The blurred area of the [`Backdrop`].
+    pub bounds: Rectangle,
+
+    /// The border radius of the blurred area.
+    pub border_radius: border::Radius,
+
+    /// The blur radius of the [`Backdrop`].
+    pub blur_radius: f32,
+
+    /// The tint drawn over the blurred area.
+    pub tint: Color,
+}
+
+impl Backdrop {
+    /// Returns the area read by the [`Backdrop`] when blurring.
+    pub fn source_bounds(&self) -> Rectangle {
+        self.bounds.expand((self.blur_radius * 3.0).ceil().max(0.0))
+    }
+}
+
+/// Grows the given damage so every [`Backdrop`] it touches is repainted
+/// at once.
+///
+/// A [`Backdrop`] blurs whatever is drawn behind it, so any damage in its
+/// surroundings changes it completely. Merging that damage into a single
+/// region lets the [`Backdrop`] read an up-to-date background.
+pub fn backdrop_damage(layers: &[Layer], mut damage: Vec<Rectangle>) -> Vec<Rectangle> {
+    let sources: Vec<_> = layers
+        .iter()
+        .filter_map(|layer| {
+            let backdrop = layer.backdrop.as_ref()?;
+
+            backdrop
+                .bounds
+                .intersection(&layer.bounds)
+                .map(|_| backdrop.source_bounds())
+        })
+        .collect();
+
+    loop {
+        let mut changed = false;
+
+        for source in &sources {
+            let (touching, rest): (Vec<&Rectangle>, Vec<&Rectangle>) =
+                damage.iter().partition(|region| region.intersects(source));
+
+            let merged = touching
+                .iter()
+                .fold(*source, |merged, region| merged.union(region));
+
+            if touching.is_empty() || touching == [&merged] {
+                continue;
+            }
+
+            damage = rest.into_iter().copied().chain([merged]).collect();
+            changed = true;
+        }
+
+        if !changed {
+            return damage;
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -408,3 +489,82 @@ impl<T> Item<T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Size;
+
+    fn rectangle(x: f32, y: f32, width: f32, height: f32) -> Rectangle {
+        Rectangle::new(Point::new(x, y), Size::new(width, height))
+    }
+
+    fn layer(bounds: Rectangle, blur_radius: f32) -> Layer {
+        Layer {
+            bounds: Rectangle::new(Point::ORIGIN, Size::new(200.0, 200.0)),
+            backdrop: Some(Backdrop {
+                bounds,
+                border_radius: border::Radius::default(),
+                blur_radius,
+                tint: Color::TRANSPARENT,
+            }),
+            ..Layer::default()
+        }
+    }
+
+    #[test]
+    fn backdrop_source_covers_three_deviations() {
+        let backdrop = layer(rectangle(10.0, 10.0, 20.0, 20.0), 2.5)
+            .backdrop
+            .unwrap();
+
+        assert_eq!(backdrop.source_bounds(), rectangle(2.0, 2.0, 36.0, 36.0));
+    }
+
+    #[test]
+    fn backdrop_damage_merges_regions_touching_a_backdrop() {
+        let layers = [layer(rectangle(50.0, 50.0, 20.0, 20.0), 2.0)];
+
+        let far = rectangle(150.0, 150.0, 10.0, 10.0);
+        let near = rectangle(40.0, 60.0, 6.0, 6.0);
+        let inside = rectangle(55.0, 55.0, 2.0, 2.0);
+
+        let damage = backdrop_damage(&layers, vec![far, near, inside]);
+
+        assert_eq!(damage, [far, rectangle(40.0, 44.0, 36.0, 32.0)]);
+
+        // Damage away from any backdrop is left untouched
+        assert_eq!(backdrop_damage(&layers, vec![far]), [far]);
+        assert_eq!(backdrop_damage(&[], vec![near, inside]), [near, inside]);
+    }
+
+    #[test]
+    fn backdrop_damage_merges_overlapping_backdrops_transitively() {
+        let layers = [
+            layer(rectangle(0.0, 0.0, 20.0, 20.0), 0.0),
+            layer(rectangle(30.0, 0.0, 20.0, 20.0), 0.0),
+        ];
+
+        // The first merged source reaches the second backdrop
+        let damage = backdrop_damage(
+            &layers,
+            vec![
+                rectangle(15.0, 5.0, 20.0, 2.0),
+                rectangle(45.0, 15.0, 1.0, 1.0),
+            ],
+        );
+
+        assert_eq!(damage, [rectangle(0.0, 0.0, 50.0, 20.0)]);
+    }
+
+    #[test]
+    fn backdrops_out_of_their_layer_cause_no_damage() {
+        let mut layer = layer(rectangle(10.0, 10.0, 20.0, 20.0), 4.0);
+        layer.bounds = rectangle(100.0, 100.0, 10.0, 10.0);
+
+        let damage = vec![rectangle(12.0, 12.0, 2.0, 2.0)];
+
+        assert_eq!(backdrop_damage(&[layer], damage.clone()), damage);
+    }
+}
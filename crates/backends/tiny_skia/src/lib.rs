@@ -29,10 +29,14 @@ pub use settings::Settings;
 pub use geometry::Geometry;
 
 use crate::core::renderer;
-use crate::core::{Background, Color, Font, Pixels, Point, Rectangle, Size, Transformation};
+use crate::core::{
+    Backdrop, Background, Color, Font, Pixels, Point, Rectangle, Size, Transformation, Vector,
+    border,
+};
 use crate::engine::Engine;
 use crate::graphics::Viewport;
 use crate::graphics::compositor;
+use crate::graphics::layer::Group;
 use crate::graphics::text::{Editor, Paragraph};
 
 /// A [`tiny-skia`] graphics renderer for [`icy_ui`].
@@ -99,13 +103,58 @@ impl Renderer {
                 None,
             );
 
-            for layer in self.layers.iter() {
+            let mut groups = self.layers.groups().iter().peekable();
+            let mut open_groups: Vec<Offscreen> = Vec::new();
+
+            for (index, layer) in self.layers.iter().enumerate() {
+                // Groups are blended into their parent once all of their
+                // layers are drawn
+                while let Some(offscreen) = open_groups.last()
+                    && offscreen.group.end <= index
+                {
+                    let offscreen = open_groups.pop().expect("Pop layer group");
+
+                    draw_group(offscreen, &mut open_groups, pixels, clip_mask);
+                }
+
+                if let Some(group) = groups.next_if(|group| group.start == index) {
+                    let offscreen = Offscreen::new(
+                        *group,
+                        layer.bounds * scale_factor,
+                        damage_bounds,
+                        open_groups.last(),
+                    );
+
+                    open_groups.push(offscreen);
+                }
+
                 let Some(layer_bounds) = damage_bounds.intersection(&(layer.bounds * scale_factor))
                 else {
                     continue;
                 };
 
-                engine::adjust_clip_mask(clip_mask, layer_bounds);
+                let Some((mut target, behind)) = target(&mut open_groups, pixels, clip_mask) else {
+                    continue;
+                };
+
+                let pixels = &mut target.pixels;
+                let clip_mask = target.clip_mask;
+                let offset = Transformation::translate(-target.origin.x, -target.origin.y);
+                let clip_bounds = layer_bounds - target.origin;
+
+                engine::adjust_clip_mask(clip_mask, clip_bounds);
+
+                if let Some(backdrop) = &layer.backdrop {
+                    self.engine.draw_backdrop(
+                        backdrop,
+                        Transformation::scale(scale_factor),
+                        behind,
+                        pixels,
+                        target.origin,
+                        layer_bounds,
+                        damage_bounds,
+                    );
+                }
 
                 if !layer.quads.is_empty() {
                     let render_span = debug::render(debug::Primitive::Quad);
@@ -113,10 +162,10 @@ impl Renderer {
                         self.engine.draw_quad(
                             quad,
                             background,
-                            Transformation::scale(scale_factor),
+                            offset * Transformation::scale(scale_factor),
                             pixels,
                             clip_mask,
-                            layer_bounds,
+                            clip_bounds,
                         );
                     }
                     render_span.finish();
@@ -133,19 +182,23 @@ impl Renderer {
                             continue;
                         };
 
+                        let group_bounds = group_bounds - target.origin;
+
                         engine::adjust_clip_mask(clip_mask, group_bounds);
 
                         for primitive in group.as_slice() {
                             self.engine.draw_primitive(
                                 primitive,
-                                group.transformation() * Transformation::scale(scale_factor),
+                                offset
+                                    * group.transformation()
+                                    * Transformation::scale(scale_factor),
                                 pixels,
                                 clip_mask,
                                 group_bounds,
                             );
                         }
 
-                        engine::adjust_clip_mask(clip_mask, layer_bounds);
+                        engine::adjust_clip_mask(clip_mask, clip_bounds);
                     }
 
                     render_span.finish();
@@ -157,10 +210,10 @@ impl Renderer {
                     for image in &layer.images {
                         self.engine.draw_image(
                             image,
-                            Transformation::scale(scale_factor),
+                            offset * Transformation::scale(scale_factor),
                             pixels,
                             clip_mask,
-                            layer_bounds,
+                            clip_bounds,
                         );
                     }
 
@@ -174,10 +227,12 @@ impl Renderer {
                         for text in group.as_slice() {
                             self.engine.draw_text(
                                 text,
-                                group.transformation() * Transformation::scale(scale_factor),
+                                offset
+                                    * group.transformation()
+                                    * Transformation::scale(scale_factor),
                                 pixels,
                                 clip_mask,
-                                layer_bounds,
+                                clip_bounds,
                             );
                         }
                     }
@@ -185,17 +240,191 @@ impl Renderer {
                     render_span.finish();
                 }
             }
+
+            while let Some(offscreen) = open_groups.pop() {
+                draw_group(offscreen, &mut open_groups, pixels, clip_mask);
+            }
         }
 
         self.engine.trim();
     }
 }
 
+/// A [`Group`] being drawn offscreen.
+struct Offscreen {
+    group: Group,
+    /// The pixels of the [`Group`], or `None` if it is not damaged.
+    surface: Option<Surface>,
+}
+
+impl Offscreen {
+    /// Creates the [`Offscreen`] of a [`Group`] with the given physical
+    /// `bounds`, nested in the given `parent`, if any.
+    ///
+    /// Only the damaged bounds of the [`Group`] are drawn offscreen, so no
+    /// pixels are allocated if it is not damaged or its parent is not drawn.
+    fn new(
+        group: Group,
+        bounds: Rectangle,
+        damage_bounds: Rectangle,
+        parent: Option<&Offscreen>,
+    ) -> Self {
+        let surface = if parent.is_some_and(|parent| parent.surface.is_none()) {
+            None
+        } else {
+            damage_bounds
+                .intersection(&bounds)
+                .and_then(Rectangle::snap)
+                .and_then(Surface::new)
+        };
+
+        Self { group, surface }
+    }
+}
+
+/// The offscreen pixels of a [`Group`].
+///
+/// They only cover its damaged bounds, placed at `origin` in the window.
+struct Surface {
+    pixels: tiny_skia::Pixmap,
+    clip_mask: tiny_skia::Mask,
+    origin: Vector,
+}
+
+impl Surface {
+    fn new(bounds: Rectangle<u32>) -> Option<Self> {
+        Some(Self {
+            pixels: tiny_skia::Pixmap::new(bounds.width, bounds.height)?,
+            clip_mask: tiny_skia::Mask::new(bounds.width, bounds.height)?,
+            origin: Vector::new(bounds.x as f32, bounds.y as f32),
+        })
+    }
+}
+
+/// The pixels where some layers must be drawn, placed at `origin` in the
+/// window.
+struct Target<'a> {
+    pixels: tiny_skia::PixmapMut<'a>,
+    clip_mask: &'a mut tiny_skia::Mask,
+    origin: Vector,
+}
+
+/// Returns the [`Target`] of the innermost open group, or of the given
+/// `pixels` if no group is open, alongside the pixels right behind it (and
+/// their origin), if different.
+///
+/// Returns `None` if the innermost group is not damaged.
+#[allow(clippy::type_complexity)]
+fn target<'a>(
+    groups: &'a mut [Offscreen],
+    pixels: &'a mut tiny_skia::PixmapMut<'_>,
+    clip_mask: &'a mut tiny_skia::Mask,
+) -> Option<(Target<'a>, Option<(tiny_skia::PixmapRef<'a>, Vector)>)> {
+    let Some((innermost, parents)) = groups.split_last_mut() else {
+        let (width, height) = (pixels.width(), pixels.height());
+
+        return Some((
+            Target {
+                pixels: tiny_skia::PixmapMut::from_bytes(pixels.data_mut(), width, height)
+                    .expect("Borrow pixels"),
+                clip_mask,
+                origin: Vector::ZERO,
+            },
+            None,
+        ));
+    };
+
+    let surface = innermost.surface.as_mut()?;
+
+    let behind = match parents.last() {
+        Some(parent) => parent
+            .surface
+            .as_ref()
+            .map(|parent| (parent.pixels.as_ref(), parent.origin)),
+        None => Some((pixels.as_ref(), Vector::ZERO)),
+    };
+
+    Some((
+        Target {
+            pixels: surface.pixels.as_mut(),
+            clip_mask: &mut surface.clip_mask,
+            origin: surface.origin,
+        },
+        behind,
+    ))
+}
+
+/// Blends the pixels of a finished [`Offscreen`] group into its parent.
+fn draw_group(
+    offscreen: Offscreen,
+    groups: &mut [Offscreen],
+    pixels: &mut tiny_skia::PixmapMut<'_>,
+    clip_mask: &mut tiny_skia::Mask,
+) {
+    let Some(surface) = offscreen.surface else {
+        return;
+    };
+
+    let Some((mut target, _)) = target(groups, pixels, clip_mask) else {
+        return;
+    };
+
+    let offset = surface.origin - target.origin;
+    let bounds = Rectangle::new(
+        Point::ORIGIN + offset,
+        Size::new(
+            surface.pixels.width() as f32,
+            surface.pixels.height() as f32,
+        ),
+    );
+
+    engine::adjust_clip_mask(target.clip_mask, bounds);
+    engine::draw_group(
+        &surface.pixels,
+        offset,
+        offscreen.group.style,
+        &mut target.pixels,
+        target.clip_mask,
+    );
+}
+
 impl core::Renderer for Renderer {
     fn start_layer(&mut self, bounds: Rectangle) {
         self.layers.push_clip(bounds);
     }
 
+    fn start_backdrop_layer(
+        &mut self,
+        bounds: Rectangle,
+        area: Rectangle,
+        border_radius: border::Radius,
+        backdrop: Backdrop,
+    ) {
+        self.layers.push_clip(bounds);
+
+        let (layer, transformation) = self.layers.current_mut();
+        let scale = transformation.scale_factor();
+
+        layer.backdrop = Some(layer::Backdrop {
+            bounds: area * transformation,
+            border_radius: border_radius * scale,
+            blur_radius: backdrop.blur_radius * scale,
+            tint: backdrop.tint,
+        });
+    }
+
+    fn start_layer_with(&mut self, bounds: Rectangle, style: renderer::LayerStyle) {
+        if style.is_identity() {
+            self.layers.push_clip(bounds);
+            return;
+        }
+
+        self.layers.push_group(bounds, style);
+
+        let (layer, _) = self.layers.current_mut();
+        layer.style = Some(style);
+    }
+
     fn end_layer(&mut self) {
         self.layers.pop_clip();
     }
@@ -420,3 +649,114 @@ impl renderer::Headless for Renderer {
         window::compositor::screenshot(self, &viewport, background_color)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::renderer::LayerStyle;
+
+    fn group() -> Group {
+        Group {
+            start: 0,
+            end: 1,
+            style: LayerStyle::default(),
+        }
+    }
+
+    fn rectangle(x: f32, y: f32, width: f32, height: f32) -> Rectangle {
+        Rectangle::new(Point::new(x, y), Size::new(width, height))
+    }
+
+    fn surface_bounds(offscreen: &Offscreen) -> Option<(Vector, u32, u32)> {
+        offscreen.surface.as_ref().map(|surface| {
+            (
+                surface.origin,
+                surface.pixels.width(),
+                surface.pixels.height(),
+            )
+        })
+    }
+
+    #[test]
+    fn group_surface_covers_its_damaged_bounds() {
+        let bounds = rectangle(10.0, 20.0, 100.0, 50.0);
+
+        let whole = Offscreen::new(group(), bounds, rectangle(0.0, 0.0, 800.0, 600.0), None);
+        let damaged = Offscreen::new(group(), bounds, rectangle(50.0, 0.0, 20.0, 30.0), None);
+
+        assert_eq!(
+            surface_bounds(&whole),
+            Some((Vector::new(10.0, 20.0), 100, 50))
+        );
+        assert_eq!(
+            surface_bounds(&damaged),
+            Some((Vector::new(50.0, 20.0), 20, 10))
+        );
+    }
+
+    #[test]
+    fn group_surface_is_snapped_to_whole_pixels() {
+        let offscreen = Offscreen::new(
+            group(),
+            rectangle(10.4, 20.6, 30.2, 9.8),
+            rectangle(0.0, 0.0, 800.0, 600.0),
+            None,
+        );
+
+        assert_eq!(
+            surface_bounds(&offscreen),
+            Some((Vector::new(10.0, 21.0), 31, 9))
+        );
+    }
+
+    #[test]
+    fn undamaged_groups_have_no_surface() {
+        let bounds = rectangle(10.0, 20.0, 100.0, 50.0);
+        let damage = rectangle(0.0, 0.0, 800.0, 600.0);
+
+        let outside = Offscreen::new(group(), bounds, rectangle(200.0, 0.0, 10.0, 10.0), None);
+        let tiny = Offscreen::new(group(), bounds, rectangle(10.0, 20.0, 0.2, 0.2), None);
+
+        assert_eq!(surface_bounds(&outside), None);
+        assert_eq!(surface_bounds(&tiny), None);
+
+        // The children of an undamaged group are not drawn either
+        let child = Offscreen::new(group(), bounds, damage, Some(&outside));
+        let grandchild = Offscreen::new(group(), bounds, damage, Some(&child));
+
+        assert_eq!(surface_bounds(&child), None);
+        assert_eq!(surface_bounds(&grandchild), None);
+    }
+
+    #[test]
+    fn group_is_blended_at_its_origin() {
+        let mut window = tiny_skia::Pixmap::new(8, 8).unwrap();
+        let mut clip_mask = tiny_skia::Mask::new(8, 8).unwrap();
+
+        let mut offscreen = Offscreen::new(
+            group(),
+            rectangle(2.0, 3.0, 4.0, 2.0),
+            rectangle(0.0, 0.0, 8.0, 8.0),
+            None,
+        );
+
+        offscreen
+            .surface
+            .as_mut()
+            .unwrap()
+            .pixels
+            .fill(tiny_skia::Color::WHITE);
+
+        draw_group(offscreen, &mut [], &mut window.as_mut(), &mut clip_mask);
+
+        for y in 0..8 {
+            for x in 0..8 {
+                let inside = (2..6).contains(&x) && (3..5).contains(&y);
+                let alpha = window.pixel(x, y).unwrap().alpha();
+
+                assert_eq!(alpha, if inside { 255 } else { 0 }, "({x}, {y})");
+            }
+        }
+    }
+}
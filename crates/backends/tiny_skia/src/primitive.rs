@@ -1,4 +1,5 @@
 use crate::core::Rectangle;
+use crate::graphics::gradient;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Primitive {
@@ -10,6 +11,10 @@ pub enum Primitive {
         paint: tiny_skia::Paint<'static>,
         /// The fill rule to follow.
         rule: tiny_skia::FillRule,
+        /// The conic gradient replacing the shader of the paint, if any.
+        ///
+        /// tiny-skia has no conic shader, so it is rasterized when drawing.
+        conic: Option<gradient::Conic>,
     },
     /// A path stroked with some paint.
     Stroke {
@@ -19,6 +24,10 @@ pub enum Primitive {
         paint: tiny_skia::Paint<'static>,
         /// The stroke settings.
         stroke: tiny_skia::Stroke,
+        /// The conic gradient replacing the shader of the paint, if any.
+        ///
+        /// tiny-skia has no conic shader, so it is rasterized when drawing.
+        conic: Option<gradient::Conic>,
     },
 }
 
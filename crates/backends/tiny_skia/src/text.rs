@@ -1,5 +1,5 @@
 use crate::core::alignment;
-use crate::core::text::{Alignment, Shaping};
+use crate::core::text::{Alignment, Shaping, Truncation};
 use crate::core::{Color, Font, Pixels, Point, Rectangle, Transformation};
 use crate::graphics::text::cache::{self, Cache};
 use crate::graphics::text::editor;
@@ -101,6 +101,7 @@ impl Pipeline {
         align_x: Alignment,
         align_y: alignment::Vertical,
         shaping: Shaping,
+        truncation: Truncation,
         pixels: &mut tiny_skia::PixmapMut<'_>,
         clip_mask: Option<&tiny_skia::Mask>,
         transformation: Transformation,
@@ -118,6 +119,7 @@ impl Pipeline {
             line_height,
             shaping,
             align_x,
+            truncation,
         };
 
         let (_, entry) = self.cache.get_mut().allocate(font_system, key);
@@ -3,6 +3,7 @@ use crate::graphics::compositor::{self, Information};
 use crate::graphics::damage;
 use crate::graphics::error::{self, Error};
 use crate::graphics::{self, Shell, Viewport};
+use crate::layer;
 use crate::{Layer, Renderer, Settings};
 
 use std::collections::VecDeque;
@@ -174,7 +175,10 @@ pub fn present(
         surface.layer_stack.push_front(renderer.layers().to_vec());
         surface.background_color = background_color;
 
-        let damage = damage::group(damage, Rectangle::with_size(viewport.logical_size()));
+        let damage = layer::backdrop_damage(
+            renderer.layers(),
+            damage::group(damage, Rectangle::with_size(viewport.logical_size())),
+        );
 
         let mut pixels = tiny_skia::PixmapMut::from_bytes(
             bytemuck::cast_slice_mut(&mut buffer),
@@ -0,0 +1,821 @@
+//! Render effects that need to read back what was drawn before them.
+use crate::Buffer;
+use crate::core::renderer::{BlendMode, LayerStyle};
+use crate::core::{self, Color, Rectangle, Size, border};
+use crate::graphics::color;
+use crate::layer;
+
+use bytemuck::{Pod, Zeroable};
+
+use std::mem;
+
+/// The maximum amount of texels sampled on each side of a blurred texel.
+///
+/// Larger blurs skip texels to stay within this budget.
+const MAX_TAPS: f32 = 64.0;
+
+#[derive(Debug, Clone)]
+pub struct Pipeline {
+    format: wgpu::TextureFormat,
+    constant_layout: wgpu::BindGroupLayout,
+    texture_layout: wgpu::BindGroupLayout,
+    blur_x: wgpu::RenderPipeline,
+    blur_y: wgpu::RenderPipeline,
+    composite: wgpu::RenderPipeline,
+    blend: wgpu::RenderPipeline,
+    present: wgpu::RenderPipeline,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let constant_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("icy_ui_wgpu::effect uniforms layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: true,
+                    min_binding_size: wgpu::BufferSize::new(
+                        mem::size_of::<Constants>() as wgpu::BufferAddress
+                    ),
+                },
+                count: None,
+            }],
+        });
+
+        let texture_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("icy_ui_wgpu::effect texture layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            }],
+        });
+
+        let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("icy_ui_wgpu::effect pipeline layout"),
+            push_constant_ranges: &[],
+            bind_group_layouts: &[&constant_layout, &texture_layout],
+        });
+
+        let blend_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("icy_ui_wgpu::effect blend pipeline layout"),
+            push_constant_ranges: &[],
+            bind_group_layouts: &[&constant_layout, &texture_layout, &texture_layout],
+        });
+
+        let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("icy_ui_wgpu effect shader"),
+            source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(include_str!(
+                "shader/effect.wgsl"
+            ))),
+        });
+
+        let pipeline = |label, layout, entry_point, blend| {
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some(label),
+                layout: Some(layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: Some("vs_main"),
+                    buffers: &[],
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: Some(entry_point),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format,
+                        blend,
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                multiview: None,
+                cache: None,
+            })
+        };
+
+        Self {
+            format,
+            blur_x: pipeline(
+                "icy_ui_wgpu::effect blur_x pipeline",
+                &layout,
+                "fs_blur_x",
+                None,
+            ),
+            blur_y: pipeline(
+                "icy_ui_wgpu::effect blur_y pipeline",
+                &layout,
+                "fs_blur_y",
+                None,
+            ),
+            composite: pipeline(
+                "icy_ui_wgpu::effect composite pipeline",
+                &layout,
+                "fs_composite",
+                Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+            ),
+            blend: pipeline(
+                "icy_ui_wgpu::effect blend pipeline",
+                &blend_layout,
+                "fs_blend",
+                None,
+            ),
+            present: pipeline(
+                "icy_ui_wgpu::effect present pipeline",
+                &layout,
+                "fs_present",
+                Some(wgpu::BlendState::PREMULTIPLIED_ALPHA_BLENDING),
+            ),
+            constant_layout,
+            texture_layout,
+        }
+    }
+}
+
+/// The rendering state of the effects of a frame.
+///
+/// When a frame contains effects, it is rendered into an offscreen canvas
+/// so what was drawn behind each effect can be read back. Groups are
+/// rendered into their own offscreen targets and then blended into their
+/// parent target. The canvas is finally presented to the actual target.
+#[derive(Debug, Default)]
+pub struct State {
+    uniforms: Option<Uniforms>,
+    backdrops: Vec<Rectangle<u32>>,
+    groups: Vec<Option<Rectangle<u32>>>,
+    targets: Option<Targets>,
+    depth: usize,
+}
+
+impl State {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prepares the backdrops and groups of a frame, in rendering order and
+    /// physical pixels.
+    pub fn prepare(
+        &mut self,
+        pipeline: &Pipeline,
+        device: &wgpu::Device,
+        belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        backdrops: &[Backdrop],
+        groups: &[Group],
+        size: Size<u32>,
+    ) {
+        self.backdrops.clear();
+        self.groups.clear();
+        self.depth = 0;
+
+        if backdrops.is_empty() && groups.is_empty() {
+            return;
+        }
+
+        let depth = groups.iter().map(|group| group.depth).max().unwrap_or(0);
+
+        let mut targets = match self.targets.take() {
+            Some(targets) if targets.size == size => targets,
+            _ => Targets::new(device, pipeline, size),
+        };
+
+        targets.reserve(device, pipeline, depth);
+
+        let amount = backdrops.len() + groups.len();
+
+        let uniforms = self
+            .uniforms
+            .get_or_insert_with(|| Uniforms::new(device, pipeline, amount));
+
+        uniforms.resize(device, pipeline, amount);
+
+        let screen = Rectangle::with_size(Size::new(size.width as f32, size.height as f32));
+
+        let mut constants = Vec::with_capacity(amount);
+
+        for backdrop in backdrops {
+            let extent = (backdrop.backdrop.blur_radius * 3.0).ceil();
+
+            let source = backdrop
+                .clip_bounds
+                .expand(extent)
+                .intersection(&screen)
+                .and_then(Rectangle::snap)
+                .unwrap_or_default();
+
+            self.backdrops.push(source);
+            constants.push(Constants::backdrop(backdrop, source));
+        }
+
+        for group in groups {
+            let region = group.bounds.intersection(&screen).and_then(Rectangle::snap);
+
+            self.groups.push(region);
+            constants.push(Constants::group(group, region.unwrap_or_default()));
+        }
+
+        let _ = uniforms.buffer.write(device, encoder, belt, 0, &constants);
+
+        self.targets = Some(targets);
+    }
+
+    /// Returns the offscreen target where the frame must currently be
+    /// rendered, if the frame has any effects.
+    pub fn target(&self) -> Option<wgpu::TextureView> {
+        if self.backdrops.is_empty() && self.groups.is_empty() {
+            return None;
+        }
+
+        self.targets
+            .as_ref()
+            .map(|targets| targets.surface(self.depth).view.clone())
+    }
+
+    /// Starts rendering a new group.
+    ///
+    /// The group must be rendered into the new [`target`](Self::target),
+    /// which needs to be cleared first.
+    pub fn start_group(&mut self) {
+        self.depth += 1;
+    }
+
+    /// Ends the group with the given index, blending its contents into the
+    /// target of its parent.
+    ///
+    /// The targets must not be used by any active render pass.
+    pub fn end_group(
+        &mut self,
+        pipeline: &Pipeline,
+        encoder: &mut wgpu::CommandEncoder,
+        index: usize,
+    ) {
+        let depth = self.depth;
+        self.depth = depth.saturating_sub(1);
+
+        let (Some(targets), Some(uniforms), Some(Some(region))) =
+            (&self.targets, &self.uniforms, self.groups.get(index))
+        else {
+            return;
+        };
+
+        let group = targets.surface(depth);
+        let parent = targets.surface(self.depth);
+
+        copy(encoder, &parent.texture, &targets.front.texture, *region);
+
+        let offset = ((self.backdrops.len() + index) * mem::size_of::<Constants>()) as u32;
+
+        let mut render_pass = begin_render_pass(encoder, &parent.view, wgpu::LoadOp::Load);
+
+        render_pass.set_pipeline(&pipeline.blend);
+        render_pass.set_bind_group(0, &uniforms.bind_group, &[offset]);
+        render_pass.set_bind_group(1, &group.bind_group, &[]);
+        render_pass.set_bind_group(2, &targets.front.bind_group, &[]);
+        render_pass.set_scissor_rect(region.x, region.y, region.width, region.height);
+        render_pass.draw(0..6, 0..1);
+    }
+
+    /// Blurs and tints the contents of the current target behind the
+    /// backdrop with the given index.
+    ///
+    /// The current target must not be used by any active render pass.
+    pub fn render_backdrop(
+        &self,
+        pipeline: &Pipeline,
+        encoder: &mut wgpu::CommandEncoder,
+        index: usize,
+        clip_bounds: Rectangle<u32>,
+    ) {
+        let (Some(targets), Some(uniforms), Some(source)) =
+            (&self.targets, &self.uniforms, self.backdrops.get(index))
+        else {
+            return;
+        };
+
+        if source.width == 0 || source.height == 0 {
+            return;
+        }
+
+        let target = targets.surface(self.depth);
+        let offset = (index * mem::size_of::<Constants>()) as u32;
+
+        copy(encoder, &target.texture, &targets.front.texture, *source);
+
+        let passes = [
+            (&pipeline.blur_x, &targets.back, &targets.front),
+            (&pipeline.blur_y, &targets.front, &targets.back),
+        ];
+
+        for (blur, output, input) in passes {
+            let mut render_pass = begin_render_pass(encoder, &output.view, wgpu::LoadOp::Load);
+
+            render_pass.set_pipeline(blur);
+            render_pass.set_bind_group(0, &uniforms.bind_group, &[offset]);
+            render_pass.set_bind_group(1, &input.bind_group, &[]);
+            render_pass.set_viewport(
+                0.0,
+                0.0,
+                source.width as f32,
+                source.height as f32,
+                0.0,
+                1.0,
+            );
+            render_pass.set_scissor_rect(0, 0, source.width, source.height);
+            render_pass.draw(0..6, 0..1);
+        }
+
+        let mut render_pass = begin_render_pass(encoder, &target.view, wgpu::LoadOp::Load);
+
+        render_pass.set_pipeline(&pipeline.composite);
+        render_pass.set_bind_group(0, &uniforms.bind_group, &[offset]);
+        render_pass.set_bind_group(1, &targets.front.bind_group, &[]);
+        render_pass.set_scissor_rect(
+            clip_bounds.x,
+            clip_bounds.y,
+            clip_bounds.width,
+            clip_bounds.height,
+        );
+        render_pass.draw(0..6, 0..1);
+    }
+
+    /// Presents the canvas to the given `target`.
+    ///
+    /// If `clear` is `true`, the `target` is cleared first.
+    pub fn present(
+        &self,
+        pipeline: &Pipeline,
+        encoder: &mut wgpu::CommandEncoder,
+        target: &wgpu::TextureView,
+        clear: bool,
+    ) {
+        let (Some(targets), Some(uniforms)) = (&self.targets, &self.uniforms) else {
+            return;
+        };
+
+        let mut render_pass = begin_render_pass(
+            encoder,
+            target,
+            if clear {
+                wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT)
+            } else {
+                wgpu::LoadOp::Load
+            },
+        );
+
+        render_pass.set_pipeline(&pipeline.present);
+        render_pass.set_bind_group(0, &uniforms.bind_group, &[0]);
+        render_pass.set_bind_group(1, &targets.canvas.bind_group, &[]);
+        render_pass.draw(0..6, 0..1);
+    }
+}
+
+/// A backdrop to be rendered.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Backdrop {
+    /// The blurred area of the backdrop.
+    pub bounds: Rectangle,
+
+    /// The clipping bounds of the backdrop.
+    pub clip_bounds: Rectangle,
+
+    /// The border radius of the blurred area.
+    pub border_radius: border::Radius,
+
+    /// The [`core::Backdrop`] effect.
+    pub backdrop: core::Backdrop,
+}
+
+impl Backdrop {
+    /// Scales the [`Backdrop`] by the given factor.
+    pub fn scale(self, factor: f32) -> Self {
+        Self {
+            bounds: self.bounds * factor,
+            clip_bounds: self.clip_bounds * factor,
+            border_radius: self.border_radius * factor,
+            backdrop: core::Backdrop {
+                blur_radius: self.backdrop.blur_radius * factor,
+                ..self.backdrop
+            },
+        }
+    }
+}
+
+/// A group of layers to be blended together.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Group {
+    /// The bounds of the group, in physical pixels.
+    pub bounds: Rectangle,
+
+    /// The nesting depth of the group, starting at `1`.
+    pub depth: usize,
+
+    /// The [`LayerStyle`] of the group.
+    pub style: LayerStyle,
+}
+
+impl Group {
+    /// Lists the groups of the given [`layer::Stack`], in rendering order.
+    pub fn list(layers: &layer::Stack, scale_factor: f32) -> Vec<Self> {
+        let mut ends: Vec<usize> = Vec::new();
+
+        layers
+            .groups()
+            .iter()
+            .map(|group| {
+                while ends.last().is_some_and(|&end| end <= group.start) {
+                    let _ = ends.pop();
+                }
+
+                ends.push(group.end);
+
+                Self {
+                    bounds: layers.as_slice()[group.start].bounds * scale_factor,
+                    depth: ends.len(),
+                    style: group.style,
+                }
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+struct Uniforms {
+    buffer: Buffer<Constants>,
+    bind_group: wgpu::BindGroup,
+}
+
+impl Uniforms {
+    fn new(device: &wgpu::Device, pipeline: &Pipeline, amount: usize) -> Self {
+        let buffer = Buffer::new(
+            device,
+            "icy_ui_wgpu::effect uniforms buffer",
+            amount,
+            wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        );
+
+        let bind_group = Self::bind_group(device, pipeline, &buffer);
+
+        Self { buffer, bind_group }
+    }
+
+    fn resize(&mut self, device: &wgpu::Device, pipeline: &Pipeline, amount: usize) {
+        if self.buffer.resize(device, amount) {
+            self.bind_group = Self::bind_group(device, pipeline, &self.buffer);
+        }
+    }
+
+    fn bind_group(
+        device: &wgpu::Device,
+        pipeline: &Pipeline,
+        buffer: &Buffer<Constants>,
+    ) -> wgpu::BindGroup {
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("icy_ui_wgpu::effect uniforms bind group"),
+            layout: &pipeline.constant_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::Buffer(wgpu::BufferBinding {
+                    buffer: &buffer.raw,
+                    offset: 0,
+                    size: wgpu::BufferSize::new(mem::size_of::<Constants>() as u64),
+                }),
+            }],
+        })
+    }
+}
+
+/// The uniforms of a single effect.
+///
+/// Its size matches the maximum dynamic offset alignment allowed by `wgpu`,
+/// so effects can be stored contiguously and bound by index.
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+#[repr(C)]
+struct Constants {
+    bounds: [f32; 4],
+    border_radius: [f32; 4],
+    tint: color::Packed,
+    source: [f32; 4],
+    sigma: f32,
+    step: f32,
+    opacity: f32,
+    blend_mode: u32,
+    _reserved: [[f32; 4]; 11],
+}
+
+impl Constants {
+    fn backdrop(backdrop: &Backdrop, source: Rectangle<u32>) -> Self {
+        let sigma = backdrop.backdrop.blur_radius.max(0.0);
+
+        Self {
+            bounds: [
+                backdrop.bounds.x,
+                backdrop.bounds.y,
+                backdrop.bounds.width,
+                backdrop.bounds.height,
+            ],
+            border_radius: backdrop.border_radius.into(),
+            tint: color::pack(Color {
+                a: backdrop.backdrop.tint.a.clamp(0.0, 1.0),
+                ..backdrop.backdrop.tint
+            }),
+            source: source_of(source),
+            sigma,
+            step: ((sigma * 3.0) / MAX_TAPS).ceil().max(1.0),
+            ..Self::zeroed()
+        }
+    }
+
+    fn group(group: &Group, region: Rectangle<u32>) -> Self {
+        Self {
+            source: source_of(region),
+            opacity: group.style.opacity.clamp(0.0, 1.0),
+            blend_mode: match group.style.blend_mode {
+                BlendMode::Normal => 0,
+                BlendMode::Multiply => 1,
+                BlendMode::Screen => 2,
+                BlendMode::Overlay => 3,
+            },
+            ..Self::zeroed()
+        }
+    }
+}
+
+fn source_of(rectangle: Rectangle<u32>) -> [f32; 4] {
+    [
+        rectangle.x as f32,
+        rectangle.y as f32,
+        rectangle.width as f32,
+        rectangle.height as f32,
+    ]
+}
+
+#[derive(Debug)]
+struct Targets {
+    size: Size<u32>,
+    canvas: Surface,
+    groups: Vec<Surface>,
+    front: Surface,
+    back: Surface,
+}
+
+impl Targets {
+    fn new(device: &wgpu::Device, pipeline: &Pipeline, size: Size<u32>) -> Self {
+        Self {
+            size,
+            canvas: Surface::new(
+                device,
+                pipeline,
+                "icy_ui_wgpu::effect canvas",
+                size,
+                wgpu::TextureUsages::COPY_SRC,
+            ),
+            groups: Vec::new(),
+            front: Surface::new(
+                device,
+                pipeline,
+                "icy_ui_wgpu::effect front target",
+                size,
+                wgpu::TextureUsages::COPY_DST,
+            ),
+            back: Surface::new(
+                device,
+                pipeline,
+                "icy_ui_wgpu::effect back target",
+                size,
+                wgpu::TextureUsages::empty(),
+            ),
+        }
+    }
+
+    fn reserve(&mut self, device: &wgpu::Device, pipeline: &Pipeline, depth: usize) {
+        while self.groups.len() < depth {
+            self.groups.push(Surface::new(
+                device,
+                pipeline,
+                "icy_ui_wgpu::effect group target",
+                self.size,
+                wgpu::TextureUsages::COPY_SRC,
+            ));
+        }
+    }
+
+    fn surface(&self, depth: usize) -> &Surface {
+        match depth {
+            0 => &self.canvas,
+            depth => &self.groups[depth - 1],
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Surface {
+    texture: wgpu::Texture,
+    view: wgpu::TextureView,
+    bind_group: wgpu::BindGroup,
+}
+
+impl Surface {
+    fn new(
+        device: &wgpu::Device,
+        pipeline: &Pipeline,
+        label: &'static str,
+        size: Size<u32>,
+        usage: wgpu::TextureUsages,
+    ) -> Self {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some(label),
+            size: wgpu::Extent3d {
+                width: size.width.max(1),
+                height: size.height.max(1),
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: pipeline.format,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::TEXTURE_BINDING
+                | usage,
+            view_formats: &[],
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("icy_ui_wgpu::effect texture bind group"),
+            layout: &pipeline.texture_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&view),
+            }],
+        });
+
+        Self {
+            texture,
+            view,
+            bind_group,
+        }
+    }
+}
+
+/// Copies the given `region` of the `source` texture to the origin of the
+/// `destination` texture.
+fn copy(
+    encoder: &mut wgpu::CommandEncoder,
+    source: &wgpu::Texture,
+    destination: &wgpu::Texture,
+    region: Rectangle<u32>,
+) {
+    encoder.copy_texture_to_texture(
+        wgpu::TexelCopyTextureInfo {
+            texture: source,
+            mip_level: 0,
+            origin: wgpu::Origin3d {
+                x: region.x,
+                y: region.y,
+                z: 0,
+            },
+            aspect: wgpu::TextureAspect::All,
+        },
+        destination.as_image_copy(),
+        wgpu::Extent3d {
+            width: region.width,
+            height: region.height,
+            depth_or_array_layers: 1,
+        },
+    );
+}
+
+fn begin_render_pass<'a>(
+    encoder: &'a mut wgpu::CommandEncoder,
+    target: &wgpu::TextureView,
+    load: wgpu::LoadOp<wgpu::Color>,
+) -> wgpu::RenderPass<'a> {
+    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("icy_ui_wgpu::effect render pass"),
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+            view: target,
+            depth_slice: None,
+            resolve_target: None,
+            ops: wgpu::Operations {
+                load,
+                store: wgpu::StoreOp::Store,
+            },
+        })],
+        depth_stencil_attachment: None,
+        timestamp_writes: None,
+        occlusion_query_set: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::Point;
+
+    fn backdrop(blur_radius: f32) -> Backdrop {
+        Backdrop {
+            bounds: Rectangle::new(Point::new(10.0, 20.0), Size::new(30.0, 40.0)),
+            clip_bounds: Rectangle::new(Point::ORIGIN, Size::new(100.0, 100.0)),
+            border_radius: border::Radius::from(4.0),
+            backdrop: core::Backdrop {
+                blur_radius,
+                tint: Color {
+                    a: 2.0,
+                    ..Color::WHITE
+                },
+            },
+        }
+    }
+
+    #[test]
+    fn constants_fill_a_dynamic_offset() {
+        assert_eq!(mem::size_of::<Constants>(), 256);
+    }
+
+    #[test]
+    fn blur_skips_texels_beyond_the_tap_budget() {
+        let source = Rectangle {
+            x: 0,
+            y: 0,
+            width: 10,
+            height: 10,
+        };
+
+        for (blur_radius, sigma, step) in [
+            (-4.0, 0.0, 1.0),
+            (0.0, 0.0, 1.0),
+            (10.0, 10.0, 1.0),
+            (MAX_TAPS / 3.0, MAX_TAPS / 3.0, 1.0),
+            (30.0, 30.0, 2.0),
+            (100.0, 100.0, 5.0),
+        ] {
+            let constants = Constants::backdrop(&backdrop(blur_radius), source);
+
+            assert_eq!(constants.sigma, sigma);
+            assert_eq!(constants.step, step);
+
+            // Three standard deviations are always covered
+            assert!(constants.step * MAX_TAPS >= constants.sigma * 3.0);
+        }
+    }
+
+    #[test]
+    fn backdrop_scales_its_blur_radius() {
+        let scaled = backdrop(5.0).scale(2.0);
+
+        assert_eq!(
+            scaled.bounds,
+            Rectangle::new(Point::new(20.0, 40.0), Size::new(60.0, 80.0))
+        );
+        assert_eq!(scaled.border_radius, border::Radius::from(8.0));
+        assert_eq!(scaled.backdrop.blur_radius, 10.0);
+        assert_eq!(scaled.backdrop.tint, backdrop(5.0).backdrop.tint);
+    }
+
+    #[test]
+    fn groups_are_listed_with_their_depth() {
+        let mut layers = layer::Stack::new();
+        let bounds = |x| Rectangle::new(Point::new(x, 0.0), Size::new(10.0, 10.0));
+
+        layers.push_group(bounds(1.0), LayerStyle::opacity(0.5));
+        layers.push_group(bounds(2.0), LayerStyle::default());
+        layers.pop_clip();
+        layers.push_clip(bounds(3.0));
+        layers.pop_clip();
+        layers.pop_clip();
+        layers.push_group(bounds(4.0), LayerStyle::default());
+        layers.pop_clip();
+
+        let groups = Group::list(&layers, 2.0);
+
+        assert_eq!(
+            groups
+                .iter()
+                .map(|group| (group.bounds.x, group.depth))
+                .collect::<Vec<_>>(),
+            [(2.0, 1), (4.0, 2), (8.0, 1)]
+        );
+        assert_eq!(groups[0].style, LayerStyle::opacity(0.5));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::effect;
 use crate::graphics::{Antialiasing, Shell};
 use crate::primitive;
 use crate::quad;
@@ -13,6 +14,7 @@ pub struct Engine {
     pub(crate) format: wgpu::TextureFormat,
 
     pub(crate) quad_pipeline: quad::Pipeline,
+    pub(crate) effect_pipeline: effect::Pipeline,
     pub(crate) text_pipeline: text::Pipeline,
     pub(crate) triangle_pipeline: triangle::Pipeline,
     #[cfg(any(feature = "image", feature = "svg"))]
@@ -34,6 +36,7 @@ impl Engine {
             format,
 
             quad_pipeline: quad::Pipeline::new(&device, format),
+            effect_pipeline: effect::Pipeline::new(&device, format),
             text_pipeline: text::Pipeline::new(&device, &queue, format),
             triangle_pipeline: triangle::Pipeline::new(&device, format, antialiasing),
 
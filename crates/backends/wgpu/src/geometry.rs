@@ -312,6 +312,7 @@ impl geometry::frame::Backend for Frame {
                 align_x: text.align_x,
                 align_y: text.align_y,
                 shaping: text.shaping,
+                truncation: core::text::Truncation::None,
                 clip_bounds: self.clip_bounds,
             });
         } else {
@@ -565,6 +566,16 @@ impl Transform {
                 linear.start = self.transform_point(linear.start);
                 linear.end = self.transform_point(linear.end);
             }
+            Gradient::Radial(radial) => {
+                let (scale_x, scale_y) = self.scale();
+
+                radial.center = self.transform_point(radial.center);
+                radial.radius *= (scale_x * scale_y).abs().sqrt();
+            }
+            Gradient::Conic(conic) => {
+                conic.center = self.transform_point(conic.center);
+                conic.angle += Radians(self.0.m12.atan2(self.0.m11));
+            }
         }
 
         gradient
@@ -1,4 +1,5 @@
 use crate::core::{self, Background, Color, Point, Rectangle, Svg, Transformation, renderer};
+use crate::effect;
 use crate::graphics;
 use crate::graphics::Mesh;
 use crate::graphics::color;
@@ -16,6 +17,7 @@ pub type Stack = layer::Stack<Layer>;
 #[derive(Debug)]
 pub struct Layer {
     pub bounds: Rectangle,
+    pub backdrop: Option<effect::Backdrop>,
     pub quads: quad::Batch,
     pub triangles: triangle::Batch,
     pub primitives: primitive::Batch,
@@ -27,7 +29,8 @@ pub struct Layer {
 
 impl Layer {
     pub fn is_empty(&self) -> bool {
-        self.quads.is_empty()
+        self.backdrop.is_none()
+            && self.quads.is_empty()
             && self.triangles.is_empty()
             && self.primitives.is_empty()
             && self.images.is_empty()
@@ -43,6 +46,7 @@ impl Layer {
         transformation: Transformation,
     ) {
         let bounds = quad.bounds * transformation;
+        let shadow = quad.shadow;
 
         let quad = Quad {
             position: [bounds.x, bounds.y],
@@ -50,13 +54,42 @@ impl Layer {
             border_color: color::pack(quad.border.color),
             border_radius: (quad.border.radius * transformation.scale_factor()).into(),
             border_width: quad.border.width * transformation.scale_factor(),
-            shadow_color: color::pack(quad.shadow.color),
-            shadow_offset: (quad.shadow.offset * transformation.scale_factor()).into(),
-            shadow_blur_radius: quad.shadow.blur_radius * transformation.scale_factor(),
+            shadow_color: color::pack(shadow.color),
+            shadow_offset: (shadow.offset * transformation.scale_factor()).into(),
+            shadow_blur_radius: shadow.blur_radius * transformation.scale_factor(),
+            shadow_spread: shadow.spread * transformation.scale_factor(),
+            shadow_inset: shadow.inset as u32,
             snap: quad.snap as u32,
         };
 
-        self.quads.add(quad, &background);
+        match background {
+            // The gradient pipeline does not draw shadows, so we draw them
+            // with a transparent solid quad below (or above, if inset)
+            Background::Gradient(_) if shadow.color.a > 0.0 => {
+                let shadow_quad = Quad {
+                    border_color: color::pack(Color::TRANSPARENT),
+                    ..quad
+                };
+
+                let background_quad = Quad {
+                    shadow_color: color::pack(Color::TRANSPARENT),
+                    ..quad
+                };
+
+                let transparent = Background::Color(Color::TRANSPARENT);
+
+                if shadow.inset {
+                    self.quads.add(background_quad, &background);
+                    self.quads.add(shadow_quad, &transparent);
+                } else {
+                    self.quads.add(shadow_quad, &transparent);
+                    self.quads.add(background_quad, &background);
+                }
+            }
+            _ => {
+                self.quads.add(quad, &background);
+            }
+        }
     }
 
     pub fn draw_paragraph(
@@ -115,6 +148,7 @@ impl Layer {
             align_x: text.align_x,
             align_y: text.align_y,
             shaping: text.shaping,
+            truncation: text.truncation,
             clip_bounds: clip_bounds * transformation,
         };
 
@@ -291,6 +325,7 @@ impl graphics::Layer for Layer {
 
     fn reset(&mut self) {
         self.bounds = Rectangle::INFINITE;
+        self.backdrop = None;
 
         self.quads.clear();
         self.triangles.clear();
@@ -356,12 +391,17 @@ impl graphics::Layer for Layer {
         self.images.append(&mut layer.images);
         self.text.append(&mut layer.text);
     }
+
+    fn is_mergeable(&self) -> bool {
+        self.backdrop.is_none()
+    }
 }
 
 impl Default for Layer {
     fn default() -> Self {
         Self {
             bounds: Rectangle::INFINITE,
+            backdrop: None,
             quads: quad::Batch::default(),
             triangles: triangle::Batch::default(),
             primitives: primitive::Batch::default(),
@@ -32,6 +32,7 @@ pub mod geometry;
 
 mod buffer;
 mod color;
+mod effect;
 mod engine;
 mod quad;
 mod text;
@@ -62,7 +63,9 @@ pub use settings::Settings;
 pub use geometry::Geometry;
 
 use crate::core::renderer;
-use crate::core::{Background, Color, Font, Pixels, Point, Rectangle, Size, Transformation};
+use crate::core::{
+    Backdrop, Background, Color, Font, Pixels, Point, Rectangle, Size, Transformation, border,
+};
 use crate::graphics::mesh;
 use crate::graphics::text::{Editor, Paragraph};
 use crate::graphics::{Shell, Viewport};
@@ -80,6 +83,7 @@ pub struct Renderer {
     scale_factor: Option<f32>,
 
     quad: quad::State,
+    effect: effect::State,
     triangle: triangle::State,
     text: text::State,
     text_viewport: text::Viewport,
@@ -103,6 +107,7 @@ impl Renderer {
             scale_factor: None,
 
             quad: quad::State::new(),
+            effect: effect::State::new(),
             triangle: triangle::State::new(&engine.device, &engine.triangle_pipeline),
             text: text::State::new(),
             text_viewport: engine.text_pipeline.create_viewport(&engine.device),
@@ -287,6 +292,8 @@ impl Renderer {
 
         self.layers.merge();
 
+        let mut backdrops = Vec::new();
+
         for layer in self.layers.iter() {
             let clip_bounds = layer.bounds * scale_factor;
 
@@ -298,6 +305,10 @@ impl Renderer {
                 continue;
             }
 
+            if let Some(backdrop) = layer.backdrop {
+                backdrops.push(backdrop.scale(scale_factor));
+            }
+
             if !layer.quads.is_empty() {
                 let prepare_span = debug::prepare(debug::Primitive::Quad);
 
@@ -388,6 +399,16 @@ impl Renderer {
                 prepare_span.finish();
             }
         }
+
+        self.effect.prepare(
+            &self.engine.effect_pipeline,
+            &self.engine.device,
+            &mut self.staging_belt,
+            encoder,
+            &backdrops,
+            &effect::Group::list(&self.layers, scale_factor),
+            viewport.physical_size(),
+        );
     }
 
     fn render(
@@ -399,37 +420,33 @@ impl Renderer {
     ) {
         use std::mem::ManuallyDrop;
 
-        let mut render_pass =
-            ManuallyDrop::new(encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                label: Some("icy_ui_wgpu render pass"),
-                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                    view: frame,
-                    depth_slice: None,
-                    resolve_target: None,
-                    ops: wgpu::Operations {
-                        load: match clear_color {
-                            Some(background_color) => wgpu::LoadOp::Clear({
-                                let [r, g, b, a] =
-                                    graphics::color::pack(background_color).components();
-
-                                wgpu::Color {
-                                    r: f64::from(r),
-                                    g: f64::from(g),
-                                    b: f64::from(b),
-                                    a: f64::from(a),
-                                }
-                            }),
-                            None => wgpu::LoadOp::Load,
-                        },
-                        store: wgpu::StoreOp::Store,
-                    },
-                })],
-                depth_stencil_attachment: None,
-                timestamp_writes: None,
-                occlusion_query_set: None,
-            }));
+        // Frames with effects are rendered into an offscreen canvas, so
+        // effects can read what was drawn behind them
+        let canvas = self.effect.target();
+        let mut target = canvas.clone().unwrap_or_else(|| frame.clone());
+
+        let mut render_pass = ManuallyDrop::new(begin_render_pass(
+            encoder,
+            &target,
+            match clear_color {
+                Some(background_color) => wgpu::LoadOp::Clear({
+                    let [r, g, b, a] = graphics::color::pack(background_color).components();
+
+                    wgpu::Color {
+                        r: f64::from(r),
+                        g: f64::from(g),
+                        b: f64::from(b),
+                        a: f64::from(a),
+                    }
+                }),
+                None if canvas.is_some() => wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                None => wgpu::LoadOp::Load,
+            },
+        ));
 
         let mut quad_layer = 0;
+        let mut backdrop_layer = 0;
+        let mut group_layer = 0;
         let mut mesh_layer = 0;
         let mut text_layer = 0;
 
@@ -442,7 +459,46 @@ impl Renderer {
 
         let scale = Transformation::scale(scale_factor);
 
-        for layer in self.layers.iter() {
+        let groups = self.layers.groups();
+        let mut open_groups: Vec<(usize, usize)> = Vec::new();
+
+        for (index, layer) in self.layers.iter().enumerate() {
+            // Groups are blended into their parent once all of their layers
+            // are rendered
+            if open_groups.last().is_some_and(|&(_, end)| end <= index) {
+                let _ = ManuallyDrop::into_inner(render_pass);
+
+                while let Some(&(group, end)) = open_groups.last()
+                    && end <= index
+                {
+                    self.effect
+                        .end_group(&self.engine.effect_pipeline, encoder, group);
+
+                    let _ = open_groups.pop();
+                }
+
+                target = self.effect.target().unwrap_or_else(|| frame.clone());
+                render_pass =
+                    ManuallyDrop::new(begin_render_pass(encoder, &target, wgpu::LoadOp::Load));
+            }
+
+            if let Some(group) = groups.get(group_layer)
+                && group.start == index
+            {
+                let _ = ManuallyDrop::into_inner(render_pass);
+
+                self.effect.start_group();
+                open_groups.push((group_layer, group.end));
+                group_layer += 1;
+
+                target = self.effect.target().unwrap_or_else(|| frame.clone());
+                render_pass = ManuallyDrop::new(begin_render_pass(
+                    encoder,
+                    &target,
+                    wgpu::LoadOp::Clear(wgpu::Color::TRANSPARENT),
+                ));
+            }
+
             let Some(physical_bounds) =
                 physical_bounds.intersection(&(layer.bounds * scale_factor))
             else {
@@ -453,6 +509,22 @@ impl Renderer {
                 continue;
             };
 
+            if layer.backdrop.is_some() {
+                let _ = ManuallyDrop::into_inner(render_pass);
+
+                self.effect.render_backdrop(
+                    &self.engine.effect_pipeline,
+                    encoder,
+                    backdrop_layer,
+                    scissor_rect,
+                );
+
+                backdrop_layer += 1;
+
+                render_pass =
+                    ManuallyDrop::new(begin_render_pass(encoder, &target, wgpu::LoadOp::Load));
+            }
+
             if !layer.quads.is_empty() {
                 let render_span = debug::render(debug::Primitive::Quad);
                 self.quad.render(
@@ -474,7 +546,7 @@ impl Renderer {
                 mesh_layer += self.triangle.render(
                     &self.engine.triangle_pipeline,
                     encoder,
-                    frame,
+                    &target,
                     mesh_layer,
                     &layer.triangles,
                     physical_bounds,
@@ -483,21 +555,7 @@ impl Renderer {
                 render_span.finish();
 
                 render_pass =
-                    ManuallyDrop::new(encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                        label: Some("icy_ui_wgpu render pass"),
-                        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                            view: frame,
-                            depth_slice: None,
-                            resolve_target: None,
-                            ops: wgpu::Operations {
-                                load: wgpu::LoadOp::Load,
-                                store: wgpu::StoreOp::Store,
-                            },
-                        })],
-                        depth_stencil_attachment: None,
-                        timestamp_writes: None,
-                        occlusion_query_set: None,
-                    }));
+                    ManuallyDrop::new(begin_render_pass(encoder, &target, wgpu::LoadOp::Load));
             }
 
             if !layer.primitives.is_empty() {
@@ -564,27 +622,16 @@ impl Renderer {
                     let _ = ManuallyDrop::into_inner(render_pass);
 
                     for (instance, clip_bounds) in need_render {
-                        instance
-                            .primitive
-                            .render(&primitive_storage, encoder, frame, &clip_bounds);
+                        instance.primitive.render(
+                            &primitive_storage,
+                            encoder,
+                            &target,
+                            &clip_bounds,
+                        );
                     }
 
                     render_pass =
-                        ManuallyDrop::new(encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                            label: Some("icy_ui_wgpu render pass"),
-                            color_attachments: &[Some(wgpu::RenderPassColorAttachment {
-                                view: frame,
-                                depth_slice: None,
-                                resolve_target: None,
-                                ops: wgpu::Operations {
-                                    load: wgpu::LoadOp::Load,
-                                    store: wgpu::StoreOp::Store,
-                                },
-                            })],
-                            depth_stencil_attachment: None,
-                            timestamp_writes: None,
-                            occlusion_query_set: None,
-                        }));
+                        ManuallyDrop::new(begin_render_pass(encoder, &target, wgpu::LoadOp::Load));
                 }
 
                 render_span.finish();
@@ -620,6 +667,20 @@ impl Renderer {
 
         let _ = ManuallyDrop::into_inner(render_pass);
 
+        while let Some((group, _)) = open_groups.pop() {
+            self.effect
+                .end_group(&self.engine.effect_pipeline, encoder, group);
+        }
+
+        if canvas.is_some() {
+            self.effect.present(
+                &self.engine.effect_pipeline,
+                encoder,
+                frame,
+                clear_color.is_some(),
+            );
+        }
+
         debug::layers_rendered(|| {
             self.layers
                 .iter()
@@ -634,11 +695,64 @@ impl Renderer {
     }
 }
 
+fn begin_render_pass<'a>(
+    encoder: &'a mut wgpu::CommandEncoder,
+    target: &wgpu::TextureView,
+    load: wgpu::LoadOp<wgpu::Color>,
+) -> wgpu::RenderPass<'a> {
+    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+        label: Some("icy_ui_wgpu render pass"),
+        color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+            view: target,
+            depth_slice: None,
+            resolve_target: None,
+            ops: wgpu::Operations {
+                load,
+                store: wgpu::StoreOp::Store,
+            },
+        })],
+        depth_stencil_attachment: None,
+        timestamp_writes: None,
+        occlusion_query_set: None,
+    })
+}
+
 impl core::Renderer for Renderer {
     fn start_layer(&mut self, bounds: Rectangle) {
         self.layers.push_clip(bounds);
     }
 
+    fn start_backdrop_layer(
+        &mut self,
+        bounds: Rectangle,
+        area: Rectangle,
+        border_radius: border::Radius,
+        backdrop: Backdrop,
+    ) {
+        self.layers.push_clip(bounds);
+
+        let (layer, transformation) = self.layers.current_mut();
+        let scale = transformation.scale_factor();
+
+        layer.backdrop = Some(effect::Backdrop {
+            bounds: area * transformation,
+            clip_bounds: layer.bounds,
+            border_radius: border_radius * scale,
+            backdrop: Backdrop {
+                blur_radius: backdrop.blur_radius * scale,
+                ..backdrop
+            },
+        });
+    }
+
+    fn start_layer_with(&mut self, bounds: Rectangle, style: renderer::LayerStyle) {
+        if style.is_identity() {
+            self.layers.push_clip(bounds);
+        } else {
+            self.layers.push_group(bounds, style);
+        }
+    }
+
     fn end_layer(&mut self) {
         self.layers.pop_clip();
     }
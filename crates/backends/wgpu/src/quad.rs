@@ -42,6 +42,12 @@ pub struct Quad {
     /// The shadow blur radius of the [`Quad`].
     pub shadow_blur_radius: f32,
 
+    /// The shadow spread radius of the [`Quad`].
+    pub shadow_spread: f32,
+
+    /// Whether the shadow of the [`Quad`] is drawn inside of it.
+    pub shadow_inset: u32,
+
     /// Whether the [`Quad`] should be snapped to the pixel grid.
     pub snap: u32,
 }
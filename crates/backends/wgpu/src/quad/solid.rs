@@ -109,6 +109,10 @@ impl Pipeline {
                         7 => Float32x2,
                         // Shadow blur radius
                         8 => Float32,
+                        // Shadow spread
+                        10 => Float32,
+                        // Shadow inset
+                        11 => Uint32,
                         // Snap
                         9 => Uint32,
                     ),
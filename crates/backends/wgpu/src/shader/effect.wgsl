@@ -0,0 +1,151 @@
+struct Effect {
+    bounds: vec4<f32>,
+    border_radius: vec4<f32>,
+    tint: vec4<f32>,
+    source: vec4<f32>,
+    sigma: f32,
+    step: f32,
+    opacity: f32,
+    blend_mode: u32,
+}
+
+var<private> positions: array<vec2<f32>, 6> = array<vec2<f32>, 6>(
+    vec2<f32>(0.0, 0.0),
+    vec2<f32>(1.0, 0.0),
+    vec2<f32>(1.0, 1.0),
+    vec2<f32>(0.0, 0.0),
+    vec2<f32>(0.0, 1.0),
+    vec2<f32>(1.0, 1.0)
+);
+
+@group(0) @binding(0) var<uniform> effect: Effect;
+@group(1) @binding(0) var u_texture: texture_2d<f32>;
+@group(2) @binding(0) var u_destination: texture_2d<f32>;
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+    let position = positions[vertex_index];
+
+    return vec4<f32>(position * vec2(2.0, -2.0) + vec2(-1.0, 1.0), 0.0, 1.0);
+}
+
+fn load(position: vec2<f32>) -> vec4<f32> {
+    let last = vec2<i32>(effect.source.zw) - vec2<i32>(1, 1);
+    let texel = clamp(vec2<i32>(floor(position)), vec2<i32>(0, 0), last);
+
+    return textureLoad(u_texture, texel, 0);
+}
+
+fn blur(position: vec2<f32>, direction: vec2<f32>) -> vec4<f32> {
+    if effect.sigma <= 0.0 {
+        return load(position);
+    }
+
+    let extent = ceil(effect.sigma * 3.0);
+
+    var color = vec4<f32>(0.0);
+    var total = 0.0;
+    var offset = -extent;
+
+    loop {
+        if offset > extent {
+            break;
+        }
+
+        let weight = exp(-(offset * offset) / (2.0 * effect.sigma * effect.sigma));
+
+        color += load(position + direction * offset) * weight;
+        total += weight;
+        offset += effect.step;
+    }
+
+    return color / total;
+}
+
+@fragment
+fn fs_blur_x(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    return blur(position.xy, vec2<f32>(1.0, 0.0));
+}
+
+@fragment
+fn fs_blur_y(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    return blur(position.xy, vec2<f32>(0.0, 1.0));
+}
+
+fn rounded_box_sdf(p: vec2<f32>, size: vec2<f32>, corners: vec4<f32>) -> f32 {
+    var box_half = select(corners.yz, corners.xw, p.x > 0.0);
+    var corner = select(box_half.y, box_half.x, p.y > 0.0);
+    var q = abs(p) - size + corner;
+    return min(max(q.x, q.y), 0.0) + length(max(q, vec2(0.0))) - corner;
+}
+
+@fragment
+fn fs_composite(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let blurred = load(position.xy - effect.source.xy);
+
+    // The tint is drawn over the blurred backdrop
+    let tint = vec4<f32>(effect.tint.rgb * effect.tint.a, effect.tint.a);
+    let color = tint + blurred * (1.0 - tint.a);
+
+    let dist = rounded_box_sdf(
+        -(position.xy - effect.bounds.xy - effect.bounds.zw * 0.5) * 2.0,
+        effect.bounds.zw,
+        effect.border_radius * 2.0
+    ) / 2.0;
+
+    return color * clamp(0.5 - dist, 0.0, 1.0);
+}
+
+@fragment
+fn fs_present(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    return textureLoad(u_texture, vec2<i32>(floor(position.xy)), 0);
+}
+
+fn unpremultiply(color: vec4<f32>) -> vec3<f32> {
+    if color.a <= 0.0 {
+        return vec3<f32>(0.0);
+    }
+
+    return color.rgb / color.a;
+}
+
+fn blend(destination: vec3<f32>, source: vec3<f32>) -> vec3<f32> {
+    switch effect.blend_mode {
+        // Multiply
+        case 1u: {
+            return destination * source;
+        }
+        // Screen
+        case 2u: {
+            return destination + source - destination * source;
+        }
+        // Overlay
+        case 3u: {
+            let multiply = 2.0 * destination * source;
+            let screen = 1.0 - 2.0 * (1.0 - destination) * (1.0 - source);
+
+            return select(screen, multiply, destination <= vec3<f32>(0.5));
+        }
+        default: {
+            return source;
+        }
+    }
+}
+
+@fragment
+fn fs_blend(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let texel = vec2<i32>(floor(position.xy));
+
+    let source = textureLoad(u_texture, texel, 0) * effect.opacity;
+    let destination = textureLoad(u_destination, texel - vec2<i32>(effect.source.xy), 0);
+
+    // Separable blending of premultiplied colors, as specified by the
+    // W3C Compositing and Blending spec
+    let mixed = blend(unpremultiply(destination), unpremultiply(source));
+
+    let color = source.rgb * (1.0 - destination.a)
+        + destination.rgb * (1.0 - source.a)
+        + mixed * source.a * destination.a;
+
+    return vec4<f32>(color, source.a + destination.a * (1.0 - source.a));
+}
@@ -6,6 +6,7 @@ struct GradientVertexInput {
     @location(3) @interpolate(flat) colors_4: vec4<u32>,
     @location(4) @interpolate(flat) offsets: vec4<u32>,
     @location(5) direction: vec4<f32>,
+    @location(11) @interpolate(flat) kind: u32,
     @location(6) position_and_scale: vec4<f32>,
     @location(7) border_color: vec4<f32>,
     @location(8) border_radius: vec4<f32>,
@@ -25,6 +26,7 @@ struct GradientVertexOutput {
     @location(8) border_color: vec4<f32>,
     @location(9) border_radius: vec4<f32>,
     @location(10) border_width: f32,
+    @location(11) @interpolate(flat) kind: u32,
 }
 
 @vertex
@@ -64,6 +66,12 @@ fn gradient_vs_main(input: GradientVertexInput) -> GradientVertexOutput {
     out.colors_4 = input.colors_4;
     out.offsets = input.offsets;
     out.direction = input.direction * globals.scale;
+    out.kind = input.kind;
+
+    // The starting angle of a conic gradient is not a distance
+    if input.kind == 2u {
+        out.direction.z = input.direction.z;
+    }
     out.position_and_scale = vec4<f32>(pos + pos_snap, scale + scale_snap);
     out.border_color = premultiply(input.border_color);
     out.border_radius = border_radius * globals.scale;
@@ -80,17 +88,34 @@ fn random(coords: vec2<f32>) -> f32 {
 fn gradient(
     raw_position: vec2<f32>,
     direction: vec4<f32>,
+    kind: u32,
     colors: array<vec4<f32>, 8>,
     offsets: array<f32, 8>,
     last_index: i32
 ) -> vec4<f32> {
-    let start = direction.xy;
-    let end = direction.zw;
+    var coord_offset: f32;
 
-    let v1 = end - start;
-    let v2 = raw_position - start;
-    let unit = normalize(v1);
-    let coord_offset = dot(unit, v2) / length(v1);
+    switch kind {
+        // Radial: center and radius
+        case 1u: {
+            coord_offset = length(raw_position - direction.xy) / direction.z;
+        }
+        // Conic: center and starting angle, clockwise from the top
+        case 2u: {
+            let v = raw_position - direction.xy;
+            coord_offset = fract((atan2(v.x, -v.y) - direction.z) / 6.283185307179586);
+        }
+        // Linear: start and end
+        default: {
+            let start = direction.xy;
+            let end = direction.zw;
+
+            let v1 = end - start;
+            let v2 = raw_position - start;
+            let unit = normalize(v1);
+            coord_offset = dot(unit, v2) / length(v1);
+        }
+    }
 
     //need to store these as a var to use dynamic indexing in a loop
     //this is already added to wgsl spec but not in wgpu yet
@@ -161,7 +186,7 @@ fn gradient_fs_main(input: GradientVertexOutput) -> @location(0) vec4<f32> {
         }
     }
 
-    var mixed_color: vec4<f32> = gradient(input.position.xy, input.direction, colors, offsets, last_index);
+    var mixed_color: vec4<f32> = gradient(input.position.xy, input.direction, input.kind, colors, offsets, last_index);
 
     let pos = input.position_and_scale.xy;
     let scale = input.position_and_scale.zw;
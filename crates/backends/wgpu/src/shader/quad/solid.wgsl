@@ -10,6 +10,8 @@ struct SolidVertexInput {
     @location(7) shadow_offset: vec2<f32>,
     @location(8) shadow_blur_radius: f32,
     @location(9) snap: u32,
+    @location(10) shadow_spread: f32,
+    @location(11) shadow_inset: u32,
 }
 
 struct SolidVertexOutput {
@@ -23,14 +25,24 @@ struct SolidVertexOutput {
     @location(6) shadow_color: vec4<f32>,
     @location(7) shadow_offset: vec2<f32>,
     @location(8) shadow_blur_radius: f32,
+    @location(9) shadow_spread: f32,
+    @location(10) @interpolate(flat) shadow_inset: u32,
 }
 
 @vertex
 fn solid_vs_main(input: SolidVertexInput) -> SolidVertexOutput {
     var out: SolidVertexOutput;
 
-    var pos: vec2<f32> = (input.pos + min(input.shadow_offset, vec2<f32>(0.0, 0.0)) - input.shadow_blur_radius) * globals.scale;
-    var scale: vec2<f32> = (input.scale + vec2<f32>(abs(input.shadow_offset.x), abs(input.shadow_offset.y)) + input.shadow_blur_radius * 2.0) * globals.scale;
+    var pos: vec2<f32> = input.pos * globals.scale;
+    var scale: vec2<f32> = input.scale * globals.scale;
+
+    // Outer shadows are drawn around the quad, so we need to grow it
+    if input.shadow_inset == 0u {
+        let extent = max(input.shadow_blur_radius + input.shadow_spread, 0.0);
+
+        pos = (input.pos + min(input.shadow_offset, vec2<f32>(0.0, 0.0)) - extent) * globals.scale;
+        scale = (input.scale + vec2<f32>(abs(input.shadow_offset.x), abs(input.shadow_offset.y)) + extent * 2.0) * globals.scale;
+    }
 
     var pos_snap = vec2<f32>(0.0, 0.0);
     var scale_snap = vec2<f32>(0.0, 0.0);
@@ -59,6 +71,8 @@ fn solid_vs_main(input: SolidVertexInput) -> SolidVertexOutput {
     out.shadow_color = premultiply(input.shadow_color);
     out.shadow_offset = input.shadow_offset * globals.scale;
     out.shadow_blur_radius = input.shadow_blur_radius * globals.scale;
+    out.shadow_spread = input.shadow_spread * globals.scale;
+    out.shadow_inset = input.shadow_inset;
 
     return out;
 }
@@ -67,7 +81,7 @@ fn solid_vs_main(input: SolidVertexInput) -> SolidVertexOutput {
 fn solid_fs_main(
     input: SolidVertexOutput
 ) -> @location(0) vec4<f32> {
-    var mixed_color: vec4<f32> = input.color;
+    var color: vec4<f32> = input.color;
 
     var dist = rounded_box_sdf(
         -(input.position.xy - input.pos - input.scale * 0.5) * 2.0,
@@ -75,9 +89,35 @@ fn solid_fs_main(
         input.border_radius * 2.0
     ) / 2.0;
 
+    // Inset shadows are drawn over the background, inside the border
+    if input.shadow_inset != 0u && input.shadow_color.a > 0.0 {
+        let inset = input.border_width + input.shadow_spread;
+        let shadow_scale = max(input.scale - 2.0 * inset, vec2<f32>(0.0, 0.0));
+        let shadow_radius = max(input.border_radius - inset, vec4<f32>(0.0, 0.0, 0.0, 0.0));
+
+        let shadow_dist: f32 = rounded_box_sdf(
+            -(input.position.xy - input.pos - input.shadow_offset - input.scale * 0.5) * 2.0,
+            shadow_scale,
+            shadow_radius * 2.0
+        ) / 2.0;
+
+        var shadow_alpha: f32 = clamp(0.5 + shadow_dist, 0.0, 1.0);
+
+        if input.shadow_blur_radius > 0.0 {
+            shadow_alpha = smoothstep(-input.shadow_blur_radius, input.shadow_blur_radius, shadow_dist);
+        }
+
+        let inner_alpha = clamp(0.5 - dist - input.border_width, 0.0, 1.0);
+        let shadow = input.shadow_color * shadow_alpha * inner_alpha;
+
+        color = shadow + color * (1.0 - shadow.a);
+    }
+
+    var mixed_color: vec4<f32> = color;
+
     if (input.border_width > 0.0) {
         mixed_color = mix(
-            input.color,
+            color,
             input.border_color,
             clamp(0.5 + dist + input.border_width, 0.0, 1.0)
         );
@@ -87,11 +127,18 @@ fn solid_fs_main(
 
     let quad_color = mixed_color * quad_alpha;
 
-    if input.shadow_color.a > 0.0 {
+    if input.shadow_inset == 0u && input.shadow_color.a > 0.0 {
+        let shadow_scale = max(input.scale + 2.0 * input.shadow_spread, vec2<f32>(0.0, 0.0));
+        let shadow_radius = select(
+            vec4<f32>(0.0, 0.0, 0.0, 0.0),
+            max(input.border_radius + input.shadow_spread, vec4<f32>(0.0, 0.0, 0.0, 0.0)),
+            input.border_radius > vec4<f32>(0.0, 0.0, 0.0, 0.0)
+        );
+
         var shadow_dist: f32 = rounded_box_sdf(
             -(input.position.xy - input.pos - input.shadow_offset - input.scale/2.0) * 2.0,
-            input.scale,
-            input.border_radius * 2.0
+            shadow_scale,
+            shadow_radius * 2.0
         ) / 2.0;
         let shadow_alpha = 1.0 - smoothstep(-input.shadow_blur_radius, input.shadow_blur_radius, max(shadow_dist, 0.0));
 
@@ -464,6 +464,7 @@ fn prepare(
                 font,
                 shaping,
                 align_x,
+                truncation,
                 ..
             } => {
                 let (key, _) = buffer_cache.allocate(
@@ -479,6 +480,7 @@ fn prepare(
                             height: bounds.height,
                         },
                         shaping: *shaping,
+                        truncation: *truncation,
                     },
                 );
 
@@ -39,10 +39,11 @@
 //! └─────────────────────────────────────────────────────────────┘
 //! ```
 
-pub use accesskit::{Action, ActionRequest, Node, NodeId, Role, Tree, TreeUpdate};
+pub use accesskit::{Action, ActionRequest, Live, Node, NodeId, Role, Tree, TreeUpdate};
 
 use crate::widget;
 use crate::{Point, Rectangle, Size};
+use std::cell::Cell;
 use std::hash::{Hash, Hasher};
 use std::sync::atomic::{AtomicBool, Ordering};
 
@@ -57,22 +58,57 @@ use std::sync::atomic::{AtomicBool, Ordering};
 /// focus rings since the screen reader provides its own highlighting).
 static ACCESSIBILITY_ACTIVE: AtomicBool = AtomicBool::new(false);
 
-/// Returns `true` if accessibility mode is globally active.
+thread_local! {
+    /// The accessibility state entered on the current thread, if any.
+    static CURRENT: Cell<Option<bool>> = const { Cell::new(None) };
+}
+
+/// Returns `true` if accessibility mode is active.
+///
+/// This is the state [`enter`]ed on the current thread (i.e. the state of the
+/// window being processed) or, if there is none, the global default.
 ///
 /// Widgets can use this to skip rendering focus indicators, since screen
 /// readers like VoiceOver provide their own focus highlighting.
 pub fn is_accessibility_active() -> bool {
-    ACCESSIBILITY_ACTIVE.load(Ordering::Relaxed)
+    CURRENT
+        .get()
+        .unwrap_or_else(|| ACCESSIBILITY_ACTIVE.load(Ordering::Relaxed))
 }
 
-/// Sets the global accessibility active state.
+/// Sets the global default accessibility active state.
 ///
-/// This should be called by the shell when a screen reader connects or
-/// disconnects.
+/// Shells that track screen readers per window should [`enter`] the state of
+/// each window instead.
 pub fn set_accessibility_active(active: bool) {
     ACCESSIBILITY_ACTIVE.store(active, Ordering::Relaxed);
 }
 
+/// Makes the given accessibility active state the current one on this thread
+/// until the returned [`Scope`] is dropped.
+///
+/// Scopes can be nested.
+pub fn enter(active: bool) -> Scope {
+    Scope {
+        previous: CURRENT.replace(Some(active)),
+    }
+}
+
+/// An accessibility active state [`enter`]ed on the current thread.
+///
+/// The previous state is restored when the [`Scope`] is dropped.
+#[derive(Debug)]
+#[must_use = "the accessibility state is only entered until the scope is dropped"]
+pub struct Scope {
+    previous: Option<bool>,
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        CURRENT.set(self.previous);
+    }
+}
+
 // ============================================================================
 // Accessibility Mode
 // ============================================================================
@@ -187,6 +223,8 @@ pub struct WidgetInfo {
     pub step: Option<f64>,
     /// Whether the widget is toggled/checked (for checkboxes, toggles).
     pub toggled: Option<bool>,
+    /// Whether the widget is the selected item of its container (for tabs).
+    pub selected: Option<bool>,
     /// Text selection start (for text inputs).
     pub text_selection_start: Option<usize>,
     /// Text selection end (for text inputs).
@@ -196,6 +234,25 @@ pub struct WidgetInfo {
     pub text_selection_target: TextSelectionTarget,
     /// Whether the widget is expanded (for combo boxes, menus).
     pub expanded: Option<bool>,
+    /// The horizontal scroll offset and its maximum (for scroll views).
+    pub scroll_x: Option<(f64, f64)>,
+    /// The vertical scroll offset and its maximum (for scroll views).
+    pub scroll_y: Option<(f64, f64)>,
+    /// The number of rows (for tables).
+    pub row_count: Option<usize>,
+    /// The number of columns (for tables).
+    pub column_count: Option<usize>,
+    /// The row index (for table cells).
+    pub row_index: Option<usize>,
+    /// The column index (for table cells and column headers).
+    pub column_index: Option<usize>,
+    /// The heading level, starting at 1 (for headings).
+    pub level: Option<usize>,
+    /// How changes to this widget are announced (for live regions).
+    ///
+    /// Screen readers announce the label of a live region whenever it
+    /// appears or changes, without moving the focus.
+    pub live: Option<Live>,
     /// Placeholder/hint text.
     pub placeholder: Option<String>,
     /// The widget bounds in window coordinates.
@@ -206,6 +263,8 @@ pub struct WidgetInfo {
     pub actions: Vec<Action>,
     /// IDs of widgets that label this widget.
     pub labelled_by: Vec<NodeId>,
+    /// IDs of widgets that describe this widget (e.g. a tooltip).
+    pub described_by: Vec<NodeId>,
     /// IDs of child widgets.
     pub children: Vec<NodeId>,
 
@@ -240,15 +299,25 @@ impl Default for WidgetInfo {
             max_value: None,
             step: None,
             toggled: None,
+            selected: None,
             text_selection_start: None,
             text_selection_end: None,
             text_selection_target: TextSelectionTarget::ThisNode,
             expanded: None,
+            scroll_x: None,
+            scroll_y: None,
+            row_count: None,
+            column_count: None,
+            row_index: None,
+            column_index: None,
+            level: None,
+            live: None,
             placeholder: None,
             bounds: Rectangle::new(Point::ORIGIN, Size::ZERO),
             focusable: false,
             actions: Vec::new(),
             labelled_by: Vec::new(),
+            described_by: Vec::new(),
             children: Vec::new(),
             extra_children: Vec::new(),
         }
@@ -464,6 +533,194 @@ impl WidgetInfo {
         }
     }
 
+    /// Creates widget info for a tab list (the strip of a tabbed view).
+    ///
+    /// VoiceOver announces this as "tab group" and navigates its tabs.
+    pub fn tab_list() -> Self {
+        Self {
+            role: Role::TabList,
+            focusable: true,
+            actions: vec![Action::Focus],
+            ..Default::default()
+        }
+    }
+
+    /// Creates widget info for a tab inside a tab list.
+    ///
+    /// VoiceOver announces this as "tab, selected, 1 of 3" etc.
+    pub fn tab(label: impl Into<String>, is_selected: bool) -> Self {
+        Self {
+            role: Role::Tab,
+            label: Some(label.into()),
+            selected: Some(is_selected),
+            focusable: true,
+            actions: vec![Action::Click, Action::Focus],
+            ..Default::default()
+        }
+    }
+
+    /// Creates widget info for a multi-line text editor.
+    ///
+    /// VoiceOver announces this as "text entry area" with its contents.
+    pub fn text_editor(value: impl Into<String>) -> Self {
+        Self {
+            role: Role::MultilineTextInput,
+            value: Some(value.into()),
+            focusable: true,
+            actions: vec![Action::Click, Action::Focus, Action::SetTextSelection],
+            ..Default::default()
+        }
+    }
+
+    /// Creates widget info for a scroll view.
+    ///
+    /// Use [`with_scroll_x`](Self::with_scroll_x) and
+    /// [`with_scroll_y`](Self::with_scroll_y) to expose its scroll ranges.
+    pub fn scroll_view() -> Self {
+        Self {
+            role: Role::ScrollView,
+            ..Default::default()
+        }
+    }
+
+    /// Creates widget info for a table with the given amount of rows and columns.
+    ///
+    /// The amount of rows includes the header row, if any.
+    pub fn table(rows: usize, columns: usize) -> Self {
+        Self {
+            role: Role::Table,
+            row_count: Some(rows),
+            column_count: Some(columns),
+            ..Default::default()
+        }
+    }
+
+    /// Creates widget info for the header of the given column of a table.
+    pub fn column_header(label: impl Into<String>, column: usize) -> Self {
+        Self {
+            role: Role::ColumnHeader,
+            label: Some(label.into()),
+            row_index: Some(0),
+            column_index: Some(column),
+            ..Default::default()
+        }
+    }
+
+    /// Creates widget info for a cell of a table.
+    pub fn cell(row: usize, column: usize) -> Self {
+        Self {
+            role: Role::Cell,
+            row_index: Some(row),
+            column_index: Some(column),
+            ..Default::default()
+        }
+    }
+
+    /// Creates widget info for a progress bar.
+    ///
+    /// VoiceOver announces this as "progress indicator" with its percentage.
+    pub fn progress_bar(value: f64, min: f64, max: f64) -> Self {
+        Self {
+            role: Role::ProgressIndicator,
+            numeric_value: Some(value),
+            min_value: Some(min),
+            max_value: Some(max),
+            ..Default::default()
+        }
+    }
+
+    /// Creates widget info for a tooltip.
+    ///
+    /// Widgets that display the tooltip should reference it with
+    /// [`with_described_by`](Self::with_described_by).
+    pub fn tooltip(text: impl Into<String>) -> Self {
+        Self {
+            role: Role::Tooltip,
+            label: Some(text.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Creates widget info for a status message, like a notification.
+    ///
+    /// Status messages are polite live regions: screen readers announce them
+    /// once they finish speaking.
+    pub fn status(message: impl Into<String>) -> Self {
+        Self {
+            role: Role::Status,
+            label: Some(message.into()),
+            live: Some(Live::Polite),
+            ..Default::default()
+        }
+    }
+
+    /// Creates widget info for an alert, like an error notification.
+    ///
+    /// Alerts are assertive live regions: screen readers interrupt their
+    /// current speech to announce them.
+    pub fn alert(message: impl Into<String>) -> Self {
+        Self {
+            role: Role::Alert,
+            label: Some(message.into()),
+            live: Some(Live::Assertive),
+            ..Default::default()
+        }
+    }
+
+    /// Creates widget info for a heading of the given level, starting at 1.
+    ///
+    /// VoiceOver announces this as "heading level 1" and lets users jump
+    /// between headings with the rotor.
+    pub fn heading(text: impl Into<String>, level: usize) -> Self {
+        Self {
+            role: Role::Heading,
+            label: Some(text.into()),
+            level: Some(level),
+            ..Default::default()
+        }
+    }
+
+    /// Creates widget info for a link.
+    pub fn link(label: impl Into<String>) -> Self {
+        Self {
+            role: Role::Link,
+            label: Some(label.into()),
+            focusable: true,
+            actions: vec![Action::Click, Action::Focus],
+            ..Default::default()
+        }
+    }
+
+    /// Creates widget info for a date input (e.g. a date picker).
+    pub fn date_input(value: impl Into<String>) -> Self {
+        Self {
+            role: Role::DateInput,
+            value: Some(value.into()),
+            focusable: true,
+            actions: vec![Action::Focus],
+            ..Default::default()
+        }
+    }
+
+    /// Creates widget info for a color well (e.g. a color picker).
+    pub fn color_well(value: impl Into<String>) -> Self {
+        Self {
+            role: Role::ColorWell,
+            value: Some(value.into()),
+            focusable: true,
+            actions: vec![Action::Focus],
+            ..Default::default()
+        }
+    }
+
+    /// Creates widget info for a pane of a split view.
+    pub fn pane() -> Self {
+        Self {
+            role: Role::Pane,
+            ..Default::default()
+        }
+    }
+
     /// Creates widget info for a generic container.
     pub fn container() -> Self {
         Self {
@@ -562,6 +819,37 @@ impl WidgetInfo {
         self
     }
 
+    /// Sets the horizontal scroll offset and its maximum.
+    pub fn with_scroll_x(mut self, offset: f64, max: f64) -> Self {
+        self.scroll_x = Some((offset, max));
+        self
+    }
+
+    /// Sets the vertical scroll offset and its maximum.
+    pub fn with_scroll_y(mut self, offset: f64, max: f64) -> Self {
+        self.scroll_y = Some((offset, max));
+        self
+    }
+
+    /// Sets the amount of rows and columns (for tables and grids).
+    pub fn with_dimensions(mut self, rows: usize, columns: usize) -> Self {
+        self.row_count = Some(rows);
+        self.column_count = Some(columns);
+        self
+    }
+
+    /// Sets how changes to this widget are announced.
+    pub fn with_live(mut self, live: Live) -> Self {
+        self.live = Some(live);
+        self
+    }
+
+    /// Adds a node that describes this widget (e.g. a tooltip).
+    pub fn with_described_by(mut self, id: NodeId) -> Self {
+        self.described_by.push(id);
+        self
+    }
+
     /// Adds a child node ID.
     pub fn with_child(mut self, child: NodeId) -> Self {
         self.children.push(child);
@@ -638,6 +926,10 @@ impl WidgetInfo {
             });
         }
 
+        if let Some(selected) = self.selected {
+            node.set_selected(selected);
+        }
+
         if let (Some(start), Some(end)) = (self.text_selection_start, self.text_selection_end) {
             node.set_text_selection(Box::new(accesskit::TextSelection {
                 anchor: accesskit::TextPosition {
@@ -659,6 +951,42 @@ impl WidgetInfo {
             node.set_placeholder(placeholder);
         }
 
+        if let Some((offset, max)) = self.scroll_x {
+            node.set_scroll_x(offset);
+            node.set_scroll_x_min(0.0);
+            node.set_scroll_x_max(max);
+        }
+
+        if let Some((offset, max)) = self.scroll_y {
+            node.set_scroll_y(offset);
+            node.set_scroll_y_min(0.0);
+            node.set_scroll_y_max(max);
+        }
+
+        if let Some(rows) = self.row_count {
+            node.set_row_count(rows);
+        }
+
+        if let Some(columns) = self.column_count {
+            node.set_column_count(columns);
+        }
+
+        if let Some(row) = self.row_index {
+            node.set_row_index(row);
+        }
+
+        if let Some(column) = self.column_index {
+            node.set_column_index(column);
+        }
+
+        if let Some(level) = self.level {
+            node.set_level(level);
+        }
+
+        if let Some(live) = self.live {
+            node.set_live(live);
+        }
+
         // Set bounds
         node.set_bounds(accesskit::Rect {
             x0: self.bounds.x as f64,
@@ -677,6 +1005,11 @@ impl WidgetInfo {
             node.push_labelled_by(id);
         }
 
+        // Add described_by references (e.g. tooltips)
+        for id in self.described_by {
+            node.push_described_by(id);
+        }
+
         // Set children
         node.set_children(children);
 
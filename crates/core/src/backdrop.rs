@@ -0,0 +1,12 @@
+use crate::Color;
+
+/// A frosted-glass effect that blurs and tints whatever is drawn behind a
+/// layer.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Backdrop {
+    /// The blur radius of the backdrop.
+    pub blur_radius: f32,
+
+    /// The [`Color`] drawn over the blurred backdrop.
+    pub tint: Color,
+}
@@ -0,0 +1,506 @@
+//! Zoom and pan around some content.
+use crate::animation::Animation;
+use crate::mouse;
+use crate::time::{Duration, Instant};
+use crate::window;
+use crate::{Event, Point, Rectangle, Size, Transformation, Vector};
+
+/// The amount of pixels of a [`mouse::ScrollDelta::Pixels`] that count as
+/// a single zoom step.
+const PIXELS_PER_STEP: f32 = 60.0;
+
+/// A 2D camera that maps some content to the screen.
+///
+/// A [`Camera`] implements the usual interactions of zoomable surfaces:
+/// zooming around the cursor with the mouse wheel, panning by dragging with
+/// a mouse button and fitting some content in view. Zoom changes are
+/// animated.
+///
+/// Screen coordinates are relative to the top-left corner of the bounds of
+/// the widget using the [`Camera`], like the cursor positions a
+/// `canvas::Program` works with.
+#[derive(Debug, Clone)]
+pub struct Camera {
+    scale: Animation<f32>,
+    anchor: Point,
+    position: Point,
+    min_scale: f32,
+    max_scale: f32,
+    scale_step: f32,
+    pan_button: mouse::Button,
+    grab: Option<Grab>,
+    now: Instant,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Grab {
+    cursor: Point,
+    position: Point,
+}
+
+impl Camera {
+    /// Creates a new [`Camera`] with no zoom nor translation.
+    pub fn new() -> Self {
+        Self {
+            scale: Animation::new(1.0).quick(),
+            anchor: Point::ORIGIN,
+            position: Point::ORIGIN,
+            min_scale: 0.1,
+            max_scale: 10.0,
+            scale_step: 0.1,
+            pan_button: mouse::Button::Middle,
+            grab: None,
+            now: Instant::now(),
+        }
+    }
+
+    /// Sets the minimum scale of the [`Camera`].
+    ///
+    /// Default is `0.1`
+    pub fn min_scale(mut self, min_scale: f32) -> Self {
+        self.min_scale = min_scale;
+        self
+    }
+
+    /// Sets the maximum scale of the [`Camera`].
+    ///
+    /// Default is `10.0`
+    pub fn max_scale(mut self, max_scale: f32) -> Self {
+        self.max_scale = max_scale;
+        self
+    }
+
+    /// Sets the percentage the [`Camera`] will be scaled by on every step of
+    /// the mouse wheel.
+    ///
+    /// Default is `0.10`
+    pub fn scale_step(mut self, scale_step: f32) -> Self {
+        self.scale_step = scale_step;
+        self
+    }
+
+    /// Sets the [`mouse::Button`] used to pan the [`Camera`] by dragging.
+    ///
+    /// Default is [`mouse::Button::Middle`].
+    pub fn pan_button(mut self, pan_button: mouse::Button) -> Self {
+        self.pan_button = pan_button;
+        self
+    }
+
+    /// Sets the duration of the zoom animations of the [`Camera`].
+    ///
+    /// A zero duration disables the animations.
+    pub fn zoom_duration(mut self, duration: Duration) -> Self {
+        self.scale = Animation::new(self.scale.value()).duration(duration);
+        self
+    }
+
+    /// Returns the current scale of the [`Camera`].
+    pub fn scale(&self) -> f32 {
+        self.scale.interpolate_with(|scale| scale, self.now)
+    }
+
+    /// Returns the scale the [`Camera`] is animating towards.
+    pub fn target_scale(&self) -> f32 {
+        self.scale.value()
+    }
+
+    /// Returns the current translation of the [`Camera`], in screen
+    /// coordinates.
+    pub fn translation(&self) -> Vector {
+        self.translation_at(self.scale())
+    }
+
+    /// Returns the [`Transformation`] mapping content coordinates to screen
+    /// coordinates.
+    pub fn transformation(&self) -> Transformation {
+        let translation = self.translation();
+
+        Transformation::translate(translation.x, translation.y)
+            * Transformation::scale(self.scale())
+    }
+
+    /// Maps a [`Point`] in screen coordinates to content coordinates.
+    pub fn to_content(&self, point: Point) -> Point {
+        let scale = self.scale();
+        let translation = self.translation_at(scale);
+
+        Point::new(
+            (point.x - translation.x) / scale,
+            (point.y - translation.y) / scale,
+        )
+    }
+
+    /// Maps a [`Point`] in content coordinates to screen coordinates.
+    pub fn to_screen(&self, point: Point) -> Point {
+        let scale = self.scale();
+
+        Point::new(point.x * scale, point.y * scale) + self.translation_at(scale)
+    }
+
+    /// Returns the position of the [`mouse::Cursor`] in content coordinates,
+    /// if it is over the given bounds.
+    pub fn cursor_position(&self, cursor: mouse::Cursor, bounds: Rectangle) -> Option<Point> {
+        cursor
+            .position_in(bounds)
+            .map(|position| self.to_content(position))
+    }
+
+    /// Returns the region of the content visible in a viewport of the given
+    /// [`Size`].
+    pub fn visible_region(&self, size: Size) -> Rectangle {
+        let top_left = self.to_content(Point::ORIGIN);
+        let scale = self.scale();
+
+        Rectangle::new(top_left, Size::new(size.width / scale, size.height / scale))
+    }
+
+    /// Returns true if the [`Camera`] is currently animating a zoom.
+    pub fn is_animating(&self) -> bool {
+        self.scale.is_animating(self.now)
+    }
+
+    /// Returns true if the [`Camera`] is currently being dragged.
+    pub fn is_panning(&self) -> bool {
+        self.grab.is_some()
+    }
+
+    /// Moves the [`Camera`] by the given amount of screen pixels.
+    pub fn pan(&mut self, delta: Vector) {
+        self.position += delta;
+    }
+
+    /// Zooms the [`Camera`] by the given factor, keeping the given screen
+    /// [`Point`] in place.
+    pub fn zoom(&mut self, factor: f32, around: Point) {
+        self.zoom_to(self.target_scale() * factor, around);
+    }
+
+    /// Zooms the [`Camera`] to the given scale, keeping the given screen
+    /// [`Point`] in place.
+    ///
+    /// The scale is clamped to the limits of the [`Camera`].
+    pub fn zoom_to(&mut self, scale: f32, around: Point) {
+        self.now = Instant::now();
+
+        self.anchor = self.to_content(around);
+        self.position = around;
+
+        self.scale
+            .go_mut(scale.clamp(self.min_scale, self.max_scale), self.now);
+    }
+
+    /// Zooms and pans the [`Camera`] so the given content region is
+    /// centered and fully visible in a viewport of the given [`Size`].
+    ///
+    /// The resulting scale is clamped to the limits of the [`Camera`].
+    pub fn fit(&mut self, content: Rectangle, size: Size) {
+        if content.width <= 0.0 || content.height <= 0.0 {
+            return;
+        }
+
+        let scale = (size.width / content.width)
+            .min(size.height / content.height)
+            .clamp(self.min_scale, self.max_scale);
+
+        let center = content.center();
+
+        self.animate_to(
+            scale,
+            Vector::new(
+                size.width / 2.0 - center.x * scale,
+                size.height / 2.0 - center.y * scale,
+            ),
+        );
+    }
+
+    /// Resets the [`Camera`] to no zoom nor translation.
+    pub fn reset(&mut self) {
+        self.animate_to(1.0, Vector::ZERO);
+    }
+
+    /// Pans the [`Camera`] so the given content region covers as much of a
+    /// viewport of the given [`Size`] as possible, once the current zoom
+    /// animation finishes.
+    ///
+    /// Content smaller than the viewport is centered.
+    pub fn constrain(&mut self, content: Rectangle, size: Size) {
+        let scale = self.target_scale();
+        let translation = self.translation_at(scale);
+
+        let constrain = |translation: f32, start: f32, length: f32, viewport: f32| {
+            let length = length * scale;
+            let start = start * scale;
+
+            if length <= viewport {
+                (viewport - length) / 2.0 - start
+            } else {
+                translation.clamp(viewport - length - start, -start)
+            }
+        };
+
+        let constrained = Vector::new(
+            constrain(translation.x, content.x, content.width, size.width),
+            constrain(translation.y, content.y, content.height, size.height),
+        );
+
+        if constrained != translation {
+            self.animate_to(scale, constrained);
+        }
+    }
+
+    /// Processes an [`Event`] happening over the given bounds.
+    ///
+    /// Returns true if the [`Camera`] handled the [`Event`], in which case
+    /// it should be captured and a redraw requested. While
+    /// [`is_animating`](Self::is_animating), redraws must keep being
+    /// requested.
+    pub fn update(&mut self, event: &Event, bounds: Rectangle, cursor: mouse::Cursor) -> bool {
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta, .. }) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    return false;
+                };
+
+                let steps = match *delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / PIXELS_PER_STEP,
+                };
+
+                if steps == 0.0 {
+                    return false;
+                }
+
+                self.zoom((1.0 + self.scale_step).powf(steps), position);
+
+                true
+            }
+            Event::Mouse(mouse::Event::ButtonPressed { button, .. })
+                if *button == self.pan_button =>
+            {
+                let Some(cursor) = cursor.position_in(bounds) else {
+                    return false;
+                };
+
+                self.grab = Some(Grab {
+                    cursor,
+                    position: self.position,
+                });
+
+                true
+            }
+            Event::Mouse(mouse::Event::ButtonReleased { button, .. })
+                if *button == self.pan_button =>
+            {
+                self.grab.take().is_some()
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position, .. }) => {
+                let Some(grab) = self.grab else {
+                    return false;
+                };
+
+                let cursor = *position - Vector::new(bounds.x, bounds.y);
+
+                self.position = grab.position + (cursor - grab.cursor);
+
+                true
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                self.now = *now;
+
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the [`mouse::Interaction`] of the [`Camera`].
+    pub fn mouse_interaction(&self) -> mouse::Interaction {
+        if self.is_panning() {
+            mouse::Interaction::Grabbing
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
+    fn translation_at(&self, scale: f32) -> Vector {
+        Vector::new(
+            self.position.x - self.anchor.x * scale,
+            self.position.y - self.anchor.y * scale,
+        )
+    }
+
+    /// Animates the [`Camera`] towards the given scale and translation.
+    ///
+    /// The zoom happens around the single content point that sits at the
+    /// same screen position both now and at the end of the animation, so
+    /// scale and translation stay in sync all the way through.
+    fn animate_to(&mut self, scale: f32, translation: Vector) {
+        self.now = Instant::now();
+
+        let current_scale = self.scale();
+        let current = self.translation_at(current_scale);
+
+        if (scale - current_scale).abs() <= f32::EPSILON {
+            self.anchor = Point::ORIGIN;
+            self.position = Point::new(translation.x, translation.y);
+        } else {
+            let anchor = (current - translation) * (1.0 / (scale - current_scale));
+
+            self.anchor = Point::new(anchor.x, anchor.y);
+            self.position = Point::new(
+                current.x + anchor.x * current_scale,
+                current.y + anchor.y * current_scale,
+            );
+        }
+
+        self.scale.go_mut(scale, self.now);
+    }
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keyboard;
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 100.0,
+        height: 100.0,
+    };
+
+    fn settle(camera: &mut Camera) {
+        let _ = camera.update(
+            &Event::Window(window::Event::RedrawRequested(
+                Instant::now() + Duration::from_secs(10),
+            )),
+            BOUNDS,
+            mouse::Cursor::Unavailable,
+        );
+
+        assert!(!camera.is_animating());
+    }
+
+    fn assert_near(a: Vector, b: Vector) {
+        assert!(
+            (a.x - b.x).abs() < 1e-3 && (a.y - b.y).abs() < 1e-3,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn zoom_keeps_point_in_place() {
+        let mut camera = Camera::new();
+        let around = Point::new(30.0, 60.0);
+
+        camera.zoom_to(2.0, around);
+        settle(&mut camera);
+
+        assert_eq!(camera.scale(), 2.0);
+        assert_near(
+            camera.to_screen(Point::new(30.0, 60.0)) - Point::ORIGIN,
+            around - Point::ORIGIN,
+        );
+        assert_near(camera.translation(), Vector::new(-30.0, -60.0));
+    }
+
+    #[test]
+    fn zoom_is_clamped_to_limits() {
+        let mut camera = Camera::new().min_scale(0.5).max_scale(4.0);
+
+        camera.zoom_to(10.0, Point::ORIGIN);
+        assert_eq!(camera.target_scale(), 4.0);
+
+        camera.zoom(0.01, Point::ORIGIN);
+        assert_eq!(camera.target_scale(), 0.5);
+    }
+
+    #[test]
+    fn fit_centers_content() {
+        let mut camera = Camera::new();
+
+        camera.fit(
+            Rectangle::new(Point::new(0.0, 0.0), Size::new(200.0, 100.0)),
+            BOUNDS.size(),
+        );
+        settle(&mut camera);
+
+        assert_eq!(camera.scale(), 0.5);
+        assert_near(
+            camera.to_screen(Point::new(100.0, 50.0)) - Point::ORIGIN,
+            Vector::new(50.0, 50.0),
+        );
+    }
+
+    #[test]
+    fn constrain_centers_small_content() {
+        let mut camera = Camera::new();
+
+        camera.pan(Vector::new(30.0, 0.0));
+        camera.constrain(Rectangle::with_size(Size::new(50.0, 50.0)), BOUNDS.size());
+        settle(&mut camera);
+
+        assert_near(camera.translation(), Vector::new(25.0, 25.0));
+    }
+
+    #[test]
+    fn constrain_clamps_large_content() {
+        let content = Rectangle::with_size(Size::new(200.0, 200.0));
+        let mut camera = Camera::new();
+
+        camera.pan(Vector::new(50.0, 50.0));
+        camera.constrain(content, BOUNDS.size());
+        settle(&mut camera);
+
+        assert_near(camera.translation(), Vector::ZERO);
+
+        camera.pan(Vector::new(-500.0, -20.0));
+        camera.constrain(content, BOUNDS.size());
+        settle(&mut camera);
+
+        assert_near(camera.translation(), Vector::new(-100.0, -20.0));
+    }
+
+    #[test]
+    fn drag_pans() {
+        let mut camera = Camera::new();
+        let modifiers = keyboard::Modifiers::default();
+
+        assert!(camera.update(
+            &Event::Mouse(mouse::Event::ButtonPressed {
+                button: mouse::Button::Middle,
+                modifiers,
+            }),
+            BOUNDS,
+            mouse::Cursor::Available(Point::new(10.0, 10.0)),
+        ));
+        assert!(camera.is_panning());
+
+        assert!(camera.update(
+            &Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::new(30.0, 40.0),
+                modifiers,
+            }),
+            BOUNDS,
+            mouse::Cursor::Available(Point::new(30.0, 40.0)),
+        ));
+        assert_eq!(camera.translation(), Vector::new(20.0, 30.0));
+
+        assert!(camera.update(
+            &Event::Mouse(mouse::Event::ButtonReleased {
+                button: mouse::Button::Middle,
+                modifiers,
+            }),
+            BOUNDS,
+            mouse::Cursor::Available(Point::new(30.0, 40.0)),
+        ));
+        assert!(!camera.is_panning());
+    }
+}
@@ -1,14 +1,18 @@
 //! Colors that transition progressively.
-use crate::{Color, Radians};
+use crate::{Color, Point, Radians, Rectangle};
 
 use std::cmp::Ordering;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-/// A fill which transitions colors progressively along a direction, either linearly, radially (TBD),
-/// or conically (TBD).
+/// A fill which transitions colors progressively along a direction, either linearly, radially,
+/// or conically.
 pub enum Gradient {
     /// A linear gradient interpolates colors along a direction at a specific angle.
     Linear(Linear),
+    /// A radial gradient interpolates colors outwards from a center point.
+    Radial(Radial),
+    /// A conic gradient interpolates colors around a center point.
+    Conic(Conic),
 }
 
 impl Gradient {
@@ -16,6 +20,8 @@ impl Gradient {
     pub fn scale_alpha(self, factor: f32) -> Self {
         match self {
             Gradient::Linear(linear) => Gradient::Linear(linear.scale_alpha(factor)),
+            Gradient::Radial(radial) => Gradient::Radial(radial.scale_alpha(factor)),
+            Gradient::Conic(conic) => Gradient::Conic(conic.scale_alpha(factor)),
         }
     }
 }
@@ -26,6 +32,18 @@ impl From<Linear> for Gradient {
     }
 }
 
+impl From<Radial> for Gradient {
+    fn from(gradient: Radial) -> Self {
+        Self::Radial(gradient)
+    }
+}
+
+impl From<Conic> for Gradient {
+    fn from(gradient: Conic) -> Self {
+        Self::Conic(gradient)
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
 /// A point along the gradient vector where the specified [`color`] is unmixed.
 ///
@@ -64,18 +82,7 @@ impl Linear {
     ///
     /// Any stop added after the 8th will be silently ignored.
     pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
-        if offset.is_finite() && (0.0..=1.0).contains(&offset) {
-            let (Ok(index) | Err(index)) = self.stops.binary_search_by(|stop| match stop {
-                None => Ordering::Greater,
-                Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
-            });
-
-            if index < 8 {
-                self.stops[index] = Some(ColorStop { offset, color });
-            }
-        } else {
-            log::warn!("Gradient color stop must be within 0.0..=1.0 range.");
-        };
+        add_stop(&mut self.stops, offset, color);
 
         self
     }
@@ -94,10 +101,207 @@ impl Linear {
     /// Scales the alpha channel of the [`Linear`] gradient by the given
     /// factor.
     pub fn scale_alpha(mut self, factor: f32) -> Self {
-        for stop in self.stops.iter_mut().flatten() {
-            stop.color.a *= factor;
+        scale_alpha(&mut self.stops, factor);
+
+        self
+    }
+}
+
+/// A radial gradient.
+///
+/// Colors are interpolated from the [`center`] (offset `0.0`) to a circle of
+/// the given [`radius`] (offset `1.0`).
+///
+/// [`center`]: Self::center
+/// [`radius`]: Self::radius
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radial {
+    /// The center of the [`Radial`] gradient, relative to its bounds.
+    ///
+    /// `(0.0, 0.0)` is the top-left corner and `(1.0, 1.0)` the bottom-right one.
+    pub center: Point,
+    /// The radius of the [`Radial`] gradient, relative to the distance from its
+    /// [`center`] to the farthest corner of its bounds.
+    ///
+    /// [`center`]: Self::center
+    pub radius: f32,
+    /// [`ColorStop`]s along the radius of the gradient.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Radial {
+    /// Creates a new [`Radial`] gradient centered in its bounds and reaching
+    /// its farthest corner.
+    pub fn new() -> Self {
+        Self {
+            center: Point::new(0.5, 0.5),
+            radius: 1.0,
+            stops: [None; 8],
         }
+    }
 
+    /// Sets the center of the [`Radial`] gradient, relative to its bounds.
+    pub fn center(mut self, center: impl Into<Point>) -> Self {
+        self.center = center.into();
         self
     }
+
+    /// Sets the radius of the [`Radial`] gradient, relative to the distance
+    /// from its center to the farthest corner of its bounds.
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(mut self, stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+
+    /// Scales the alpha channel of the [`Radial`] gradient by the given
+    /// factor.
+    pub fn scale_alpha(mut self, factor: f32) -> Self {
+        scale_alpha(&mut self.stops, factor);
+
+        self
+    }
+
+    /// Returns the absolute center and radius of the [`Radial`] gradient
+    /// within the given bounds.
+    pub fn to_absolute(&self, bounds: &Rectangle) -> (Point, f32) {
+        let center = absolute_center(self.center, bounds);
+
+        let farthest_x = (center.x - bounds.x).max(bounds.x + bounds.width - center.x);
+        let farthest_y = (center.y - bounds.y).max(bounds.y + bounds.height - center.y);
+
+        (center, farthest_x.hypot(farthest_y) * self.radius)
+    }
+}
+
+impl Default for Radial {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A conic gradient.
+///
+/// Colors are interpolated clockwise around the [`center`], starting at the
+/// given [`angle`] (offset `0.0`) and completing a full turn (offset `1.0`).
+///
+/// The software renderer has no native conic gradients and computes the
+/// color of every visible pixel each time a [`Conic`] gradient is redrawn,
+/// which is noticeably slower than the other gradients on large areas.
+///
+/// [`center`]: Self::center
+/// [`angle`]: Self::angle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conic {
+    /// The center of the [`Conic`] gradient, relative to its bounds.
+    ///
+    /// `(0.0, 0.0)` is the top-left corner and `(1.0, 1.0)` the bottom-right one.
+    pub center: Point,
+    /// The angle where the [`Conic`] gradient starts. `0` points up.
+    pub angle: Radians,
+    /// [`ColorStop`]s around the gradient.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Conic {
+    /// Creates a new [`Conic`] gradient centered in its bounds and starting at
+    /// the given angle in [`Radians`].
+    pub fn new(angle: impl Into<Radians>) -> Self {
+        Self {
+            center: Point::new(0.5, 0.5),
+            angle: angle.into(),
+            stops: [None; 8],
+        }
+    }
+
+    /// Sets the center of the [`Conic`] gradient, relative to its bounds.
+    pub fn center(mut self, center: impl Into<Point>) -> Self {
+        self.center = center.into();
+        self
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(mut self, stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+
+    /// Scales the alpha channel of the [`Conic`] gradient by the given
+    /// factor.
+    pub fn scale_alpha(mut self, factor: f32) -> Self {
+        scale_alpha(&mut self.stops, factor);
+
+        self
+    }
+
+    /// Returns the absolute center of the [`Conic`] gradient within the given
+    /// bounds.
+    pub fn to_absolute(&self, bounds: &Rectangle) -> Point {
+        absolute_center(self.center, bounds)
+    }
+}
+
+fn absolute_center(center: Point, bounds: &Rectangle) -> Point {
+    Point::new(
+        bounds.x + bounds.width * center.x,
+        bounds.y + bounds.height * center.y,
+    )
+}
+
+fn add_stop(stops: &mut [Option<ColorStop>; 8], offset: f32, color: Color) {
+    if offset.is_finite() && (0.0..=1.0).contains(&offset) {
+        let (Ok(index) | Err(index)) = stops.binary_search_by(|stop| match stop {
+            None => Ordering::Greater,
+            Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
+        });
+
+        if index < 8 {
+            stops[index] = Some(ColorStop { offset, color });
+        }
+    } else {
+        log::warn!("Gradient color stop must be within 0.0..=1.0 range.");
+    };
+}
+
+fn scale_alpha(stops: &mut [Option<ColorStop>; 8], factor: f32) {
+    for stop in stops.iter_mut().flatten() {
+        stop.color.a *= factor;
+    }
 }
@@ -69,6 +69,60 @@ impl Preedit {
     }
 }
 
+impl<T: AsRef<str>> Preedit<T> {
+    /// Returns the byte ranges of the content of the [`Preedit`], together
+    /// with the [`Underline`] they should be drawn with.
+    ///
+    /// A non-empty selection is the clause currently being converted by the
+    /// input method, and is underlined more heavily than the rest of the
+    /// composition.
+    pub fn segments(&self) -> Vec<(Range<usize>, Underline)> {
+        let length = self.content.as_ref().len();
+
+        let Some(selection) = self
+            .selection
+            .as_ref()
+            .map(|selection| selection.start.min(length)..selection.end.min(length))
+            .filter(|selection| selection.start < selection.end)
+        else {
+            return if length > 0 {
+                vec![(0..length, Underline::Thin)]
+            } else {
+                Vec::new()
+            };
+        };
+
+        [
+            (0..selection.start, Underline::Thin),
+            (selection.clone(), Underline::Thick),
+            (selection.end..length, Underline::Thin),
+        ]
+        .into_iter()
+        .filter(|(range, _)| !range.is_empty())
+        .collect()
+    }
+
+    /// Returns the byte offset of the caret inside the content of the
+    /// [`Preedit`], if it should be visible.
+    ///
+    /// The caret is hidden while a clause is selected.
+    pub fn caret(&self) -> Option<usize> {
+        self.selection
+            .as_ref()
+            .filter(|selection| selection.is_empty())
+            .map(|selection| selection.start.min(self.content.as_ref().len()))
+    }
+}
+
+/// The underline of a segment of a [`Preedit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Underline {
+    /// A thin underline, for the text being composed.
+    Thin,
+    /// A thick underline, for the clause being converted.
+    Thick,
+}
+
 /// The purpose of an [`InputMethod`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Purpose {
@@ -188,3 +242,27 @@ pub enum Event {
     /// [`Shell::request_input_method`]: crate::Shell::request_input_method
     Closed,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_preedit_into_clauses() {
+        let preedit = |selection| Preedit {
+            content: "にほんご",
+            selection,
+            text_size: None,
+        };
+
+        assert_eq!(preedit(Some(6..6)).segments(), [(0..12, Underline::Thin)]);
+        assert_eq!(preedit(Some(6..6)).caret(), Some(6));
+
+        assert_eq!(
+            preedit(Some(0..6)).segments(),
+            [(0..6, Underline::Thick), (6..12, Underline::Thin)]
+        );
+        assert_eq!(preedit(Some(0..6)).caret(), None);
+        assert_eq!(preedit(None).caret(), None);
+    }
+}
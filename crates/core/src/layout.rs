@@ -53,6 +53,17 @@ impl<'a> Layout<'a> {
         }
     }
 
+    /// Returns this [`Layout`] moved by the given translation.
+    ///
+    /// This can be useful for widgets that draw some of their children at a
+    /// different position than the one they were laid out at (e.g. sticky headers).
+    pub fn translate(self, translation: Vector) -> Self {
+        Self {
+            position: self.position + translation,
+            node: self.node,
+        }
+    }
+
     /// Returns an iterator over the children of this [`Layout`].
     pub fn children(self) -> impl DoubleEndedIterator<Item = Layout<'a>> + ExactSizeIterator {
         self.node.children().iter().map(move |node| {
@@ -18,6 +18,21 @@
 //! assert!(layout_direction().is_rtl());
 //! ```
 //!
+//! # Per-Window Layout Direction
+//!
+//! The global direction is only a default. Shells [`enter`] the direction of a
+//! window while building, updating and drawing its interface, so each window
+//! can have its own direction:
+//!
+//! ```rust,ignore
+//! use icy_ui_core::layout_direction::{self, LayoutDirection};
+//!
+//! let _direction = layout_direction::enter(LayoutDirection::Rtl);
+//!
+//! // Until `_direction` is dropped, widgets on this thread lay out RTL
+//! assert!(layout_direction::layout_direction().is_rtl());
+//! ```
+//!
 //! # Per-Widget Override
 //!
 //! Widgets can override the global direction via `.layout_direction()`:
@@ -29,6 +44,7 @@
 //! menu_bar(items).layout_direction(LayoutDirection::Rtl)
 //! ```
 
+use std::cell::Cell;
 use std::sync::atomic::{AtomicU8, Ordering};
 
 // ============================================================================
@@ -40,18 +56,31 @@ use std::sync::atomic::{AtomicU8, Ordering};
 /// Stored as u8: 0 = Ltr, 1 = Rtl
 static LAYOUT_DIRECTION: AtomicU8 = AtomicU8::new(0);
 
-/// Returns the global layout direction.
+thread_local! {
+    /// The layout direction entered on the current thread, if any.
+    static CURRENT: Cell<Option<LayoutDirection>> = const { Cell::new(None) };
+}
+
+/// Returns the current layout direction.
 ///
 /// Widgets use this as a fallback when they don't have an explicit direction set.
-/// This is typically set by the shell based on system locale or user preference.
+/// This is the direction [`enter`]ed on the current thread (i.e. the direction of
+/// the window being processed) or, if there is none, the global default.
 pub fn layout_direction() -> LayoutDirection {
+    CURRENT.get().unwrap_or_else(default_layout_direction)
+}
+
+/// Returns the global default layout direction.
+///
+/// This is typically set by the shell based on system locale or user preference.
+pub fn default_layout_direction() -> LayoutDirection {
     match LAYOUT_DIRECTION.load(Ordering::Relaxed) {
         1 => LayoutDirection::Rtl,
         _ => LayoutDirection::Ltr,
     }
 }
 
-/// Sets the global layout direction.
+/// Sets the global default layout direction.
 ///
 /// This should be called by the shell when the layout direction changes,
 /// for example via `window::set_layout_direction()`.
@@ -59,6 +88,32 @@ pub fn set_layout_direction(direction: LayoutDirection) {
     LAYOUT_DIRECTION.store(direction as u8, Ordering::Relaxed);
 }
 
+/// Makes the given [`LayoutDirection`] the current one on this thread until the
+/// returned [`Scope`] is dropped.
+///
+/// Shells enter the direction of a window while processing it, so windows can
+/// have different directions. Scopes can be nested.
+pub fn enter(direction: LayoutDirection) -> Scope {
+    Scope {
+        previous: CURRENT.replace(Some(direction)),
+    }
+}
+
+/// A layout direction [`enter`]ed on the current thread.
+///
+/// The previous direction is restored when the [`Scope`] is dropped.
+#[derive(Debug)]
+#[must_use = "the layout direction is only entered until the scope is dropped"]
+pub struct Scope {
+    previous: Option<LayoutDirection>,
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        CURRENT.set(self.previous);
+    }
+}
+
 // ============================================================================
 // Layout Direction Enum
 // ============================================================================
@@ -107,3 +162,27 @@ impl LayoutDirection {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entered_direction_overrides_default_until_dropped() {
+        assert_eq!(layout_direction(), default_layout_direction());
+
+        {
+            let _rtl = enter(LayoutDirection::Rtl);
+            assert_eq!(layout_direction(), LayoutDirection::Rtl);
+
+            {
+                let _ltr = enter(LayoutDirection::Ltr);
+                assert_eq!(layout_direction(), LayoutDirection::Ltr);
+            }
+
+            assert_eq!(layout_direction(), LayoutDirection::Rtl);
+        }
+
+        assert_eq!(layout_direction(), default_layout_direction());
+    }
+}
@@ -16,6 +16,7 @@ pub mod accessibility;
 pub mod alignment;
 pub mod animation;
 pub mod border;
+pub mod camera;
 pub mod clipboard;
 pub mod dnd;
 pub mod event;
@@ -25,6 +26,8 @@ pub mod image;
 pub mod input_method;
 pub mod keyboard;
 pub mod layout;
+pub mod layout_direction;
+pub mod localization;
 /// Application menu model types.
 pub mod menu;
 pub mod mouse;
@@ -40,11 +43,11 @@ pub mod widget;
 pub mod window;
 
 mod angle;
+mod backdrop;
 mod background;
 mod color;
 mod content_fit;
 mod element;
-mod layout_direction;
 mod length;
 mod pixels;
 mod point;
@@ -60,8 +63,10 @@ mod vector;
 pub use alignment::Alignment;
 pub use angle::{Degrees, Radians};
 pub use animation::Animation;
+pub use backdrop::Backdrop;
 pub use background::Background;
 pub use border::Border;
+pub use camera::Camera;
 pub use clipboard::{Clipboard, ClipboardData};
 pub use color::Color;
 pub use content_fit::ContentFit;
@@ -77,6 +82,7 @@ pub use input_method::InputMethod;
 pub use layout::Layout;
 pub use layout_direction::{LayoutDirection, layout_direction, set_layout_direction};
 pub use length::Length;
+pub use localization::Locale;
 pub use overlay::Overlay;
 pub use padding::Padding;
 pub use pixels::Pixels;
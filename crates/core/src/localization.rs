@@ -0,0 +1,540 @@
+//! Translate the user interface and switch languages at runtime.
+//!
+//! Translations are stored in [`Bundle`]s of messages written in a subset of
+//! the [Fluent] syntax:
+//!
+//! ```ftl
+//! # Comments start with a hash
+//! greeting = Hello, { $name }!
+//! inbox = You have { $count } new messages.
+//!     .tooltip = Open { inbox-title }
+//! inbox-title = Inbox
+//! ```
+//!
+//! Messages may span multiple indented lines, reference variables with
+//! `{ $variable }`, other messages with `{ message }` and their attributes
+//! with `{ message.attribute }`. Attributes are looked up as
+//! `message.attribute`.
+//!
+//! Built-in widgets translate their own strings (month names, menu roles,
+//! developer tools, etc.) through [`text`] and [`format`]. English, German,
+//! French and Arabic translations are included; applications can override
+//! them and add their own messages with [`add_bundle`]:
+//!
+//! ```rust,ignore
+//! use icy_ui_core::localization::{self, Bundle};
+//!
+//! localization::add_bundle(Bundle::parse("de", include_str!("../i18n/de.ftl"))?);
+//! localization::set_locale("de");
+//!
+//! assert_eq!(localization::text("menu-quit"), "Beenden");
+//! ```
+//!
+//! Applications should switch languages with the `localization::set_locale`
+//! task of the runtime, which also flips the layout direction for
+//! right-to-left locales and rebuilds every window.
+//!
+//! [Fluent]: https://projectfluent.org
+use crate::LayoutDirection;
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+/// The maximum depth of nested message references.
+const MAX_DEPTH: usize = 8;
+
+static STATE: RwLock<State> = RwLock::new(State {
+    locale: None,
+    bundles: Vec::new(),
+});
+
+#[derive(Debug)]
+struct State {
+    locale: Option<Locale>,
+    bundles: Vec<Bundle>,
+}
+
+/// Returns the current [`Locale`].
+pub fn locale() -> Locale {
+    STATE
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .locale
+        .clone()
+        .unwrap_or_default()
+}
+
+/// Sets the current [`Locale`].
+///
+/// This only changes the language used by subsequent lookups. Use the
+/// `localization::set_locale` task of the runtime to also update the layout
+/// direction and rebuild the user interface.
+pub fn set_locale(locale: impl Into<Locale>) {
+    STATE.write().unwrap_or_else(PoisonError::into_inner).locale = Some(locale.into());
+}
+
+/// Adds a [`Bundle`] of messages.
+///
+/// Messages of the [`Bundle`] take precedence over the built-in translations
+/// and over the messages previously added for the same [`Locale`].
+pub fn add_bundle(bundle: Bundle) {
+    let mut state = STATE.write().unwrap_or_else(PoisonError::into_inner);
+
+    if let Some(existing) = state
+        .bundles
+        .iter_mut()
+        .find(|existing| existing.locale == bundle.locale)
+    {
+        existing.messages.extend(bundle.messages);
+    } else {
+        state.bundles.push(bundle);
+    }
+}
+
+/// Returns the translation of the message with the given key in the current
+/// [`Locale`].
+///
+/// If the message is not translated, the English message is returned. If
+/// there is no such message at all, the key itself is returned.
+pub fn text(key: &str) -> String {
+    format(key, &[])
+}
+
+/// Returns the translation of the message with the given key in the current
+/// [`Locale`], replacing its variables with the given arguments.
+///
+/// Falls back like [`text`].
+pub fn format(key: &str, args: &[(&str, &dyn fmt::Display)]) -> String {
+    let state = STATE.read().unwrap_or_else(PoisonError::into_inner);
+    let locale = state.locale.clone().unwrap_or_default();
+
+    let english = Locale::default();
+
+    matching(&state.bundles, &locale)
+        .chain(matching(builtin(), &locale))
+        .chain(matching(&state.bundles, &english))
+        .chain(matching(builtin(), &english))
+        .find_map(|bundle| bundle.format(key, args))
+        .unwrap_or_else(|| key.to_owned())
+}
+
+/// Returns the [`Bundle`]s that can be used for the given [`Locale`].
+fn matching<'a>(bundles: &'a [Bundle], locale: &'a Locale) -> impl Iterator<Item = &'a Bundle> {
+    bundles
+        .iter()
+        .filter(move |bundle| bundle.locale.matches(locale))
+}
+
+/// Returns the built-in [`Bundle`]s.
+fn builtin() -> &'static [Bundle] {
+    static BUILTIN: OnceLock<Vec<Bundle>> = OnceLock::new();
+
+    BUILTIN.get_or_init(|| {
+        [
+            ("en", include_str!("localization/en.ftl")),
+            ("de", include_str!("localization/de.ftl")),
+            ("fr", include_str!("localization/fr.ftl")),
+            ("ar", include_str!("localization/ar.ftl")),
+        ]
+        .into_iter()
+        .map(|(locale, source)| {
+            Bundle::parse(locale, source).expect("Built-in translations should be valid")
+        })
+        .collect()
+    })
+}
+
+/// A language, optionally specialized for a region.
+///
+/// Locales are identified by a [BCP 47] language tag, like `en`, `de-CH` or
+/// `ar-EG`.
+///
+/// [BCP 47]: https://www.rfc-editor.org/info/bcp47
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Locale(Cow<'static, str>);
+
+impl Locale {
+    /// Creates a new [`Locale`] from a language tag.
+    ///
+    /// Underscores are accepted as separators, so POSIX locales like `fr_CA`
+    /// work too.
+    pub fn new(tag: impl Into<Cow<'static, str>>) -> Self {
+        let tag = tag.into();
+
+        if tag.contains('_') {
+            Self(Cow::Owned(tag.replace('_', "-")))
+        } else {
+            Self(tag)
+        }
+    }
+
+    /// Returns the language tag of the [`Locale`].
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the language of the [`Locale`], without its region.
+    pub fn language(&self) -> &str {
+        self.0.split('-').next().unwrap_or_default()
+    }
+
+    /// Returns the [`LayoutDirection`] of the script of the [`Locale`].
+    pub fn layout_direction(&self) -> LayoutDirection {
+        const RTL_LANGUAGES: &[&str] = &[
+            "ar", "arc", "ckb", "dv", "fa", "he", "iw", "ks", "ps", "sd", "ug", "ur", "yi",
+        ];
+
+        let language = self.language();
+
+        if RTL_LANGUAGES
+            .iter()
+            .any(|rtl| rtl.eq_ignore_ascii_case(language))
+        {
+            LayoutDirection::Rtl
+        } else {
+            LayoutDirection::Ltr
+        }
+    }
+
+    /// Returns whether messages of this [`Locale`] can be used for the
+    /// `requested` one; that is, if they are the same or this [`Locale`] is
+    /// the language of the `requested` one.
+    fn matches(&self, requested: &Locale) -> bool {
+        self.as_str().eq_ignore_ascii_case(requested.as_str())
+            || self.as_str().eq_ignore_ascii_case(requested.language())
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self(Cow::Borrowed("en"))
+    }
+}
+
+impl From<&'static str> for Locale {
+    fn from(tag: &'static str) -> Self {
+        Self::new(tag)
+    }
+}
+
+impl From<String> for Locale {
+    fn from(tag: String) -> Self {
+        Self::new(tag)
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// The messages of a [`Locale`].
+#[derive(Debug, Clone, Default)]
+pub struct Bundle {
+    locale: Locale,
+    messages: HashMap<String, Vec<Element>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Element {
+    Text(String),
+    Variable(String),
+    Message(String),
+}
+
+impl Bundle {
+    /// Creates an empty [`Bundle`] for the given [`Locale`].
+    pub fn new(locale: impl Into<Locale>) -> Self {
+        Self {
+            locale: locale.into(),
+            messages: HashMap::new(),
+        }
+    }
+
+    /// Parses a [`Bundle`] for the given [`Locale`] from the given source,
+    /// written in a subset of the Fluent syntax.
+    pub fn parse(locale: impl Into<Locale>, source: &str) -> Result<Self, Error> {
+        let mut bundle = Self::new(locale);
+        let mut current: Option<(String, String, usize)> = None;
+        let mut message: Option<String> = None;
+
+        for (i, line) in source.lines().enumerate() {
+            let number = i + 1;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            if line.starts_with('#') {
+                bundle.flush(current.take())?;
+                continue;
+            }
+
+            let is_indented = line.starts_with([' ', '\t']);
+            let trimmed = line.trim();
+
+            if is_indented && let Some(attribute) = trimmed.strip_prefix('.') {
+                let Some(id) = &message else {
+                    return Err(Error::syntax(number, "attribute without a message"));
+                };
+
+                let (name, value) = split_entry(attribute, number)?;
+
+                bundle.flush(current.take())?;
+                current = Some((format!("{id}.{name}"), value.to_owned(), number));
+            } else if is_indented {
+                let Some((_, value, _)) = &mut current else {
+                    return Err(Error::syntax(number, "indented text without a message"));
+                };
+
+                if !value.is_empty() {
+                    value.push('\n');
+                }
+
+                value.push_str(trimmed);
+            } else {
+                let (id, value) = split_entry(line, number)?;
+
+                bundle.flush(current.take())?;
+                message = Some(id.to_owned());
+                current = Some((id.to_owned(), value.to_owned(), number));
+            }
+        }
+
+        bundle.flush(current)?;
+
+        Ok(bundle)
+    }
+
+    /// Returns the [`Locale`] of the [`Bundle`].
+    pub fn locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    /// Returns whether the [`Bundle`] has a message with the given key.
+    pub fn contains(&self, key: &str) -> bool {
+        self.messages.contains_key(key)
+    }
+
+    /// Formats the message with the given key of the [`Bundle`], replacing
+    /// its variables with the given arguments.
+    ///
+    /// Unknown variables are kept as `{$variable}`.
+    pub fn format(&self, key: &str, args: &[(&str, &dyn fmt::Display)]) -> Option<String> {
+        let mut output = String::new();
+
+        self.write(key, args, &mut output, 0).then_some(output)
+    }
+
+    fn write(
+        &self,
+        key: &str,
+        args: &[(&str, &dyn fmt::Display)],
+        output: &mut String,
+        depth: usize,
+    ) -> bool {
+        use std::fmt::Write as _;
+
+        let Some(pattern) = self.messages.get(key) else {
+            return false;
+        };
+
+        if depth > MAX_DEPTH {
+            return false;
+        }
+
+        for element in pattern {
+            match element {
+                Element::Text(text) => output.push_str(text),
+                Element::Variable(name) => match args.iter().find(|(arg, _)| arg == name) {
+                    Some((_, value)) => {
+                        let _ = write!(output, "{value}");
+                    }
+                    None => {
+                        let _ = write!(output, "{{${name}}}");
+                    }
+                },
+                Element::Message(reference) => {
+                    if !self.write(reference, args, output, depth + 1) {
+                        let _ = write!(output, "{{{reference}}}");
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
+    fn flush(&mut self, entry: Option<(String, String, usize)>) -> Result<(), Error> {
+        if let Some((key, value, line)) = entry {
+            let pattern = parse_pattern(&value, line)?;
+
+            let _ = self.messages.insert(key, pattern);
+        }
+
+        Ok(())
+    }
+}
+
+/// An error produced while parsing a [`Bundle`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum Error {
+    /// The source is not valid.
+    #[error("invalid message on line {line}: {reason}")]
+    Syntax {
+        /// The line of the source where the error was found.
+        line: usize,
+        /// The description of the error.
+        reason: &'static str,
+    },
+}
+
+impl Error {
+    fn syntax(line: usize, reason: &'static str) -> Self {
+        Self::Syntax { line, reason }
+    }
+}
+
+fn split_entry(line: &str, number: usize) -> Result<(&str, &str), Error> {
+    let Some((id, value)) = line.split_once('=') else {
+        return Err(Error::syntax(number, "expected `identifier = value`"));
+    };
+
+    let id = id.trim();
+
+    if !is_identifier(id) {
+        return Err(Error::syntax(number, "invalid identifier"));
+    }
+
+    Ok((id, value.trim()))
+}
+
+fn is_identifier(id: &str) -> bool {
+    let mut chars = id.chars();
+
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn parse_pattern(value: &str, line: usize) -> Result<Vec<Element>, Error> {
+    let mut pattern = Vec::new();
+    let mut rest = value;
+
+    while let Some(start) = rest.find('{') {
+        if start > 0 {
+            pattern.push(Element::Text(rest[..start].to_owned()));
+        }
+
+        let inner = &rest[start + 1..];
+
+        // String literals may contain braces
+        let end = match inner.trim_start().strip_prefix('"') {
+            Some(literal) => literal.find('"').and_then(|quote| {
+                let after = inner.len() - literal.len() + quote + 1;
+
+                inner[after..].find('}').map(|end| after + end)
+            }),
+            None => inner.find('}'),
+        };
+
+        let Some(end) = end else {
+            return Err(Error::syntax(line, "unclosed placeable"));
+        };
+
+        let placeable = inner[..end].trim();
+
+        let element = if let Some(name) = placeable.strip_prefix('$') {
+            if !is_identifier(name) {
+                return Err(Error::syntax(line, "invalid variable"));
+            }
+
+            Element::Variable(name.to_owned())
+        } else if let Some(literal) = placeable
+            .strip_prefix('"')
+            .and_then(|literal| literal.strip_suffix('"'))
+        {
+            Element::Text(literal.to_owned())
+        } else if placeable.split('.').all(is_identifier) && placeable.split('.').count() <= 2 {
+            Element::Message(placeable.to_owned())
+        } else {
+            return Err(Error::syntax(line, "unsupported placeable"));
+        };
+
+        pattern.push(element);
+        rest = &inner[end + 1..];
+    }
+
+    if !rest.is_empty() {
+        pattern.push(Element::Text(rest.to_owned()));
+    }
+
+    Ok(pattern)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_formats_messages() {
+        let bundle = Bundle::parse(
+            "en",
+            "# Messages
+inbox = You have { $count } new messages in your { inbox-title }.
+    .tooltip = Open { inbox-title }
+inbox-title = Inbox
+braces = { \"{\" }literal{ \"}\" }
+long =
+    First line
+    second line
+",
+        )
+        .expect("Bundle should parse");
+
+        assert_eq!(
+            bundle.format("inbox", &[("count", &3)]).as_deref(),
+            Some("You have 3 new messages in your Inbox.")
+        );
+        assert_eq!(
+            bundle.format("inbox.tooltip", &[]).as_deref(),
+            Some("Open Inbox")
+        );
+        assert_eq!(bundle.format("braces", &[]).as_deref(), Some("{literal}"));
+        assert_eq!(
+            bundle.format("long", &[]).as_deref(),
+            Some("First line\nsecond line")
+        );
+        assert_eq!(bundle.format("missing", &[]), None);
+
+        assert_eq!(
+            Bundle::parse("en", "broken = { $oops").unwrap_err(),
+            Error::Syntax {
+                line: 1,
+                reason: "unclosed placeable"
+            }
+        );
+    }
+
+    #[test]
+    fn resolves_locales_and_directions() {
+        assert_eq!(Locale::new("fr_CA").as_str(), "fr-CA");
+        assert_eq!(Locale::new("fr-CA").language(), "fr");
+
+        assert!(Locale::new("ar-EG").layout_direction().is_rtl());
+        assert!(Locale::new("de").layout_direction().is_ltr());
+
+        assert!(Locale::new("de").matches(&Locale::new("de-AT")));
+        assert!(!Locale::new("de-AT").matches(&Locale::new("de")));
+
+        for bundle in builtin() {
+            assert!(
+                builtin()[0].messages.keys().all(|key| bundle.contains(key)),
+                "{} translation is incomplete",
+                bundle.locale()
+            );
+        }
+    }
+}
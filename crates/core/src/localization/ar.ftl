@@ -0,0 +1,63 @@
+# الترجمة العربية للرسائل المدمجة في icy_ui.
+
+## منتقي التاريخ
+
+date-picker-january = يناير
+date-picker-february = فبراير
+date-picker-march = مارس
+date-picker-april = أبريل
+date-picker-may = مايو
+date-picker-june = يونيو
+date-picker-july = يوليو
+date-picker-august = أغسطس
+date-picker-september = سبتمبر
+date-picker-october = أكتوبر
+date-picker-november = نوفمبر
+date-picker-december = ديسمبر
+date-picker-monday = إثنين
+date-picker-tuesday = ثلاثاء
+date-picker-wednesday = أربعاء
+date-picker-thursday = خميس
+date-picker-friday = جمعة
+date-picker-saturday = سبت
+date-picker-sunday = أحد
+date-picker-month-year = { $month } { $year }
+
+## القوائم
+
+menu-quit = إنهاء
+
+## أدوات المطورين
+
+devtools-open-metrics = اضغط F12 لفتح مقاييس التصحيح
+devtools-hotpatch-stale = تغيرت الأنواع. أعد التشغيل لإعادة تفعيل التصحيح الفوري.
+devtools-cancel = إلغاء
+devtools-install = تثبيت
+devtools-update = تحديث
+devtools-comet-missing = comet غير مثبت!
+devtools-comet-missing-description = لعرض مقاييس الأداء، يجب تثبيت مصحح comet على نظامك.
+devtools-comet-about = مصحح comet أداة رسمية مرافقة تساعدك على تصحيح تطبيقات iced.
+devtools-comet-install-prompt = هل تريد تثبيته باستخدام الأمر التالي؟
+devtools-comet-outdated = comet قديم!
+devtools-comet-update-prompt = هل تريد تحديثه باستخدام الأمر التالي؟
+devtools-installed-revision = المراجعة المثبتة:
+devtools-compatible-revision = المراجعة المتوافقة:
+devtools-unknown-revision = غير معروفة
+devtools-installing = جارٍ تثبيت comet...
+
+## أداة الاختبار
+
+tester-idle = خامل
+tester-recording = تسجيل
+tester-asserting = تحقق
+tester-playing = تشغيل
+tester-failed = فشل
+tester-success = نجاح
+tester-width = العرض
+tester-height = الارتفاع
+tester-default-preset = افتراضي
+tester-no-instructions = لم تُسجَّل أي تعليمات بعد!
+tester-viewport = منطقة العرض
+tester-mode = الوضع
+tester-preset = الإعداد المسبق
+tester-instructions = التعليمات
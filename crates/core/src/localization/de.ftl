@@ -0,0 +1,63 @@
+# Deutsche Übersetzung der eingebauten Meldungen von icy_ui.
+
+## Datumsauswahl
+
+date-picker-january = Januar
+date-picker-february = Februar
+date-picker-march = März
+date-picker-april = April
+date-picker-may = Mai
+date-picker-june = Juni
+date-picker-july = Juli
+date-picker-august = August
+date-picker-september = September
+date-picker-october = Oktober
+date-picker-november = November
+date-picker-december = Dezember
+date-picker-monday = Mo
+date-picker-tuesday = Di
+date-picker-wednesday = Mi
+date-picker-thursday = Do
+date-picker-friday = Fr
+date-picker-saturday = Sa
+date-picker-sunday = So
+date-picker-month-year = { $month } { $year }
+
+## Menüs
+
+menu-quit = Beenden
+
+## Entwicklerwerkzeuge
+
+devtools-open-metrics = F12 drücken, um die Debug-Metriken zu öffnen
+devtools-hotpatch-stale = Typen haben sich geändert. Neu starten, um Hotpatching wieder zu aktivieren.
+devtools-cancel = Abbrechen
+devtools-install = Installieren
+devtools-update = Aktualisieren
+devtools-comet-missing = comet ist nicht installiert!
+devtools-comet-missing-description = Um Leistungsmetriken anzuzeigen, muss der comet-Debugger auf Ihrem System installiert sein.
+devtools-comet-about = Der comet-Debugger ist ein offizielles Begleitwerkzeug, das Ihnen beim Debuggen Ihrer iced-Anwendungen hilft.
+devtools-comet-install-prompt = Möchten Sie ihn mit dem folgenden Befehl installieren?
+devtools-comet-outdated = comet ist veraltet!
+devtools-comet-update-prompt = Möchten Sie ihn mit dem folgenden Befehl aktualisieren?
+devtools-installed-revision = Installierte Revision:
+devtools-compatible-revision = Kompatible Revision:
+devtools-unknown-revision = Unbekannt
+devtools-installing = comet wird installiert...
+
+## Tester
+
+tester-idle = Bereit
+tester-recording = Aufnahme
+tester-asserting = Prüfung
+tester-playing = Wiedergabe
+tester-failed = Fehlgeschlagen
+tester-success = Erfolgreich
+tester-width = Breite
+tester-height = Höhe
+tester-default-preset = Standard
+tester-no-instructions = Noch keine Anweisungen aufgezeichnet!
+tester-viewport = Ansicht
+tester-mode = Modus
+tester-preset = Vorlage
+tester-instructions = Anweisungen
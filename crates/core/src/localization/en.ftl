@@ -0,0 +1,65 @@
+# Built-in messages of icy_ui.
+#
+# Every message must be translated in the other built-in bundles.
+
+## Date picker
+
+date-picker-january = January
+date-picker-february = February
+date-picker-march = March
+date-picker-april = April
+date-picker-may = May
+date-picker-june = June
+date-picker-july = July
+date-picker-august = August
+date-picker-september = September
+date-picker-october = October
+date-picker-november = November
+date-picker-december = December
+date-picker-monday = Mon
+date-picker-tuesday = Tue
+date-picker-wednesday = Wed
+date-picker-thursday = Thu
+date-picker-friday = Fri
+date-picker-saturday = Sat
+date-picker-sunday = Sun
+date-picker-month-year = { $month } { $year }
+
+## Menus
+
+menu-quit = Quit
+
+## Developer tools
+
+devtools-open-metrics = Press F12 to open debug metrics
+devtools-hotpatch-stale = Types have changed. Restart to re-enable hotpatching.
+devtools-cancel = Cancel
+devtools-install = Install
+devtools-update = Update
+devtools-comet-missing = comet is not installed!
+devtools-comet-missing-description = In order to display performance metrics, the comet debugger must be installed in your system.
+devtools-comet-about = The comet debugger is an official companion tool that helps you debug your iced applications.
+devtools-comet-install-prompt = Do you wish to install it with the following command?
+devtools-comet-outdated = comet is out of date!
+devtools-comet-update-prompt = Do you wish to update it with the following command?
+devtools-installed-revision = Installed revision:
+devtools-compatible-revision = Compatible revision:
+devtools-unknown-revision = Unknown
+devtools-installing = Installing comet...
+
+## Tester
+
+tester-idle = Idle
+tester-recording = Recording
+tester-asserting = Asserting
+tester-playing = Playing
+tester-failed = Failed
+tester-success = Success
+tester-width = Width
+tester-height = Height
+tester-default-preset = Default
+tester-no-instructions = No instructions recorded yet!
+tester-viewport = Viewport
+tester-mode = Mode
+tester-preset = Preset
+tester-instructions = Instructions
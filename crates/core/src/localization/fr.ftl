@@ -0,0 +1,63 @@
+# Traduction française des messages intégrés d'icy_ui.
+
+## Sélecteur de date
+
+date-picker-january = janvier
+date-picker-february = février
+date-picker-march = mars
+date-picker-april = avril
+date-picker-may = mai
+date-picker-june = juin
+date-picker-july = juillet
+date-picker-august = août
+date-picker-september = septembre
+date-picker-october = octobre
+date-picker-november = novembre
+date-picker-december = décembre
+date-picker-monday = lun.
+date-picker-tuesday = mar.
+date-picker-wednesday = mer.
+date-picker-thursday = jeu.
+date-picker-friday = ven.
+date-picker-saturday = sam.
+date-picker-sunday = dim.
+date-picker-month-year = { $month } { $year }
+
+## Menus
+
+menu-quit = Quitter
+
+## Outils de développement
+
+devtools-open-metrics = Appuyez sur F12 pour ouvrir les métriques de débogage
+devtools-hotpatch-stale = Les types ont changé. Redémarrez pour réactiver le hotpatching.
+devtools-cancel = Annuler
+devtools-install = Installer
+devtools-update = Mettre à jour
+devtools-comet-missing = comet n'est pas installé !
+devtools-comet-missing-description = Pour afficher les métriques de performance, le débogueur comet doit être installé sur votre système.
+devtools-comet-about = Le débogueur comet est un outil compagnon officiel qui vous aide à déboguer vos applications iced.
+devtools-comet-install-prompt = Voulez-vous l'installer avec la commande suivante ?
+devtools-comet-outdated = comet n'est pas à jour !
+devtools-comet-update-prompt = Voulez-vous le mettre à jour avec la commande suivante ?
+devtools-installed-revision = Révision installée :
+devtools-compatible-revision = Révision compatible :
+devtools-unknown-revision = Inconnue
+devtools-installing = Installation de comet...
+
+## Testeur
+
+tester-idle = Inactif
+tester-recording = Enregistrement
+tester-asserting = Vérification
+tester-playing = Lecture
+tester-failed = Échec
+tester-success = Réussite
+tester-width = Largeur
+tester-height = Hauteur
+tester-default-preset = Par défaut
+tester-no-instructions = Aucune instruction enregistrée pour le moment !
+tester-viewport = Fenêtre
+tester-mode = Mode
+tester-preset = Préréglage
+tester-instructions = Instructions
@@ -675,6 +675,8 @@ impl<Message> MenuNode<Message> {
 
     /// Creates a "Quit" menu item with [`MenuRole::Quit`].
     ///
+    /// The label is translated into the current [`Locale`](crate::Locale).
+    ///
     /// On macOS this will be relocated to the application menu with ⌘Q.
     /// On other platforms, Ctrl+Q is used as the default shortcut.
     #[must_use]
@@ -683,7 +685,7 @@ impl<Message> MenuNode<Message> {
             id: MenuId::from_str("app.quit"),
             role: MenuRole::Quit,
             kind: MenuKind::Item {
-                label: "Quit".into(),
+                label: crate::localization::text("menu-quit"),
                 enabled: true,
                 shortcut: Some(MenuShortcut::cmd(keyboard::Key::Character("q".into()))),
                 on_activate,
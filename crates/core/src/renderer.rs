@@ -2,9 +2,11 @@
 #[cfg(debug_assertions)]
 mod null;
 
+use crate::border;
 use crate::image;
 use crate::{
-    Background, Border, Color, Font, Pixels, Rectangle, Shadow, Size, Transformation, Vector,
+    Backdrop, Background, Border, Color, Font, Pixels, Rectangle, Shadow, Size, Transformation,
+    Vector,
 };
 
 /// Whether anti-aliasing should be avoided by snapping primitive coordinates to the
@@ -30,6 +32,75 @@ pub trait Renderer {
         self.end_layer();
     }
 
+    /// Starts recording a new layer that blurs and tints whatever was drawn
+    /// behind it, as described by the given [`Backdrop`].
+    ///
+    /// The blurred `area` is a rectangle with the given `border_radius`. Like
+    /// any other layer, the new layer clips its contents (and the blurred
+    /// `area`) to the provided `bounds` and must be ended with
+    /// [`end_layer`](Self::end_layer).
+    ///
+    /// By default, the [`Backdrop`] is ignored and a regular layer is started.
+    fn start_backdrop_layer(
+        &mut self,
+        bounds: Rectangle,
+        area: Rectangle,
+        border_radius: border::Radius,
+        backdrop: Backdrop,
+    ) {
+        let _ = (area, border_radius, backdrop);
+
+        self.start_layer(bounds);
+    }
+
+    /// Draws the primitives recorded in the given closure in a new layer with
+    /// a [`Backdrop`].
+    ///
+    /// See [`start_backdrop_layer`](Self::start_backdrop_layer) for details.
+    fn with_backdrop_layer(
+        &mut self,
+        bounds: Rectangle,
+        area: Rectangle,
+        border_radius: border::Radius,
+        backdrop: Backdrop,
+        f: impl FnOnce(&mut Self),
+    ) {
+        self.start_backdrop_layer(bounds, area, border_radius, backdrop);
+        f(self);
+        self.end_layer();
+    }
+
+    /// Starts recording a new layer that is composited as a whole with what
+    /// was drawn behind it, as described by the given [`LayerStyle`].
+    ///
+    /// The contents of the layer, including any nested layers, are first
+    /// rendered offscreen and then blended using the opacity and
+    /// [`BlendMode`] of the [`LayerStyle`]. The new layer will clip its
+    /// contents to the provided `bounds` and must be ended with
+    /// [`end_layer`](Self::end_layer).
+    ///
+    /// By default, the [`LayerStyle`] is ignored and a regular layer is started.
+    fn start_layer_with(&mut self, bounds: Rectangle, style: LayerStyle) {
+        let _ = style;
+
+        self.start_layer(bounds);
+    }
+
+    /// Draws the primitives recorded in the given closure in a new layer with
+    /// the given [`LayerStyle`].
+    ///
+    /// See [`start_layer_with`](Self::start_layer_with) for details.
+    fn with_layer_style(
+        &mut self,
+        bounds: Rectangle,
+        style: LayerStyle,
+        f: impl FnOnce(&mut Self),
+    ) {
+        self.start_layer_with(bounds, style);
+        f(self);
+        self.end_layer();
+    }
+
     /// Starts recording with a new [`Transformation`].
     fn start_transformation(&mut self, transformation: Transformation);
 
@@ -109,6 +180,65 @@ impl Default for Quad {
     }
 }
 
+/// The compositing style of a layer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayerStyle {
+    /// The opacity of the layer, from `0.0` (invisible) to `1.0` (opaque).
+    pub opacity: f32,
+
+    /// The [`BlendMode`] used to composite the layer.
+    pub blend_mode: BlendMode,
+}
+
+impl LayerStyle {
+    /// Creates a new [`LayerStyle`] with the given opacity.
+    pub fn opacity(opacity: f32) -> Self {
+        Self {
+            opacity,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the [`BlendMode`] of the [`LayerStyle`].
+    pub fn blend_mode(self, blend_mode: BlendMode) -> Self {
+        Self { blend_mode, ..self }
+    }
+
+    /// Returns whether the [`LayerStyle`] leaves the contents of a layer
+    /// untouched.
+    pub fn is_identity(&self) -> bool {
+        self.opacity >= 1.0 && self.blend_mode == BlendMode::Normal
+    }
+}
+
+impl Default for LayerStyle {
+    fn default() -> Self {
+        Self {
+            opacity: 1.0,
+            blend_mode: BlendMode::Normal,
+        }
+    }
+}
+
+/// The way the colors of a layer are combined with the colors behind it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BlendMode {
+    /// The layer is drawn over what is behind it.
+    #[default]
+    Normal,
+
+    /// The colors are multiplied, which always results in a darker color.
+    Multiply,
+
+    /// The inverted colors are multiplied, which always results in a
+    /// lighter color.
+    Screen,
+
+    /// Multiplies dark backgrounds and screens light backgrounds, increasing
+    /// the contrast of what is behind the layer.
+    Overlay,
+}
+
 /// The styling attributes of a [`Renderer`].
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Style {
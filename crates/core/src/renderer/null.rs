@@ -129,10 +129,30 @@ impl text::Paragraph for () {
         text::Shaping::default()
     }
 
+    fn truncation(&self) -> text::Truncation {
+        text::Truncation::default()
+    }
+
+    fn is_truncated(&self) -> bool {
+        false
+    }
+
     fn grapheme_position(&self, _line: usize, _index: usize) -> Option<Point> {
         None
     }
 
+    fn hit_offset(&self, _point: Point) -> Option<usize> {
+        None
+    }
+
+    fn range_bounds(&self, _range: std::ops::Range<usize>) -> Vec<Rectangle> {
+        Vec::new()
+    }
+
+    fn visual_clusters(&self, _line: usize) -> Vec<text::bidi::Cluster> {
+        Vec::new()
+    }
+
     fn bounds(&self) -> Size {
         Size::ZERO
     }
@@ -174,6 +194,10 @@ impl text::Editor for () {
         text::editor::Selection::Caret(Point::ORIGIN)
     }
 
+    fn split_caret(&self) -> Option<Point> {
+        None
+    }
+
     fn copy(&self) -> Option<String> {
         None
     }
@@ -11,4 +11,16 @@ pub struct Shadow {
 
     /// The blur radius of the shadow.
     pub blur_radius: f32,
+
+    /// The spread radius of the shadow.
+    ///
+    /// Positive values grow the shadow in every direction, while negative
+    /// values shrink it.
+    pub spread: f32,
+
+    /// Whether the shadow is drawn inside the shape instead of below it.
+    ///
+    /// Inset shadows are drawn above the background and inside the border,
+    /// like a carved-in surface.
+    pub inset: bool,
 }
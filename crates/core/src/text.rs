@@ -1,11 +1,15 @@
 //! Draw and interact with text.
+pub mod bidi;
+pub mod document;
 pub mod editor;
 pub mod highlighter;
 pub mod paragraph;
+pub mod selection;
 
 pub use editor::Editor;
 pub use highlighter::Highlighter;
 pub use paragraph::Paragraph;
+pub use selection::Selection;
 
 use crate::alignment;
 use crate::{Background, Border, Color, Padding, Pixels, Point, Rectangle, Size};
@@ -43,6 +47,9 @@ pub struct Text<Content = String, Font = crate::Font> {
     /// The [`Wrapping`] strategy of the [`Text`].
     pub wrapping: Wrapping,
 
+    /// The [`Truncation`] strategy of the [`Text`].
+    pub truncation: Truncation,
+
     /// The scale factor that may be used to internally scale the layout
     /// calculation of the [`Paragraph`] and leverage metrics hinting.
     ///
@@ -71,6 +78,7 @@ where
             align_y: self.align_y,
             shaping: self.shaping,
             wrapping: self.wrapping,
+            truncation: self.truncation,
             hint_factor: self.hint_factor,
         }
     }
@@ -221,6 +229,50 @@ pub enum Wrapping {
     WordOrGlyph,
 }
 
+/// The truncation strategy of some text that does not fit its bounds.
+///
+/// Truncated text is laid out in a single line per paragraph, ignoring
+/// its [`Wrapping`] strategy.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Truncation {
+    /// No truncation; the text is wrapped and clipped.
+    ///
+    /// This is the default.
+    #[default]
+    None,
+    /// Replaces the end of the text with an ellipsis.
+    End,
+    /// Replaces the start of the text with an ellipsis.
+    Start,
+    /// Replaces the middle of the text with an ellipsis, keeping both
+    /// ends visible.
+    ///
+    /// Useful for file paths.
+    Middle,
+    /// Fades out the end of the text over the given distance.
+    ///
+    /// The fade is drawn by the `text` and `rich_text` widgets; text filled
+    /// directly with [`Renderer::fill_text`] is clipped instead.
+    Fade(Pixels),
+}
+
+impl Truncation {
+    /// Returns true if the [`Truncation`] replaces text with an ellipsis.
+    pub fn is_ellipsis(self) -> bool {
+        matches!(self, Self::End | Self::Start | Self::Middle)
+    }
+}
+
+impl Hash for Truncation {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+
+        if let Self::Fade(distance) = self {
+            distance.0.to_bits().hash(state);
+        }
+    }
+}
+
 /// The height of a line of text in a paragraph.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LineHeight {
@@ -0,0 +1,212 @@
+//! Move the cursor and highlight selections in bidirectional text.
+//!
+//! Text mixing left-to-right and right-to-left scripts is stored in logical
+//! order, but displayed in visual order. A single logical cursor index can
+//! therefore sit at two different places on screen, and a contiguous logical
+//! selection can cover several disjoint visual runs.
+//!
+//! The functions in this module work on the [`Cluster`]s of a single visual
+//! line, as returned by [`Paragraph::visual_clusters`].
+//!
+//! [`Paragraph::visual_clusters`]: crate::text::Paragraph::visual_clusters
+use crate::text::editor::Direction;
+
+use std::ops::Range;
+
+/// A cluster of characters laid out in a visual line.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cluster {
+    /// The logical range of the [`Cluster`] in the text.
+    pub range: Range<usize>,
+    /// The horizontal position of the left edge of the [`Cluster`].
+    pub x: f32,
+    /// The width of the [`Cluster`].
+    pub width: f32,
+    /// Whether the [`Cluster`] is part of a right-to-left run.
+    pub is_rtl: bool,
+}
+
+impl Cluster {
+    /// Returns the horizontal position of the edge of the [`Cluster`] where
+    /// its text begins.
+    pub fn leading_edge(&self) -> f32 {
+        if self.is_rtl {
+            self.x + self.width
+        } else {
+            self.x
+        }
+    }
+
+    /// Returns the horizontal position of the edge of the [`Cluster`] where
+    /// its text ends.
+    pub fn trailing_edge(&self) -> f32 {
+        if self.is_rtl {
+            self.x
+        } else {
+            self.x + self.width
+        }
+    }
+}
+
+/// Returns whether any of the given [`Cluster`]s is right-to-left.
+pub fn is_mixed(clusters: &[Cluster]) -> bool {
+    clusters.iter().any(|cluster| cluster.is_rtl)
+}
+
+/// Moves the logical cursor `index` one visual step in the given [`Direction`]
+/// over the [`Cluster`]s of a line, which must be sorted in visual order.
+///
+/// Returns `None` if the cursor is already at the visual edge of the line.
+pub fn move_visually(clusters: &[Cluster], index: usize, direction: Direction) -> Option<usize> {
+    let slot = slot(clusters, index)?;
+
+    match direction {
+        Direction::Left => (0..slot)
+            .rev()
+            .map(|slot| logical_index(clusters, slot))
+            .find(|candidate| *candidate != index),
+        Direction::Right => (slot + 1..=clusters.len())
+            .map(|slot| logical_index(clusters, slot))
+            .find(|candidate| *candidate != index),
+    }
+}
+
+/// Returns the horizontal positions of the caret at the logical `index`.
+///
+/// The first position is the primary caret, placed at the leading edge of
+/// the character following the cursor. The second one is only present at a
+/// direction boundary, and points at the trailing edge of the character
+/// preceding the cursor.
+///
+/// Returns `None` if the line has no [`Cluster`]s.
+pub fn caret_positions(clusters: &[Cluster], index: usize) -> Option<(f32, Option<f32>)> {
+    let next = clusters.iter().find(|cluster| cluster.range.start == index);
+    let previous = clusters.iter().find(|cluster| cluster.range.end == index);
+
+    match (previous, next) {
+        (Some(previous), Some(next)) => {
+            let primary = next.leading_edge();
+            let secondary = previous.trailing_edge();
+
+            let is_split =
+                previous.is_rtl != next.is_rtl && (primary - secondary).abs() > f32::EPSILON;
+
+            Some((primary, is_split.then_some(secondary)))
+        }
+        (None, Some(next)) => Some((next.leading_edge(), None)),
+        (Some(previous), None) => Some((previous.trailing_edge(), None)),
+        (None, None) => None,
+    }
+}
+
+/// Returns the horizontal spans, as `(x, width)` pairs, covered by the given
+/// logical `range` in a line.
+///
+/// Adjacent [`Cluster`]s are merged into a single span, so a selection inside
+/// a single direction run produces a single span.
+pub fn selection_spans(clusters: &[Cluster], range: Range<usize>) -> Vec<(f32, f32)> {
+    let mut spans: Vec<(f32, f32)> = Vec::new();
+
+    for cluster in clusters
+        .iter()
+        .filter(|cluster| cluster.range.start < range.end && cluster.range.end > range.start)
+    {
+        match spans.last_mut() {
+            Some((x, width)) if (*x + *width - cluster.x).abs() < 0.5 => {
+                *width = cluster.x + cluster.width - *x;
+            }
+            _ => spans.push((cluster.x, cluster.width)),
+        }
+    }
+
+    spans
+}
+
+/// Returns the visual slot of the caret at the logical `index`.
+///
+/// Slot `k` is the left edge of the `k`-th cluster, while the last slot is the
+/// right edge of the last cluster.
+fn slot(clusters: &[Cluster], index: usize) -> Option<usize> {
+    if let Some(position) = clusters
+        .iter()
+        .position(|cluster| cluster.range.start == index)
+    {
+        return Some(position + usize::from(clusters[position].is_rtl));
+    }
+
+    let position = clusters
+        .iter()
+        .position(|cluster| cluster.range.end == index)?;
+
+    Some(position + usize::from(!clusters[position].is_rtl))
+}
+
+/// Returns the logical index of the caret at the given visual slot.
+fn logical_index(clusters: &[Cluster], slot: usize) -> usize {
+    match clusters.get(slot) {
+        Some(cluster) if cluster.is_rtl => cluster.range.end,
+        Some(cluster) => cluster.range.start,
+        None => {
+            let last = &clusters[slot - 1];
+
+            if last.is_rtl {
+                last.range.start
+            } else {
+                last.range.end
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `abc` followed by three right-to-left characters, displayed as
+    /// `a b c ג ב א`.
+    fn mixed() -> Vec<Cluster> {
+        let ltr = (0..3).map(|i| (i..i + 1, false));
+        let rtl = (3..6).rev().map(|i| (i..i + 1, true));
+
+        ltr.chain(rtl)
+            .enumerate()
+            .map(|(position, (range, is_rtl))| Cluster {
+                range,
+                x: position as f32 * 10.0,
+                width: 10.0,
+                is_rtl,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn moves_visually_across_direction_runs() {
+        let clusters = mixed();
+
+        let mut index = 0;
+        let mut visited = vec![index];
+
+        while let Some(next) = move_visually(&clusters, index, Direction::Right) {
+            index = next;
+            visited.push(index);
+        }
+
+        assert_eq!(visited, [0, 1, 2, 6, 5, 4, 3]);
+        assert_eq!(move_visually(&clusters, 3, Direction::Left), Some(4));
+        assert_eq!(move_visually(&clusters, 0, Direction::Left), None);
+    }
+
+    #[test]
+    fn splits_caret_and_selection_at_direction_boundaries() {
+        let clusters = mixed();
+
+        assert_eq!(caret_positions(&clusters, 1), Some((10.0, None)));
+        assert_eq!(caret_positions(&clusters, 3), Some((60.0, Some(30.0))));
+
+        assert_eq!(selection_spans(&clusters, 1..3), [(10.0, 20.0)]);
+        assert_eq!(
+            selection_spans(&clusters, 2..4),
+            [(20.0, 10.0), (50.0, 10.0)]
+        );
+    }
+}
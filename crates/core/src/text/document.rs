@@ -0,0 +1,712 @@
+//! Edit rich text documents and convert them from and to HTML and RTF.
+//!
+//! A [`Document`] is a list of [`Block`]s (paragraphs, headings and list
+//! items) made of styled [`Run`]s of text. Inline images are runs holding a
+//! single [`OBJECT_REPLACEMENT`] character, so every [`Position`] in a
+//! [`Document`] is a byte offset into the text of one of its blocks.
+mod html;
+mod rtf;
+
+use crate::Color;
+
+use std::ops::Range;
+
+/// The character standing for an inline [`Image`] in the text of a [`Block`].
+pub const OBJECT_REPLACEMENT: char = '\u{FFFC}';
+
+/// A rich text document.
+///
+/// A [`Document`] always contains at least one [`Block`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Document {
+    blocks: Vec<Block>,
+}
+
+impl Document {
+    /// Creates an empty [`Document`].
+    pub fn new() -> Self {
+        Self {
+            blocks: vec![Block::default()],
+        }
+    }
+
+    /// Creates a [`Document`] with the given plain text, one paragraph per
+    /// line.
+    pub fn with_text(text: &str) -> Self {
+        let mut document = Self::new();
+        let _ = document.insert(Position::default(), text, &Style::default());
+
+        document
+    }
+
+    /// Parses a [`Document`] from HTML.
+    ///
+    /// Unsupported markup is ignored, keeping only its text.
+    pub fn from_html(html: &str) -> Self {
+        html::parse(html)
+    }
+
+    /// Serializes the [`Document`] as an HTML fragment.
+    pub fn to_html(&self) -> String {
+        html::write(self)
+    }
+
+    /// Parses a [`Document`] from RTF.
+    ///
+    /// Unsupported control words are ignored, keeping only their text.
+    pub fn from_rtf(rtf: &str) -> Self {
+        rtf::parse(rtf)
+    }
+
+    /// Serializes the [`Document`] as RTF.
+    ///
+    /// Images are written as `INCLUDEPICTURE` fields pointing at their
+    /// source, since their data is not part of the [`Document`].
+    pub fn to_rtf(&self) -> String {
+        rtf::write(self)
+    }
+
+    /// Returns the [`Block`]s of the [`Document`].
+    pub fn blocks(&self) -> &[Block] {
+        &self.blocks
+    }
+
+    /// Returns whether the [`Document`] contains no text at all.
+    pub fn is_empty(&self) -> bool {
+        self.blocks.iter().all(Block::is_empty)
+    }
+
+    /// Returns the plain text of the [`Document`], one line per [`Block`].
+    ///
+    /// Images are replaced by their alternative text.
+    pub fn text(&self) -> String {
+        self.blocks
+            .iter()
+            .map(|block| {
+                block
+                    .runs
+                    .iter()
+                    .map(|run| match &run.image {
+                        Some(image) => image.alt.as_str(),
+                        None => run.text.as_str(),
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Returns the [`Position`] at the end of the [`Document`].
+    pub fn end(&self) -> Position {
+        let block = self.blocks.len() - 1;
+
+        Position {
+            block,
+            offset: self.blocks[block].len(),
+        }
+    }
+
+    /// Clamps the given [`Position`] to the [`Document`], moving it back to
+    /// the nearest character boundary.
+    pub fn clamp(&self, position: Position) -> Position {
+        let block = position.block.min(self.blocks.len() - 1);
+        let text = self.blocks[block].text();
+
+        let mut offset = position.offset.min(text.len());
+
+        while !text.is_char_boundary(offset) {
+            offset -= 1;
+        }
+
+        Position { block, offset }
+    }
+
+    /// Returns the [`Style`] that text typed at the given [`Position`]
+    /// should have: the style of the character before it, if any.
+    pub fn style_at(&self, position: Position) -> Style {
+        let position = self.clamp(position);
+        let block = &self.blocks[position.block];
+
+        let mut start = 0;
+
+        for run in &block.runs {
+            let end = start + run.text.len();
+
+            if position.offset > start && position.offset <= end {
+                return run.style.clone();
+            }
+
+            start = end;
+        }
+
+        block
+            .runs
+            .first()
+            .map(|run| run.style.clone())
+            .unwrap_or_default()
+    }
+
+    /// Inserts plain text with the given [`Style`] at a [`Position`],
+    /// breaking blocks at every newline.
+    ///
+    /// Returns the [`Position`] at the end of the inserted text.
+    pub fn insert(&mut self, position: Position, text: &str, style: &Style) -> Position {
+        let text = text.replace(OBJECT_REPLACEMENT, "");
+        let mut position = self.clamp(position);
+
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                position = self.split(position);
+            }
+
+            let line = line.strip_suffix('\r').unwrap_or(line);
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let block = &mut self.blocks[position.block];
+            let index = block.split_at(position.offset);
+
+            block.runs.insert(index, Run::new(line, style.clone()));
+            block.normalize();
+
+            position.offset += line.len();
+        }
+
+        position
+    }
+
+    /// Inserts an inline [`Image`] with the given [`Style`] at a
+    /// [`Position`].
+    ///
+    /// Returns the [`Position`] right after the image.
+    pub fn insert_image(&mut self, position: Position, image: Image, style: &Style) -> Position {
+        let position = self.clamp(position);
+        let block = &mut self.blocks[position.block];
+        let index = block.split_at(position.offset);
+
+        block.runs.insert(index, Run::image(image, style.clone()));
+        block.normalize();
+
+        Position {
+            offset: position.offset + OBJECT_REPLACEMENT.len_utf8(),
+            ..position
+        }
+    }
+
+    /// Inserts the contents of another [`Document`] at a [`Position`].
+    ///
+    /// The first block of `document` is merged into the block at the
+    /// [`Position`], and the rest of that block is moved after the last one.
+    ///
+    /// Returns the [`Position`] at the end of the inserted contents.
+    pub fn insert_document(&mut self, position: Position, document: &Document) -> Position {
+        let position = self.clamp(position);
+        let mut blocks = document.blocks.clone();
+
+        let was_empty = self.blocks[position.block].is_empty();
+        let tail = self.blocks[position.block].split_off(position.offset);
+
+        let first = blocks.remove(0);
+        let target = &mut self.blocks[position.block];
+
+        if was_empty && !first.is_empty() {
+            target.kind = first.kind;
+        }
+
+        let mut end = Position {
+            block: position.block,
+            offset: target.len() + first.len(),
+        };
+
+        target.runs.extend(first.runs);
+
+        if let Some(last) = blocks.last() {
+            end = Position {
+                block: position.block + blocks.len(),
+                offset: last.len(),
+            };
+        }
+
+        let _ = self
+            .blocks
+            .splice(position.block + 1..position.block + 1, blocks);
+
+        let last = &mut self.blocks[end.block];
+        last.runs.extend(tail);
+
+        for block in &mut self.blocks[position.block..=end.block] {
+            block.normalize();
+        }
+
+        end
+    }
+
+    /// Breaks the [`Block`] at the given [`Position`] in two.
+    ///
+    /// The new block keeps the [`Kind`] of the original one, except when
+    /// breaking at the end of a heading, which continues as a paragraph.
+    ///
+    /// Returns the [`Position`] at the start of the new block.
+    pub fn split(&mut self, position: Position) -> Position {
+        let position = self.clamp(position);
+        let block = &mut self.blocks[position.block];
+
+        let kind = match block.kind {
+            Kind::Heading(_) if position.offset == block.len() => Kind::Paragraph,
+            kind => kind,
+        };
+
+        let runs = block.split_off(position.offset);
+        block.normalize();
+
+        self.blocks.insert(position.block + 1, Block { kind, runs });
+
+        Position {
+            block: position.block + 1,
+            offset: 0,
+        }
+    }
+
+    /// Deletes the contents of the [`Document`] in the given range, merging
+    /// the blocks at both ends.
+    pub fn delete(&mut self, range: Range<Position>) {
+        let (start, end) = (self.clamp(range.start), self.clamp(range.end));
+
+        if start >= end {
+            return;
+        }
+
+        if start.block == end.block {
+            let block = &mut self.blocks[start.block];
+
+            let from = block.split_at(start.offset);
+            let to = block.split_at(end.offset);
+
+            let _ = block.runs.drain(from..to);
+            block.normalize();
+
+            return;
+        }
+
+        let tail = self.blocks[end.block].split_off(end.offset);
+        let _ = self.blocks.drain(start.block + 1..=end.block);
+
+        let block = &mut self.blocks[start.block];
+        let _ = block.split_off(start.offset);
+
+        block.runs.extend(tail);
+        block.normalize();
+    }
+
+    /// Returns a copy of the contents of the [`Document`] in the given range.
+    pub fn slice(&self, range: Range<Position>) -> Document {
+        let (start, end) = (self.clamp(range.start), self.clamp(range.end));
+
+        if start >= end {
+            return Document::new();
+        }
+
+        let mut blocks = self.blocks[start.block..=end.block].to_vec();
+
+        if let Some(last) = blocks.last_mut() {
+            let _ = last.split_off(end.offset);
+        }
+
+        let first = &mut blocks[0];
+        first.runs = first.split_off(start.offset);
+
+        for block in &mut blocks {
+            block.normalize();
+        }
+
+        Document { blocks }
+    }
+
+    /// Applies the given function to the [`Style`] of every run in the
+    /// range.
+    pub fn update_style(&mut self, range: Range<Position>, f: impl Fn(&mut Style)) {
+        let (start, end) = (self.clamp(range.start), self.clamp(range.end));
+
+        for index in start.block..=end.block.max(start.block) {
+            let block = &mut self.blocks[index];
+
+            let from = if index == start.block {
+                start.offset
+            } else {
+                0
+            };
+
+            let to = if index == end.block {
+                end.offset
+            } else {
+                block.len()
+            };
+
+            if from >= to {
+                continue;
+            }
+
+            let from = block.split_at(from);
+            let to = block.split_at(to);
+
+            for run in &mut block.runs[from..to] {
+                f(&mut run.style);
+            }
+
+            block.normalize();
+        }
+    }
+
+    /// Returns whether every run in the range satisfies the given predicate.
+    ///
+    /// An empty range checks the [`Style`] at its start instead.
+    pub fn is_styled(&self, range: Range<Position>, f: impl Fn(&Style) -> bool) -> bool {
+        let (start, end) = (self.clamp(range.start), self.clamp(range.end));
+
+        if start >= end {
+            return f(&self.style_at(start));
+        }
+
+        (start.block..=end.block).all(|index| {
+            let block = &self.blocks[index];
+
+            let from = if index == start.block {
+                start.offset
+            } else {
+                0
+            };
+
+            let to = if index == end.block {
+                end.offset
+            } else {
+                block.len()
+            };
+
+            let mut offset = 0;
+
+            block.runs.iter().all(|run| {
+                let run_start = offset;
+                offset += run.text.len();
+
+                offset <= from || run_start >= to || f(&run.style)
+            })
+        })
+    }
+
+    /// Changes the [`Kind`] of the blocks with the given indices.
+    pub fn set_kind(&mut self, blocks: Range<usize>, kind: Kind) {
+        let end = blocks.end.min(self.blocks.len());
+
+        for block in &mut self.blocks[blocks.start.min(end)..end] {
+            block.kind = kind;
+        }
+    }
+}
+
+impl Default for Document {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A block of a [`Document`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Block {
+    /// The [`Kind`] of the [`Block`].
+    pub kind: Kind,
+    /// The styled runs of text of the [`Block`].
+    pub runs: Vec<Run>,
+}
+
+impl Block {
+    fn new(kind: Kind) -> Self {
+        Self {
+            kind,
+            runs: Vec::new(),
+        }
+    }
+
+    /// Returns the text of the [`Block`], with images as
+    /// [`OBJECT_REPLACEMENT`] characters.
+    pub fn text(&self) -> String {
+        self.runs.iter().map(|run| run.text.as_str()).collect()
+    }
+
+    /// Returns the length of the text of the [`Block`], in bytes.
+    pub fn len(&self) -> usize {
+        self.runs.iter().map(|run| run.text.len()).sum()
+    }
+
+    /// Returns whether the [`Block`] contains no text.
+    pub fn is_empty(&self) -> bool {
+        self.runs.iter().all(|run| run.text.is_empty())
+    }
+
+    /// Ensures a run starts at the given byte offset and returns its index.
+    fn split_at(&mut self, offset: usize) -> usize {
+        let mut start = 0;
+
+        for index in 0..self.runs.len() {
+            let end = start + self.runs[index].text.len();
+
+            if offset <= start {
+                return index;
+            }
+
+            if offset < end {
+                let run = &mut self.runs[index];
+
+                let rest = Run {
+                    text: run.text.split_off(offset - start),
+                    style: run.style.clone(),
+                    image: None,
+                };
+
+                self.runs.insert(index + 1, rest);
+
+                return index + 1;
+            }
+
+            start = end;
+        }
+
+        self.runs.len()
+    }
+
+    /// Removes and returns the runs after the given byte offset.
+    fn split_off(&mut self, offset: usize) -> Vec<Run> {
+        let index = self.split_at(offset);
+
+        self.runs.split_off(index)
+    }
+
+    /// Drops empty runs and merges adjacent runs sharing the same [`Style`].
+    fn normalize(&mut self) {
+        self.runs.retain(|run| !run.text.is_empty());
+
+        let mut runs: Vec<Run> = Vec::with_capacity(self.runs.len());
+
+        for run in self.runs.drain(..) {
+            match runs.last_mut() {
+                Some(last)
+                    if last.image.is_none() && run.image.is_none() && last.style == run.style =>
+                {
+                    last.text.push_str(&run.text);
+                }
+                _ => runs.push(run),
+            }
+        }
+
+        self.runs = runs;
+    }
+}
+
+/// The kind of a [`Block`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Kind {
+    /// A paragraph.
+    #[default]
+    Paragraph,
+    /// A heading of the given level, from 1 to 6.
+    Heading(u8),
+    /// An item of a bulleted list.
+    Bullet,
+    /// An item of a numbered list.
+    Numbered,
+}
+
+/// A styled run of text in a [`Block`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    /// The text of the [`Run`].
+    pub text: String,
+    /// The [`Style`] of the [`Run`].
+    pub style: Style,
+    /// The [`Image`] of the [`Run`], if it is an inline image.
+    pub image: Option<Image>,
+}
+
+impl Run {
+    /// Creates a new [`Run`] of text.
+    pub fn new(text: impl Into<String>, style: Style) -> Self {
+        Self {
+            text: text.into(),
+            style,
+            image: None,
+        }
+    }
+
+    /// Creates a new [`Run`] for an inline [`Image`].
+    pub fn image(image: Image, style: Style) -> Self {
+        Self {
+            text: OBJECT_REPLACEMENT.to_string(),
+            style,
+            image: Some(image),
+        }
+    }
+}
+
+/// The style of a [`Run`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Style {
+    /// Whether the text is bold.
+    pub bold: bool,
+    /// Whether the text is italic.
+    pub italic: bool,
+    /// Whether the text is underlined.
+    pub underline: bool,
+    /// Whether the text is struck through.
+    pub strikethrough: bool,
+    /// The [`Color`] of the text, if different from the default one.
+    pub color: Option<Color>,
+    /// The URL the text links to, if any.
+    pub link: Option<String>,
+}
+
+/// An inline image of a [`Document`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Image {
+    /// The source of the [`Image`], usually a path or a URL.
+    pub source: String,
+    /// The alternative text of the [`Image`].
+    pub alt: String,
+    /// The width of the [`Image`], in logical pixels.
+    pub width: Option<f32>,
+    /// The height of the [`Image`], in logical pixels.
+    pub height: Option<f32>,
+}
+
+/// A position in a [`Document`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Position {
+    /// The index of the [`Block`].
+    pub block: usize,
+    /// The byte offset in the text of the [`Block`].
+    pub offset: usize,
+}
+
+impl Position {
+    /// Creates a new [`Position`].
+    pub const fn new(block: usize, offset: usize) -> Self {
+        Self { block, offset }
+    }
+}
+
+/// Accumulates blocks while parsing a [`Document`].
+#[derive(Default)]
+struct Builder {
+    blocks: Vec<Block>,
+    current: Block,
+}
+
+impl Builder {
+    fn text(&mut self, text: &str, style: &Style) {
+        let text = text.replace(OBJECT_REPLACEMENT, "");
+
+        if text.is_empty() {
+            return;
+        }
+
+        match self.current.runs.last_mut() {
+            Some(run) if run.image.is_none() && run.style == *style => run.text.push_str(&text),
+            _ => self.current.runs.push(Run::new(text, style.clone())),
+        }
+    }
+
+    fn image(&mut self, image: Image, style: &Style) {
+        self.current.runs.push(Run::image(image, style.clone()));
+    }
+
+    fn kind(&mut self, kind: Kind) {
+        self.current.kind = kind;
+    }
+
+    fn is_empty(&self) -> bool {
+        self.current.runs.is_empty()
+    }
+
+    fn finish_block(&mut self) {
+        let kind = self.current.kind;
+        let mut block = std::mem::replace(&mut self.current, Block::new(kind));
+
+        block.normalize();
+        self.blocks.push(block);
+    }
+
+    fn build(mut self) -> Document {
+        if !self.is_empty() || self.blocks.is_empty() {
+            self.finish_block();
+        }
+
+        Document {
+            blocks: self.blocks,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bold() -> Style {
+        Style {
+            bold: true,
+            ..Style::default()
+        }
+    }
+
+    #[test]
+    fn edits_across_blocks() {
+        let mut document = Document::with_text("Hello\nworld");
+
+        let end = document.insert(Position::new(0, 5), ", big\nround", &bold());
+        assert_eq!(end, Position::new(1, 5));
+        assert_eq!(document.text(), "Hello, big\nround\nworld");
+
+        document.delete(Position::new(0, 5)..Position::new(2, 0));
+        assert_eq!(document.text(), "Helloworld");
+        assert_eq!(document.blocks()[0].runs.len(), 1);
+
+        document.update_style(Position::new(0, 0)..Position::new(0, 5), |style| {
+            style.italic = true;
+        });
+
+        assert_eq!(document.blocks()[0].runs[0].text, "Hello");
+        assert!(
+            document.is_styled(Position::new(0, 1)..Position::new(0, 4), |style| style
+                .italic)
+        );
+        assert!(
+            !document.is_styled(Position::new(0, 0)..Position::new(0, 6), |style| style
+                .italic)
+        );
+    }
+
+    #[test]
+    fn round_trips_html_and_rtf() {
+        let mut document = Document::with_text("Title\nSome bold text\nItem 1\nItem 2");
+
+        document.set_kind(0..1, Kind::Heading(1));
+        document.set_kind(2..4, Kind::Numbered);
+        document.update_style(Position::new(1, 5)..Position::new(1, 9), |style| {
+            *style = bold();
+        });
+        document.update_style(Position::new(1, 10)..Position::new(1, 14), |style| {
+            style.color = Some(Color::from_rgb8(255, 0, 0));
+            style.link = Some("https://example.com/?a=1&b=2".to_owned());
+        });
+        let _ = document.insert_image(
+            Position::new(3, 6),
+            Image {
+                source: "cat.png".to_owned(),
+                ..Image::default()
+            },
+            &Style::default(),
+        );
+
+        assert_eq!(Document::from_html(&document.to_html()), document);
+        assert_eq!(Document::from_rtf(&document.to_rtf()), document);
+    }
+}
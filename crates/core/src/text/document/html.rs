@@ -0,0 +1,624 @@
+//! Convert documents from and to HTML.
+use super::{Block, Builder, Document, Image, Kind, Run, Style};
+use crate::Color;
+
+use std::fmt::Write;
+
+/// Serializes a [`Document`] as an HTML fragment.
+pub fn write(document: &Document) -> String {
+    let mut html = String::new();
+    let mut list = None;
+
+    for block in &document.blocks {
+        let block_list = match block.kind {
+            Kind::Bullet => Some("ul"),
+            Kind::Numbered => Some("ol"),
+            Kind::Paragraph | Kind::Heading(_) => None,
+        };
+
+        if list != block_list {
+            if let Some(list) = list {
+                let _ = write!(html, "</{list}>");
+            }
+
+            if let Some(list) = block_list {
+                let _ = write!(html, "<{list}>");
+            }
+
+            list = block_list;
+        }
+
+        write_block(&mut html, block);
+    }
+
+    if let Some(list) = list {
+        let _ = write!(html, "</{list}>");
+    }
+
+    html
+}
+
+fn write_block(html: &mut String, block: &Block) {
+    let tag = match block.kind {
+        Kind::Paragraph => "p".to_owned(),
+        Kind::Heading(level) => format!("h{}", level.clamp(1, 6)),
+        Kind::Bullet | Kind::Numbered => "li".to_owned(),
+    };
+
+    let _ = write!(html, "<{tag}>");
+
+    if block.is_empty() {
+        html.push_str("<br>");
+    }
+
+    for run in &block.runs {
+        write_run(html, run);
+    }
+
+    let _ = write!(html, "</{tag}>");
+}
+
+fn write_run(html: &mut String, run: &Run) {
+    let style = &run.style;
+    let mut closing = Vec::new();
+
+    if let Some(link) = &style.link {
+        let _ = write!(html, "<a href=\"{}\">", escape(link));
+        closing.push("</a>");
+    }
+
+    if let Some(color) = style.color {
+        let [r, g, b, _] = color.into_rgba8();
+
+        let _ = write!(html, "<span style=\"color: #{r:02x}{g:02x}{b:02x}\">");
+        closing.push("</span>");
+    }
+
+    for (enabled, tag) in [
+        (style.bold, "b"),
+        (style.italic, "i"),
+        (style.underline, "u"),
+        (style.strikethrough, "s"),
+    ] {
+        if enabled {
+            let _ = write!(html, "<{tag}>");
+            closing.push(match tag {
+                "b" => "</b>",
+                "i" => "</i>",
+                "u" => "</u>",
+                _ => "</s>",
+            });
+        }
+    }
+
+    match &run.image {
+        Some(image) => {
+            let _ = write!(
+                html,
+                "<img src=\"{}\" alt=\"{}\"",
+                escape(&image.source),
+                escape(&image.alt)
+            );
+
+            if let Some(width) = image.width {
+                let _ = write!(html, " width=\"{width}\"");
+            }
+
+            if let Some(height) = image.height {
+                let _ = write!(html, " height=\"{height}\"");
+            }
+
+            html.push('>');
+        }
+        None => html.push_str(&escape(&run.text)),
+    }
+
+    for tag in closing.into_iter().rev() {
+        html.push_str(tag);
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\u{a0}' => escaped.push_str("&nbsp;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Parses a [`Document`] from HTML.
+pub fn parse(html: &str) -> Document {
+    let mut parser = Parser {
+        has_space: true,
+        ..Parser::default()
+    };
+    let mut rest = fragment(html);
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment
+                .find("-->")
+                .map_or("", |end| &comment[end + "-->".len()..]);
+
+            continue;
+        }
+
+        if rest.starts_with('<')
+            && let Some(end) = tag_end(rest)
+        {
+            if let Some(tag) = Tag::parse(&rest[1..end]) {
+                parser.tag(tag);
+            }
+
+            rest = &rest[end + 1..];
+            continue;
+        }
+
+        let first = rest.chars().next().map_or(0, char::len_utf8);
+        let end = rest[first..]
+            .find('<')
+            .map_or(rest.len(), |end| end + first);
+
+        parser.text(&rest[..end]);
+        rest = &rest[end..];
+    }
+
+    parser.builder.build()
+}
+
+/// Returns the fragment of an HTML clipboard payload, skipping its headers
+/// and the surrounding document, if present.
+fn fragment(html: &str) -> &str {
+    const START: &str = "<!--StartFragment-->";
+    const END: &str = "<!--EndFragment-->";
+
+    if let Some(start) = html.find(START) {
+        let fragment = &html[start + START.len()..];
+
+        return fragment.find(END).map_or(fragment, |end| &fragment[..end]);
+    }
+
+    if let Some(body) = html.find("<body")
+        && let Some(end) = tag_end(&html[body..])
+    {
+        return &html[body + end + 1..];
+    }
+
+    html
+}
+
+/// Returns the index of the `>` closing the tag at the start of `source`,
+/// skipping quoted attribute values.
+fn tag_end(source: &str) -> Option<usize> {
+    let mut quote = None;
+
+    for (index, c) in source.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(open), _) if c == open => quote = None,
+            (None, '>') => return Some(index),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[derive(Default)]
+struct Parser {
+    builder: Builder,
+    styles: Vec<(String, Style)>,
+    kinds: Vec<(String, Kind)>,
+    lists: Vec<Kind>,
+    skipping: Option<String>,
+    /// Whether the last character was a collapsed space or the start of a
+    /// block, so further whitespace is dropped.
+    has_space: bool,
+}
+
+impl Parser {
+    fn style(&self) -> Style {
+        self.styles
+            .last()
+            .map(|(_, style)| style.clone())
+            .unwrap_or_default()
+    }
+
+    fn kind(&self) -> Kind {
+        self.kinds.last().map_or(Kind::Paragraph, |(_, kind)| *kind)
+    }
+
+    fn tag(&mut self, tag: Tag) {
+        if let Some(skipping) = &self.skipping {
+            if tag.is_closing && tag.name == *skipping {
+                self.skipping = None;
+            }
+
+            return;
+        }
+
+        if tag.is_closing {
+            self.close(&tag.name);
+        } else {
+            self.open(tag);
+        }
+    }
+
+    fn open(&mut self, tag: Tag) {
+        let name = tag.name.as_str();
+
+        match name {
+            "head" | "style" | "script" | "title" | "template" => {
+                self.skipping = Some(tag.name);
+                return;
+            }
+            "br" => {
+                self.finish_block(true);
+                return;
+            }
+            "img" => {
+                let dimension = |name| {
+                    tag.attribute(name)
+                        .and_then(|value| value.trim_end_matches("px").trim().parse().ok())
+                };
+
+                if let Some(source) = tag.attribute("src") {
+                    let image = Image {
+                        source: source.to_owned(),
+                        alt: tag.attribute("alt").unwrap_or_default().to_owned(),
+                        width: dimension("width"),
+                        height: dimension("height"),
+                    };
+
+                    self.builder.image(image, &self.style());
+                    self.has_space = false;
+                }
+
+                return;
+            }
+            "ul" | "ol" => {
+                self.lists.push(if name == "ul" {
+                    Kind::Bullet
+                } else {
+                    Kind::Numbered
+                });
+            }
+            _ => {}
+        }
+
+        let kind = match name {
+            "li" => Some(self.lists.last().copied().unwrap_or(Kind::Bullet)),
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                Some(Kind::Heading(name.as_bytes()[1] - b'0'))
+            }
+            "p" | "div" | "blockquote" | "pre" | "section" | "article" | "header" | "footer"
+            | "tr" | "dt" | "dd" => Some(match self.kind() {
+                kind @ (Kind::Bullet | Kind::Numbered) => kind,
+                _ => Kind::Paragraph,
+            }),
+            _ => None,
+        };
+
+        if let Some(kind) = kind {
+            self.finish_block(false);
+            self.builder.kind(kind);
+            self.kinds.push((tag.name.clone(), kind));
+        }
+
+        if matches!(
+            name,
+            "meta" | "link" | "hr" | "input" | "wbr" | "col" | "area"
+        ) {
+            return;
+        }
+
+        let mut style = self.style();
+
+        match name {
+            "b" | "strong" => style.bold = true,
+            "i" | "em" | "cite" | "var" => style.italic = true,
+            "u" | "ins" => style.underline = true,
+            "s" | "strike" | "del" => style.strikethrough = true,
+            "a" => {
+                if let Some(href) = tag.attribute("href") {
+                    style.link = Some(href.to_owned());
+                }
+            }
+            "font" => {
+                if let Some(color) = tag.attribute("color").and_then(parse_color) {
+                    style.color = Some(color);
+                }
+            }
+            _ => {}
+        }
+
+        if let Some(css) = tag.attribute("style") {
+            apply_css(&mut style, css);
+        }
+
+        self.styles.push((tag.name, style));
+    }
+
+    fn close(&mut self, name: &str) {
+        if let Some(index) = self.styles.iter().rposition(|(tag, _)| tag == name) {
+            self.styles.truncate(index);
+        }
+
+        if let Some(index) = self.kinds.iter().rposition(|(tag, _)| tag == name) {
+            self.finish_block(false);
+            self.kinds.truncate(index);
+            self.builder.kind(self.kind());
+        }
+
+        if matches!(name, "ul" | "ol") {
+            let _ = self.lists.pop();
+        }
+    }
+
+    fn finish_block(&mut self, force: bool) {
+        if let Some(run) = self.builder.current.runs.last_mut()
+            && run.image.is_none()
+        {
+            let length = run.text.trim_end_matches(' ').len();
+            run.text.truncate(length);
+        }
+
+        if force || !self.builder.is_empty() {
+            self.builder.finish_block();
+        }
+
+        self.has_space = true;
+    }
+
+    fn text(&mut self, raw: &str) {
+        if self.skipping.is_some() {
+            return;
+        }
+
+        let mut collapsed = String::with_capacity(raw.len());
+
+        for c in raw.chars() {
+            if !c.is_ascii_whitespace() {
+                self.has_space = false;
+                collapsed.push(c);
+            } else if !self.has_space {
+                self.has_space = true;
+                collapsed.push(' ');
+            }
+        }
+
+        self.builder.text(&decode(&collapsed), &self.style());
+    }
+}
+
+struct Tag {
+    name: String,
+    is_closing: bool,
+    attributes: Vec<(String, String)>,
+}
+
+impl Tag {
+    fn parse(source: &str) -> Option<Self> {
+        if source.starts_with(['!', '?']) {
+            return None;
+        }
+
+        let (is_closing, source) = match source.strip_prefix('/') {
+            Some(source) => (true, source),
+            None => (false, source.trim_end_matches('/')),
+        };
+
+        let end = source
+            .find(|c: char| c.is_ascii_whitespace())
+            .unwrap_or(source.len());
+
+        let name = source[..end].to_ascii_lowercase();
+        let mut rest = source[end..].trim_start();
+        let mut attributes = Vec::new();
+
+        while !rest.is_empty() {
+            let end = rest
+                .find(|c: char| c == '=' || c.is_ascii_whitespace())
+                .unwrap_or(rest.len());
+
+            let key = rest[..end].to_ascii_lowercase();
+            rest = rest[end..].trim_start();
+
+            let value = if let Some(value) = rest.strip_prefix('=') {
+                let value = value.trim_start();
+
+                match value.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let value = &value[1..];
+                        let end = value.find(quote).unwrap_or(value.len());
+
+                        rest = value.get(end + 1..).unwrap_or_default();
+                        &value[..end]
+                    }
+                    _ => {
+                        let end = value
+                            .find(|c: char| c.is_ascii_whitespace())
+                            .unwrap_or(value.len());
+
+                        rest = &value[end..];
+                        &value[..end]
+                    }
+                }
+            } else {
+                ""
+            };
+
+            if !key.is_empty() {
+                attributes.push((key, decode(value)));
+            }
+
+            rest = rest.trim_start();
+        }
+
+        Some(Self {
+            name,
+            is_closing,
+            attributes,
+        })
+    }
+
+    fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+fn apply_css(style: &mut Style, css: &str) {
+    for declaration in css.split(';') {
+        let Some((property, value)) = declaration.split_once(':') else {
+            continue;
+        };
+
+        let value = value.trim().to_ascii_lowercase();
+
+        match property.trim().to_ascii_lowercase().as_str() {
+            "font-weight" => {
+                style.bold = match value.as_str() {
+                    "bold" | "bolder" => true,
+                    "normal" | "lighter" => false,
+                    weight => weight
+                        .parse::<u16>()
+                        .map_or(style.bold, |weight| weight >= 600),
+                };
+            }
+            "font-style" => style.italic = value == "italic" || value == "oblique",
+            "text-decoration" | "text-decoration-line" => {
+                style.underline |= value.contains("underline");
+                style.strikethrough |= value.contains("line-through");
+            }
+            "color" => {
+                if let Some(color) = parse_color(&value) {
+                    style.color = Some(color);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn parse_color(value: &str) -> Option<Color> {
+    let value = value.trim().to_ascii_lowercase();
+
+    if let Some(hex) = value.strip_prefix('#') {
+        let channel = |range: std::ops::Range<usize>| u8::from_str_radix(hex.get(range)?, 16).ok();
+
+        return match hex.len() {
+            3 => Some(Color::from_rgb8(
+                channel(0..1)? * 17,
+                channel(1..2)? * 17,
+                channel(2..3)? * 17,
+            )),
+            6 | 8 => Some(Color::from_rgb8(
+                channel(0..2)?,
+                channel(2..4)?,
+                channel(4..6)?,
+            )),
+            _ => None,
+        };
+    }
+
+    if let Some(arguments) = value
+        .strip_prefix("rgba(")
+        .or_else(|| value.strip_prefix("rgb("))
+    {
+        let mut channels = arguments
+            .trim_end_matches(')')
+            .split([',', ' ', '/'])
+            .filter(|channel| !channel.is_empty())
+            .map(|channel| channel.trim().parse::<f32>().ok());
+
+        let mut channel = || Some(channels.next()??.clamp(0.0, 255.0) as u8);
+
+        return Some(Color::from_rgb8(channel()?, channel()?, channel()?));
+    }
+
+    let [r, g, b] = match value.as_str() {
+        "black" => [0, 0, 0],
+        "white" => [255, 255, 255],
+        "red" => [255, 0, 0],
+        "green" => [0, 128, 0],
+        "blue" => [0, 0, 255],
+        "yellow" => [255, 255, 0],
+        "orange" => [255, 165, 0],
+        "purple" => [128, 0, 128],
+        "gray" | "grey" => [128, 128, 128],
+        _ => return None,
+    };
+
+    Some(Color::from_rgb8(r, g, b))
+}
+
+fn decode(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 10)
+            .and_then(|end| Some((end, character(&rest[1..end + 1])?)));
+
+        match entity {
+            Some((end, c)) => {
+                decoded.push(c);
+                rest = &rest[end + 2..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+fn character(entity: &str) -> Option<char> {
+    if let Some(number) = entity.strip_prefix('#') {
+        let code = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+
+        return char::from_u32(code);
+    }
+
+    Some(match entity {
+        "amp" => '&',
+        "lt" => '<',
+        "gt" => '>',
+        "quot" => '"',
+        "apos" => '\'',
+        "nbsp" => '\u{a0}',
+        "ndash" => '–',
+        "mdash" => '—',
+        "hellip" => '…',
+        "lsquo" => '‘',
+        "rsquo" => '’',
+        "ldquo" => '“',
+        "rdquo" => '”',
+        "bull" => '•',
+        "copy" => '©',
+        _ => return None,
+    })
+}
@@ -0,0 +1,519 @@
+//! Convert documents from and to RTF.
+use super::{Builder, Document, Image, Kind, Run, Style};
+use crate::Color;
+
+use std::fmt::Write;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// The font size of each heading level, in half points.
+const HEADING_SIZES: [u8; 6] = [48, 40, 32, 28, 24, 24];
+
+/// Serializes a [`Document`] as RTF.
+pub fn write(document: &Document) -> String {
+    let mut colors: Vec<[u8; 3]> = Vec::new();
+
+    for color in document
+        .blocks
+        .iter()
+        .flat_map(|block| &block.runs)
+        .filter_map(|run| run.style.color)
+    {
+        let [r, g, b, _] = color.into_rgba8();
+
+        if !colors.contains(&[r, g, b]) {
+            colors.push([r, g, b]);
+        }
+    }
+
+    let mut rtf = String::from(
+        "{\\rtf1\\ansi\\ansicpg1252\\deff0\\uc1{\\fonttbl{\\f0\\fswiss Helvetica;}}{\\colortbl;",
+    );
+
+    for [r, g, b] in &colors {
+        let _ = write!(rtf, "\\red{r}\\green{g}\\blue{b};");
+    }
+
+    rtf.push_str("}\n");
+
+    let mut number = 0;
+
+    for block in &document.blocks {
+        rtf.push_str("\\pard\\plain");
+
+        if block.kind != Kind::Numbered {
+            number = 0;
+        }
+
+        match block.kind {
+            Kind::Paragraph => {}
+            Kind::Heading(level) => {
+                let level = level.clamp(1, 6);
+
+                let _ = write!(
+                    rtf,
+                    "\\outlinelevel{}\\fs{}",
+                    level - 1,
+                    HEADING_SIZES[usize::from(level - 1)]
+                );
+            }
+            Kind::Bullet => {
+                rtf.push_str(
+                    "{\\pntext\\bullet\\tab}{\\*\\pn\\pnlvlblt{\\pntxtb\\bullet}}\\fi-360\\li720",
+                );
+            }
+            Kind::Numbered => {
+                number += 1;
+
+                let _ = write!(
+                    rtf,
+                    "{{\\pntext {number}.\\tab}}{{\\*\\pn\\pnlvlbody\\pndec{{\\pntxta .}}}}\\fi-360\\li720"
+                );
+            }
+        }
+
+        rtf.push(' ');
+
+        for run in &block.runs {
+            write_run(&mut rtf, run, &colors);
+        }
+
+        rtf.push_str("\\par\n");
+    }
+
+    rtf.push('}');
+    rtf
+}
+
+fn write_run(rtf: &mut String, run: &Run, colors: &[[u8; 3]]) {
+    let style = &run.style;
+    let mut controls = String::new();
+
+    for (enabled, control) in [
+        (style.bold, "\\b"),
+        (style.italic, "\\i"),
+        (style.underline, "\\ul"),
+        (style.strikethrough, "\\strike"),
+    ] {
+        if enabled {
+            controls.push_str(control);
+        }
+    }
+
+    if let Some(color) = style.color {
+        let [r, g, b, _] = color.into_rgba8();
+
+        if let Some(index) = colors.iter().position(|color| *color == [r, g, b]) {
+            let _ = write!(controls, "\\cf{}", index + 1);
+        }
+    }
+
+    let field = match (&run.image, &style.link) {
+        (Some(image), _) => Some((
+            format!("INCLUDEPICTURE \"{}\"", image.source.replace('"', "%22")),
+            escape(&image.alt),
+        )),
+        (None, Some(link)) => Some((
+            format!("HYPERLINK \"{}\"", link.replace('"', "%22")),
+            escape(&run.text),
+        )),
+        (None, None) => None,
+    };
+
+    match field {
+        Some((instruction, result)) => {
+            let _ = write!(
+                rtf,
+                "{{{controls}{{\\field{{\\*\\fldinst{{{}}}}}{{\\fldrslt{{{result}}}}}}}}}",
+                escape(&instruction)
+            );
+        }
+        None => {
+            let delimiter = if controls.is_empty() { "" } else { " " };
+
+            let _ = write!(rtf, "{{{controls}{delimiter}{}}}", escape(&run.text));
+        }
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '\\' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\t' => escaped.push_str("\\tab "),
+            '\u{a0}' => escaped.push_str("\\~"),
+            _ if c.is_ascii() && !c.is_ascii_control() => escaped.push(c),
+            _ => {
+                for unit in c.encode_utf16(&mut [0; 2]) {
+                    let _ = write!(escaped, "\\u{}?", *unit as i16);
+                }
+            }
+        }
+    }
+
+    escaped
+}
+
+/// Parses a [`Document`] from RTF.
+pub fn parse(rtf: &str) -> Document {
+    let mut lexer = Lexer {
+        chars: rtf.chars().peekable(),
+    };
+
+    let mut parser = Parser {
+        builder: Builder::default(),
+        groups: Vec::new(),
+        group: Group {
+            style: Style::default(),
+            destination: Destination::Text,
+            unicode_skip: 1,
+            is_ignorable: false,
+        },
+        kind: Kind::Paragraph,
+        colors: Vec::new(),
+        color: [0; 3],
+        instruction: String::new(),
+        marker: String::new(),
+        skip: 0,
+        surrogate: None,
+    };
+
+    while let Some(token) = lexer.next() {
+        parser.token(token);
+    }
+
+    if !parser.builder.is_empty() {
+        parser.builder.kind(parser.kind);
+    }
+
+    parser.builder.build()
+}
+
+enum Token {
+    Open,
+    Close,
+    Word(String, Option<i32>),
+    Symbol(char),
+    Byte(u8),
+    Text(char),
+}
+
+struct Lexer<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl Lexer<'_> {
+    fn next(&mut self) -> Option<Token> {
+        loop {
+            let token = match self.chars.next()? {
+                '{' => Token::Open,
+                '}' => Token::Close,
+                '\\' => match self.chars.next()? {
+                    c if c.is_ascii_alphabetic() => {
+                        let mut word = String::from(c);
+
+                        while let Some(c) = self.chars.next_if(char::is_ascii_alphabetic) {
+                            word.push(c);
+                        }
+
+                        let mut parameter = String::new();
+
+                        if let Some(sign) = self.chars.next_if_eq(&'-') {
+                            parameter.push(sign);
+                        }
+
+                        while let Some(digit) = self.chars.next_if(char::is_ascii_digit) {
+                            parameter.push(digit);
+                        }
+
+                        let _ = self.chars.next_if_eq(&' ');
+
+                        Token::Word(word, parameter.parse().ok())
+                    }
+                    '\'' => {
+                        let hex: String = self.chars.by_ref().take(2).collect();
+
+                        Token::Byte(u8::from_str_radix(&hex, 16).unwrap_or(b'?'))
+                    }
+                    symbol => Token::Symbol(symbol),
+                },
+                '\r' | '\n' => continue,
+                c => Token::Text(c),
+            };
+
+            return Some(token);
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Group {
+    style: Style,
+    destination: Destination,
+    unicode_skip: usize,
+    is_ignorable: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Destination {
+    Text,
+    Skip,
+    Colors,
+    Instruction,
+    Marker,
+    Numbering,
+}
+
+struct Parser {
+    builder: Builder,
+    groups: Vec<Group>,
+    group: Group,
+    kind: Kind,
+    colors: Vec<Option<Color>>,
+    color: [u8; 3],
+    instruction: String,
+    marker: String,
+    /// The amount of fallback characters left to skip after a `\u`.
+    skip: usize,
+    surrogate: Option<u16>,
+}
+
+impl Parser {
+    fn token(&mut self, token: Token) {
+        match token {
+            Token::Open => {
+                self.skip = 0;
+                self.groups.push(self.group.clone());
+                self.group.is_ignorable = false;
+            }
+            Token::Close => {
+                self.skip = 0;
+
+                let Some(parent) = self.groups.pop() else {
+                    return;
+                };
+
+                let closed = std::mem::replace(&mut self.group, parent);
+
+                if closed.destination == Destination::Marker
+                    && self.group.destination != Destination::Marker
+                {
+                    self.finish_marker();
+                }
+            }
+            Token::Word(word, parameter) => {
+                self.skip = 0;
+                self.word(&word, parameter);
+            }
+            Token::Symbol(symbol) => match symbol {
+                '*' => self.group.is_ignorable = true,
+                '~' => self.character('\u{a0}'),
+                '_' => self.character('\u{2011}'),
+                '\n' | '\r' => self.word("par", None),
+                '-' => {}
+                c => self.character(c),
+            },
+            Token::Byte(byte) => self.character(decode_byte(byte)),
+            Token::Text(c) => self.character(c),
+        }
+    }
+
+    fn word(&mut self, word: &str, parameter: Option<i32>) {
+        let is_ignorable = std::mem::take(&mut self.group.is_ignorable);
+
+        if self.group.destination == Destination::Skip {
+            return;
+        }
+
+        let is_enabled = parameter != Some(0);
+
+        match word {
+            "fonttbl" | "stylesheet" | "info" | "pict" | "header" | "headerl" | "headerr"
+            | "headerf" | "footer" | "footerl" | "footerr" | "footerf" | "footnote" | "object"
+            | "listtable" | "listoverridetable" | "themedata" | "colorschememapping"
+            | "latentstyles" | "datastore" | "xmlnstbl" | "rsidtbl" | "generator" | "filetbl"
+            | "revtbl" | "annotation" | "pntxta" | "pntxtb" => {
+                self.group.destination = Destination::Skip;
+            }
+            "colortbl" => self.group.destination = Destination::Colors,
+            "red" => self.color[0] = channel(parameter),
+            "green" => self.color[1] = channel(parameter),
+            "blue" => self.color[2] = channel(parameter),
+            "field" => self.instruction.clear(),
+            "fldinst" => self.group.destination = Destination::Instruction,
+            "fldrslt" => self.field_result(),
+            "pntext" | "listtext" => {
+                self.group.destination = Destination::Marker;
+                self.marker.clear();
+            }
+            "pn" => self.group.destination = Destination::Numbering,
+            "pnlvlblt" => self.kind = Kind::Bullet,
+            "pnlvlbody" | "pndec" | "pnlcltr" | "pnucltr" | "pnlcrm" | "pnucrm" => {
+                self.kind = Kind::Numbered;
+            }
+            "pard" => self.kind = Kind::Paragraph,
+            "outlinelevel" => {
+                if let Some(level @ 0..=5) = parameter {
+                    self.kind = Kind::Heading(level as u8 + 1);
+                }
+            }
+            "par" | "line" | "sect" | "page" if self.group.destination == Destination::Text => {
+                self.builder.kind(self.kind);
+                self.builder.finish_block();
+            }
+            "plain" => {
+                self.group.style = Style {
+                    link: self.group.style.link.take(),
+                    ..Style::default()
+                };
+            }
+            "b" => self.group.style.bold = is_enabled,
+            "i" => self.group.style.italic = is_enabled,
+            "ul" => self.group.style.underline = is_enabled,
+            "ulnone" => self.group.style.underline = false,
+            "strike" => self.group.style.strikethrough = is_enabled,
+            "cf" => {
+                self.group.style.color = parameter
+                    .and_then(|index| self.colors.get(usize::try_from(index).ok()?))
+                    .copied()
+                    .flatten();
+            }
+            "uc" => {
+                self.group.unicode_skip = parameter
+                    .and_then(|skip| usize::try_from(skip).ok())
+                    .unwrap_or(1);
+            }
+            "u" => {
+                let unit = parameter.unwrap_or_default() as i16 as u16;
+
+                match (self.surrogate.take(), unit) {
+                    (None, 0xD800..=0xDBFF) => self.surrogate = Some(unit),
+                    (Some(high), 0xDC00..=0xDFFF) => {
+                        if let Some(Ok(c)) = char::decode_utf16([high, unit]).next() {
+                            self.character(c);
+                        }
+                    }
+                    (_, unit) => {
+                        if let Some(c) = char::from_u32(u32::from(unit)) {
+                            self.character(c);
+                        }
+                    }
+                }
+
+                self.skip = self.group.unicode_skip;
+            }
+            "tab" => self.character('\t'),
+            "emdash" => self.character('—'),
+            "endash" => self.character('–'),
+            "bullet" => self.character('•'),
+            "lquote" => self.character('‘'),
+            "rquote" => self.character('’'),
+            "ldblquote" => self.character('“'),
+            "rdblquote" => self.character('”'),
+            _ if is_ignorable => self.group.destination = Destination::Skip,
+            _ => {}
+        }
+    }
+
+    fn character(&mut self, c: char) {
+        if self.skip > 0 {
+            self.skip -= 1;
+            return;
+        }
+
+        match self.group.destination {
+            Destination::Text => self
+                .builder
+                .text(c.encode_utf8(&mut [0; 4]), &self.group.style),
+            Destination::Colors => {
+                if c == ';' {
+                    let [r, g, b] = std::mem::take(&mut self.color);
+
+                    // The first entry of the table is the default color
+                    self.colors.push(
+                        (!self.colors.is_empty() || [r, g, b] != [0; 3])
+                            .then(|| Color::from_rgb8(r, g, b)),
+                    );
+                }
+            }
+            Destination::Instruction => self.instruction.push(c),
+            Destination::Marker => self.marker.push(c),
+            Destination::Skip | Destination::Numbering => {}
+        }
+    }
+
+    fn field_result(&mut self) {
+        let instruction = self.instruction.trim();
+
+        if let Some(link) = argument(instruction, "HYPERLINK") {
+            self.group.style.link = Some(link);
+        } else if let Some(source) = argument(instruction, "INCLUDEPICTURE") {
+            if self.group.destination == Destination::Text {
+                let image = Image {
+                    source,
+                    ..Image::default()
+                };
+
+                self.builder.image(image, &self.group.style);
+            }
+
+            // The result is a rendition of the picture
+            self.group.destination = Destination::Skip;
+        }
+    }
+
+    fn finish_marker(&mut self) {
+        let marker = self.marker.trim();
+
+        if marker.is_empty() {
+            return;
+        }
+
+        let is_numbered = marker
+            .trim_end_matches(['.', ')'])
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric());
+
+        self.kind = if is_numbered {
+            Kind::Numbered
+        } else {
+            Kind::Bullet
+        };
+    }
+}
+
+fn channel(parameter: Option<i32>) -> u8 {
+    parameter.unwrap_or_default().clamp(0, 255) as u8
+}
+
+/// Returns the first argument of a field instruction, if the instruction
+/// has the given name.
+fn argument(instruction: &str, name: &str) -> Option<String> {
+    let rest = instruction.strip_prefix(name)?.trim_start();
+
+    let argument = match rest.strip_prefix('"') {
+        Some(quoted) => quoted.split('"').next().unwrap_or_default(),
+        None => rest.split_whitespace().next().unwrap_or_default(),
+    };
+
+    Some(argument.to_owned())
+}
+
+/// Decodes a byte of the Windows-1252 code page.
+fn decode_byte(byte: u8) -> char {
+    const HIGH: [char; 32] = [
+        '€', '\u{81}', '‚', 'ƒ', '„', '…', '†', '‡', 'ˆ', '‰', 'Š', '‹', 'Œ', '\u{8d}', 'Ž',
+        '\u{8f}', '\u{90}', '‘', '’', '“', '”', '•', '–', '—', '˜', '™', 'š', '›', 'œ', '\u{9d}',
+        'ž', 'Ÿ',
+    ];
+
+    match byte {
+        0x80..=0x9f => HIGH[usize::from(byte - 0x80)],
+        _ => char::from(byte),
+    }
+}
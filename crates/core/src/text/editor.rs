@@ -23,6 +23,13 @@ pub trait Editor: Sized + Default {
     /// Returns the current [`Selection`] of the [`Editor`].
     fn selection(&self) -> Selection;
 
+    /// Returns the position of the secondary caret of the [`Editor`], if the
+    /// cursor sits at the boundary between a left-to-right and a
+    /// right-to-left run of text.
+    ///
+    /// The primary caret is the one returned by [`Editor::selection`].
+    fn split_caret(&self) -> Option<Point>;
+
     /// Returns the current selected text of the [`Editor`].
     fn copy(&self) -> Option<String>;
 
@@ -1,8 +1,12 @@
 //! Draw paragraphs.
 use crate::alignment;
-use crate::text::{Alignment, Difference, Hit, LineHeight, Shaping, Span, Text, Wrapping};
+use crate::text::{
+    Alignment, Difference, Hit, LineHeight, Shaping, Span, Text, Truncation, Wrapping, bidi,
+};
 use crate::{Pixels, Point, Rectangle, Size};
 
+use std::ops::Range;
+
 /// A text paragraph.
 pub trait Paragraph: Sized + Default {
     /// The font of this [`Paragraph`].
@@ -45,6 +49,13 @@ pub trait Paragraph: Sized + Default {
     /// Returns the [`Shaping`] strategy of the [`Paragraph`]>
     fn shaping(&self) -> Shaping;
 
+    /// Returns the [`Truncation`] strategy of the [`Paragraph`].
+    fn truncation(&self) -> Truncation;
+
+    /// Returns true if the contents of the [`Paragraph`] do not fit its
+    /// bounds and have been truncated.
+    fn is_truncated(&self) -> bool;
+
     /// Returns the available bounds used to layout the [`Paragraph`].
     fn bounds(&self) -> Size;
 
@@ -68,6 +79,21 @@ pub trait Paragraph: Sized + Default {
     /// Returns the distance to the given grapheme index in the [`Paragraph`].
     fn grapheme_position(&self, line: usize, index: usize) -> Option<Point>;
 
+    /// Returns the byte offset, counted from the start of the whole contents
+    /// of the [`Paragraph`], of the character boundary nearest to the given
+    /// point.
+    fn hit_offset(&self, point: Point) -> Option<usize>;
+
+    /// Returns all bounds for the provided byte range of the contents of the
+    /// [`Paragraph`]. A range can have multiple bounds for each line it's on.
+    fn range_bounds(&self, range: Range<usize>) -> Vec<Rectangle>;
+
+    /// Returns the grapheme clusters of the given visual line of the
+    /// [`Paragraph`], sorted in visual order.
+    ///
+    /// The ranges of the clusters are grapheme indices into the line.
+    fn visual_clusters(&self, line: usize) -> Vec<bidi::Cluster>;
+
     /// Returns the minimum width that can fit the contents of the [`Paragraph`].
     fn min_width(&self) -> f32 {
         self.min_bounds().width
@@ -146,6 +172,13 @@ impl<P: Paragraph> Plain<P> {
         self.raw.min_height()
     }
 
+    /// Returns true if the [`Paragraph`] has been truncated.
+    ///
+    /// The full text is still available through [`content`](Self::content).
+    pub fn is_truncated(&self) -> bool {
+        self.raw.is_truncated()
+    }
+
     /// Returns the cached [`Paragraph`].
     pub fn raw(&self) -> &P {
         &self.raw
@@ -168,6 +201,7 @@ impl<P: Paragraph> Plain<P> {
             align_y: self.raw.align_y(),
             shaping: self.raw.shaping(),
             wrapping: self.raw.wrapping(),
+            truncation: self.raw.truncation(),
             hint_factor: self.raw.hint_factor(),
         }
     }
@@ -0,0 +1,398 @@
+//! Select and copy static text.
+use crate::clipboard::{self, Clipboard};
+use crate::keyboard;
+use crate::mouse;
+use crate::mouse::click::{self, Click};
+use crate::renderer;
+use crate::text::Paragraph;
+use crate::{Color, Event, Point, Rectangle, Shell, Vector};
+
+use std::ops::Range;
+
+/// The selection of some static text.
+///
+/// A [`Selection`] lets widgets displaying read-only text, like `text` and
+/// `rich_text`, be selected with the mouse and copied to the clipboard:
+///
+/// - Dragging selects a range of characters.
+/// - Double-clicking selects a word; dragging afterwards extends the
+///   selection word by word.
+/// - Triple-clicking selects a whole line.
+/// - Shift-clicking extends the current selection.
+/// - The command modifier plus `C` copies the selection, while the command
+///   modifier plus `A` selects everything.
+///
+/// Offsets are byte indices into the whole contents of the [`Paragraph`].
+#[derive(Debug, Clone, Default)]
+pub struct Selection {
+    origin: Range<usize>,
+    head: usize,
+    granularity: Granularity,
+    is_dragging: bool,
+    last_click: Option<Click>,
+    text: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Granularity {
+    #[default]
+    Character,
+    Word,
+    Line,
+}
+
+impl Selection {
+    /// Creates a new empty [`Selection`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the selected range of bytes, if it is not empty.
+    pub fn range(&self) -> Option<Range<usize>> {
+        let start = self.origin.start.min(self.head);
+        let end = self.origin.end.max(self.head);
+
+        (start < end).then_some(start..end)
+    }
+
+    /// Returns the selected text, if any.
+    pub fn selected_text(&self) -> Option<&str> {
+        (!self.text.is_empty()).then_some(self.text.as_str())
+    }
+
+    /// Returns true if the [`Selection`] is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.is_dragging
+    }
+
+    /// Selects all of the given content.
+    pub fn select_all(&mut self, content: &str) {
+        self.origin = 0..content.len();
+        self.head = content.len();
+        self.sync(content);
+    }
+
+    /// Clears the [`Selection`].
+    pub fn clear(&mut self) {
+        self.origin = 0..0;
+        self.head = 0;
+        self.is_dragging = false;
+        self.text.clear();
+    }
+
+    /// Processes an [`Event`] over some selectable text.
+    ///
+    /// The [`Paragraph`] must be drawn at the given `position` and laid out
+    /// with the given `content`. The `bounds` are the bounds of the widget
+    /// that displays it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn update<Message>(
+        &mut self,
+        event: &Event,
+        bounds: Rectangle,
+        position: Point,
+        cursor: mouse::Cursor,
+        paragraph: &impl Paragraph,
+        content: &str,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let offset_at =
+            |point: Point| paragraph.hit_offset(point - Vector::new(position.x, position.y));
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed {
+                button: mouse::Button::Left,
+                modifiers,
+            }) => {
+                let Some(cursor_position) = cursor.position_over(bounds) else {
+                    if self.range().is_some() {
+                        self.clear();
+                        shell.request_redraw();
+                    }
+
+                    return;
+                };
+
+                let Some(offset) = offset_at(cursor_position) else {
+                    return;
+                };
+
+                let click = Click::new(cursor_position, mouse::Button::Left, self.last_click);
+
+                match click.kind() {
+                    click::Kind::Single if modifiers.shift() && self.range().is_some() => {
+                        self.granularity = Granularity::Character;
+                        self.head = offset;
+                    }
+                    click::Kind::Single => {
+                        self.granularity = Granularity::Character;
+                        self.origin = offset..offset;
+                        self.head = offset;
+                    }
+                    click::Kind::Double => {
+                        self.granularity = Granularity::Word;
+                        self.origin = word_at(content, offset);
+                        self.head = self.origin.end;
+                    }
+                    click::Kind::Triple => {
+                        self.granularity = Granularity::Line;
+                        self.origin = line_at(content, offset);
+                        self.head = self.origin.end;
+                    }
+                }
+
+                self.last_click = Some(click);
+                self.is_dragging = true;
+                self.sync(content);
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::CursorMoved {
+                position: moved, ..
+            }) if self.is_dragging => {
+                let Some(offset) = offset_at(*moved) else {
+                    return;
+                };
+
+                let head = match self.granularity {
+                    Granularity::Character => offset,
+                    Granularity::Word | Granularity::Line => {
+                        let range = if self.granularity == Granularity::Word {
+                            word_at(content, offset)
+                        } else {
+                            line_at(content, offset)
+                        };
+
+                        if range.start < self.origin.start {
+                            range.start
+                        } else {
+                            range.end
+                        }
+                    }
+                };
+
+                if head != self.head {
+                    self.head = head;
+                    self.sync(content);
+
+                    shell.request_redraw();
+                }
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased {
+                button: mouse::Button::Left,
+                ..
+            }) if self.is_dragging => {
+                self.is_dragging = false;
+
+                if let Some(text) = self.selected_text() {
+                    clipboard.write_text(clipboard::Kind::Primary, text.to_owned());
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                physical_key,
+                modifiers,
+                ..
+            }) if modifiers.command() && self.range().is_some() => {
+                match key.to_latin(*physical_key) {
+                    Some('c') => {
+                        if let Some(text) = self.selected_text() {
+                            clipboard.write_text(clipboard::Kind::Standard, text.to_owned());
+                        }
+
+                        shell.capture_event();
+                    }
+                    Some('a') => {
+                        self.select_all(content);
+
+                        shell.request_redraw();
+                        shell.capture_event();
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Draws the highlight of the [`Selection`] of a [`Paragraph`] drawn at
+    /// the given position.
+    pub fn draw<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        paragraph: &Renderer::Paragraph,
+        position: Point,
+        color: Color,
+        viewport: &Rectangle,
+    ) where
+        Renderer: crate::text::Renderer,
+    {
+        let Some(range) = self.range() else {
+            return;
+        };
+
+        let translation = Vector::new(position.x, position.y);
+
+        for bounds in paragraph.range_bounds(range) {
+            let bounds = bounds + translation;
+
+            if !bounds.intersects(viewport) {
+                continue;
+            }
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+    }
+
+    fn sync(&mut self, content: &str) {
+        self.text.clear();
+
+        if let Some(range) = self.range()
+            && let Some(text) = content.get(range)
+        {
+            self.text.push_str(text);
+        }
+    }
+}
+
+/// Returns the range of the word, run of whitespace or punctuation character
+/// at the given offset.
+fn word_at(content: &str, offset: usize) -> Range<usize> {
+    #[derive(PartialEq)]
+    enum Class {
+        Word,
+        Whitespace,
+        Other,
+    }
+
+    fn class(c: char) -> Class {
+        if c.is_alphanumeric() || c == '_' {
+            Class::Word
+        } else if c.is_whitespace() {
+            Class::Whitespace
+        } else {
+            Class::Other
+        }
+    }
+
+    let offset = floor_char_boundary(content, offset);
+
+    let Some(current) = content[offset..]
+        .chars()
+        .next()
+        .or_else(|| content[..offset].chars().next_back())
+    else {
+        return offset..offset;
+    };
+
+    let current = class(current);
+
+    if current == Class::Other {
+        let start = floor_char_boundary(content, offset.min(content.len().saturating_sub(1)));
+        let end = start + content[start..].chars().next().map_or(0, char::len_utf8);
+
+        return start..end;
+    }
+
+    let start = content[..offset]
+        .char_indices()
+        .rev()
+        .take_while(|(_, c)| class(*c) == current)
+        .last()
+        .map_or(offset, |(i, _)| i);
+
+    let end = content[offset..]
+        .char_indices()
+        .find(|(_, c)| class(*c) != current)
+        .map_or(content.len(), |(i, _)| offset + i);
+
+    start..end
+}
+
+/// Returns the range of the line at the given offset, without its line
+/// ending.
+fn line_at(content: &str, offset: usize) -> Range<usize> {
+    let offset = floor_char_boundary(content, offset);
+
+    let start = content[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let end = content[offset..]
+        .find(['\r', '\n'])
+        .map_or(content.len(), |i| offset + i);
+
+    start..end
+}
+
+fn floor_char_boundary(content: &str, offset: usize) -> usize {
+    let mut offset = offset.min(content.len());
+
+    while !content.is_char_boundary(offset) {
+        offset -= 1;
+    }
+
+    offset
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn word_at_selects_words() {
+        let content = "hello big_world";
+
+        assert_eq!(word_at(content, 0), 0..5);
+        assert_eq!(word_at(content, 3), 0..5);
+        assert_eq!(word_at(content, 8), 6..15);
+        assert_eq!(word_at(content, content.len()), 6..15);
+    }
+
+    #[test]
+    fn word_at_selects_whitespace_runs() {
+        assert_eq!(word_at("a   b", 2), 1..4);
+    }
+
+    #[test]
+    fn word_at_selects_single_punctuation() {
+        let content = "foo, bar";
+
+        assert_eq!(word_at(content, 3), 3..4);
+        assert_eq!(word_at("a--b", 1), 1..2);
+    }
+
+    #[test]
+    fn word_at_respects_char_boundaries() {
+        let content = "año dos";
+
+        // Offset 2 falls inside the two bytes of `ñ`
+        assert_eq!(word_at(content, 2), 0..4);
+        assert_eq!(word_at("", 0), 0..0);
+    }
+
+    #[test]
+    fn line_at_selects_lines() {
+        let content = "first\nsecond\r\nthird";
+
+        assert_eq!(line_at(content, 2), 0..5);
+        assert_eq!(line_at(content, 6), 6..12);
+        assert_eq!(&content[line_at(content, 8)], "second");
+        assert_eq!(&content[line_at(content, content.len())], "third");
+    }
+
+    #[test]
+    fn line_at_handles_empty_lines() {
+        let content = "a\n\nb";
+
+        assert_eq!(line_at(content, 2), 2..2);
+        assert_eq!(line_at("", 0), 0..0);
+    }
+}
@@ -21,6 +21,16 @@ impl Id {
 
         Self(Internal::Unique(id))
     }
+
+    /// Returns the name of the [`Id`], if it was created from a string.
+    ///
+    /// Unique identifiers have no name.
+    pub fn name(&self) -> Option<&str> {
+        match &self.0 {
+            Internal::Custom(name) => Some(name),
+            Internal::Unique(_) => None,
+        }
+    }
 }
 
 impl From<&'static str> for Id {
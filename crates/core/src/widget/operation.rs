@@ -3,6 +3,7 @@
 pub mod accessibility;
 pub mod focusable;
 pub mod scrollable;
+pub mod selection;
 pub mod text_input;
 
 #[cfg(feature = "accessibility")]
@@ -360,6 +361,16 @@ where
             self.operation.text(id, bounds, text);
         }
 
+        #[cfg(feature = "accessibility")]
+        fn accessibility(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            info: crate::accessibility::WidgetInfo,
+        ) {
+            self.operation.accessibility(id, bounds, info);
+        }
+
         fn custom(&mut self, id: Option<&Id>, bounds: Rectangle, state: &mut dyn Any) {
             self.operation.custom(id, bounds, state);
         }
@@ -445,6 +456,16 @@ where
             self.operation.text(id, bounds, text);
         }
 
+        #[cfg(feature = "accessibility")]
+        fn accessibility(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            info: crate::accessibility::WidgetInfo,
+        ) {
+            self.operation.accessibility(id, bounds, info);
+        }
+
         fn custom(&mut self, id: Option<&Id>, bounds: Rectangle, state: &mut dyn Any) {
             self.operation.custom(id, bounds, state);
         }
@@ -269,6 +269,104 @@ pub fn collect() -> impl Operation<AccessibilityTree> {
     }
 }
 
+/// Wraps an [`Operation`] so that every accessibility node it collects is
+/// described by the node with the given [`NodeId`].
+///
+/// This is used by widgets like tooltips, which describe the widgets they
+/// are displayed over. The `description` is also set as the accessible
+/// description of nodes that do not have one.
+pub fn described_by<'a, T>(
+    operation: &'a mut dyn Operation<T>,
+    node: NodeId,
+    description: Option<&'a str>,
+) -> impl Operation<T> + 'a
+where
+    T: 'a,
+{
+    use crate::widget::operation::{Focusable, TextInput};
+
+    use std::any::Any;
+
+    struct DescribedBy<'a, T> {
+        operation: &'a mut dyn Operation<T>,
+        node: NodeId,
+        description: Option<&'a str>,
+    }
+
+    impl<T> Operation<T> for DescribedBy<'_, T> {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+            let node = self.node;
+            let description = self.description;
+
+            self.operation.traverse(&mut |operation| {
+                operate(&mut DescribedBy {
+                    operation,
+                    node,
+                    description,
+                });
+            });
+        }
+
+        fn container(&mut self, id: Option<&Id>, bounds: Rectangle) {
+            self.operation.container(id, bounds);
+        }
+
+        fn leave_container(&mut self) {
+            self.operation.leave_container();
+        }
+
+        fn scrollable(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            content_bounds: Rectangle,
+            translation: Vector,
+            state: &mut dyn Scrollable,
+        ) {
+            self.operation
+                .scrollable(id, bounds, content_bounds, translation, state);
+        }
+
+        fn focusable(&mut self, id: Option<&Id>, bounds: Rectangle, state: &mut dyn Focusable) {
+            self.operation.focusable(id, bounds, state);
+        }
+
+        fn text_input(&mut self, id: Option<&Id>, bounds: Rectangle, state: &mut dyn TextInput) {
+            self.operation.text_input(id, bounds, state);
+        }
+
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, text: &str) {
+            self.operation.text(id, bounds, text);
+        }
+
+        fn accessibility(&mut self, id: Option<&Id>, bounds: Rectangle, info: WidgetInfo) {
+            let mut info = info.with_described_by(self.node);
+
+            if info.description.is_none()
+                && let Some(description) = self.description
+            {
+                info.description = Some(description.to_owned());
+            }
+
+            self.operation.accessibility(id, bounds, info);
+        }
+
+        fn custom(&mut self, id: Option<&Id>, bounds: Rectangle, state: &mut dyn Any) {
+            self.operation.custom(id, bounds, state);
+        }
+
+        fn finish(&self) -> Outcome<T> {
+            self.operation.finish()
+        }
+    }
+
+    DescribedBy {
+        operation,
+        node,
+        description,
+    }
+}
+
 /// Produces an [`Operation`] that finds the currently focused accessibility node.
 ///
 /// Unlike `operation::focusable::find_focused`, this does not require widgets to
@@ -757,3 +855,48 @@ pub fn focus_widget_by_node_id<T>(target_node_id: NodeId) -> impl Operation<T> {
         pending_node_id: None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn described_by_links_nested_nodes() {
+        let tooltip = Id::new("tooltip");
+        let tooltip_node = node_id_from_widget_id(&tooltip);
+
+        let mut collect = collect();
+
+        collect.accessibility(
+            Some(&tooltip),
+            Rectangle::default(),
+            WidgetInfo::tooltip("Saves the file"),
+        );
+
+        {
+            let mut operation = described_by(&mut collect, tooltip_node, Some("Saves the file"));
+
+            operation.traverse(&mut |operation| {
+                operation.accessibility(
+                    Some(&Id::new("save")),
+                    Rectangle::default(),
+                    WidgetInfo::button("Save"),
+                );
+            });
+        }
+
+        let Outcome::Some(tree) = collect.finish() else {
+            panic!("collect should produce a tree");
+        };
+
+        let button = node_id_from_widget_id(&Id::new("save"));
+        let (_, node) = tree
+            .nodes
+            .iter()
+            .find(|(id, _)| *id == button)
+            .expect("button node");
+
+        assert_eq!(node.described_by(), [tooltip_node]);
+        assert_eq!(node.description(), Some("Saves the file"));
+    }
+}
@@ -1,7 +1,10 @@
 //! Operate on widgets that can be scrolled.
+use crate::widget::operation::Outcome;
 use crate::widget::{Id, Operation};
 use crate::{Rectangle, Vector};
 
+use std::collections::HashMap;
+
 /// The internal state of a widget that can be scrolled.
 pub trait Scrollable {
     /// Snaps the scroll of the widget to the given `percentage` along the horizontal & vertical axis.
@@ -38,6 +41,20 @@ pub trait Scrollable {
         // Default: fall back to immediate scroll
         self.scroll_by(offset, bounds, content_bounds);
     }
+
+    /// Returns the current [`AbsoluteOffset`] of the widget, if it can report it.
+    ///
+    /// The offset is measured from the anchor of each axis, so feeding it back to
+    /// [`Scrollable::scroll_to`] restores the same scroll position.
+    fn absolute_offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Option<AbsoluteOffset> {
+        let _ = bounds;
+        let _ = content_bounds;
+        None
+    }
 }
 
 /// Produces an [`Operation`] that snaps the widget with the given [`Id`] to
@@ -270,6 +287,274 @@ pub fn ensure_visible_animated<T>(target: Id, target_rect: Rectangle) -> impl Op
     }
 }
 
+/// Produces an [`Operation`] that scrolls the widget with the given [`Id`] so
+/// the `widget` with the given [`Id`] inside of it is at the top of its
+/// viewport; e.g. to jump to a heading of a long document.
+pub fn scroll_to_widget<T>(target: Id, widget: Id) -> impl Operation<T>
+where
+    T: Send + 'static,
+{
+    struct FindWidget {
+        target: Id,
+        widget: Id,
+        content_bounds: Option<Rectangle>,
+        bounds: Option<Rectangle>,
+        depth: usize,
+        /// The depth of the target while its contents are being traversed.
+        inside: Option<usize>,
+    }
+
+    impl FindWidget {
+        fn visit(&mut self, id: Option<&Id>, bounds: Rectangle) {
+            let is_inside = self.inside.is_some_and(|depth| self.depth > depth);
+
+            if is_inside && self.bounds.is_none() && Some(&self.widget) == id {
+                self.bounds = Some(bounds);
+            }
+        }
+    }
+
+    impl<T> Operation<T> for FindWidget
+    where
+        T: Send + 'static,
+    {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+            self.depth += 1;
+            operate(self);
+            self.depth -= 1;
+
+            // The contents of the target have been traversed
+            if self.inside == Some(self.depth) {
+                self.inside = None;
+            }
+        }
+
+        fn container(&mut self, id: Option<&Id>, bounds: Rectangle) {
+            self.visit(id, bounds);
+        }
+
+        fn scrollable(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            content_bounds: Rectangle,
+            _translation: Vector,
+            _state: &mut dyn Scrollable,
+        ) {
+            if Some(&self.target) == id && self.content_bounds.is_none() {
+                self.content_bounds = Some(content_bounds);
+                self.inside = Some(self.depth);
+            }
+
+            self.visit(id, bounds);
+        }
+
+        fn focusable(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            _state: &mut dyn super::Focusable,
+        ) {
+            self.visit(id, bounds);
+        }
+
+        fn text(&mut self, id: Option<&Id>, bounds: Rectangle, _text: &str) {
+            self.visit(id, bounds);
+        }
+
+        fn custom(&mut self, id: Option<&Id>, bounds: Rectangle, _state: &mut dyn std::any::Any) {
+            self.visit(id, bounds);
+        }
+
+        fn finish(&self) -> Outcome<T> {
+            let (Some(content_bounds), Some(bounds)) = (self.content_bounds, self.bounds) else {
+                return Outcome::None;
+            };
+
+            // Layout bounds of the contents are not affected by scrolling
+            Outcome::Chain(Box::new(scroll_to(
+                self.target.clone(),
+                AbsoluteOffset {
+                    x: None,
+                    y: Some((bounds.y - content_bounds.y).max(0.0)),
+                },
+            )))
+        }
+    }
+
+    FindWidget {
+        target,
+        widget,
+        content_bounds: None,
+        bounds: None,
+        depth: 0,
+        inside: None,
+    }
+}
+
+/// Produces an [`Operation`] that collects the [`AbsoluteOffset`] of every
+/// scrollable widget with an [`Id`].
+pub fn positions() -> impl Operation<ScrollPositions> {
+    struct Positions {
+        positions: ScrollPositions,
+    }
+
+    impl Operation<ScrollPositions> for Positions {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<ScrollPositions>)) {
+            operate(self);
+        }
+
+        fn scrollable(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            content_bounds: Rectangle,
+            translation: Vector,
+            state: &mut dyn Scrollable,
+        ) {
+            let Some(id) = id else {
+                return;
+            };
+
+            let offset = state
+                .absolute_offset(bounds, content_bounds)
+                .unwrap_or(AbsoluteOffset {
+                    x: translation.x,
+                    y: translation.y,
+                });
+
+            self.positions.insert(id.clone(), offset);
+        }
+
+        fn finish(&self) -> Outcome<ScrollPositions> {
+            Outcome::Some(self.positions.clone())
+        }
+    }
+
+    Positions {
+        positions: ScrollPositions::default(),
+    }
+}
+
+/// Produces an [`Operation`] that scrolls every scrollable widget found in the
+/// given [`ScrollPositions`] back to its recorded [`AbsoluteOffset`].
+///
+/// Scrollables that are not part of the widget tree are simply ignored.
+pub fn restore<T>(positions: ScrollPositions) -> impl Operation<T> {
+    struct Restore {
+        positions: ScrollPositions,
+    }
+
+    impl<T> Operation<T> for Restore {
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+            operate(self);
+        }
+
+        fn scrollable(
+            &mut self,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+            _content_bounds: Rectangle,
+            _translation: Vector,
+            state: &mut dyn Scrollable,
+        ) {
+            if let Some(offset) = id.and_then(|id| self.positions.get(id)) {
+                state.scroll_to(offset.into());
+            }
+        }
+    }
+
+    Restore { positions }
+}
+
+/// The scroll positions of a set of scrollable widgets, keyed by their [`Id`].
+///
+/// It can be obtained with the [`positions`] operation and applied back with
+/// [`restore`]. With the `serde` feature enabled, the positions of widgets with
+/// a named [`Id`] (e.g. `Id::new("sidebar")`) can be persisted; unique ids only
+/// live as long as the process and are skipped when serializing.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScrollPositions {
+    offsets: HashMap<Id, AbsoluteOffset>,
+}
+
+impl ScrollPositions {
+    /// Creates an empty set of [`ScrollPositions`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the recorded [`AbsoluteOffset`] of the scrollable with the given [`Id`].
+    pub fn get(&self, id: &Id) -> Option<AbsoluteOffset> {
+        self.offsets.get(id).copied()
+    }
+
+    /// Records the [`AbsoluteOffset`] of the scrollable with the given [`Id`].
+    pub fn insert(&mut self, id: impl Into<Id>, offset: AbsoluteOffset) {
+        let _ = self.offsets.insert(id.into(), offset);
+    }
+
+    /// Removes the recorded position of the scrollable with the given [`Id`].
+    pub fn remove(&mut self, id: &Id) -> Option<AbsoluteOffset> {
+        self.offsets.remove(id)
+    }
+
+    /// Merges the given [`ScrollPositions`] into these, overwriting existing entries.
+    pub fn extend(&mut self, other: ScrollPositions) {
+        self.offsets.extend(other.offsets);
+    }
+
+    /// Returns an iterator over the recorded positions.
+    pub fn iter(&self) -> impl Iterator<Item = (&Id, AbsoluteOffset)> {
+        self.offsets.iter().map(|(id, offset)| (id, *offset))
+    }
+
+    /// Returns the number of recorded positions.
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Returns true if no positions are recorded.
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ScrollPositions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use std::collections::BTreeMap;
+
+        let named: BTreeMap<&str, &AbsoluteOffset> = self
+            .offsets
+            .iter()
+            .filter_map(|(id, offset)| Some((id.name()?, offset)))
+            .collect();
+
+        serializer.collect_map(named)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ScrollPositions {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let named = HashMap::<String, AbsoluteOffset>::deserialize(deserializer)?;
+
+        Ok(Self {
+            offsets: named
+                .into_iter()
+                .map(|(name, offset)| (Id::from(name), offset))
+                .collect(),
+        })
+    }
+}
+
 /// Computes the scroll delta needed to make `target_rect` visible within the viewport.
 fn compute_visible_delta(
     bounds: Rectangle,
@@ -308,6 +593,7 @@ fn compute_visible_delta(
 
 /// The amount of absolute offset in each direction of a [`Scrollable`].
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AbsoluteOffset<T = f32> {
     /// The amount of horizontal offset
     pub x: T,
@@ -328,6 +614,7 @@ impl From<AbsoluteOffset> for AbsoluteOffset<Option<f32>> {
 ///
 /// A value of `0.0` means start, while `1.0` means end.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RelativeOffset<T = f32> {
     /// The amount of horizontal offset
     pub x: T,
@@ -351,3 +638,112 @@ impl From<RelativeOffset> for RelativeOffset<Option<f32>> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct State {
+        offset: AbsoluteOffset,
+    }
+
+    impl Scrollable for State {
+        fn snap_to(&mut self, _offset: RelativeOffset<Option<f32>>) {}
+
+        fn scroll_to(&mut self, offset: AbsoluteOffset<Option<f32>>) {
+            self.offset.x = offset.x.unwrap_or(self.offset.x);
+            self.offset.y = offset.y.unwrap_or(self.offset.y);
+        }
+
+        fn scroll_by(&mut self, _offset: AbsoluteOffset, _bounds: Rectangle, _content: Rectangle) {}
+
+        fn absolute_offset(
+            &self,
+            _bounds: Rectangle,
+            _content: Rectangle,
+        ) -> Option<AbsoluteOffset> {
+            Some(self.offset)
+        }
+    }
+
+    fn visit(operation: &mut dyn Operation<impl Sized>, id: Option<&Id>, state: &mut State) {
+        operation.scrollable(
+            id,
+            Rectangle::default(),
+            Rectangle::default(),
+            Vector::ZERO,
+            state,
+        );
+    }
+
+    #[test]
+    fn positions_are_restored_by_id() {
+        let sidebar = Id::new("sidebar");
+        let mut state = State {
+            offset: AbsoluteOffset { x: 0.0, y: 120.0 },
+        };
+        let mut anonymous = State {
+            offset: AbsoluteOffset { x: 5.0, y: 5.0 },
+        };
+
+        let mut collect = positions();
+        visit(&mut collect, Some(&sidebar), &mut state);
+        visit(&mut collect, None, &mut anonymous);
+
+        let Outcome::Some(positions) = collect.finish() else {
+            panic!("positions operation must produce an outcome");
+        };
+
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions.get(&sidebar), Some(state.offset));
+
+        let mut restored = State::default();
+        let mut restore = restore::<()>(positions);
+        visit(&mut restore, Some(&sidebar), &mut restored);
+
+        assert_eq!(restored.offset, AbsoluteOffset { x: 0.0, y: 120.0 });
+    }
+
+    #[test]
+    fn scrolls_to_widget_by_id() {
+        let document = Id::new("document");
+        let heading = Id::new("installation");
+
+        let content = Rectangle::new(crate::Point::new(0.0, 40.0), crate::Size::new(100.0, 900.0));
+
+        let mut find = scroll_to_widget::<()>(document.clone(), heading.clone());
+        let mut state = State::default();
+
+        let heading_at = |operation: &mut dyn Operation<()>, y: f32| {
+            operation.container(
+                Some(&heading),
+                Rectangle::new(crate::Point::new(0.0, y), crate::Size::new(100.0, 20.0)),
+            );
+        };
+
+        find.traverse(&mut |operation| {
+            // Widgets with the same `Id` outside of the target are ignored
+            heading_at(operation, 10.0);
+
+            operation.scrollable(
+                Some(&document),
+                Rectangle::default(),
+                content,
+                Vector::ZERO,
+                &mut state,
+            );
+            operation.traverse(&mut |operation| heading_at(operation, 340.0));
+
+            heading_at(operation, 2000.0);
+        });
+
+        let Outcome::Chain(mut scroll) = find.finish() else {
+            panic!("widget must be found");
+        };
+
+        visit(scroll.as_mut(), Some(&document), &mut state);
+
+        assert_eq!(state.offset, AbsoluteOffset { x: 0.0, y: 300.0 });
+    }
+}
@@ -0,0 +1,61 @@
+//! Operate on widgets that display selectable text.
+use crate::Rectangle;
+use crate::text::Selection;
+use crate::widget::Id;
+use crate::widget::operation::{Operation, Outcome};
+
+use std::any::Any;
+
+/// Produces an [`Operation`] that returns the currently selected text of any
+/// selectable text widget, if any.
+pub fn selected_text() -> impl Operation<Option<String>> {
+    struct SelectedText {
+        text: Option<String>,
+    }
+
+    impl Operation<Option<String>> for SelectedText {
+        fn custom(&mut self, _id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Any) {
+            if self.text.is_some() {
+                return;
+            }
+
+            if let Some(selection) = state.downcast_ref::<Selection>() {
+                self.text = selection.selected_text().map(str::to_owned);
+            }
+        }
+
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<Option<String>>)) {
+            if self.text.is_some() {
+                return;
+            }
+
+            operate(self);
+        }
+
+        fn finish(&self) -> Outcome<Option<String>> {
+            Outcome::Some(self.text.clone())
+        }
+    }
+
+    SelectedText { text: None }
+}
+
+/// Produces an [`Operation`] that clears the selection of every selectable
+/// text widget.
+pub fn clear<T>() -> impl Operation<T> {
+    struct Clear;
+
+    impl<T> Operation<T> for Clear {
+        fn custom(&mut self, _id: Option<&Id>, _bounds: Rectangle, state: &mut dyn Any) {
+            if let Some(selection) = state.downcast_mut::<Selection>() {
+                selection.clear();
+            }
+        }
+
+        fn traverse(&mut self, operate: &mut dyn FnMut(&mut dyn Operation<T>)) {
+            operate(self);
+        }
+    }
+
+    Clear
+}
@@ -27,9 +27,12 @@ use crate::renderer;
 use crate::text;
 use crate::text::paragraph::{self, Paragraph};
 use crate::widget::tree::{self, Tree};
-use crate::{Color, Element, Layout, Length, Pixels, Rectangle, Size, Theme, Widget};
+use crate::{
+    Clipboard, Color, Element, Event, Layout, Length, Pixels, Point, Rectangle, Shell, Size, Theme,
+    Widget,
+};
 
-pub use text::{Alignment, LineHeight, Shaping, Wrapping};
+pub use text::{Alignment, LineHeight, Shaping, Truncation, Wrapping};
 
 /// A bunch of text.
 ///
@@ -60,6 +63,7 @@ where
 {
     fragment: text::Fragment<'a>,
     format: Format<Renderer::Font>,
+    is_selectable: bool,
     class: Theme::Class<'a>,
 }
 
@@ -73,6 +77,7 @@ where
         Text {
             fragment: fragment.into_fragment(),
             format: Format::default(),
+            is_selectable: false,
             class: Theme::default(),
         }
     }
@@ -147,6 +152,24 @@ where
         self
     }
 
+    /// Sets the [`Truncation`] strategy of the [`Text`].
+    ///
+    /// Truncated text is laid out in a single line.
+    pub fn truncation(mut self, truncation: Truncation) -> Self {
+        self.format.truncation = truncation;
+        self
+    }
+
+    /// Sets whether the [`Text`] can be selected and copied by the user.
+    ///
+    /// Selectable text can be selected by dragging and double or triple
+    /// clicking, and copied with the usual keyboard shortcut. See
+    /// [`text::Selection`] for the details.
+    pub fn selectable(mut self, is_selectable: bool) -> Self {
+        self.is_selectable = is_selectable;
+        self
+    }
+
     /// Sets the style of the [`Text`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -187,17 +210,55 @@ where
 /// The internal state of a [`Text`] widget.
 pub type State<P> = paragraph::Plain<P>;
 
+/// The internal state of a selectable [`Text`] widget.
+#[derive(Debug, Default)]
+struct Selectable<P: Paragraph> {
+    paragraph: State<P>,
+    selection: text::Selection,
+}
+
+impl<Theme, Renderer> Text<'_, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn paragraph<'b>(&self, tree: &'b Tree) -> &'b State<Renderer::Paragraph> {
+        if self.is_selectable {
+            &tree
+                .state
+                .downcast_ref::<Selectable<Renderer::Paragraph>>()
+                .paragraph
+        } else {
+            tree.state.downcast_ref::<State<Renderer::Paragraph>>()
+        }
+    }
+
+    /// Selection offsets index the laid out paragraph, which no longer
+    /// matches the content once it has been truncated.
+    fn is_selectable(&self, tree: &Tree) -> bool {
+        self.is_selectable && !self.paragraph(tree).is_truncated()
+    }
+}
+
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Text<'_, Theme, Renderer>
 where
     Theme: Catalog,
     Renderer: text::Renderer,
 {
     fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<State<Renderer::Paragraph>>()
+        if self.is_selectable {
+            tree::Tag::of::<Selectable<Renderer::Paragraph>>()
+        } else {
+            tree::Tag::of::<State<Renderer::Paragraph>>()
+        }
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(paragraph::Plain::<Renderer::Paragraph>::default())
+        if self.is_selectable {
+            tree::State::new(Selectable::<Renderer::Paragraph>::default())
+        } else {
+            tree::State::new(paragraph::Plain::<Renderer::Paragraph>::default())
+        }
     }
 
     fn size(&self) -> Size<Length> {
@@ -213,13 +274,35 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        layout(
-            tree.state.downcast_mut::<State<Renderer::Paragraph>>(),
+        if !self.is_selectable {
+            return layout(
+                tree.state.downcast_mut::<State<Renderer::Paragraph>>(),
+                renderer,
+                limits,
+                &self.fragment,
+                self.format,
+            );
+        }
+
+        let state = tree.state.downcast_mut::<Selectable<Renderer::Paragraph>>();
+
+        if state.paragraph.content() != self.fragment.as_ref() {
+            state.selection.clear();
+        }
+
+        let node = layout(
+            &mut state.paragraph,
             renderer,
             limits,
             &self.fragment,
             self.format,
-        )
+        );
+
+        if state.paragraph.is_truncated() {
+            state.selection.clear();
+        }
+
+        node
     }
 
     fn draw(
@@ -232,9 +315,21 @@ where
         _cursor_position: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let state = self.paragraph(tree);
         let style = theme.style(&self.class);
 
+        if self.is_selectable(tree) {
+            let selectable = tree.state.downcast_ref::<Selectable<Renderer::Paragraph>>();
+
+            selectable.selection.draw(
+                renderer,
+                state.raw(),
+                anchor(layout.bounds(), state.raw()),
+                theme.selection(),
+                viewport,
+            );
+        }
+
         draw(
             renderer,
             defaults,
@@ -245,14 +340,70 @@ where
         );
     }
 
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        if !self.is_selectable(tree) {
+            return;
+        }
+
+        let state = tree.state.downcast_mut::<Selectable<Renderer::Paragraph>>();
+
+        state.selection.update(
+            event,
+            layout.bounds(),
+            anchor(layout.bounds(), state.paragraph.raw()),
+            cursor,
+            state.paragraph.raw(),
+            &self.fragment,
+            clipboard,
+            shell,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if !self.is_selectable(tree) {
+            return mouse::Interaction::None;
+        }
+
+        let state = tree.state.downcast_ref::<Selectable<Renderer::Paragraph>>();
+
+        if state.selection.is_dragging() || cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::None
+        }
+    }
+
     fn operate(
         &mut self,
-        _tree: &mut Tree,
+        tree: &mut Tree,
         layout: Layout<'_>,
         _renderer: &Renderer,
         operation: &mut dyn super::Operation,
     ) {
         operation.text(None, layout.bounds(), &self.fragment);
+
+        if self.is_selectable(tree) {
+            let state = tree.state.downcast_mut::<Selectable<Renderer::Paragraph>>();
+
+            operation.custom(None, layout.bounds(), &mut state.selection);
+        }
     }
 
     #[cfg(feature = "accessibility")]
@@ -286,6 +437,7 @@ pub struct Format<Font> {
     pub align_y: alignment::Vertical,
     pub shaping: Shaping,
     pub wrapping: Wrapping,
+    pub truncation: Truncation,
 }
 
 impl<Font> Default for Format<Font> {
@@ -300,6 +452,7 @@ impl<Font> Default for Format<Font> {
             align_y: alignment::Vertical::Top,
             shaping: Shaping::default(),
             wrapping: Wrapping::default(),
+            truncation: Truncation::default(),
         }
     }
 }
@@ -331,6 +484,7 @@ where
             align_y: format.align_y,
             shaping: format.shaping,
             wrapping: format.wrapping,
+            truncation: format.truncation,
             hint_factor: renderer.scale_factor(),
         });
 
@@ -349,18 +503,89 @@ pub fn draw<Renderer>(
 ) where
     Renderer: text::Renderer,
 {
-    let anchor = bounds.anchor(
+    fill_paragraph(
+        renderer,
+        paragraph,
+        anchor(bounds, paragraph),
+        appearance.color.unwrap_or(style.text_color),
+        bounds,
+        viewport,
+    );
+}
+
+/// Returns the position where a [`Paragraph`] laid out in the given bounds
+/// is drawn, following its alignment.
+pub fn anchor(bounds: Rectangle, paragraph: &impl Paragraph) -> Point {
+    bounds.anchor(
         paragraph.min_bounds(),
         paragraph.align_x(),
         paragraph.align_y(),
-    );
+    )
+}
 
-    renderer.fill_paragraph(
-        paragraph,
-        anchor,
-        appearance.color.unwrap_or(style.text_color),
-        *viewport,
-    );
+/// Fills the given [`Paragraph`] laid out in the given bounds, fading out
+/// its end if it is truncated with [`Truncation::Fade`].
+pub fn fill_paragraph<Renderer>(
+    renderer: &mut Renderer,
+    paragraph: &Renderer::Paragraph,
+    position: Point,
+    color: Color,
+    bounds: Rectangle,
+    viewport: &Rectangle,
+) where
+    Renderer: text::Renderer,
+{
+    const FADE_STEPS: u16 = 8;
+
+    let Truncation::Fade(distance) = paragraph.truncation() else {
+        renderer.fill_paragraph(paragraph, position, color, *viewport);
+        return;
+    };
+
+    if !paragraph.is_truncated() {
+        renderer.fill_paragraph(paragraph, position, color, *viewport);
+        return;
+    }
+
+    let distance = distance.0.min(bounds.width);
+    let step = distance / f32::from(FADE_STEPS);
+    let is_rtl = crate::layout_direction().is_rtl();
+
+    let solid = Rectangle {
+        x: if is_rtl {
+            bounds.x + distance
+        } else {
+            bounds.x
+        },
+        width: bounds.width - distance,
+        ..bounds
+    };
+
+    if let Some(clip_bounds) = solid.intersection(viewport) {
+        renderer.fill_paragraph(paragraph, position, color, clip_bounds);
+    }
+
+    for i in 0..FADE_STEPS {
+        let offset = step * f32::from(i);
+
+        let strip = Rectangle {
+            x: if is_rtl {
+                bounds.x + distance - offset - step
+            } else {
+                bounds.x + bounds.width - distance + offset
+            },
+            width: step,
+            ..bounds
+        };
+
+        let Some(clip_bounds) = strip.intersection(viewport) else {
+            continue;
+        };
+
+        let alpha = 1.0 - (f32::from(i) + 0.5) / f32::from(FADE_STEPS);
+
+        renderer.fill_paragraph(paragraph, position, color.scale_alpha(alpha), clip_bounds);
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<Text<'a, Theme, Renderer>>
@@ -413,6 +638,10 @@ pub trait Catalog: Sized {
 
     /// The [`Style`] of a class with the given status.
     fn style(&self, item: &Self::Class<'_>) -> Style;
+
+    /// The [`Color`] of the highlight of the selection of a selectable
+    /// [`Text`].
+    fn selection(&self) -> Color;
 }
 
 /// A styling function for a [`Text`].
@@ -430,6 +659,10 @@ impl Catalog for Theme {
     fn style(&self, class: &Self::Class<'_>) -> Style {
         class(self)
     }
+
+    fn selection(&self) -> Color {
+        self.accent.base.scale_alpha(0.3)
+    }
 }
 
 /// The default text styling; color is inherited.
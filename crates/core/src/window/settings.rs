@@ -24,8 +24,8 @@ mod platform;
 #[path = "settings/other.rs"]
 mod platform;
 
-use crate::Size;
 use crate::window::{Icon, Level, Position};
+use crate::{LayoutDirection, Size};
 
 pub use platform::PlatformSpecific;
 
@@ -98,6 +98,11 @@ pub struct Settings {
     ///
     /// By default this is enabled.
     pub exit_on_close_request: bool,
+
+    /// The layout direction of the window.
+    ///
+    /// If `None`, the window follows the global layout direction.
+    pub layout_direction: Option<LayoutDirection>,
 }
 
 impl Default for Settings {
@@ -120,6 +125,7 @@ impl Default for Settings {
             icon: None,
             exit_on_close_request: true,
             platform_specific: PlatformSpecific::default(),
+            layout_direction: None,
         }
     }
 }
@@ -2,7 +2,9 @@
 pub mod fill;
 pub mod frame;
 pub mod path;
+pub mod scene;
 pub mod stroke;
+pub mod svg;
 
 mod cache;
 mod style;
@@ -12,6 +14,7 @@ pub use cache::Cache;
 pub use fill::Fill;
 pub use frame::Frame;
 pub use path::Path;
+pub use scene::Scene;
 pub use stroke::{LineCap, LineDash, LineJoin, Stroke};
 pub use style::Style;
 pub use text::Text;
@@ -10,7 +10,15 @@ pub use builder::Builder;
 pub use lyon_path;
 
 use crate::core::border;
-use crate::core::{Point, Size};
+use crate::core::{Point, Rectangle, Size};
+use crate::geometry::fill;
+
+use lyon_algorithms::measure::{PathMeasurements, SampleType};
+use lyon_path::iterator::PathIterator;
+
+/// The maximum distance between a curve and its flattened approximation
+/// used when measuring or combining a [`Path`].
+const TOLERANCE: f32 = 0.01;
 
 /// An immutable set of points that may or may not be connected.
 ///
@@ -76,4 +84,293 @@ impl Path {
             raw: self.raw.clone().transformed(transform),
         }
     }
+
+    /// Returns true if the given [`Point`] is inside the area of the [`Path`]
+    /// when filled with the given [`fill::Rule`].
+    pub fn contains(&self, point: Point, rule: fill::Rule) -> bool {
+        let rule = match rule {
+            fill::Rule::NonZero => lyon_path::FillRule::NonZero,
+            fill::Rule::EvenOdd => lyon_path::FillRule::EvenOdd,
+        };
+
+        lyon_algorithms::hit_test::hit_test_path(
+            &lyon_path::math::point(point.x, point.y),
+            self.raw.iter(),
+            rule,
+            TOLERANCE,
+        )
+    }
+
+    /// Returns true if the given [`Point`] is covered by a stroke of the
+    /// [`Path`] with the given width.
+    ///
+    /// Joins and caps are considered round, which is usually what you want
+    /// when picking thin lines with a cursor.
+    pub fn stroke_contains(&self, point: Point, width: f32) -> bool {
+        let point = lyon_path::math::point(point.x, point.y);
+        let max_distance = width / 2.0;
+
+        let near = |from: lyon_path::math::Point, to: lyon_path::math::Point| {
+            lyon_path::geom::LineSegment { from, to }.distance_to_point(point) <= max_distance
+        };
+
+        self.raw
+            .iter()
+            .flattened(TOLERANCE)
+            .any(|event| match event {
+                lyon_path::Event::Begin { at } => (at - point).length() <= max_distance,
+                lyon_path::Event::Line { from, to } => near(from, to),
+                lyon_path::Event::End { last, first, close } => close && near(last, first),
+                lyon_path::Event::Quadratic { .. } | lyon_path::Event::Cubic { .. } => false,
+            })
+    }
+
+    /// Returns the smallest [`Rectangle`] containing the whole [`Path`],
+    /// including its curves.
+    ///
+    /// An empty [`Path`] has empty bounds at the origin.
+    pub fn bounds(&self) -> Rectangle {
+        if self.raw.iter().next().is_none() {
+            return Rectangle::default();
+        }
+
+        let bounds = lyon_algorithms::aabb::bounding_box(self.raw.iter());
+
+        Rectangle {
+            x: bounds.min.x,
+            y: bounds.min.y,
+            width: bounds.width(),
+            height: bounds.height(),
+        }
+    }
+
+    /// Returns the total length of the [`Path`], adding up all of its
+    /// subpaths.
+    pub fn length(&self) -> f32 {
+        PathMeasurements::from_path(&self.raw, TOLERANCE).length()
+    }
+
+    /// Returns the [`Point`] found at the given distance along the [`Path`],
+    /// measured from its start.
+    ///
+    /// Returns `None` if the distance is negative or longer than the
+    /// [`length`](Self::length) of the [`Path`].
+    pub fn point_at_length(&self, length: f32) -> Option<Point> {
+        let measurements = PathMeasurements::from_path(&self.raw, TOLERANCE);
+
+        if !(0.0..=measurements.length()).contains(&length) {
+            return None;
+        }
+
+        let mut sampler = measurements.create_sampler(&self.raw, SampleType::Distance);
+        let position = sampler.sample(length).position();
+
+        Some(Point::new(position.x, position.y))
+    }
+
+    /// Returns a new [`Path`] covering the area of both the current [`Path`]
+    /// and the given one.
+    ///
+    /// Boolean operations work on the areas of filled paths with the
+    /// [`fill::Rule::NonZero`] rule; open subpaths are closed implicitly
+    /// and curves are flattened.
+    pub fn union(&self, other: &Path) -> Path {
+        self.overlay(other, i_overlay::core::overlay_rule::OverlayRule::Union)
+    }
+
+    /// Returns a new [`Path`] covering the area shared by both the current
+    /// [`Path`] and the given one.
+    ///
+    /// See [`union`](Self::union) for the details of boolean operations.
+    pub fn intersection(&self, other: &Path) -> Path {
+        self.overlay(other, i_overlay::core::overlay_rule::OverlayRule::Intersect)
+    }
+
+    /// Returns a new [`Path`] covering the area of the current [`Path`]
+    /// that is not covered by the given one.
+    ///
+    /// See [`union`](Self::union) for the details of boolean operations.
+    pub fn difference(&self, other: &Path) -> Path {
+        self.overlay(
+            other,
+            i_overlay::core::overlay_rule::OverlayRule::Difference,
+        )
+    }
+
+    fn overlay(&self, other: &Path, rule: i_overlay::core::overlay_rule::OverlayRule) -> Path {
+        use i_overlay::core::fill_rule::FillRule;
+        use i_overlay::float::single::SingleFloatOverlay;
+
+        let shapes = self
+            .contours()
+            .overlay(&other.contours(), rule, FillRule::NonZero);
+
+        Self::new(|builder| {
+            for contour in shapes.iter().flatten() {
+                let mut points = contour
+                    .iter()
+                    .map(|[x, y]| Point::new(*x as f32, *y as f32));
+
+                let Some(first) = points.next() else {
+                    continue;
+                };
+
+                builder.move_to(first);
+
+                for point in points {
+                    builder.line_to(point);
+                }
+
+                builder.close();
+            }
+        })
+    }
+
+    fn contours(&self) -> Vec<Vec<[f64; 2]>> {
+        let mut contours = Vec::new();
+        let mut contour = Vec::new();
+
+        for event in self.raw.iter().flattened(TOLERANCE) {
+            match event {
+                lyon_path::Event::Begin { at } => {
+                    contour.push([f64::from(at.x), f64::from(at.y)]);
+                }
+                lyon_path::Event::Line { to, .. } => {
+                    contour.push([f64::from(to.x), f64::from(to.y)]);
+                }
+                lyon_path::Event::End { .. } => {
+                    if contour.len() > 2 {
+                        contours.push(std::mem::take(&mut contour));
+                    } else {
+                        contour.clear();
+                    }
+                }
+                lyon_path::Event::Quadratic { .. } | lyon_path::Event::Cubic { .. } => {}
+            }
+        }
+
+        contours
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the total area of the closed subpaths of a [`Path`], using
+    /// the shoelace formula.
+    fn area(path: &Path) -> f32 {
+        let mut area = 0.0;
+
+        for event in path.raw().iter().flattened(TOLERANCE) {
+            match event {
+                lyon_path::Event::Line { from, to } => area += from.x * to.y - to.x * from.y,
+                lyon_path::Event::End { last, first, .. } => {
+                    area += last.x * first.y - first.x * last.y;
+                }
+                _ => {}
+            }
+        }
+
+        (area / 2.0).abs()
+    }
+
+    fn assert_near(a: f32, b: f32) {
+        assert!((a - b).abs() < 0.01, "{a} is not close to {b}");
+    }
+
+    #[test]
+    fn rectangle_contains() {
+        let rectangle = Path::rectangle(Point::new(10.0, 10.0), Size::new(20.0, 10.0));
+
+        assert!(rectangle.contains(Point::new(15.0, 15.0), fill::Rule::NonZero));
+        assert!(!rectangle.contains(Point::new(5.0, 15.0), fill::Rule::NonZero));
+        assert!(!rectangle.contains(Point::new(15.0, 25.0), fill::Rule::EvenOdd));
+    }
+
+    #[test]
+    fn circle_contains() {
+        let circle = Path::circle(Point::new(50.0, 50.0), 10.0);
+
+        assert!(circle.contains(Point::new(50.0, 50.0), fill::Rule::NonZero));
+        assert!(circle.contains(Point::new(57.0, 57.0), fill::Rule::NonZero));
+        assert!(!circle.contains(Point::new(58.0, 58.0), fill::Rule::NonZero));
+    }
+
+    #[test]
+    fn even_odd_excludes_holes() {
+        let ring = Path::new(|builder| {
+            builder.circle(Point::ORIGIN, 10.0);
+            builder.circle(Point::ORIGIN, 5.0);
+        });
+
+        assert!(ring.contains(Point::ORIGIN, fill::Rule::NonZero));
+        assert!(!ring.contains(Point::ORIGIN, fill::Rule::EvenOdd));
+        assert!(ring.contains(Point::new(7.0, 0.0), fill::Rule::EvenOdd));
+    }
+
+    #[test]
+    fn stroke_contains() {
+        let line = Path::line(Point::ORIGIN, Point::new(100.0, 0.0));
+
+        assert!(line.stroke_contains(Point::new(50.0, 1.5), 4.0));
+        assert!(!line.stroke_contains(Point::new(50.0, 2.5), 4.0));
+        assert!(line.stroke_contains(Point::new(-1.5, 0.0), 4.0));
+
+        let rectangle = Path::rectangle(Point::ORIGIN, Size::new(10.0, 10.0));
+
+        assert!(rectangle.stroke_contains(Point::new(0.5, 5.0), 2.0));
+        assert!(!rectangle.stroke_contains(Point::new(5.0, 5.0), 2.0));
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(Path::new(|_| {}).bounds(), Rectangle::default());
+
+        assert_eq!(
+            Path::rectangle(Point::new(10.0, 20.0), Size::new(30.0, 40.0)).bounds(),
+            Rectangle::new(Point::new(10.0, 20.0), Size::new(30.0, 40.0))
+        );
+
+        let circle = Path::circle(Point::new(50.0, 50.0), 10.0).bounds();
+
+        assert_near(circle.x, 40.0);
+        assert_near(circle.y, 40.0);
+        assert_near(circle.width, 20.0);
+        assert_near(circle.height, 20.0);
+    }
+
+    #[test]
+    fn length_of_polyline() {
+        let polyline = Path::new(|builder| {
+            builder.move_to(Point::ORIGIN);
+            builder.line_to(Point::new(30.0, 0.0));
+            builder.line_to(Point::new(30.0, 40.0));
+        });
+
+        assert_near(polyline.length(), 70.0);
+
+        let point = polyline.point_at_length(50.0).unwrap();
+
+        assert_near(point.x, 30.0);
+        assert_near(point.y, 20.0);
+
+        assert_eq!(polyline.point_at_length(-1.0), None);
+        assert_eq!(polyline.point_at_length(71.0), None);
+    }
+
+    #[test]
+    fn boolean_operations() {
+        let a = Path::rectangle(Point::ORIGIN, Size::new(20.0, 10.0));
+        let b = Path::rectangle(Point::new(10.0, 0.0), Size::new(20.0, 10.0));
+
+        assert_near(area(&a.union(&b)), 300.0);
+        assert_near(area(&a.intersection(&b)), 100.0);
+        assert_near(area(&a.difference(&b)), 100.0);
+
+        let far = Path::rectangle(Point::new(100.0, 100.0), Size::new(5.0, 5.0));
+
+        assert_near(area(&a.intersection(&far)), 0.0);
+        assert_near(area(&a.union(&far)), 225.0);
+    }
 }
@@ -0,0 +1,765 @@
+//! Retain shapes in a scene graph and redraw only what changed.
+//!
+//! A [`Scene`] owns a set of [`Node`]s, each with its own transform,
+//! z-index and geometry [`Cache`]. Changing a node only invalidates its
+//! own cache, so large diagrams do not need to be tessellated from scratch
+//! every time a single shape moves.
+use crate::cache::Group;
+use crate::core::{Point, Radians, Rectangle, Size, Vector};
+use crate::damage;
+use crate::geometry::{self, Cache, Fill, Frame, Path, Stroke};
+
+use rustc_hash::FxHashMap;
+
+/// The default size of the cells of the spatial index of a [`Scene`].
+const DEFAULT_CELL_SIZE: f32 = 256.0;
+
+/// The maximum amount of cells a single [`Node`] may occupy in the spatial
+/// index before it is tracked separately.
+const MAX_CELLS_PER_NODE: i64 = 64;
+
+/// The identifier of a [`Node`] in a [`Scene`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Id(u64);
+
+/// A drawable shape of a [`Node`].
+#[derive(Debug, Clone)]
+pub enum Shape {
+    /// A [`Path`] filled with the given [`Fill`].
+    Fill {
+        /// The [`Path`] to fill.
+        path: Path,
+        /// The [`Fill`] style.
+        fill: Fill,
+    },
+    /// A [`Path`] stroked with the given [`Stroke`].
+    Stroke {
+        /// The [`Path`] to stroke.
+        path: Path,
+        /// The [`Stroke`] style.
+        stroke: Stroke<'static>,
+    },
+}
+
+impl Shape {
+    fn bounds(&self) -> Rectangle {
+        match self {
+            Shape::Fill { path, .. } => path.bounds(),
+            Shape::Stroke { path, stroke } => path.bounds().expand(stroke.width / 2.0),
+        }
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        match self {
+            Shape::Fill { path, fill } => path.contains(point, fill.rule),
+            Shape::Stroke { path, stroke } => path.stroke_contains(point, stroke.width),
+        }
+    }
+}
+
+/// The placement of a [`Node`] in its [`Scene`].
+///
+/// Scaling is applied first, then rotation around the local origin and,
+/// finally, the translation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform {
+    /// The translation of the [`Node`].
+    pub translation: Vector,
+    /// The rotation of the [`Node`].
+    pub rotation: Radians,
+    /// The uniform scale factor of the [`Node`].
+    pub scale: f32,
+}
+
+impl Transform {
+    /// The identity [`Transform`].
+    pub const IDENTITY: Self = Self {
+        translation: Vector::ZERO,
+        rotation: Radians(0.0),
+        scale: 1.0,
+    };
+
+    /// Maps a [`Point`] in the local coordinates of a [`Node`] to the
+    /// coordinates of its [`Scene`].
+    pub fn apply(&self, point: Point) -> Point {
+        let (sin, cos) = self.rotation.0.sin_cos();
+        let x = point.x * self.scale;
+        let y = point.y * self.scale;
+
+        Point::new(
+            x * cos - y * sin + self.translation.x,
+            x * sin + y * cos + self.translation.y,
+        )
+    }
+
+    /// Maps a [`Point`] in the coordinates of a [`Scene`] to the local
+    /// coordinates of a [`Node`].
+    ///
+    /// Returns `None` if the [`Transform`] has a scale of zero.
+    pub fn invert(&self, point: Point) -> Option<Point> {
+        if self.scale == 0.0 {
+            return None;
+        }
+
+        let (sin, cos) = self.rotation.0.sin_cos();
+        let x = point.x - self.translation.x;
+        let y = point.y - self.translation.y;
+
+        Some(Point::new(
+            (x * cos + y * sin) / self.scale,
+            (y * cos - x * sin) / self.scale,
+        ))
+    }
+
+    fn apply_to_bounds(&self, bounds: Rectangle) -> Rectangle {
+        let corners = [
+            Point::new(bounds.x, bounds.y),
+            Point::new(bounds.x + bounds.width, bounds.y),
+            Point::new(bounds.x, bounds.y + bounds.height),
+            Point::new(bounds.x + bounds.width, bounds.y + bounds.height),
+        ]
+        .map(|corner| self.apply(corner));
+
+        let (min, max) = corners.iter().fold(
+            (
+                Point::new(f32::INFINITY, f32::INFINITY),
+                Point::new(f32::NEG_INFINITY, f32::NEG_INFINITY),
+            ),
+            |(min, max), corner| {
+                (
+                    Point::new(min.x.min(corner.x), min.y.min(corner.y)),
+                    Point::new(max.x.max(corner.x), max.y.max(corner.y)),
+                )
+            },
+        );
+
+        Rectangle::new(min, Size::new(max.x - min.x, max.y - min.y))
+    }
+}
+
+impl Default for Transform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// A set of shapes drawn together in a [`Scene`].
+#[derive(Debug, Clone)]
+pub struct Node {
+    /// The shapes of the [`Node`], drawn in order and expressed in local
+    /// coordinates.
+    pub shapes: Vec<Shape>,
+    /// The [`Transform`] of the [`Node`].
+    pub transform: Transform,
+    /// The z-index of the [`Node`].
+    ///
+    /// Nodes with a higher z-index are drawn on top. Nodes with the same
+    /// z-index are drawn in insertion order.
+    pub z_index: i32,
+    /// Whether the [`Node`] is drawn and can be picked.
+    pub visible: bool,
+}
+
+impl Node {
+    /// Creates a new empty and visible [`Node`].
+    pub fn new() -> Self {
+        Self {
+            shapes: Vec::new(),
+            transform: Transform::IDENTITY,
+            z_index: 0,
+            visible: true,
+        }
+    }
+
+    /// Adds a [`Path`] filled with the given style to the [`Node`].
+    pub fn fill(mut self, path: Path, fill: impl Into<Fill>) -> Self {
+        self.shapes.push(Shape::Fill {
+            path,
+            fill: fill.into(),
+        });
+        self
+    }
+
+    /// Adds a [`Path`] stroked with the given style to the [`Node`].
+    pub fn stroke(mut self, path: Path, stroke: impl Into<Stroke<'static>>) -> Self {
+        self.shapes.push(Shape::Stroke {
+            path,
+            stroke: stroke.into(),
+        });
+        self
+    }
+
+    /// Sets the [`Transform`] of the [`Node`].
+    pub fn transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// Sets the translation of the [`Node`].
+    pub fn translate(mut self, translation: Vector) -> Self {
+        self.transform.translation = translation;
+        self
+    }
+
+    /// Sets the z-index of the [`Node`].
+    pub fn z_index(mut self, z_index: i32) -> Self {
+        self.z_index = z_index;
+        self
+    }
+
+    /// Returns the bounds of the [`Node`] in the coordinates of its
+    /// [`Scene`].
+    pub fn bounds(&self) -> Rectangle {
+        let mut shapes = self.shapes.iter().map(Shape::bounds);
+
+        let Some(first) = shapes.next() else {
+            return Rectangle::new(Point::ORIGIN + self.transform.translation, Size::ZERO);
+        };
+
+        self.transform
+            .apply_to_bounds(shapes.fold(first, |bounds, shape| bounds.union(&shape)))
+    }
+
+    /// Returns true if the given [`Point`], in the coordinates of the
+    /// [`Scene`], hits any of the shapes of the [`Node`].
+    pub fn contains(&self, point: Point) -> bool {
+        let Some(local) = self.transform.invert(point) else {
+            return false;
+        };
+
+        self.shapes.iter().any(|shape| shape.contains(local))
+    }
+
+    fn draw<Renderer>(&self, frame: &mut Frame<Renderer>)
+    where
+        Renderer: geometry::Renderer,
+    {
+        frame.with_save(|frame| {
+            frame.translate(self.transform.translation);
+            frame.rotate(self.transform.rotation);
+            frame.scale(self.transform.scale);
+
+            for shape in &self.shapes {
+                match shape {
+                    Shape::Fill { path, fill } => frame.fill(path, *fill),
+                    Shape::Stroke { path, stroke } => frame.stroke(path, *stroke),
+                }
+            }
+        });
+    }
+}
+
+impl Default for Node {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A retained set of [`Node`]s with per-node invalidation and picking.
+///
+/// Every [`Node`] keeps its own geometry [`Cache`], so [`draw`] only
+/// tessellates the nodes that changed since the previous frame. The
+/// regions affected by those changes can be obtained with
+/// [`take_damage`].
+///
+/// [`draw`]: Self::draw
+/// [`take_damage`]: Self::take_damage
+pub struct Scene<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    entries: FxHashMap<Id, Entry<Renderer>>,
+    index: Index,
+    damage: Vec<Rectangle>,
+    group: Group,
+    next_id: u64,
+}
+
+struct Entry<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    node: Node,
+    bounds: Rectangle,
+    cache: Cache<Renderer>,
+}
+
+impl<Renderer> Scene<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    /// Creates a new empty [`Scene`].
+    pub fn new() -> Self {
+        Self::with_cell_size(DEFAULT_CELL_SIZE)
+    }
+
+    /// Creates a new empty [`Scene`] whose spatial index uses cells of the
+    /// given size.
+    ///
+    /// Smaller cells speed up picking in dense scenes, at the cost of
+    /// bookkeeping for large nodes.
+    pub fn with_cell_size(cell_size: f32) -> Self {
+        Self {
+            entries: FxHashMap::default(),
+            index: Index::new(cell_size),
+            damage: Vec::new(),
+            group: Group::unique(),
+            next_id: 0,
+        }
+    }
+
+    /// Returns the amount of nodes in the [`Scene`].
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the [`Scene`] has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Adds a [`Node`] to the [`Scene`] and returns its [`Id`].
+    pub fn insert(&mut self, node: Node) -> Id {
+        let id = Id(self.next_id);
+        self.next_id += 1;
+
+        let bounds = node.bounds();
+
+        self.index.insert(id, bounds);
+        self.damage.push(bounds);
+
+        let _ = self.entries.insert(
+            id,
+            Entry {
+                node,
+                bounds,
+                cache: Cache::with_group(self.group),
+            },
+        );
+
+        id
+    }
+
+    /// Removes the [`Node`] with the given [`Id`] from the [`Scene`].
+    pub fn remove(&mut self, id: Id) -> Option<Node> {
+        let entry = self.entries.remove(&id)?;
+
+        self.index.remove(id, entry.bounds);
+        self.damage.push(entry.bounds);
+
+        Some(entry.node)
+    }
+
+    /// Removes all the nodes of the [`Scene`].
+    pub fn clear(&mut self) {
+        self.damage
+            .extend(self.entries.drain().map(|(_, entry)| entry.bounds));
+        self.index.clear();
+    }
+
+    /// Returns the [`Node`] with the given [`Id`], if any.
+    pub fn get(&self, id: Id) -> Option<&Node> {
+        self.entries.get(&id).map(|entry| &entry.node)
+    }
+
+    /// Returns the bounds of the [`Node`] with the given [`Id`], if any.
+    pub fn bounds(&self, id: Id) -> Option<Rectangle> {
+        self.entries.get(&id).map(|entry| entry.bounds)
+    }
+
+    /// Iterates over all the nodes of the [`Scene`] in an arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (Id, &Node)> {
+        self.entries.iter().map(|(id, entry)| (*id, &entry.node))
+    }
+
+    /// Changes the [`Node`] with the given [`Id`] and invalidates its
+    /// geometry.
+    ///
+    /// Returns false if the [`Node`] does not exist.
+    pub fn update(&mut self, id: Id, f: impl FnOnce(&mut Node)) -> bool {
+        let Some(entry) = self.entries.get_mut(&id) else {
+            return false;
+        };
+
+        f(&mut entry.node);
+
+        let bounds = entry.node.bounds();
+
+        if bounds != entry.bounds {
+            self.index.remove(id, entry.bounds);
+            self.index.insert(id, bounds);
+        }
+
+        self.damage.push(entry.bounds);
+        self.damage.push(bounds);
+
+        entry.bounds = bounds;
+        entry.cache.clear();
+
+        true
+    }
+
+    /// Sets the [`Transform`] of the [`Node`] with the given [`Id`].
+    pub fn set_transform(&mut self, id: Id, transform: Transform) -> bool {
+        self.update(id, |node| node.transform = transform)
+    }
+
+    /// Sets the z-index of the [`Node`] with the given [`Id`].
+    ///
+    /// Reordering a [`Node`] does not invalidate its geometry.
+    pub fn set_z_index(&mut self, id: Id, z_index: i32) -> bool {
+        let Some(entry) = self.entries.get_mut(&id) else {
+            return false;
+        };
+
+        if entry.node.z_index != z_index {
+            entry.node.z_index = z_index;
+            self.damage.push(entry.bounds);
+        }
+
+        true
+    }
+
+    /// Sets whether the [`Node`] with the given [`Id`] is visible.
+    ///
+    /// Hiding a [`Node`] does not invalidate its geometry.
+    pub fn set_visible(&mut self, id: Id, visible: bool) -> bool {
+        let Some(entry) = self.entries.get_mut(&id) else {
+            return false;
+        };
+
+        if entry.node.visible != visible {
+            entry.node.visible = visible;
+            self.damage.push(entry.bounds);
+        }
+
+        true
+    }
+
+    /// Returns the [`Id`] of the topmost visible [`Node`] hit by the given
+    /// [`Point`], if any.
+    pub fn pick(&self, point: Point) -> Option<Id> {
+        self.index
+            .candidates(Rectangle::new(point, Size::ZERO))
+            .filter_map(|id| Some((id, self.entries.get(&id)?)))
+            .filter(|(_, entry)| entry.node.visible && entry.node.contains(point))
+            .max_by_key(|(id, entry)| (entry.node.z_index, *id))
+            .map(|(id, _)| id)
+    }
+
+    /// Returns the [`Id`]s of all the visible nodes whose bounds intersect
+    /// the given region, from bottom to top.
+    pub fn query(&self, region: Rectangle) -> Vec<Id> {
+        let mut ids: Vec<_> = self
+            .index
+            .candidates(region)
+            .filter_map(|id| Some((id, self.entries.get(&id)?)))
+            .filter(|(_, entry)| entry.node.visible && intersects(&entry.bounds, &region))
+            .map(|(id, entry)| (entry.node.z_index, id))
+            .collect();
+
+        ids.sort_unstable();
+        ids.dedup();
+
+        ids.into_iter().map(|(_, id)| id).collect()
+    }
+
+    /// Draws the visible nodes of the [`Scene`] that fit in a frame of the
+    /// given [`Size`], from bottom to top.
+    ///
+    /// Only the nodes that changed since the last call are tessellated
+    /// again, unless the size changed.
+    pub fn draw(&self, renderer: &Renderer, size: Size) -> Vec<Renderer::Geometry> {
+        self.query(Rectangle::with_size(size))
+            .into_iter()
+            .filter_map(|id| self.entries.get(&id))
+            .map(|entry| {
+                entry
+                    .cache
+                    .draw(renderer, size, |frame| entry.node.draw(frame))
+            })
+            .collect()
+    }
+
+    /// Returns the regions of the given bounds that changed since the last
+    /// call, grouping the ones that are close together.
+    pub fn take_damage(&mut self, bounds: Rectangle) -> Vec<Rectangle> {
+        damage::group(
+            std::mem::take(&mut self.damage)
+                .into_iter()
+                .map(|region| region.expand(1.0))
+                .collect(),
+            bounds,
+        )
+    }
+}
+
+impl<Renderer> std::fmt::Debug for Scene<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Scene")
+            .field("nodes", &self.entries.len())
+            .field("damage", &self.damage)
+            .finish()
+    }
+}
+
+impl<Renderer> Default for Scene<Renderer>
+where
+    Renderer: geometry::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Like [`Rectangle::intersects`], but also true for degenerate regions
+/// like the ones used when picking a single point.
+fn intersects(a: &Rectangle, b: &Rectangle) -> bool {
+    a.x <= b.x + b.width && b.x <= a.x + a.width && a.y <= b.y + b.height && b.y <= a.y + a.height
+}
+
+/// A uniform grid mapping cells to the nodes overlapping them.
+#[derive(Debug)]
+struct Index {
+    cell_size: f32,
+    cells: FxHashMap<(i64, i64), Vec<Id>>,
+    oversized: Vec<Id>,
+}
+
+impl Index {
+    fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size: cell_size.max(1.0),
+            cells: FxHashMap::default(),
+            oversized: Vec::new(),
+        }
+    }
+
+    fn cells(&self, bounds: Rectangle) -> Option<impl Iterator<Item = (i64, i64)> + use<>> {
+        let min_x = (bounds.x / self.cell_size).floor() as i64;
+        let min_y = (bounds.y / self.cell_size).floor() as i64;
+        let max_x = ((bounds.x + bounds.width) / self.cell_size).floor() as i64;
+        let max_y = ((bounds.y + bounds.height) / self.cell_size).floor() as i64;
+
+        if (max_x - min_x + 1) * (max_y - min_y + 1) > MAX_CELLS_PER_NODE {
+            return None;
+        }
+
+        Some((min_x..=max_x).flat_map(move |x| (min_y..=max_y).map(move |y| (x, y))))
+    }
+
+    fn insert(&mut self, id: Id, bounds: Rectangle) {
+        match self.cells(bounds) {
+            Some(cells) => {
+                for cell in cells {
+                    self.cells.entry(cell).or_default().push(id);
+                }
+            }
+            None => self.oversized.push(id),
+        }
+    }
+
+    fn remove(&mut self, id: Id, bounds: Rectangle) {
+        match self.cells(bounds) {
+            Some(cells) => {
+                for cell in cells {
+                    if let Some(ids) = self.cells.get_mut(&cell) {
+                        ids.retain(|other| *other != id);
+
+                        if ids.is_empty() {
+                            let _ = self.cells.remove(&cell);
+                        }
+                    }
+                }
+            }
+            None => self.oversized.retain(|other| *other != id),
+        }
+    }
+
+    fn clear(&mut self) {
+        self.cells.clear();
+        self.oversized.clear();
+    }
+
+    fn candidates(&self, region: Rectangle) -> impl Iterator<Item = Id> + '_ {
+        let cells: Box<dyn Iterator<Item = (i64, i64)> + '_> = match self.cells(region) {
+            Some(cells) => Box::new(cells),
+            None => Box::new(self.cells.keys().copied()),
+        };
+
+        cells
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .chain(&self.oversized)
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Color;
+
+    fn square(x: f32, y: f32, size: f32) -> Node {
+        Node::new()
+            .fill(
+                Path::rectangle(Point::ORIGIN, Size::new(size, size)),
+                Color::BLACK,
+            )
+            .translate(Vector::new(x, y))
+    }
+
+    #[test]
+    fn picks_topmost_node() {
+        let mut scene = Scene::<()>::new();
+
+        let bottom = scene.insert(square(0.0, 0.0, 20.0));
+        let top = scene.insert(square(10.0, 10.0, 20.0));
+
+        assert_eq!(scene.pick(Point::new(5.0, 5.0)), Some(bottom));
+        assert_eq!(scene.pick(Point::new(15.0, 15.0)), Some(top));
+        assert_eq!(scene.pick(Point::new(50.0, 50.0)), None);
+
+        let _ = scene.set_z_index(bottom, 1);
+        assert_eq!(scene.pick(Point::new(15.0, 15.0)), Some(bottom));
+
+        let _ = scene.set_visible(bottom, false);
+        assert_eq!(scene.pick(Point::new(15.0, 15.0)), Some(top));
+        assert_eq!(scene.pick(Point::new(5.0, 5.0)), None);
+    }
+
+    #[test]
+    fn queries_from_bottom_to_top() {
+        let mut scene = Scene::<()>::new();
+
+        let a = scene.insert(square(0.0, 0.0, 10.0).z_index(2));
+        let b = scene.insert(square(5.0, 0.0, 10.0));
+        let c = scene.insert(square(50.0, 0.0, 10.0));
+        let d = scene.insert(square(8.0, 0.0, 10.0));
+
+        assert_eq!(
+            scene.query(Rectangle::new(Point::ORIGIN, Size::new(20.0, 20.0))),
+            [b, d, a]
+        );
+        assert_eq!(
+            scene.query(Rectangle::new(Point::new(55.0, 5.0), Size::ZERO)),
+            [c]
+        );
+    }
+
+    #[test]
+    fn updates_index_after_move_and_remove() {
+        // Small cells, so nodes span many cells and large ones are oversized
+        let mut scene = Scene::<()>::with_cell_size(8.0);
+
+        let small = scene.insert(square(0.0, 0.0, 20.0));
+        let large = scene.insert(square(1000.0, 1000.0, 500.0));
+
+        assert_eq!(scene.pick(Point::new(5.0, 5.0)), Some(small));
+        assert_eq!(scene.pick(Point::new(1200.0, 1200.0)), Some(large));
+
+        let _ = scene.set_transform(
+            small,
+            Transform {
+                translation: Vector::new(300.0, 300.0),
+                ..Transform::IDENTITY
+            },
+        );
+
+        assert_eq!(scene.pick(Point::new(5.0, 5.0)), None);
+        assert_eq!(scene.pick(Point::new(305.0, 305.0)), Some(small));
+        assert_eq!(
+            scene.bounds(small),
+            Some(Rectangle::new(
+                Point::new(300.0, 300.0),
+                Size::new(20.0, 20.0)
+            ))
+        );
+
+        let _ = scene.update(large, |node| {
+            node.transform.translation = Vector::new(0.0, 400.0);
+        });
+
+        assert_eq!(scene.pick(Point::new(1200.0, 1200.0)), None);
+        assert_eq!(scene.pick(Point::new(5.0, 405.0)), Some(large));
+
+        assert!(scene.remove(small).is_some());
+        assert_eq!(scene.pick(Point::new(305.0, 305.0)), None);
+        assert!(
+            scene
+                .query(Rectangle::new(
+                    Point::new(300.0, 300.0),
+                    Size::new(20.0, 20.0)
+                ))
+                .is_empty()
+        );
+        assert_eq!(scene.len(), 1);
+
+        assert!(scene.remove(small).is_none());
+        assert!(!scene.set_transform(small, Transform::IDENTITY));
+    }
+
+    #[test]
+    fn tracks_damage() {
+        let bounds = Rectangle::with_size(Size::new(1000.0, 1000.0));
+        let mut scene = Scene::<()>::new();
+
+        let id = scene.insert(square(100.0, 100.0, 10.0));
+
+        assert_eq!(
+            scene.take_damage(bounds),
+            [Rectangle::new(
+                Point::new(99.0, 99.0),
+                Size::new(12.0, 12.0)
+            )]
+        );
+        assert!(scene.take_damage(bounds).is_empty());
+
+        // Regions far apart are kept separate
+        let _ = scene.set_transform(
+            id,
+            Transform {
+                translation: Vector::new(800.0, 800.0),
+                ..Transform::IDENTITY
+            },
+        );
+
+        let mut damage = scene.take_damage(bounds);
+        damage.sort_by(|a, b| a.x.total_cmp(&b.x));
+
+        assert_eq!(
+            damage,
+            [
+                Rectangle::new(Point::new(99.0, 99.0), Size::new(12.0, 12.0)),
+                Rectangle::new(Point::new(799.0, 799.0), Size::new(12.0, 12.0)),
+            ]
+        );
+
+        // Hiding or reordering only damages the bounds of the node, once
+        let _ = scene.set_visible(id, false);
+        let _ = scene.set_visible(id, false);
+        let _ = scene.set_z_index(id, 0);
+
+        assert_eq!(
+            scene.take_damage(bounds),
+            [Rectangle::new(
+                Point::new(799.0, 799.0),
+                Size::new(12.0, 12.0)
+            )]
+        );
+
+        // Removed nodes damage their last bounds, clipped to the given ones
+        let _ = scene.remove(id);
+
+        assert_eq!(
+            scene.take_damage(Rectangle::with_size(Size::new(805.0, 805.0))),
+            [Rectangle::new(
+                Point::new(799.0, 799.0),
+                Size::new(6.0, 6.0)
+            )]
+        );
+    }
+}
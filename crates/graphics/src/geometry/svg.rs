@@ -0,0 +1,1070 @@
+//! Export geometry as Scalable Vector Graphics.
+//!
+//! The [`Renderer`] in this module can be used to draw the exact same
+//! geometry a `Canvas` would, which can then be turned into an SVG
+//! [`Document`].
+//!
+//! SVG has no conic gradients, so they are exported as a flat fill of the
+//! color at their starting angle. Images in memory are embedded as data
+//! URLs.
+//!
+//! # Example
+//! ```no_run
+//! use icy_ui_graphics::core::{Color, Point, Size};
+//! use icy_ui_graphics::geometry::svg;
+//! use icy_ui_graphics::geometry::{Frame, Path};
+//!
+//! let size = Size::new(200.0, 200.0);
+//!
+//! let renderer = svg::Renderer::new();
+//! let mut frame = Frame::new(&renderer, size);
+//!
+//! frame.fill(&Path::circle(Point::new(100.0, 100.0), 50.0), Color::BLACK);
+//!
+//! let svg = svg::Document::new(size)
+//!     .background(Color::WHITE)
+//!     .draw(frame.into_geometry())
+//!     .to_string();
+//! ```
+use crate::cache::{self, Cached};
+use crate::core::image;
+use crate::core::renderer;
+use crate::core::{
+    self, Background, Color, Point, Radians, Rectangle, Size, Svg, Transformation, Vector,
+};
+use crate::geometry::fill::{self, Fill};
+use crate::geometry::path::lyon_path;
+use crate::geometry::stroke::{self, Stroke};
+use crate::geometry::{self, Path, Style, Text};
+use crate::gradient::{self, Gradient};
+
+use base64::prelude::{BASE64_STANDARD, Engine as _};
+
+use std::borrow::Cow;
+use std::fmt::{self, Write};
+use std::sync::Arc;
+
+/// A renderer that records geometry to be exported as an SVG [`Document`].
+///
+/// Only geometry is recorded. Any other primitive drawn with this
+/// [`Renderer`] (e.g. quads) is ignored.
+#[derive(Debug, Default)]
+pub struct Renderer {
+    geometry: Vec<Geometry>,
+}
+
+impl Renderer {
+    /// Creates a new [`Renderer`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Turns the [`Renderer`] into a [`Document`] of the given [`Size`]
+    /// containing all the recorded geometry.
+    pub fn into_document(self, size: Size) -> Document {
+        self.geometry
+            .into_iter()
+            .fold(Document::new(size), Document::draw)
+    }
+}
+
+impl core::Renderer for Renderer {
+    fn start_layer(&mut self, _bounds: Rectangle) {}
+
+    fn end_layer(&mut self) {}
+
+    fn start_transformation(&mut self, _transformation: Transformation) {}
+
+    fn end_transformation(&mut self) {}
+
+    fn fill_quad(&mut self, _quad: renderer::Quad, _background: impl Into<Background>) {}
+
+    fn allocate_image(
+        &mut self,
+        _handle: &image::Handle,
+        callback: impl FnOnce(Result<image::Allocation, image::Error>) + Send + 'static,
+    ) {
+        callback(Err(image::Error::Unsupported));
+    }
+
+    fn hint(&mut self, _scale_factor: f32) {}
+
+    fn scale_factor(&self) -> Option<f32> {
+        None
+    }
+
+    fn reset(&mut self, _new_bounds: Rectangle) {
+        self.geometry.clear();
+    }
+}
+
+impl geometry::Renderer for Renderer {
+    type Geometry = Geometry;
+    type Frame = Frame;
+
+    fn new_frame(&self, bounds: Rectangle) -> Self::Frame {
+        Frame::new(bounds)
+    }
+
+    fn draw_geometry(&mut self, geometry: Self::Geometry) {
+        self.geometry.push(geometry);
+    }
+}
+
+/// Some geometry recorded by a [`Frame`].
+#[derive(Debug, Clone)]
+pub struct Geometry {
+    nodes: Arc<[Node]>,
+}
+
+impl Cached for Geometry {
+    type Cache = Self;
+
+    fn load(cache: &Self) -> Self {
+        cache.clone()
+    }
+
+    fn cache(self, _group: cache::Group, _previous: Option<Self>) -> Self {
+        self
+    }
+}
+
+/// A [`Frame`] that records geometry for an SVG [`Document`].
+///
+/// Text is recorded as outlines, so the resulting [`Document`] does
+/// not depend on the fonts available where it is displayed.
+///
+/// Images and vector graphics loaded from a path are linked with a `file://`
+/// URL, while the rest are embedded as data URLs; raw pixel data is encoded
+/// as PNG. Compressed SVG is ignored.
+#[derive(Debug)]
+pub struct Frame {
+    clip_bounds: Rectangle,
+    transform: lyon_path::math::Transform,
+    stack: Vec<lyon_path::math::Transform>,
+    nodes: Vec<Node>,
+}
+
+impl Frame {
+    /// Creates a new [`Frame`] with the given clip bounds.
+    pub fn new(bounds: Rectangle) -> Self {
+        Self {
+            clip_bounds: bounds,
+            transform: lyon_path::math::Transform::identity(),
+            stack: Vec::new(),
+            nodes: Vec::new(),
+        }
+    }
+
+    fn transform_point(&self, point: Point) -> Point {
+        let point = self
+            .transform
+            .transform_point(lyon_path::math::Point::new(point.x, point.y));
+
+        Point::new(point.x, point.y)
+    }
+
+    fn transform_style(&self, style: Style) -> Style {
+        match style {
+            Style::Solid(color) => Style::Solid(color),
+            Style::Gradient(mut gradient) => {
+                match &mut gradient {
+                    Gradient::Linear(linear) => {
+                        linear.start = self.transform_point(linear.start);
+                        linear.end = self.transform_point(linear.end);
+                    }
+                    Gradient::Radial(radial) => {
+                        radial.center = self.transform_point(radial.center);
+                        radial.radius *= (self.transform.m11 * self.transform.m22).abs().sqrt();
+                    }
+                    Gradient::Conic(conic) => {
+                        conic.center = self.transform_point(conic.center);
+                        conic.angle += Radians(self.transform.m12.atan2(self.transform.m11));
+                    }
+                }
+
+                Style::Gradient(gradient)
+            }
+        }
+    }
+
+    fn transform_rectangle(&self, rectangle: Rectangle) -> (Rectangle, Radians) {
+        let transform = &self.transform;
+
+        // Avoid rounding errors when the rectangle is only translated and scaled
+        if transform.m12 == 0.0
+            && transform.m21 == 0.0
+            && transform.m11 > 0.0
+            && transform.m22 > 0.0
+        {
+            let top_left = self.transform_point(rectangle.position());
+
+            return (
+                Rectangle::new(
+                    top_left,
+                    Size::new(
+                        rectangle.width * transform.m11,
+                        rectangle.height * transform.m22,
+                    ),
+                ),
+                Radians(0.0),
+            );
+        }
+
+        let top_left = self.transform_point(rectangle.position());
+        let top_right =
+            self.transform_point(rectangle.position() + Vector::new(rectangle.width, 0.0));
+        let bottom_left =
+            self.transform_point(rectangle.position() + Vector::new(0.0, rectangle.height));
+
+        Rectangle::with_vertices(top_left, top_right, bottom_left)
+    }
+}
+
+impl geometry::frame::Backend for Frame {
+    type Geometry = Geometry;
+
+    fn width(&self) -> f32 {
+        self.clip_bounds.width
+    }
+
+    fn height(&self) -> f32 {
+        self.clip_bounds.height
+    }
+
+    fn size(&self) -> Size {
+        self.clip_bounds.size()
+    }
+
+    fn center(&self) -> Point {
+        Point::new(self.clip_bounds.width / 2.0, self.clip_bounds.height / 2.0)
+    }
+
+    fn push_transform(&mut self) {
+        self.stack.push(self.transform);
+    }
+
+    fn pop_transform(&mut self) {
+        self.transform = self.stack.pop().expect("Pop transform");
+    }
+
+    fn translate(&mut self, translation: Vector) {
+        self.transform = self
+            .transform
+            .pre_translate(lyon_path::math::Vector::new(translation.x, translation.y));
+    }
+
+    fn rotate(&mut self, angle: impl Into<Radians>) {
+        self.transform = self
+            .transform
+            .pre_rotate(lyon_path::math::Angle::radians(angle.into().0));
+    }
+
+    fn scale(&mut self, scale: impl Into<f32>) {
+        let scale = scale.into();
+
+        self.scale_nonuniform(Vector { x: scale, y: scale });
+    }
+
+    fn scale_nonuniform(&mut self, scale: impl Into<Vector>) {
+        let scale = scale.into();
+
+        self.transform = self.transform.pre_scale(scale.x, scale.y);
+    }
+
+    fn draft(&mut self, clip_bounds: Rectangle) -> Self {
+        Self::new(clip_bounds)
+    }
+
+    fn paste(&mut self, frame: Self) {
+        self.nodes.push(Node::Clip {
+            bounds: frame.clip_bounds,
+            nodes: frame.nodes,
+        });
+    }
+
+    fn stroke<'a>(&mut self, path: &Path, stroke: impl Into<Stroke<'a>>) {
+        let stroke = stroke.into();
+
+        if stroke.width <= 0.0 {
+            return;
+        }
+
+        self.nodes.push(Node::Stroke {
+            data: path_data(&path.transform(&self.transform)),
+            style: self.transform_style(stroke.style),
+            width: stroke.width,
+            line_cap: stroke.line_cap,
+            line_join: stroke.line_join,
+            dash: stroke.line_dash.segments.to_vec(),
+            dash_offset: stroke.line_dash.offset as f32,
+        });
+    }
+
+    fn stroke_rectangle<'a>(&mut self, top_left: Point, size: Size, stroke: impl Into<Stroke<'a>>) {
+        self.stroke(&Path::rectangle(top_left, size), stroke);
+    }
+
+    fn stroke_text<'a>(&mut self, text: impl Into<Text>, stroke: impl Into<Stroke<'a>>) {
+        let text = text.into();
+        let stroke = stroke.into();
+
+        text.draw_with(|path, _color| self.stroke(&path, stroke));
+    }
+
+    fn fill(&mut self, path: &Path, fill: impl Into<Fill>) {
+        let fill = fill.into();
+
+        self.nodes.push(Node::Fill {
+            data: path_data(&path.transform(&self.transform)),
+            style: self.transform_style(fill.style),
+            rule: fill.rule,
+        });
+    }
+
+    fn fill_text(&mut self, text: impl Into<Text>) {
+        let text = text.into();
+
+        text.draw_with(|path, color| self.fill(&path, color));
+    }
+
+    fn fill_rectangle(&mut self, top_left: Point, size: Size, fill: impl Into<Fill>) {
+        self.fill(&Path::rectangle(top_left, size), fill);
+    }
+
+    fn draw_image(&mut self, bounds: Rectangle, image: impl Into<core::Image>) {
+        let image = image.into();
+
+        let href = match &image.handle {
+            image::Handle::Path(_, path) => file_url(path),
+            image::Handle::Bytes(_, bytes) => {
+                let Some(url) = data_url(bytes) else {
+                    return;
+                };
+
+                url
+            }
+            image::Handle::Rgba {
+                width,
+                height,
+                pixels,
+                ..
+            } => {
+                if *width == 0 || *height == 0 {
+                    return;
+                }
+
+                let Some(url) = png(*width, *height, pixels)
+                    .ok()
+                    .and_then(|png| data_url(&png))
+                else {
+                    return;
+                };
+
+                url
+            }
+        };
+
+        let (bounds, mut rotation) = self.transform_rectangle(bounds);
+        rotation += image.rotation;
+
+        self.nodes.push(Node::Image {
+            href,
+            bounds,
+            rotation,
+            opacity: image.opacity,
+        });
+    }
+
+    fn draw_svg(&mut self, bounds: Rectangle, svg: impl Into<Svg>) {
+        let svg = svg.into();
+
+        let href = match svg.handle.data() {
+            core::svg::Data::Path(path) => file_url(path),
+            core::svg::Data::Bytes(bytes) => {
+                let Some(url) = data_url(bytes) else {
+                    return;
+                };
+
+                url
+            }
+        };
+
+        let (bounds, mut rotation) = self.transform_rectangle(bounds);
+        rotation += svg.rotation;
+
+        self.nodes.push(Node::Image {
+            href,
+            bounds,
+            rotation,
+            opacity: svg.opacity,
+        });
+    }
+
+    fn into_geometry(self) -> Geometry {
+        Geometry {
+            nodes: Arc::from([Node::Clip {
+                bounds: self.clip_bounds,
+                nodes: self.nodes,
+            }]),
+        }
+    }
+}
+
+/// An SVG document.
+///
+/// A [`Document`] implements [`Display`](fmt::Display), which produces
+/// its SVG markup.
+#[derive(Debug, Clone)]
+pub struct Document {
+    size: Size,
+    background: Option<Color>,
+    geometry: Vec<Geometry>,
+}
+
+impl Document {
+    /// Creates a new empty [`Document`] with the given [`Size`].
+    pub fn new(size: Size) -> Self {
+        Self {
+            size,
+            background: None,
+            geometry: Vec::new(),
+        }
+    }
+
+    /// Sets the background [`Color`] of the [`Document`].
+    ///
+    /// By default, the [`Document`] is transparent.
+    pub fn background(mut self, color: impl Into<Color>) -> Self {
+        self.background = Some(color.into());
+        self
+    }
+
+    /// Draws the given [`Geometry`] on top of the contents of the [`Document`].
+    pub fn draw(mut self, geometry: impl Into<Geometry>) -> Self {
+        self.geometry.push(geometry.into());
+        self
+    }
+
+    /// Returns the [`Size`] of the [`Document`].
+    pub fn size(&self) -> Size {
+        self.size
+    }
+}
+
+impl fmt::Display for Document {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut writer = Writer {
+            definitions: String::new(),
+            body: String::new(),
+            next_id: 0,
+        };
+
+        if let Some(background) = self.background {
+            let _ = write!(
+                writer.body,
+                r#"<rect width="{}" height="{}"{}/>"#,
+                self.size.width,
+                self.size.height,
+                paint("fill", background)
+            );
+        }
+
+        for geometry in &self.geometry {
+            for node in geometry.nodes.iter() {
+                writer.node(node)?;
+            }
+        }
+
+        write!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#,
+            width = self.size.width,
+            height = self.size.height,
+        )?;
+
+        if !writer.definitions.is_empty() {
+            write!(f, "<defs>{}</defs>", writer.definitions)?;
+        }
+
+        write!(f, "{}</svg>", writer.body)
+    }
+}
+
+#[derive(Debug)]
+enum Node {
+    Fill {
+        data: String,
+        style: Style,
+        rule: fill::Rule,
+    },
+    Stroke {
+        data: String,
+        style: Style,
+        width: f32,
+        line_cap: stroke::LineCap,
+        line_join: stroke::LineJoin,
+        dash: Vec<f32>,
+        dash_offset: f32,
+    },
+    Image {
+        href: String,
+        bounds: Rectangle,
+        rotation: Radians,
+        opacity: f32,
+    },
+    Clip {
+        bounds: Rectangle,
+        nodes: Vec<Node>,
+    },
+}
+
+struct Writer {
+    definitions: String,
+    body: String,
+    next_id: usize,
+}
+
+impl Writer {
+    fn id(&mut self, prefix: &str) -> String {
+        let id = format!("{prefix}{}", self.next_id);
+        self.next_id += 1;
+
+        id
+    }
+
+    fn node(&mut self, node: &Node) -> fmt::Result {
+        match node {
+            Node::Fill { data, style, rule } => {
+                let fill = self.style("fill", *style)?;
+
+                write!(
+                    self.body,
+                    r#"<path d="{data}"{fill}{rule}/>"#,
+                    rule = match rule {
+                        fill::Rule::NonZero => "",
+                        fill::Rule::EvenOdd => r#" fill-rule="evenodd""#,
+                    }
+                )
+            }
+            Node::Stroke {
+                data,
+                style,
+                width,
+                line_cap,
+                line_join,
+                dash,
+                dash_offset,
+            } => {
+                let stroke = self.style("stroke", *style)?;
+
+                write!(
+                    self.body,
+                    r#"<path d="{data}" fill="none"{stroke} stroke-width="{width}""#,
+                )?;
+
+                match line_cap {
+                    stroke::LineCap::Butt => {}
+                    stroke::LineCap::Square => self.body.push_str(r#" stroke-linecap="square""#),
+                    stroke::LineCap::Round => self.body.push_str(r#" stroke-linecap="round""#),
+                }
+
+                match line_join {
+                    stroke::LineJoin::Miter => {}
+                    stroke::LineJoin::Round => self.body.push_str(r#" stroke-linejoin="round""#),
+                    stroke::LineJoin::Bevel => self.body.push_str(r#" stroke-linejoin="bevel""#),
+                }
+
+                if !dash.is_empty() {
+                    self.body.push_str(r#" stroke-dasharray=""#);
+
+                    for (i, segment) in dash.iter().enumerate() {
+                        if i > 0 {
+                            self.body.push(' ');
+                        }
+
+                        write!(self.body, "{segment}")?;
+                    }
+
+                    write!(self.body, r#"" stroke-dashoffset="{dash_offset}""#)?;
+                }
+
+                self.body.push_str("/>");
+
+                Ok(())
+            }
+            Node::Image {
+                href,
+                bounds,
+                rotation,
+                opacity,
+            } => {
+                write!(
+                    self.body,
+                    r#"<image x="{}" y="{}" width="{}" height="{}" preserveAspectRatio="none" href="{}""#,
+                    bounds.x,
+                    bounds.y,
+                    bounds.width,
+                    bounds.height,
+                    escape(href),
+                )?;
+
+                if *opacity < 1.0 {
+                    write!(self.body, r#" opacity="{opacity}""#)?;
+                }
+
+                if rotation.0 != 0.0 {
+                    let center = bounds.center();
+
+                    write!(
+                        self.body,
+                        r#" transform="rotate({} {} {})""#,
+                        rotation.0.to_degrees(),
+                        center.x,
+                        center.y
+                    )?;
+                }
+
+                self.body.push_str("/>");
+
+                Ok(())
+            }
+            Node::Clip { bounds, nodes } => {
+                if bounds.width.is_infinite() || bounds.height.is_infinite() {
+                    self.body.push_str("<g>");
+                } else {
+                    let id = self.id("clip");
+
+                    write!(
+                        self.definitions,
+                        r#"<clipPath id="{id}"><rect x="{}" y="{}" width="{}" height="{}"/></clipPath>"#,
+                        bounds.x, bounds.y, bounds.width, bounds.height
+                    )?;
+
+                    write!(self.body, r#"<g clip-path="url(#{id})">"#)?;
+                }
+
+                for node in nodes {
+                    self.node(node)?;
+                }
+
+                self.body.push_str("</g>");
+
+                Ok(())
+            }
+        }
+    }
+
+    fn style(&mut self, attribute: &str, style: Style) -> Result<String, fmt::Error> {
+        let gradient = match style {
+            Style::Solid(color) => return Ok(paint(attribute, color)),
+            Style::Gradient(Gradient::Conic(conic)) => {
+                // SVG has no conic gradients; we settle for the color at the
+                // starting angle
+                return Ok(paint(attribute, gradient::color_at(&conic.stops, 0.0)));
+            }
+            Style::Gradient(gradient) => gradient,
+        };
+
+        let id = self.id("gradient");
+
+        let (stops, end) = match gradient {
+            Gradient::Linear(linear) => {
+                write!(
+                    self.definitions,
+                    r#"<linearGradient id="{id}" gradientUnits="userSpaceOnUse" x1="{}" y1="{}" x2="{}" y2="{}">"#,
+                    linear.start.x, linear.start.y, linear.end.x, linear.end.y
+                )?;
+
+                (linear.stops, "</linearGradient>")
+            }
+            Gradient::Radial(radial) => {
+                write!(
+                    self.definitions,
+                    r#"<radialGradient id="{id}" gradientUnits="userSpaceOnUse" cx="{}" cy="{}" r="{}">"#,
+                    radial.center.x, radial.center.y, radial.radius
+                )?;
+
+                (radial.stops, "</radialGradient>")
+            }
+            Gradient::Conic(_) => unreachable!(),
+        };
+
+        for stop in stops.iter().flatten() {
+            write!(
+                self.definitions,
+                r#"<stop offset="{}"{}/>"#,
+                stop.offset,
+                paint("stop-color", stop.color)
+            )?;
+        }
+
+        self.definitions.push_str(end);
+
+        Ok(format!(r#" {attribute}="url(#{id})""#))
+    }
+}
+
+fn paint(attribute: &str, color: Color) -> String {
+    let [r, g, b, a] = color.into_rgba8();
+
+    let opacity = match attribute {
+        "stop-color" => "stop-opacity".to_owned(),
+        _ => format!("{attribute}-opacity"),
+    };
+
+    if a == 255 {
+        format!(r##" {attribute}="#{r:02x}{g:02x}{b:02x}""##)
+    } else {
+        format!(
+            r##" {attribute}="#{r:02x}{g:02x}{b:02x}" {opacity}="{}""##,
+            color.a
+        )
+    }
+}
+
+fn path_data(path: &Path) -> String {
+    let mut data = String::new();
+    let mut last_point = lyon_path::math::Point::default();
+
+    for event in path.raw() {
+        let _ = match event {
+            lyon_path::Event::Begin { at } => {
+                last_point = at;
+
+                write!(data, "M{} {}", at.x, at.y)
+            }
+            lyon_path::Event::Line { from, to } => {
+                if last_point != from {
+                    let _ = write!(data, "M{} {}", from.x, from.y);
+                }
+
+                last_point = to;
+
+                write!(data, "L{} {}", to.x, to.y)
+            }
+            lyon_path::Event::Quadratic { from, ctrl, to } => {
+                if last_point != from {
+                    let _ = write!(data, "M{} {}", from.x, from.y);
+                }
+
+                last_point = to;
+
+                write!(data, "Q{} {} {} {}", ctrl.x, ctrl.y, to.x, to.y)
+            }
+            lyon_path::Event::Cubic {
+                from,
+                ctrl1,
+                ctrl2,
+                to,
+            } => {
+                if last_point != from {
+                    let _ = write!(data, "M{} {}", from.x, from.y);
+                }
+
+                last_point = to;
+
+                write!(
+                    data,
+                    "C{} {} {} {} {} {}",
+                    ctrl1.x, ctrl1.y, ctrl2.x, ctrl2.y, to.x, to.y
+                )
+            }
+            lyon_path::Event::End { close, .. } => {
+                if close {
+                    data.push('Z');
+                }
+
+                Ok(())
+            }
+        };
+    }
+
+    data
+}
+
+/// Escapes the given text to be used as the value of an XML attribute.
+fn escape(text: &str) -> Cow<'_, str> {
+    if !text.contains(['&', '<', '>', '"', '\'']) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    Cow::Owned(escaped)
+}
+
+/// Returns the `file://` URL of the given path, percent-encoding any byte
+/// that is not allowed in a URL path.
+///
+/// Relative paths are made absolute first.
+fn file_url(path: &std::path::Path) -> String {
+    let path = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    let mut path = path.to_string_lossy().into_owned();
+
+    if cfg!(windows) {
+        path = path.replace('\\', "/");
+    }
+
+    let mut url = String::from("file://");
+
+    // Windows paths start with a drive letter (e.g. `C:/`)
+    if !path.starts_with('/') {
+        url.push('/');
+    }
+
+    for byte in path.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' | b':' => {
+                url.push(char::from(byte));
+            }
+            _ => {
+                let _ = write!(url, "%{byte:02X}");
+            }
+        }
+    }
+
+    url
+}
+
+/// Encodes the given RGBA pixels as a PNG image.
+fn png(width: u32, height: u32, pixels: &[u8]) -> Result<Vec<u8>, png::EncodingError> {
+    let mut bytes = Vec::new();
+
+    let mut encoder = png::Encoder::new(&mut bytes, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(pixels)?;
+    writer.finish()?;
+
+    Ok(bytes)
+}
+
+fn data_url(bytes: &[u8]) -> Option<String> {
+    let mime = match bytes {
+        [0x89, b'P', b'N', b'G', ..] => "image/png",
+        [0xFF, 0xD8, 0xFF, ..] => "image/jpeg",
+        [b'G', b'I', b'F', ..] => "image/gif",
+        [
+            b'R',
+            b'I',
+            b'F',
+            b'F',
+            _,
+            _,
+            _,
+            _,
+            b'W',
+            b'E',
+            b'B',
+            b'P',
+            ..,
+        ] => "image/webp",
+        [b'B', b'M', ..] => "image/bmp",
+        // Compressed SVG cannot be embedded
+        [0x1F, 0x8B, ..] => return None,
+        _ => "image/svg+xml",
+    };
+
+    Some(format!(
+        "data:{mime};base64,{}",
+        BASE64_STANDARD.encode(bytes)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Font;
+    use crate::geometry::frame::Backend;
+
+    fn document(draw: impl FnOnce(&mut Frame)) -> String {
+        let size = Size::new(100.0, 50.0);
+        let mut frame = Frame::new(Rectangle::with_size(size));
+
+        draw(&mut frame);
+
+        Document::new(size).draw(frame.into_geometry()).to_string()
+    }
+
+    #[test]
+    fn exports_paths() {
+        let svg = document(|frame| {
+            frame.fill(
+                &Path::rectangle(Point::new(10.0, 10.0), Size::new(20.0, 5.0)),
+                Color::BLACK,
+            );
+
+            frame.stroke(
+                &Path::line(Point::ORIGIN, Point::new(100.0, 50.0)),
+                Stroke::default()
+                    .with_color(Color::from_rgba(1.0, 0.0, 0.0, 0.5))
+                    .with_width(2.0)
+                    .with_line_cap(stroke::LineCap::Round),
+            );
+        });
+
+        assert_eq!(
+            svg,
+            concat!(
+                r##"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" width="100" height="50" viewBox="0 0 100 50">"##,
+                r##"<defs>"##,
+                r##"<clipPath id="clip0">"##,
+                r##"<rect x="0" y="0" width="100" height="50"/>"##,
+                r##"</clipPath>"##,
+                r##"</defs>"##,
+                r##"<g clip-path="url(#clip0)">"##,
+                r##"<path d="M10 10L30 10L30 15L10 15Z" fill="#000000"/>"##,
+                r##"<path d="M0 0L100 50" fill="none" stroke="#ff0000" stroke-opacity="0.5" stroke-width="2" stroke-linecap="round"/>"##,
+                r##"</g>"##,
+                r##"</svg>"##,
+            )
+        );
+    }
+
+    #[test]
+    fn exports_gradients() {
+        let svg = document(|frame| {
+            let gradient = gradient::Linear::new(Point::ORIGIN, Point::new(100.0, 0.0))
+                .add_stop(0.0, Color::BLACK)
+                .add_stop(1.0, Color::WHITE);
+
+            frame.translate(Vector::new(10.0, 0.0));
+            frame.fill(
+                &Path::rectangle(Point::ORIGIN, Size::new(100.0, 50.0)),
+                gradient,
+            );
+        });
+
+        assert_eq!(
+            svg,
+            concat!(
+                r##"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" width="100" height="50" viewBox="0 0 100 50">"##,
+                r##"<defs>"##,
+                r##"<clipPath id="clip0">"##,
+                r##"<rect x="0" y="0" width="100" height="50"/>"##,
+                r##"</clipPath>"##,
+                r##"<linearGradient id="gradient1" gradientUnits="userSpaceOnUse" x1="10" y1="0" x2="110" y2="0">"##,
+                r##"<stop offset="0" stop-color="#000000"/>"##,
+                r##"<stop offset="1" stop-color="#ffffff"/>"##,
+                r##"</linearGradient>"##,
+                r##"</defs>"##,
+                r##"<g clip-path="url(#clip0)">"##,
+                r##"<path d="M10 0L110 0L110 50L10 50Z" fill="url(#gradient1)"/>"##,
+                r##"</g>"##,
+                r##"</svg>"##,
+            )
+        );
+    }
+
+    #[test]
+    fn exports_text_as_outlines() {
+        let svg = document(|frame| {
+            frame.fill_text(Text {
+                content: String::from("\u{f00c}"),
+                position: Point::new(10.0, 10.0),
+                color: Color::from_rgb8(0x12, 0x34, 0x56),
+                font: Font::with_name("Iced-Icons"),
+                ..Text::default()
+            });
+        });
+
+        assert!(!svg.contains("<text"));
+        assert!(svg.contains(r##"<path d="M"##));
+        assert!(svg.contains(r##" fill="#123456""##));
+    }
+
+    #[test]
+    fn exports_images() {
+        let (path, href) = if cfg!(windows) {
+            (
+                r#"C:\images\a b&<c".png"#,
+                "file:///C:/images/a%20b%26%3Cc%22.png",
+            )
+        } else {
+            (
+                r#"/images/a b&<c".png"#,
+                "file:///images/a%20b%26%3Cc%22.png",
+            )
+        };
+
+        let svg = document(|frame| {
+            frame.draw_image(
+                Rectangle::new(Point::ORIGIN, Size::new(10.0, 10.0)),
+                &image::Handle::from_path(path),
+            );
+
+            frame.draw_image(
+                Rectangle::new(Point::new(20.0, 0.0), Size::new(10.0, 10.0)),
+                &image::Handle::from_rgba(1, 1, vec![255, 0, 0, 255]),
+            );
+        });
+
+        assert_eq!(
+            svg,
+            format!(
+                concat!(
+                    r##"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" width="100" height="50" viewBox="0 0 100 50">"##,
+                    r##"<defs>"##,
+                    r##"<clipPath id="clip0">"##,
+                    r##"<rect x="0" y="0" width="100" height="50"/>"##,
+                    r##"</clipPath>"##,
+                    r##"</defs>"##,
+                    r##"<g clip-path="url(#clip0)">"##,
+                    r##"<image x="0" y="0" width="10" height="10" preserveAspectRatio="none" href="{href}"/>"##,
+                    r##"<image x="20" y="0" width="10" height="10" preserveAspectRatio="none" href="data:image/png;base64,{png}"/>"##,
+                    r##"</g>"##,
+                    r##"</svg>"##,
+                ),
+                href = href,
+                png = BASE64_STANDARD.encode(png(1, 1, &[255, 0, 0, 255]).unwrap()),
+            )
+        );
+    }
+
+    #[test]
+    fn encodes_images_as_png() {
+        let pixels: Vec<u8> = (0..4 * 3 * 2).map(|i| i * 10).collect();
+
+        let png = png(3, 2, &pixels).unwrap();
+
+        let mut decoder = png::Decoder::new(std::io::Cursor::new(png))
+            .read_info()
+            .unwrap();
+        let mut decoded = vec![0; decoder.output_buffer_size().unwrap()];
+        let info = decoder.next_frame(&mut decoded).unwrap();
+
+        assert_eq!((info.width, info.height), (3, 2));
+        assert_eq!(info.color_type, png::ColorType::Rgba);
+        assert_eq!(decoded, pixels);
+
+        // Pixels must match the size of the image
+        assert!(super::png(3, 2, &pixels[1..]).is_err());
+    }
+
+    #[test]
+    fn data_urls_are_base64_encoded() {
+        assert_eq!(
+            data_url(b"GIF89a").as_deref(),
+            Some("data:image/gif;base64,R0lGODlh")
+        );
+        assert_eq!(
+            data_url(b"<svg/>").as_deref(),
+            Some("data:image/svg+xml;base64,PHN2Zy8+")
+        );
+        assert_eq!(data_url(&[0x1F, 0x8B, 0x08]), None);
+    }
+}
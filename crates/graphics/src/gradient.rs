@@ -3,20 +3,24 @@
 //! For a gradient that you can use as a background variant for a widget, see [`Gradient`].
 use crate::color;
 use crate::core::gradient::ColorStop;
-use crate::core::{self, Color, Point, Rectangle};
+use crate::core::{self, Color, Point, Radians, Rectangle};
 
 use bytemuck::{Pod, Zeroable};
 use half::f16;
 use std::cmp::Ordering;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-/// A fill which linearly interpolates colors along a direction.
+/// A fill which interpolates colors along a direction, outwards from a point, or around it.
 ///
 /// For a gradient which can be used as a fill for a background of a widget, see [`crate::core::Gradient`].
 pub enum Gradient {
     /// A linear gradient interpolates colors along a direction from its `start` to its `end`
     /// point.
     Linear(Linear),
+    /// A radial gradient interpolates colors from its `center` to a circle of some `radius`.
+    Radial(Radial),
+    /// A conic gradient interpolates colors clockwise around its `center`.
+    Conic(Conic),
 }
 
 impl From<Linear> for Gradient {
@@ -25,11 +29,25 @@ impl From<Linear> for Gradient {
     }
 }
 
+impl From<Radial> for Gradient {
+    fn from(gradient: Radial) -> Self {
+        Self::Radial(gradient)
+    }
+}
+
+impl From<Conic> for Gradient {
+    fn from(gradient: Conic) -> Self {
+        Self::Conic(gradient)
+    }
+}
+
 impl Gradient {
     /// Packs the [`Gradient`] for use in shader code.
     pub fn pack(&self) -> Packed {
         match self {
             Gradient::Linear(linear) => linear.pack(),
+            Gradient::Radial(radial) => radial.pack(),
+            Gradient::Conic(conic) => conic.pack(),
         }
     }
 }
@@ -63,18 +81,118 @@ impl Linear {
     ///
     /// Any stop added after the 8th will be silently ignored.
     pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
-        if offset.is_finite() && (0.0..=1.0).contains(&offset) {
-            let (Ok(index) | Err(index)) = self.stops.binary_search_by(|stop| match stop {
-                None => Ordering::Greater,
-                Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
-            });
-
-            if index < 8 {
-                self.stops[index] = Some(ColorStop { offset, color });
-            }
-        } else {
-            log::warn!("Gradient: ColorStop must be within 0.0..=1.0 range.");
-        };
+        add_stop(&mut self.stops, offset, color);
+
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(mut self, stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+
+    /// Packs the [`Gradient`] for use in shader code.
+    pub fn pack(&self) -> Packed {
+        Packed::new(
+            &self.stops,
+            [self.start.x, self.start.y, self.end.x, self.end.y],
+            Packed::LINEAR,
+        )
+    }
+}
+
+/// A radial gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Radial {
+    /// The absolute center of the gradient.
+    pub center: Point,
+
+    /// The radius of the gradient, where its last [`ColorStop`] is reached.
+    pub radius: f32,
+
+    /// [`ColorStop`]s along the radius of the gradient.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Radial {
+    /// Creates a new [`Radial`] builder.
+    pub fn new(center: Point, radius: f32) -> Self {
+        Self {
+            center,
+            radius,
+            stops: [None; 8],
+        }
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
+
+        self
+    }
+
+    /// Adds multiple [`ColorStop`]s to the gradient.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stops(mut self, stops: impl IntoIterator<Item = ColorStop>) -> Self {
+        for stop in stops {
+            self = self.add_stop(stop.offset, stop.color);
+        }
+
+        self
+    }
+
+    /// Packs the [`Gradient`] for use in shader code.
+    pub fn pack(&self) -> Packed {
+        Packed::new(
+            &self.stops,
+            [self.center.x, self.center.y, self.radius, 0.0],
+            Packed::RADIAL,
+        )
+    }
+}
+
+/// A conic gradient.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conic {
+    /// The absolute center of the gradient.
+    pub center: Point,
+
+    /// The angle where the gradient starts. `0` points up and angles grow
+    /// clockwise.
+    pub angle: Radians,
+
+    /// [`ColorStop`]s around the gradient.
+    pub stops: [Option<ColorStop>; 8],
+}
+
+impl Conic {
+    /// Creates a new [`Conic`] builder.
+    pub fn new(center: Point, angle: impl Into<Radians>) -> Self {
+        Self {
+            center,
+            angle: angle.into(),
+            stops: [None; 8],
+        }
+    }
+
+    /// Adds a new [`ColorStop`], defined by an offset and a color, to the gradient.
+    ///
+    /// Any `offset` that is not within `0.0..=1.0` will be silently ignored.
+    ///
+    /// Any stop added after the 8th will be silently ignored.
+    pub fn add_stop(mut self, offset: f32, color: Color) -> Self {
+        add_stop(&mut self.stops, offset, color);
 
         self
     }
@@ -90,12 +208,47 @@ impl Linear {
         self
     }
 
+    /// Returns the offset of the given point around the [`Conic`] gradient,
+    /// in `0.0..1.0`.
+    pub fn offset(&self, point: Point) -> f32 {
+        let turn = (point.x - self.center.x).atan2(self.center.y - point.y) - self.angle.0;
+
+        (turn / std::f32::consts::TAU).rem_euclid(1.0)
+    }
+
     /// Packs the [`Gradient`] for use in shader code.
     pub fn pack(&self) -> Packed {
+        Packed::new(
+            &self.stops,
+            [self.center.x, self.center.y, self.angle.0, 0.0],
+            Packed::CONIC,
+        )
+    }
+}
+
+/// Packed [`Gradient`] data for use in shader code.
+#[derive(Debug, Copy, Clone, PartialEq, Zeroable, Pod)]
+#[repr(C)]
+pub struct Packed {
+    // 8 colors, each channel = 16 bit float, 2 colors packed into 1 u32
+    colors: [[u32; 2]; 8],
+    // 8 offsets, 8x 16 bit floats packed into 4 u32s
+    offsets: [u32; 4],
+    // Linear: start & end; radial: center & radius; conic: center & angle
+    direction: [f32; 4],
+    kind: u32,
+}
+
+impl Packed {
+    const LINEAR: u32 = 0;
+    const RADIAL: u32 = 1;
+    const CONIC: u32 = 2;
+
+    fn new(stops: &[Option<ColorStop>; 8], direction: [f32; 4], kind: u32) -> Self {
         let mut colors = [[0u32; 2]; 8];
         let mut offsets = [f16::from(0u8); 8];
 
-        for (index, stop) in self.stops.iter().enumerate() {
+        for (index, stop) in stops.iter().enumerate() {
             let [r, g, b, a] = color::pack(stop.map_or(Color::default(), |s| s.color)).components();
 
             colors[index] = [
@@ -113,66 +266,104 @@ impl Linear {
             pack_f16s([offsets[6], offsets[7]]),
         ];
 
-        let direction = [self.start.x, self.start.y, self.end.x, self.end.y];
-
-        Packed {
+        Self {
             colors,
             offsets,
             direction,
+            kind,
         }
     }
 }
 
-/// Packed [`Gradient`] data for use in shader code.
-#[derive(Debug, Copy, Clone, PartialEq, Zeroable, Pod)]
-#[repr(C)]
-pub struct Packed {
-    // 8 colors, each channel = 16 bit float, 2 colors packed into 1 u32
-    colors: [[u32; 2]; 8],
-    // 8 offsets, 8x 16 bit floats packed into 4 u32s
-    offsets: [u32; 4],
-    direction: [f32; 4],
-}
-
 /// Creates a new [`Packed`] gradient for use in shader code.
 pub fn pack(gradient: &core::Gradient, bounds: Rectangle) -> Packed {
     match gradient {
         core::Gradient::Linear(linear) => {
-            let mut colors = [[0u32; 2]; 8];
-            let mut offsets = [f16::from(0u8); 8];
-
-            for (index, stop) in linear.stops.iter().enumerate() {
-                let [r, g, b, a] =
-                    color::pack(stop.map_or(Color::default(), |s| s.color)).components();
-
-                colors[index] = [
-                    pack_f16s([f16::from_f32(r), f16::from_f32(g)]),
-                    pack_f16s([f16::from_f32(b), f16::from_f32(a)]),
-                ];
-
-                offsets[index] = stop.map_or(f16::from_f32(2.0), |s| f16::from_f32(s.offset));
-            }
-
-            let offsets = [
-                pack_f16s([offsets[0], offsets[1]]),
-                pack_f16s([offsets[2], offsets[3]]),
-                pack_f16s([offsets[4], offsets[5]]),
-                pack_f16s([offsets[6], offsets[7]]),
-            ];
-
             let (start, end) = linear.angle.to_distance(&bounds);
 
-            let direction = [start.x, start.y, end.x, end.y];
+            Packed::new(
+                &linear.stops,
+                [start.x, start.y, end.x, end.y],
+                Packed::LINEAR,
+            )
+        }
+        core::Gradient::Radial(radial) => {
+            let (center, radius) = radial.to_absolute(&bounds);
+
+            Packed::new(
+                &radial.stops,
+                [center.x, center.y, radius, 0.0],
+                Packed::RADIAL,
+            )
+        }
+        core::Gradient::Conic(conic) => {
+            let center = conic.to_absolute(&bounds);
 
-            Packed {
-                colors,
-                offsets,
-                direction,
-            }
+            Packed::new(
+                &conic.stops,
+                [center.x, center.y, conic.angle.0, 0.0],
+                Packed::CONIC,
+            )
         }
     }
 }
 
+/// Returns the color of the given [`ColorStop`]s at some offset, interpolating
+/// linearly between them.
+///
+/// This can be used by renderers to rasterize gradients without native support.
+pub fn color_at(stops: &[Option<ColorStop>; 8], offset: f32) -> Color {
+    let mut stops = stops.iter().flatten();
+
+    let Some(first) = stops.next() else {
+        return Color::TRANSPARENT;
+    };
+
+    if offset <= first.offset {
+        return first.color;
+    }
+
+    let mut previous = first;
+
+    for stop in stops {
+        if offset <= stop.offset {
+            let range = stop.offset - previous.offset;
+
+            let factor = if range > 0.0 {
+                (offset - previous.offset) / range
+            } else {
+                1.0
+            };
+
+            return Color {
+                r: previous.color.r + (stop.color.r - previous.color.r) * factor,
+                g: previous.color.g + (stop.color.g - previous.color.g) * factor,
+                b: previous.color.b + (stop.color.b - previous.color.b) * factor,
+                a: previous.color.a + (stop.color.a - previous.color.a) * factor,
+            };
+        }
+
+        previous = stop;
+    }
+
+    previous.color
+}
+
+fn add_stop(stops: &mut [Option<ColorStop>; 8], offset: f32, color: Color) {
+    if offset.is_finite() && (0.0..=1.0).contains(&offset) {
+        let (Ok(index) | Err(index)) = stops.binary_search_by(|stop| match stop {
+            None => Ordering::Greater,
+            Some(stop) => stop.offset.partial_cmp(&offset).unwrap(),
+        });
+
+        if index < 8 {
+            stops[index] = Some(ColorStop { offset, color });
+        }
+    } else {
+        log::warn!("Gradient: ColorStop must be within 0.0..=1.0 range.");
+    };
+}
+
 /// Packs two f16s into one u32.
 fn pack_f16s(f: [f16; 2]) -> u32 {
     let one = (f[0].to_bits() as u32) << 16;
@@ -180,3 +371,84 @@ fn pack_f16s(f: [f16; 2]) -> u32 {
 
     one | two
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::f32::consts::{FRAC_PI_2, PI};
+
+    const EPSILON: f32 = 1e-5;
+
+    #[test]
+    fn conic_offset_grows_clockwise_from_the_top() {
+        let conic = Conic::new(Point::new(10.0, 10.0), Radians(0.0));
+
+        for (point, offset) in [
+            (Point::new(10.0, 0.0), 0.0),
+            (Point::new(20.0, 10.0), 0.25),
+            (Point::new(10.0, 20.0), 0.5),
+            (Point::new(0.0, 10.0), 0.75),
+        ] {
+            assert!((conic.offset(point) - offset).abs() < EPSILON);
+        }
+    }
+
+    #[test]
+    fn conic_offset_wraps_around_the_angle() {
+        let conic = Conic::new(Point::ORIGIN, Radians(FRAC_PI_2));
+
+        // The start angle is offset 0, so what lies before it wraps to the end
+        assert!(conic.offset(Point::new(1.0, 0.0)).abs() < EPSILON);
+        assert!((conic.offset(Point::new(0.0, -1.0)) - 0.75).abs() < EPSILON);
+
+        // Angles beyond a full turn are equivalent
+        let turned = Conic::new(Point::ORIGIN, Radians(FRAC_PI_2 + 2.0 * PI));
+        let point = Point::new(-3.0, 2.0);
+
+        assert!((conic.offset(point) - turned.offset(point)).abs() < EPSILON);
+
+        for point in [
+            Point::new(0.0, -1.0),
+            Point::new(-1.0, -0.0001),
+            Point::new(0.0001, -1.0),
+        ] {
+            assert!((0.0..1.0).contains(&conic.offset(point)));
+        }
+    }
+
+    #[test]
+    fn color_at_interpolates_between_stops() {
+        let stops = Conic::new(Point::ORIGIN, Radians(0.0))
+            .add_stop(0.25, Color::BLACK)
+            .add_stop(0.75, Color::WHITE)
+            .stops;
+
+        assert_eq!(color_at(&stops, 0.0), Color::BLACK);
+        assert_eq!(color_at(&stops, 0.25), Color::BLACK);
+        assert_eq!(color_at(&stops, 0.5), Color::from_rgb(0.5, 0.5, 0.5));
+        assert_eq!(color_at(&stops, 0.75), Color::WHITE);
+        assert_eq!(color_at(&stops, 1.0), Color::WHITE);
+    }
+
+    #[test]
+    fn color_at_interpolates_within_the_enclosing_stops() {
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+        let blue = Color::from_rgb(0.0, 0.0, 1.0);
+
+        let stops = Conic::new(Point::ORIGIN, Radians(0.0))
+            .add_stop(0.0, Color::BLACK)
+            .add_stop(0.5, red)
+            .add_stop(1.0, blue)
+            .stops;
+
+        assert_eq!(color_at(&stops, 0.25), Color::from_rgb(0.5, 0.0, 0.0));
+        assert_eq!(color_at(&stops, 0.5), red);
+        assert_eq!(color_at(&stops, 0.75), Color::from_rgb(0.5, 0.0, 0.5));
+    }
+
+    #[test]
+    fn color_at_without_stops_is_transparent() {
+        assert_eq!(color_at(&[None; 8], 0.5), Color::TRANSPARENT);
+    }
+}
@@ -152,6 +152,103 @@ pub fn load(handle: &image::Handle) -> Result<Buffer, image::Error> {
     }
 }
 
+/// A frame of an animated image.
+#[cfg(feature = "animated-image")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    /// The decoded pixels of the [`Frame`].
+    pub handle: image::Handle,
+
+    /// How long the [`Frame`] should be displayed.
+    pub delay: std::time::Duration,
+}
+
+#[cfg(feature = "animated-image")]
+/// Tries to decode all the frames of an animated image by its [`Handle`].
+///
+/// GIF, APNG and animated WebP images are supported. Any other image is
+/// decoded as a single [`Frame`] without delay.
+///
+/// Every [`Frame`] is fully composited and has the size of the whole image.
+///
+/// [`Handle`]: image::Handle
+pub fn load_frames(handle: &image::Handle) -> Result<Vec<Frame>, image::Error> {
+    use ::image::codecs::gif::GifDecoder;
+    use ::image::codecs::png::PngDecoder;
+    use ::image::codecs::webp::WebPDecoder;
+    use ::image::{AnimationDecoder, ImageFormat};
+
+    use std::io::Cursor;
+
+    let bytes = match handle {
+        image::Handle::Path(_, path) => Bytes::from(
+            std::fs::read(path)
+                .map_err(|error| image::Error::Inaccessible(std::sync::Arc::new(error)))?,
+        ),
+        image::Handle::Bytes(_, bytes) => bytes.clone(),
+        image::Handle::Rgba { .. } => {
+            return Ok(vec![Frame {
+                handle: handle.clone(),
+                delay: std::time::Duration::ZERO,
+            }]);
+        }
+    };
+
+    let frames = match ::image::guess_format(&bytes) {
+        Ok(ImageFormat::Gif) => GifDecoder::new(Cursor::new(&bytes))
+            .map_err(to_error)?
+            .into_frames(),
+        Ok(ImageFormat::Png) => {
+            let decoder = PngDecoder::new(Cursor::new(&bytes)).map_err(to_error)?;
+
+            if decoder.is_apng().map_err(to_error)? {
+                decoder.apng().map_err(to_error)?.into_frames()
+            } else {
+                return still(&bytes);
+            }
+        }
+        Ok(ImageFormat::WebP) => {
+            let decoder = WebPDecoder::new(Cursor::new(&bytes)).map_err(to_error)?;
+
+            if decoder.has_animation() {
+                decoder.into_frames()
+            } else {
+                return still(&bytes);
+            }
+        }
+        _ => return still(&bytes),
+    };
+
+    frames
+        .map(|frame| {
+            let frame = frame.map_err(to_error)?;
+            let delay = frame.delay().into();
+            let buffer = frame.into_buffer();
+
+            Ok(Frame {
+                handle: image::Handle::from_rgba(
+                    buffer.width(),
+                    buffer.height(),
+                    buffer.into_raw(),
+                ),
+                delay,
+            })
+        })
+        .collect()
+}
+
+/// Decodes the given bytes, already read by [`load_frames`], as a single
+/// [`Frame`].
+#[cfg(feature = "animated-image")]
+fn still(bytes: &Bytes) -> Result<Vec<Frame>, image::Error> {
+    let buffer = load(&image::Handle::from_bytes(bytes.clone()))?;
+
+    Ok(vec![Frame {
+        handle: image::Handle::from_rgba(buffer.width(), buffer.height(), buffer.into_raw()),
+        delay: std::time::Duration::ZERO,
+    }])
+}
+
 #[cfg(feature = "image")]
 fn to_error(error: ::image::ImageError) -> image::Error {
     use std::sync::Arc;
@@ -161,3 +258,31 @@ fn to_error(error: ::image::ImageError) -> image::Error {
         error => image::Error::Invalid(Arc::new(error)),
     }
 }
+
+#[cfg(all(test, feature = "animated-image"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn still_images_load_as_a_single_frame() {
+        let mut png = Vec::new();
+
+        ::image::RgbaImage::from_raw(2, 1, vec![255, 0, 0, 255, 0, 0, 255, 128])
+            .unwrap()
+            .write_to(
+                &mut std::io::Cursor::new(&mut png),
+                ::image::ImageFormat::Png,
+            )
+            .unwrap();
+
+        let frames = load_frames(&image::Handle::from_bytes(png)).unwrap();
+
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].delay, std::time::Duration::ZERO);
+        assert!(matches!(
+            &frames[0].handle,
+            image::Handle::Rgba { width: 2, height: 1, pixels, .. }
+                if pixels.as_ref() == [255, 0, 0, 255, 0, 0, 255, 128]
+        ));
+    }
+}
@@ -1,4 +1,5 @@
 //! Draw and stack layers of graphical primitives.
+use crate::core::renderer::LayerStyle;
 use crate::core::{Rectangle, Transformation};
 
 /// A layer of graphical primitives.
@@ -41,6 +42,14 @@ pub trait Layer: Default {
 
     /// Merges a [`Layer`] with the current one.
     fn merge(&mut self, _layer: &mut Self);
+
+    /// Returns whether the [`Layer`] can be merged into the layers below it.
+    ///
+    /// Layers with effects that depend on what was drawn before them (like a
+    /// backdrop blur) must be rendered on their own.
+    fn is_mergeable(&self) -> bool {
+        true
+    }
 }
 
 /// A stack of layers used for drawing.
@@ -51,6 +60,8 @@ pub struct Stack<T: Layer> {
     previous: Vec<usize>,
     current: usize,
     active_count: usize,
+    groups: Vec<Group>,
+    open_groups: Vec<usize>,
 }
 
 impl<T: Layer> Stack<T> {
@@ -62,6 +73,8 @@ impl<T: Layer> Stack<T> {
             previous: vec![],
             current: 0,
             active_count: 1,
+            groups: Vec::new(),
+            open_groups: Vec::new(),
         }
     }
 
@@ -97,12 +110,35 @@ impl<T: Layer> Stack<T> {
         }
     }
 
+    /// Pushes a new [`Group`] in the [`Stack`] with the given [`LayerStyle`];
+    /// creating a new clipping layer in the process.
+    ///
+    /// Every layer pushed until the layer of the [`Group`] is popped becomes
+    /// part of the [`Group`].
+    pub fn push_group(&mut self, bounds: Rectangle, style: LayerStyle) {
+        self.push_clip(bounds);
+
+        self.open_groups.push(self.groups.len());
+        self.groups.push(Group {
+            start: self.current,
+            end: usize::MAX,
+            style,
+        });
+    }
+
     /// Pops the current clipping region from the [`Stack`] and restores the previous one.
     ///
     /// The current layer will be recorded for drawing.
     pub fn pop_clip(&mut self) {
         self.flush();
 
+        if let Some(&group) = self.open_groups.last()
+            && self.groups[group].start == self.current
+        {
+            self.groups[group].end = self.active_count;
+            let _ = self.open_groups.pop();
+        }
+
         self.current = self.previous.pop().unwrap();
     }
 
@@ -132,6 +168,11 @@ impl<T: Layer> Stack<T> {
         &self.layers[..self.active_count]
     }
 
+    /// Returns the groups of layers in the [`Stack`], sorted by their first layer.
+    pub fn groups(&self) -> &[Group] {
+        &self.groups
+    }
+
     /// Flushes and settles any primitives in the [`Stack`].
     pub fn flush(&mut self) {
         self.layers[self.current].flush();
@@ -160,15 +201,23 @@ impl<T: Layer> Stack<T> {
                 let start = candidate.start();
                 let end = candidate.end();
 
-                // We skip empty layers
-                if end == 0 {
+                // We skip empty layers, unless they have effects
+                if end == 0 && candidate.is_mergeable() {
                     current -= 1;
                     continue;
                 }
 
                 // Candidate can be merged if primitive sublayers do not overlap with
-                // previous targets and the clipping bounds match
-                if end > target_start || candidate.bounds() != target.bounds() {
+                // previous targets, the clipping bounds match, the target has
+                // no effects of its own, and both belong to the same groups
+                if end > target_start
+                    || candidate.bounds() != target.bounds()
+                    || !target.is_mergeable()
+                    || self
+                        .groups
+                        .iter()
+                        .any(|group| group.contains(current - 1) != group.contains(left - 1))
+                {
                     break;
                 }
 
@@ -211,6 +260,29 @@ impl<T: Layer> Stack<T> {
         self.current = 0;
         self.active_count = 1;
         self.previous.clear();
+        self.groups.clear();
+        self.open_groups.clear();
+    }
+}
+
+/// A contiguous range of layers in a [`Stack`] that must be composited
+/// together, as if they were a single layer.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Group {
+    /// The index of the first layer of the [`Group`].
+    pub start: usize,
+
+    /// The index right after the last layer of the [`Group`].
+    pub end: usize,
+
+    /// The [`LayerStyle`] used to composite the [`Group`].
+    pub style: LayerStyle,
+}
+
+impl Group {
+    /// Returns whether the layer with the given index is part of the [`Group`].
+    pub fn contains(&self, index: usize) -> bool {
+        (self.start..self.end).contains(&index)
     }
 }
 
@@ -11,7 +11,7 @@ pub use cosmic_text;
 
 use crate::core::alignment;
 use crate::core::font::{self, Font};
-use crate::core::text::{Alignment, Shaping, Wrapping};
+use crate::core::text::{Alignment, Shaping, Truncation, Wrapping};
 use crate::core::{Color, Pixels, Point, Rectangle, Size, Transformation};
 
 use std::borrow::Cow;
@@ -20,6 +20,8 @@ use std::sync::{Arc, OnceLock, RwLock, Weak};
 
 const MAX_BUFFER_DIMENSION: f32 = 1_000_000.0;
 
+const ELLIPSIS: &str = "\u{2026}";
+
 pub(crate) fn sanitize_buffer_dimension(value: f32) -> Option<f32> {
     if value.is_finite() {
         Some(value.clamp(0.0, MAX_BUFFER_DIMENSION))
@@ -69,6 +71,8 @@ pub enum Text {
         align_y: alignment::Vertical,
         /// The shaping strategy of the text.
         shaping: Shaping,
+        /// The truncation strategy of the text.
+        truncation: Truncation,
         /// The clip bounds of the text.
         clip_bounds: Rectangle,
     },
@@ -268,6 +272,138 @@ pub fn align(
     min_bounds
 }
 
+/// Truncates the lines of the given [`cosmic_text::Buffer`] that do not fit
+/// in the given width, replacing the hidden text with an ellipsis.
+///
+/// The [`cosmic_text::Buffer`] must be laid out without wrapping.
+///
+/// Returns true if any line was truncated.
+pub fn truncate(
+    buffer: &mut cosmic_text::Buffer,
+    font_system: &mut cosmic_text::FontSystem,
+    truncation: Truncation,
+    max_width: f32,
+) -> bool {
+    if !truncation.is_ellipsis() || !max_width.is_finite() {
+        return false;
+    }
+
+    let metrics = buffer.metrics();
+    let mut is_truncated = false;
+
+    for line in &mut buffer.lines {
+        let Some(layout) = line.layout_opt().and_then(|layout| layout.first()) else {
+            continue;
+        };
+
+        if layout.w <= max_width {
+            continue;
+        }
+
+        // Group glyphs into clusters in logical order, so we never cut
+        // through ligatures or combining marks
+        let mut clusters: Vec<(usize, usize, f32)> = Vec::new();
+
+        let mut glyphs: Vec<_> = layout.glyphs.iter().collect();
+        glyphs.sort_by_key(|glyph| glyph.start);
+
+        for glyph in glyphs {
+            match clusters.last_mut() {
+                Some((start, end, width)) if *start == glyph.start => {
+                    *end = (*end).max(glyph.end);
+                    *width += glyph.w;
+                }
+                _ => clusters.push((glyph.start, glyph.end, glyph.w)),
+            }
+        }
+
+        let ellipsis_width = {
+            let mut ellipsis = cosmic_text::Buffer::new(font_system, metrics);
+
+            ellipsis.set_text(
+                font_system,
+                ELLIPSIS,
+                &line.attrs_list().defaults(),
+                cosmic_text::Shaping::Advanced,
+                None,
+            );
+
+            measure(&ellipsis).0.width
+        };
+
+        let budget = (max_width - ellipsis_width).max(0.0);
+        let text_len = line.text().len();
+
+        let fit_start = |budget: f32| {
+            let mut width = 0.0;
+            let mut index = 0;
+
+            for (_, end, advance) in &clusters {
+                if width + advance > budget {
+                    break;
+                }
+
+                width += advance;
+                index = *end;
+            }
+
+            (index, width)
+        };
+
+        let fit_end = |budget: f32| {
+            let mut width = 0.0;
+            let mut index = text_len;
+
+            for (start, _, advance) in clusters.iter().rev() {
+                if width + advance > budget {
+                    break;
+                }
+
+                width += advance;
+                index = *start;
+            }
+
+            index
+        };
+
+        let (cut_start, cut_end) = match truncation {
+            Truncation::End => (fit_start(budget).0, text_len),
+            Truncation::Start => (0, fit_end(budget)),
+            Truncation::Middle => {
+                let (start, width) = fit_start(budget / 2.0);
+
+                (start, fit_end(budget - width).max(start))
+            }
+            Truncation::None | Truncation::Fade(_) => continue,
+        };
+
+        let ellipsis = cosmic_text::BufferLine::new(
+            ELLIPSIS,
+            line.ending(),
+            cosmic_text::AttrsList::new(
+                &line
+                    .attrs_list()
+                    .get_span(cut_start.min(text_len.saturating_sub(1))),
+            ),
+            cosmic_text::Shaping::Advanced,
+        );
+
+        let suffix = line.split_off(cut_end);
+        let _ = line.split_off(cut_start);
+
+        line.append(&ellipsis);
+        line.append(&suffix);
+
+        is_truncated = true;
+    }
+
+    if is_truncated {
+        buffer.shape_until_scroll(font_system, false);
+    }
+
+    is_truncated
+}
+
 /// Returns the attributes of the given [`Font`].
 pub fn to_attributes(font: Font) -> cosmic_text::Attrs<'static> {
     cosmic_text::Attrs::new()
@@ -383,3 +519,96 @@ pub trait Renderer {
     /// Draws the given [`Raw`] text.
     fn fill_raw(&mut self, raw: Raw);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATH: &str = "/home/user/documents/projects/report.txt";
+
+    fn truncated(text: &str, truncation: Truncation, ratio: f32) -> (String, bool) {
+        let mut database = cosmic_text::fontdb::Database::new();
+        database.load_font_data(include_bytes!("../fonts/FiraSans-Regular.ttf").to_vec());
+
+        let mut font_system =
+            cosmic_text::FontSystem::new_with_locale_and_db("en-US".to_owned(), database);
+
+        let mut buffer =
+            cosmic_text::Buffer::new(&mut font_system, cosmic_text::Metrics::new(16.0, 20.0));
+
+        buffer.set_size(&mut font_system, None, None);
+        buffer.set_wrap(&mut font_system, cosmic_text::Wrap::None);
+        buffer.set_text(
+            &mut font_system,
+            text,
+            &cosmic_text::Attrs::new().family(cosmic_text::Family::Name("Fira Sans")),
+            cosmic_text::Shaping::Advanced,
+            None,
+        );
+        buffer.shape_until_scroll(&mut font_system, false);
+
+        let (bounds, _) = measure(&buffer);
+        let is_truncated = truncate(
+            &mut buffer,
+            &mut font_system,
+            truncation,
+            bounds.width * ratio,
+        );
+
+        (buffer.lines[0].text().to_owned(), is_truncated)
+    }
+
+    #[test]
+    fn truncate_end_keeps_the_start() {
+        let (text, is_truncated) = truncated(PATH, Truncation::End, 0.5);
+
+        assert!(is_truncated);
+        assert!(text.ends_with(ELLIPSIS));
+        assert!(PATH.starts_with(text.trim_end_matches(ELLIPSIS)));
+        assert!(text.len() < PATH.len());
+    }
+
+    #[test]
+    fn truncate_start_keeps_the_end() {
+        let (text, is_truncated) = truncated(PATH, Truncation::Start, 0.5);
+
+        assert!(is_truncated);
+        assert!(text.starts_with(ELLIPSIS));
+        assert!(PATH.ends_with(text.trim_start_matches(ELLIPSIS)));
+        assert!(text.len() < PATH.len());
+    }
+
+    #[test]
+    fn truncate_middle_keeps_both_ends() {
+        let (text, is_truncated) = truncated("abcdefghijklmnopqrstuvwxyz", Truncation::Middle, 0.5);
+
+        let (start, end) = text.split_once(ELLIPSIS).expect("ellipsis in the middle");
+
+        assert!(is_truncated);
+        assert!(!start.is_empty() && !end.is_empty());
+        assert!("abcdefghijklmnopqrstuvwxyz".starts_with(start));
+        assert!("abcdefghijklmnopqrstuvwxyz".ends_with(end));
+    }
+
+    #[test]
+    fn truncate_middle_keeps_the_root_and_file_name_of_a_path() {
+        let (text, is_truncated) = truncated(PATH, Truncation::Middle, 0.6);
+
+        let (start, end) = text.split_once(ELLIPSIS).expect("ellipsis in the middle");
+
+        assert!(is_truncated);
+        assert!(start.starts_with("/home"));
+        assert!(end.ends_with("report.txt"));
+        assert!(PATH.starts_with(start) && PATH.ends_with(end));
+    }
+
+    #[test]
+    fn truncate_leaves_fitting_text_untouched() {
+        for truncation in [Truncation::End, Truncation::Start, Truncation::Middle] {
+            let (text, is_truncated) = truncated(PATH, truncation, 1.0);
+
+            assert!(!is_truncated);
+            assert_eq!(text, PATH);
+        }
+    }
+}
@@ -49,6 +49,11 @@ impl Cache {
                 text::sanitize_buffer_dimension(key.bounds.height.max(key.line_height));
 
             buffer.set_size(font_system, width_opt, height_opt);
+
+            if key.truncation != text::Truncation::None {
+                buffer.set_wrap(font_system, cosmic_text::Wrap::None);
+            }
+
             buffer.set_text(
                 font_system,
                 key.content,
@@ -57,6 +62,9 @@ impl Cache {
                 None,
             );
 
+            let is_truncated =
+                text::truncate(&mut buffer, font_system, key.truncation, key.bounds.width);
+
             let bounds = text::align(&mut buffer, font_system, key.align_x);
 
             let _ = entry.insert(Entry {
@@ -71,7 +79,8 @@ impl Cache {
                     ..bounds
                 },
             ] {
-                if key.bounds != bounds {
+                // Truncated text depends on its exact bounds
+                if key.bounds != bounds && !is_truncated {
                     let _ = self
                         .aliases
                         .insert(Key { bounds, ..key }.hash(FxHasher::default()), hash);
@@ -115,6 +124,8 @@ pub struct Key<'a> {
     pub shaping: text::Shaping,
     /// The alignment of the text.
     pub align_x: text::Alignment,
+    /// The truncation strategy of the text.
+    pub truncation: text::Truncation,
 }
 
 impl Key<'_> {
@@ -128,6 +139,7 @@ impl Key<'_> {
         self.shaping.hash(&mut hasher);
         // Resolve alignment to ensure RTL changes invalidate cache
         self.align_x.resolve().hash(&mut hasher);
+        self.truncation.hash(&mut hasher);
 
         hasher.finish()
     }
@@ -3,7 +3,7 @@ use crate::core::text::editor::{
     self, Action, Cursor, Direction, Edit, Motion, Position, Selection,
 };
 use crate::core::text::highlighter::{self, Highlighter};
-use crate::core::text::{LineHeight, Wrapping};
+use crate::core::text::{LineHeight, Wrapping, bidi};
 use crate::core::{Font, Pixels, Point, Rectangle, Size};
 use crate::text;
 
@@ -141,7 +141,6 @@ impl editor::Editor for Editor {
             return cursor.clone();
         }
 
-        let cursor = internal.editor.cursor();
         let buffer = buffer_from_editor(&internal.editor);
 
         let cursor = match internal.editor.selection_bounds() {
@@ -169,9 +168,9 @@ impl editor::Editor for Editor {
                         )
                     })
                     .enumerate()
-                    .filter_map(|(visual_line, (x, width))| {
-                        if width > 0.0 {
-                            Some(
+                    .flat_map(|(visual_line, spans)| {
+                        spans.into_iter().filter(|(_, width)| *width > 0.0).map(
+                            move |(x, width)| {
                                 Rectangle {
                                     x,
                                     width,
@@ -179,76 +178,18 @@ impl editor::Editor for Editor {
                                         * line_height
                                         - buffer.scroll().vertical,
                                     height: line_height,
-                                } * (1.0 / internal.hint_factor),
-                            )
-                        } else {
-                            None
-                        }
+                                } * (1.0 / internal.hint_factor)
+                            },
+                        )
                     })
                     .collect();
 
                 Selection::Range(regions)
             }
             _ => {
-                let line_height = buffer.metrics().line_height;
+                let (caret, _) = caret(internal);
 
-                let visual_lines_offset = visual_lines_offset(cursor.line, buffer);
-
-                let line = buffer
-                    .lines
-                    .get(cursor.line)
-                    .expect("Cursor line should be present");
-
-                let layout = line.layout_opt().expect("Line layout should be cached");
-
-                let mut lines = layout.iter().enumerate();
-
-                let (visual_line, offset) = lines
-                    .find_map(|(i, line)| {
-                        let start = line.glyphs.first().map(|glyph| glyph.start).unwrap_or(0);
-                        let end = line.glyphs.last().map(|glyph| glyph.end).unwrap_or(0);
-
-                        let is_cursor_before_start = start > cursor.index;
-
-                        let is_cursor_before_end = match cursor.affinity {
-                            cosmic_text::Affinity::Before => cursor.index <= end,
-                            cosmic_text::Affinity::After => cursor.index < end,
-                        };
-
-                        if is_cursor_before_start {
-                            // Sometimes, the glyph we are looking for is right
-                            // between lines. This can happen when a line wraps
-                            // on a space.
-                            // In that case, we can assume the cursor is at the
-                            // end of the previous line.
-                            // i is guaranteed to be > 0 because `start` is always
-                            // 0 for the first line, so there is no way for the
-                            // cursor to be before it.
-                            Some((i - 1, layout[i - 1].w))
-                        } else if is_cursor_before_end {
-                            let offset = line
-                                .glyphs
-                                .iter()
-                                .take_while(|glyph| cursor.index > glyph.start)
-                                .map(|glyph| glyph.w)
-                                .sum();
-
-                            Some((i, offset))
-                        } else {
-                            None
-                        }
-                    })
-                    .unwrap_or((
-                        layout.len().saturating_sub(1),
-                        layout.last().map(|line| line.w).unwrap_or(0.0),
-                    ));
-
-                Selection::Caret(Point::new(
-                    offset / internal.hint_factor,
-                    ((visual_lines_offset + visual_line as i32) as f32 * line_height
-                        - buffer.scroll().vertical)
-                        / internal.hint_factor,
-                ))
+                Selection::Caret(caret)
             }
         };
 
@@ -257,6 +198,18 @@ impl editor::Editor for Editor {
         cursor
     }
 
+    fn split_caret(&self) -> Option<Point> {
+        let internal = self.internal();
+
+        if internal.editor.selection_bounds().is_some() {
+            return None;
+        }
+
+        let (_, split_caret) = caret(internal);
+
+        split_caret
+    }
+
     fn cursor(&self) -> Cursor {
         let editor = &self.internal().editor;
 
@@ -315,7 +268,7 @@ impl editor::Editor for Editor {
                                 Direction::Right => end,
                             }),
                         }
-                    } else {
+                    } else if !move_visually(editor, motion) {
                         editor.action(
                             font_system.raw(),
                             cosmic_text::Action::Motion(to_motion(motion)),
@@ -331,10 +284,12 @@ impl editor::Editor for Editor {
                         editor.set_selection(cosmic_text::Selection::Normal(cursor));
                     }
 
-                    editor.action(
-                        font_system.raw(),
-                        cosmic_text::Action::Motion(to_motion(motion)),
-                    );
+                    if !move_visually(editor, motion) {
+                        editor.action(
+                            font_system.raw(),
+                            cosmic_text::Action::Motion(to_motion(motion)),
+                        );
+                    }
 
                     // Deselect if selection matches cursor position
                     if let Some((start, end)) = editor.selection_bounds()
@@ -759,7 +714,7 @@ fn highlight_line(
     line: &cosmic_text::BufferLine,
     from: usize,
     to: usize,
-) -> impl Iterator<Item = (f32, f32)> + '_ {
+) -> impl Iterator<Item = Vec<(f32, f32)>> + '_ {
     let layout = line.layout_opt().map(Vec::as_slice).unwrap_or_default();
 
     layout.iter().map(move |visual_line| {
@@ -777,9 +732,12 @@ fn highlight_line(
         let range = start.max(from)..end.min(to);
 
         if range.is_empty() {
-            (0.0, 0.0)
+            vec![(0.0, 0.0)]
         } else if range.start == start && range.end == end {
-            (0.0, visual_line.w)
+            vec![(0.0, visual_line.w)]
+        } else if is_mixed(visual_line) {
+            // A logical range may cover disjoint runs of mixed-direction text
+            bidi::selection_spans(&visual_clusters(visual_line), range)
         } else {
             let first_glyph = visual_line
                 .glyphs
@@ -796,11 +754,159 @@ fn highlight_line(
                 .map(|glyph| glyph.w)
                 .sum();
 
-            (x, width)
+            vec![(x, width)]
         }
     })
 }
 
+/// Returns the position of the caret of the [`Editor`], and of its split
+/// caret at direction boundaries.
+fn caret(internal: &Internal) -> (Point, Option<Point>) {
+    let cursor = internal.editor.cursor();
+    let buffer = buffer_from_editor(&internal.editor);
+
+    let line_height = buffer.metrics().line_height;
+
+    let visual_lines_offset = visual_lines_offset(cursor.line, buffer);
+
+    let line = buffer
+        .lines
+        .get(cursor.line)
+        .expect("Cursor line should be present");
+
+    let layout = line.layout_opt().expect("Line layout should be cached");
+
+    let mut lines = layout.iter().enumerate();
+
+    let (visual_line, offset, split_offset) = lines
+        .find_map(|(i, line)| {
+            let start = line.glyphs.first().map(|glyph| glyph.start).unwrap_or(0);
+            let end = line.glyphs.last().map(|glyph| glyph.end).unwrap_or(0);
+
+            let is_cursor_before_start = start > cursor.index;
+
+            let is_cursor_before_end = match cursor.affinity {
+                cosmic_text::Affinity::Before => cursor.index <= end,
+                cosmic_text::Affinity::After => cursor.index < end,
+            };
+
+            if is_cursor_before_start {
+                // Sometimes, the glyph we are looking for is right
+                // between lines. This can happen when a line wraps
+                // on a space.
+                // In that case, we can assume the cursor is at the
+                // end of the previous line.
+                // i is guaranteed to be > 0 because `start` is always
+                // 0 for the first line, so there is no way for the
+                // cursor to be before it.
+                Some((i - 1, layout[i - 1].w, None))
+            } else if is_cursor_before_end {
+                // Glyphs of mixed-direction lines are not in logical order
+                if is_mixed(line)
+                    && let Some((offset, split_offset)) =
+                        bidi::caret_positions(&visual_clusters(line), cursor.index)
+                {
+                    return Some((i, offset, split_offset));
+                }
+
+                let offset = line
+                    .glyphs
+                    .iter()
+                    .take_while(|glyph| cursor.index > glyph.start)
+                    .map(|glyph| glyph.w)
+                    .sum();
+
+                Some((i, offset, None))
+            } else {
+                None
+            }
+        })
+        .unwrap_or((
+            layout.len().saturating_sub(1),
+            layout.last().map(|line| line.w).unwrap_or(0.0),
+            None,
+        ));
+
+    let y = ((visual_lines_offset + visual_line as i32) as f32 * line_height
+        - buffer.scroll().vertical)
+        / internal.hint_factor;
+
+    (
+        Point::new(offset / internal.hint_factor, y),
+        split_offset.map(|x| Point::new(x / internal.hint_factor, y)),
+    )
+}
+
+/// Moves the cursor of the editor one visual step to the left or to the right,
+/// if it sits in a line with mixed-direction text.
+///
+/// Returns `false` if the [`Motion`] must be performed logically instead.
+fn move_visually(editor: &mut cosmic_text::Editor<'static>, motion: Motion) -> bool {
+    let direction = match motion {
+        Motion::Left => Direction::Left,
+        Motion::Right => Direction::Right,
+        _ => return false,
+    };
+
+    let cursor = editor.cursor();
+    let buffer = buffer_from_editor(editor);
+
+    let Some(line) = buffer.lines.get(cursor.line) else {
+        return false;
+    };
+
+    let Some(clusters) = line
+        .layout_opt()
+        .into_iter()
+        .flatten()
+        .filter(|visual_line| is_mixed(visual_line))
+        .map(visual_clusters)
+        .find(|clusters| {
+            clusters.iter().any(|cluster| {
+                cluster.range.start == cursor.index || cluster.range.end == cursor.index
+            })
+        })
+    else {
+        return false;
+    };
+
+    match bidi::move_visually(&clusters, cursor.index, direction) {
+        Some(index) => {
+            editor.set_cursor(cosmic_text::Cursor { index, ..cursor });
+
+            true
+        }
+        // At the visual edge of the line, only move to adjacent lines
+        None => cursor.index != 0 && cursor.index != line.text().len(),
+    }
+}
+
+/// Returns whether the given visual line contains right-to-left text.
+fn is_mixed(visual_line: &cosmic_text::LayoutLine) -> bool {
+    visual_line.glyphs.iter().any(|glyph| glyph.level.is_rtl())
+}
+
+/// Returns the [`bidi::Cluster`]s of the given visual line, with byte ranges.
+fn visual_clusters(visual_line: &cosmic_text::LayoutLine) -> Vec<bidi::Cluster> {
+    let mut clusters: Vec<bidi::Cluster> = Vec::new();
+
+    for glyph in &visual_line.glyphs {
+        match clusters.last_mut() {
+            Some(last) if last.range == (glyph.start..glyph.end) => {
+                last.width = glyph.x + glyph.w - last.x;
+            }
+            _ => clusters.push(bidi::Cluster {
+                range: glyph.start..glyph.end,
+                x: glyph.x,
+                width: glyph.w,
+                is_rtl: glyph.level.is_rtl(),
+            }),
+        }
+    }
+
+    clusters
+}
+
 fn visual_lines_offset(line: usize, buffer: &cosmic_text::Buffer) -> i32 {
     let scroll = buffer.scroll();
 
@@ -1,11 +1,14 @@
 //! Draw paragraphs.
 use crate::core;
 use crate::core::alignment;
-use crate::core::text::{Alignment, Hit, LineHeight, Shaping, Span, Text, Wrapping};
+use crate::core::text::{
+    Alignment, Hit, LineHeight, Shaping, Span, Text, Truncation, Wrapping, bidi,
+};
 use crate::core::{Font, Pixels, Point, Rectangle, Size};
 use crate::text;
 
 use std::fmt;
+use std::ops::Range;
 use std::sync::{self, Arc};
 
 /// A bunch of text.
@@ -18,6 +21,8 @@ struct Internal {
     font: Font,
     shaping: Shaping,
     wrapping: Wrapping,
+    truncation: Truncation,
+    is_truncated: bool,
     align_x: Alignment,
     align_y: alignment::Vertical,
     bounds: Size,
@@ -90,7 +95,14 @@ impl core::text::Paragraph for Paragraph {
             text::sanitize_buffer_dimension(text.bounds.height * hint_factor),
         );
 
-        buffer.set_wrap(font_system.raw(), text::to_wrap(text.wrapping));
+        buffer.set_wrap(
+            font_system.raw(),
+            if text.truncation == Truncation::None {
+                text::to_wrap(text.wrapping)
+            } else {
+                cosmic_text::Wrap::None
+            },
+        );
 
         buffer.set_text(
             font_system.raw(),
@@ -100,6 +112,13 @@ impl core::text::Paragraph for Paragraph {
             None,
         );
 
+        let is_truncated = text::truncate(
+            &mut buffer,
+            font_system.raw(),
+            text.truncation,
+            text.bounds.width * hint_factor,
+        );
+
         // Resolve alignment now and store the resolved value to ensure consistency
         let resolved_align_x = text.align_x.resolve();
         let min_bounds =
@@ -114,6 +133,8 @@ impl core::text::Paragraph for Paragraph {
             align_y: text.align_y,
             shaping: text.shaping,
             wrapping: text.wrapping,
+            truncation: text.truncation,
+            is_truncated,
             bounds: text.bounds,
             min_bounds,
             version: font_system.version(),
@@ -148,7 +169,14 @@ impl core::text::Paragraph for Paragraph {
             text::sanitize_buffer_dimension(text.bounds.height * hint_factor),
         );
 
-        buffer.set_wrap(font_system.raw(), text::to_wrap(text.wrapping));
+        buffer.set_wrap(
+            font_system.raw(),
+            if text.truncation == Truncation::None {
+                text::to_wrap(text.wrapping)
+            } else {
+                cosmic_text::Wrap::None
+            },
+        );
 
         buffer.set_rich_text(
             font_system.raw(),
@@ -184,6 +212,13 @@ impl core::text::Paragraph for Paragraph {
             None,
         );
 
+        let is_truncated = text::truncate(
+            &mut buffer,
+            font_system.raw(),
+            text.truncation,
+            text.bounds.width * hint_factor,
+        );
+
         // Resolve alignment now and store the resolved value to ensure consistency
         let resolved_align_x = text.align_x.resolve();
         let min_bounds =
@@ -198,6 +233,8 @@ impl core::text::Paragraph for Paragraph {
             align_y: text.align_y,
             shaping: text.shaping,
             wrapping: text.wrapping,
+            truncation: text.truncation,
+            is_truncated,
             bounds: text.bounds,
             min_bounds,
             version: font_system.version(),
@@ -234,6 +271,9 @@ impl core::text::Paragraph for Paragraph {
             || paragraph.font != text.font
             || paragraph.shaping != text.shaping
             || paragraph.wrapping != text.wrapping
+            || paragraph.truncation != text.truncation
+            // Ellipses are computed for a specific width
+            || paragraph.truncation.is_ellipsis() && paragraph.bounds.width != text.bounds.width
             // paragraph.align_x is already resolved; compare with resolved new alignment
             || paragraph.align_x != text.align_x.resolve()
             || paragraph.align_y != text.align_y
@@ -282,6 +322,18 @@ impl core::text::Paragraph for Paragraph {
         self.0.shaping
     }
 
+    fn truncation(&self) -> Truncation {
+        self.0.truncation
+    }
+
+    fn is_truncated(&self) -> bool {
+        match self.0.truncation {
+            Truncation::None => false,
+            Truncation::Fade(_) => self.0.min_bounds.width > self.0.bounds.width,
+            Truncation::End | Truncation::Start | Truncation::Middle => self.0.is_truncated,
+        }
+    }
+
     fn bounds(&self) -> Size {
         self.0.bounds
     }
@@ -386,6 +438,15 @@ impl core::text::Paragraph for Paragraph {
 
         let run = self.internal().buffer.layout_runs().nth(line)?;
 
+        // Glyphs of mixed-direction runs are not in logical order
+        if run.glyphs.iter().any(|glyph| glyph.level.is_rtl()) {
+            let (x, _) = bidi::caret_positions(&self.visual_clusters(line), index)?;
+
+            let y = run.glyphs.first().map_or(0.0, |glyph| glyph.y);
+
+            return Some(Point::new(x, y / self.0.hint_factor));
+        }
+
         // index represents a grapheme, not a glyph
         // Let's find the first glyph for the given grapheme cluster
         let mut last_start = None;
@@ -420,6 +481,124 @@ impl core::text::Paragraph for Paragraph {
             (glyph.y - glyph.y_offset * glyph.font_size) / self.0.hint_factor,
         ))
     }
+
+    fn hit_offset(&self, point: Point) -> Option<usize> {
+        let buffer = &self.internal().buffer;
+
+        let cursor = buffer.hit(point.x * self.0.hint_factor, point.y * self.0.hint_factor)?;
+
+        let line_offset: usize = buffer
+            .lines
+            .iter()
+            .take(cursor.line)
+            .map(|line| line.text().len() + line.ending().as_str().len())
+            .sum();
+
+        Some(line_offset + cursor.index)
+    }
+
+    fn range_bounds(&self, range: Range<usize>) -> Vec<Rectangle> {
+        let buffer = &self.internal().buffer;
+
+        let start = to_cursor(buffer, range.start);
+        let end = to_cursor(buffer, range.end);
+
+        buffer
+            .layout_runs()
+            .filter_map(|run| {
+                let (x, width) = run.highlight(start, end)?;
+
+                (width > 0.0).then(|| {
+                    Rectangle {
+                        x,
+                        y: run.line_top,
+                        width,
+                        height: run.line_height,
+                    } * (1.0 / self.0.hint_factor)
+                })
+            })
+            .collect()
+    }
+
+    fn visual_clusters(&self, line: usize) -> Vec<bidi::Cluster> {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        let Some(run) = self.internal().buffer.layout_runs().nth(line) else {
+            return Vec::new();
+        };
+
+        let graphemes: Vec<usize> = run
+            .text
+            .grapheme_indices(true)
+            .map(|(start, _)| start)
+            .collect();
+
+        let mut clusters: Vec<bidi::Cluster> = Vec::new();
+
+        for glyph in run.glyphs {
+            let start = graphemes
+                .partition_point(|grapheme| *grapheme <= glyph.start)
+                .saturating_sub(1);
+            let end = graphemes.partition_point(|grapheme| *grapheme < glyph.end);
+
+            let x = glyph.x / self.0.hint_factor;
+            let width = glyph.w / self.0.hint_factor;
+
+            match clusters.last_mut() {
+                // Combining marks are shaped as separate glyphs
+                Some(last) if last.range == (start..end) => {
+                    last.width = x + width - last.x;
+                }
+                _ => clusters.push(bidi::Cluster {
+                    range: start..end,
+                    x,
+                    width,
+                    is_rtl: glyph.level.is_rtl(),
+                }),
+            }
+        }
+
+        // Ligatures may cover multiple graphemes
+        clusters
+            .into_iter()
+            .flat_map(|cluster| {
+                let count = cluster.range.len().max(1);
+                let width = cluster.width / count as f32;
+
+                (0..count).map(move |i| {
+                    let position = if cluster.is_rtl { count - 1 - i } else { i };
+
+                    bidi::Cluster {
+                        range: cluster.range.start + i..cluster.range.start + i + 1,
+                        x: cluster.x + position as f32 * width,
+                        width,
+                        is_rtl: cluster.is_rtl,
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+/// Converts a byte offset into the whole contents of a buffer into a
+/// [`cosmic_text::Cursor`].
+fn to_cursor(buffer: &cosmic_text::Buffer, offset: usize) -> cosmic_text::Cursor {
+    let mut start = 0;
+
+    for (i, line) in buffer.lines.iter().enumerate() {
+        let end = start + line.text().len();
+
+        if offset <= end {
+            return cosmic_text::Cursor::new(i, offset - start);
+        }
+
+        start = end + line.ending().as_str().len();
+    }
+
+    cosmic_text::Cursor::new(
+        buffer.lines.len().saturating_sub(1),
+        buffer.lines.last().map_or(0, |line| line.text().len()),
+    )
 }
 
 impl Default for Paragraph {
@@ -466,6 +645,8 @@ impl Default for Internal {
             font: Font::default(),
             shaping: Shaping::default(),
             wrapping: Wrapping::default(),
+            truncation: Truncation::default(),
+            is_truncated: false,
             // Store resolved value for consistency
             align_x: Alignment::Default.resolve(),
             align_y: alignment::Vertical::Top,
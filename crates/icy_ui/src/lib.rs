@@ -680,6 +680,12 @@ pub mod keyboard {
     pub use icy_ui_futures::keyboard::listen;
 }
 
+pub mod localization {
+    //! Translate the user interface and switch languages at runtime.
+    pub use crate::core::localization::{Bundle, Error, Locale, add_bundle, format, locale, text};
+    pub use crate::runtime::localization::set_locale;
+}
+
 pub mod mouse {
     //! Listen and react to mouse events.
     pub use crate::core::mouse::{Button, Cursor, Event, Interaction, ScrollDelta};
@@ -779,6 +785,36 @@ pub mod menu {
     };
 }
 
+#[cfg(feature = "offscreen")]
+pub mod offscreen {
+    //! Render user interfaces offscreen, without any windows.
+    //!
+    //! This lets you produce thumbnails, reports, or any other kind of image
+    //! out of the same view code used by your application.
+    //!
+    //! # Example
+    //! ```no_run
+    //! use icy_ui::offscreen::{self, Headless};
+    //! use icy_ui::widget::text;
+    //! use icy_ui::{Element, Font, Pixels, Renderer, Theme};
+    //!
+    //! # async fn export() -> Result<(), offscreen::Error> {
+    //! let mut renderer = Renderer::new(Font::DEFAULT, Pixels(16.0), None)
+    //!     .await
+    //!     .expect("Create headless renderer");
+    //!
+    //! let view: Element<'_, ()> = text("Hello, world!").into();
+    //!
+    //! let screenshot = offscreen::render(&mut renderer, view, (400.0, 300.0), 2.0, &Theme::Light);
+    //! let png = offscreen::encode_png(&screenshot)?;
+    //! # Ok(())
+    //! # }
+    //! ```
+    pub use crate::core::renderer::Headless;
+    pub use crate::core::window::Screenshot;
+    pub use crate::runtime::offscreen::{Error, encode_png, render};
+}
+
 pub mod overlay {
     //! Display interactive elements on top of other widgets.
 
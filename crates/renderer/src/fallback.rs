@@ -1,9 +1,11 @@
 //! Compose existing renderers and create type-safe fallback strategies.
+use crate::core::border;
 use crate::core::image;
 use crate::core::renderer;
 use crate::core::svg;
 use crate::core::{
-    self, Background, Color, Font, Image, Pixels, Point, Rectangle, Size, Svg, Transformation,
+    self, Backdrop, Background, Color, Font, Image, Pixels, Point, Rectangle, Size, Svg,
+    Transformation,
 };
 use crate::graphics::compositor;
 use crate::graphics::mesh;
@@ -46,6 +48,24 @@ where
         delegate!(self, renderer, renderer.start_layer(bounds));
     }
 
+    fn start_backdrop_layer(
+        &mut self,
+        bounds: Rectangle,
+        area: Rectangle,
+        border_radius: border::Radius,
+        backdrop: Backdrop,
+    ) {
+        delegate!(
+            self,
+            renderer,
+            renderer.start_backdrop_layer(bounds, area, border_radius, backdrop)
+        );
+    }
+
+    fn start_layer_with(&mut self, bounds: Rectangle, style: renderer::LayerStyle) {
+        delegate!(self, renderer, renderer.start_layer_with(bounds, style));
+    }
+
     fn end_layer(&mut self) {
         delegate!(self, renderer, renderer.end_layer());
     }
@@ -19,6 +19,9 @@ pub mod dnd;
 pub mod font;
 pub mod image;
 pub mod keyboard;
+pub mod localization;
+#[cfg(feature = "offscreen")]
+pub mod offscreen;
 pub mod system;
 pub mod task;
 pub mod user_interface;
@@ -68,6 +71,9 @@ pub enum Action<T> {
     /// Run an image action.
     Image(image::Action),
 
+    /// Run a localization action.
+    Localization(localization::Action),
+
     /// Run an accessibility action.
     #[cfg(feature = "accessibility")]
     Accessibility(accessibility::Action),
@@ -101,6 +107,7 @@ impl<T> Action<T> {
             Action::Window(action) => Err(Action::Window(action)),
             Action::System(action) => Err(Action::System(action)),
             Action::Image(action) => Err(Action::Image(action)),
+            Action::Localization(action) => Err(Action::Localization(action)),
             #[cfg(feature = "accessibility")]
             Action::Accessibility(action) => Err(Action::Accessibility(action)),
             Action::Tick => Err(Action::Tick),
@@ -132,6 +139,7 @@ where
             Action::Window(_) => write!(f, "Action::Window"),
             Action::System(action) => write!(f, "Action::System({action:?})"),
             Action::Image(_) => write!(f, "Action::Image"),
+            Action::Localization(action) => write!(f, "Action::Localization({action:?})"),
             #[cfg(feature = "accessibility")]
             Action::Accessibility(action) => {
                 write!(f, "Action::Accessibility({action:?})")
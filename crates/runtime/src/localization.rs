@@ -0,0 +1,29 @@
+//! Switch the language of the user interface at runtime.
+pub use crate::core::localization::{Bundle, Locale};
+
+use crate::task::{self, Task};
+use crate::window;
+
+/// A localization action to be performed by some [`Task`].
+#[derive(Debug, Clone)]
+pub enum Action {
+    /// Set the current [`Locale`].
+    SetLocale(Locale),
+}
+
+/// Switches the language of the user interface to the given [`Locale`].
+///
+/// The layout direction of every window following the global direction is
+/// flipped to the direction of the [`Locale`], and all user interfaces are
+/// rebuilt so built-in widgets pick up their new translations.
+pub fn set_locale<T>(locale: impl Into<Locale>) -> Task<T>
+where
+    T: 'static,
+{
+    let locale = locale.into();
+    let direction = locale.layout_direction();
+
+    task::effect(crate::Action::Localization(Action::SetLocale(locale)))
+        .chain(window::set_layout_direction(direction))
+        .chain(task::effect(crate::Action::Reload))
+}
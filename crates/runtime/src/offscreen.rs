@@ -0,0 +1,99 @@
+//! Render user interfaces offscreen, without any windows.
+//!
+//! This is useful to produce thumbnails, reports, or any other kind of
+//! image out of the same view code used by an application.
+use crate::core::clipboard;
+use crate::core::mouse;
+use crate::core::renderer::{self, Headless};
+use crate::core::theme;
+use crate::core::time::Instant;
+use crate::core::window::{self, Screenshot};
+use crate::core::{self, Element, Event, Size};
+use crate::user_interface::{self, UserInterface};
+
+use std::sync::Arc;
+
+/// Renders the given [`Element`] with a [`Headless`] renderer and returns
+/// the resulting [`Screenshot`].
+///
+/// The `size` is given in logical pixels. The [`Screenshot`] will have the
+/// physical size given by the `scale_factor`.
+///
+/// The [`Element`] is drawn once, right after its first redraw request.
+/// Any messages it may produce are discarded.
+pub fn render<'a, Message, Theme, Renderer>(
+    renderer: &mut Renderer,
+    element: impl Into<Element<'a, Message, Theme, Renderer>>,
+    size: impl Into<Size>,
+    scale_factor: f32,
+    theme: &Theme,
+) -> Screenshot
+where
+    Theme: theme::Base,
+    Renderer: core::Renderer + Headless,
+{
+    let size = size.into();
+    let base = theme.base();
+
+    let mut user_interface =
+        UserInterface::build(element, size, user_interface::Cache::default(), renderer);
+
+    let mut messages = Vec::new();
+
+    let _ = user_interface.update(
+        &[Event::Window(
+            window::Event::RedrawRequested(Instant::now()),
+        )],
+        mouse::Cursor::Unavailable,
+        renderer,
+        &mut clipboard::Null,
+        &mut messages,
+    );
+
+    user_interface.draw(
+        renderer,
+        theme,
+        &renderer::Style {
+            text_color: base.text_color,
+        },
+        mouse::Cursor::Unavailable,
+    );
+
+    let physical_size = Size::new(
+        (size.width * scale_factor).round() as u32,
+        (size.height * scale_factor).round() as u32,
+    );
+
+    let rgba = renderer.screenshot(physical_size, scale_factor, base.background_color);
+
+    Screenshot::new(rgba, physical_size, scale_factor)
+}
+
+/// Encodes the given [`Screenshot`] as a PNG image.
+pub fn encode_png(screenshot: &Screenshot) -> Result<Vec<u8>, Error> {
+    let mut bytes = Vec::new();
+
+    let mut encoder = png::Encoder::new(&mut bytes, screenshot.size.width, screenshot.size.height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&screenshot.rgba)?;
+    writer.finish()?;
+
+    Ok(bytes)
+}
+
+/// An error produced while exporting an offscreen render.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum Error {
+    /// The encoding of the PNG image failed.
+    #[error("the encoding of the PNG image failed: {0}")]
+    PngEncodingFailed(Arc<png::EncodingError>),
+}
+
+impl From<png::EncodingError> for Error {
+    fn from(error: png::EncodingError) -> Self {
+        Self::PngEncodingFailed(Arc::new(error))
+    }
+}
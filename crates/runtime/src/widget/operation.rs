@@ -2,11 +2,14 @@
 use crate::core::Rectangle;
 use crate::core::widget::Id;
 use crate::core::widget::operation;
+use crate::futures::MaybeSend;
 use crate::task;
 use crate::{Action, Task};
 
 pub use crate::core::widget::operation::focusable::{FocusLevel, FocusTier};
-pub use crate::core::widget::operation::scrollable::{AbsoluteOffset, RelativeOffset};
+pub use crate::core::widget::operation::scrollable::{
+    AbsoluteOffset, RelativeOffset, ScrollPositions,
+};
 
 /// Snaps the scrollable with the given [`Id`] to the provided [`RelativeOffset`].
 pub fn snap_to<T>(id: impl Into<Id>, offset: impl Into<RelativeOffset<Option<f32>>>) -> Task<T> {
@@ -80,6 +83,33 @@ pub fn ensure_visible_animated<T>(id: impl Into<Id>, target_rect: Rectangle) ->
     ))
 }
 
+/// Scrolls the scrollable with the given [`Id`] so the widget with the `widget`
+/// [`Id`] inside of it is at the top; e.g. to jump to a heading of a Markdown
+/// document.
+pub fn scroll_to_widget<T>(id: impl Into<Id>, widget: impl Into<Id>) -> Task<T> {
+    task::effect(Action::widget(operation::scrollable::scroll_to_widget(
+        id.into(),
+        widget.into(),
+    )))
+}
+
+/// Captures the [`AbsoluteOffset`] of every scrollable with an [`Id`].
+///
+/// The resulting [`ScrollPositions`] can be kept around (or persisted, with the
+/// `serde` feature) and applied later with [`restore_scroll_positions`]; e.g. to
+/// bring back the scroll state of a tab after switching to it again.
+pub fn scroll_positions() -> Task<ScrollPositions> {
+    task::widget(operation::scrollable::positions())
+}
+
+/// Scrolls every scrollable recorded in the given [`ScrollPositions`] back to its
+/// recorded [`AbsoluteOffset`].
+///
+/// Scrollables that are not currently part of the widget tree are ignored.
+pub fn restore_scroll_positions<T>(positions: ScrollPositions) -> Task<T> {
+    task::effect(Action::widget(operation::scrollable::restore(positions)))
+}
+
 /// Focuses the previous focusable widget.
 pub fn focus_previous<T>() -> Task<T> {
     task::effect(Action::widget(operation::focusable::focus_previous()))
@@ -154,3 +184,29 @@ pub fn select_range<T>(id: impl Into<Id>, start: usize, end: usize) -> Task<T> {
         end,
     )))
 }
+
+/// Returns the text currently selected in any selectable `text` or
+/// `rich_text` widget, if any.
+pub fn selected_text() -> Task<Option<String>> {
+    task::widget(operation::selection::selected_text())
+}
+
+/// Copies the text currently selected in any selectable `text` or
+/// `rich_text` widget to the standard clipboard.
+///
+/// This is useful to implement the "Copy" entry of a context menu.
+pub fn copy_selection<T>() -> Task<T>
+where
+    T: MaybeSend + 'static,
+{
+    selected_text().then(|text| {
+        text.map_or_else(Task::none, |text| {
+            crate::clipboard::STANDARD.write_text(text)
+        })
+    })
+}
+
+/// Clears the selection of every selectable `text` or `rich_text` widget.
+pub fn clear_selection<T>() -> Task<T> {
+    task::effect(Action::widget(operation::selection::clear()))
+}
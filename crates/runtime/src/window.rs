@@ -180,6 +180,10 @@ pub enum Action {
     /// Set the layout direction for all windows.
     SetLayoutDirection(LayoutDirection),
 
+    /// Set the layout direction of the window with the given [`Id`], or make it
+    /// follow the global layout direction with `None`.
+    SetWindowLayoutDirection(Id, Option<LayoutDirection>),
+
     /// Set whether the system can automatically organize windows into tabs.
     ///
     /// See <https://developer.apple.com/documentation/appkit/nswindow/1646657-allowsautomaticwindowtabbing>
@@ -486,10 +490,20 @@ pub fn monitor_size(id: Id) -> Task<Option<Size>> {
 /// Sets the layout direction for all windows.
 ///
 /// This affects how widgets are laid out (left-to-right or right-to-left).
+/// Windows with their own layout direction are not affected.
 pub fn set_layout_direction<T>(direction: LayoutDirection) -> Task<T> {
     task::effect(crate::Action::Window(Action::SetLayoutDirection(direction)))
 }
 
+/// Sets the layout direction of the window with the given [`Id`].
+///
+/// Passing `None` makes the window follow the global layout direction again.
+pub fn set_window_layout_direction<T>(id: Id, direction: Option<LayoutDirection>) -> Task<T> {
+    task::effect(crate::Action::Window(Action::SetWindowLayoutDirection(
+        id, direction,
+    )))
+}
+
 /// Sets whether the system can automatically organize windows into tabs.
 ///
 /// See <https://developer.apple.com/documentation/appkit/nswindow/1646657-allowsautomaticwindowtabbing>
@@ -330,6 +330,7 @@ where
                                 on_open,
                             } => {
                                 let exit_on_close_request = settings.exit_on_close_request;
+                                let layout_direction = settings.layout_direction;
 
                                 let visible = settings.visible;
 
@@ -423,6 +424,7 @@ where
                                         id,
                                         window: Arc::new(window),
                                         exit_on_close_request,
+                                        layout_direction,
                                         make_visible: visible,
                                         #[cfg(feature = "accessibility")]
                                         accessibility,
@@ -495,6 +497,7 @@ enum Event<Message: 'static> {
         id: window::Id,
         window: Arc<winit::window::Window>,
         exit_on_close_request: bool,
+        layout_direction: Option<core::LayoutDirection>,
         make_visible: bool,
         #[cfg(feature = "accessibility")]
         accessibility: Option<crate::accessibility::AccessibilityAdapter>,
@@ -634,6 +637,7 @@ async fn run_instance<P>(
                 id,
                 window,
                 exit_on_close_request,
+                layout_direction,
                 make_visible,
                 #[cfg(feature = "accessibility")]
                     accessibility: a11y_adapter,
@@ -719,6 +723,7 @@ async fn run_instance<P>(
                     &program,
                     compositor.as_mut().expect("Compositor must be initialized"),
                     exit_on_close_request,
+                    layout_direction,
                     system_theme,
                 );
 
@@ -726,6 +731,8 @@ async fn run_instance<P>(
                     .raw
                     .set_theme(conversion::window_theme(window.state.theme_mode()));
 
+                let _context = window.enter();
+
                 debug::theme_changed(|| {
                     if is_first {
                         theme::Base::palette(window.state.theme())
@@ -888,6 +895,8 @@ async fn run_instance<P>(
                             continue;
                         };
 
+                        let _context = window.enter();
+
                         let physical_size = window.state.physical_size();
                         let mut logical_size = window.state.logical_size();
 
@@ -1341,14 +1350,14 @@ async fn run_instance<P>(
                                         ProcessedEvent::Activated => {
                                             // Screen reader connected - enter accessibility mode
                                             state.state.activate();
-                                            crate::core::accessibility::set_accessibility_active(
-                                                true,
-                                            );
                                             log::info!(
                                                 "Accessibility mode activated for window {:?}",
                                                 id
                                             );
                                             if let Some(window) = window_manager.get_mut(*id) {
+                                                window.is_accessibility_active = true;
+
+                                                let _context = window.enter();
                                                 window.raw.request_redraw();
 
                                                 // Publish an initial tree immediately so the
@@ -1381,6 +1390,8 @@ async fn run_instance<P>(
                                                     if let Some(window) =
                                                         window_manager.get_mut(*id)
                                                     {
+                                                        let _context = window.enter();
+
                                                         let mut focus_op: Box<dyn Operation<()>> =
                                                             Box::new(
                                                                 operation::accessibility::focus_widget_by_node_id(
@@ -1411,9 +1422,11 @@ async fn run_instance<P>(
                                                 );
                                             }
                                             state.state.deactivate();
-                                            crate::core::accessibility::set_accessibility_active(
-                                                false,
-                                            );
+
+                                            if let Some(window) = window_manager.get_mut(*id) {
+                                                window.is_accessibility_active = false;
+                                            }
+
                                             log::info!(
                                                 "Accessibility mode deactivated for window {:?}",
                                                 id
@@ -1434,6 +1447,7 @@ async fn run_instance<P>(
                         let mut uis_stale = false;
 
                         for (id, window) in window_manager.iter_mut() {
+                            let _context = window.enter();
                             let interact_span = debug::interact(id);
                             let mut window_events = vec![];
 
@@ -1942,6 +1956,8 @@ fn update_accessibility_tree<'a, P, C>(
         return;
     }
 
+    let _context = window.enter();
+
     if crate::accessibility::trace_enabled() {
         eprintln!(
             "[a11y] update_accessibility_tree window={:?} adapter_enabled={} ",
@@ -2843,12 +2859,30 @@ fn run_action<'a, P, C>(
             window::Action::SetLayoutDirection(direction) => {
                 core::set_layout_direction(direction);
                 for (id, window) in window_manager.iter_mut() {
+                    let _context = window.enter();
+
+                    if let Some(ui) = interfaces.remove(&id) {
+                        let _ = interfaces.insert(
+                            id,
+                            ui.relayout(window.state.logical_size(), &mut window.renderer),
+                        );
+                    }
+                    window.raw.request_redraw();
+                }
+            }
+            window::Action::SetWindowLayoutDirection(id, direction) => {
+                if let Some(window) = window_manager.get_mut(id) {
+                    window.layout_direction = direction;
+
+                    let _context = window.enter();
+
                     if let Some(ui) = interfaces.remove(&id) {
                         let _ = interfaces.insert(
                             id,
                             ui.relayout(window.state.logical_size(), &mut window.renderer),
                         );
                     }
+
                     window.raw.request_redraw();
                 }
             }
@@ -2859,6 +2893,8 @@ fn run_action<'a, P, C>(
             }
             window::Action::RelayoutAll => {
                 for (id, window) in window_manager.iter_mut() {
+                    let _context = window.enter();
+
                     if let Some(ui) = interfaces.remove(&id) {
                         let _ = interfaces.insert(
                             id,
@@ -2914,6 +2950,8 @@ fn run_action<'a, P, C>(
             while let Some(mut operation) = current_operation.take() {
                 for (id, ui) in interfaces.iter_mut() {
                     if let Some(window) = window_manager.get_mut(*id) {
+                        let _context = window.enter();
+
                         ui.operate(&window.renderer, operation.as_mut());
                     }
                 }
@@ -2937,6 +2975,11 @@ fn run_action<'a, P, C>(
                 }
             }
         },
+        Action::Localization(action) => match action {
+            runtime::localization::Action::SetLocale(locale) => {
+                core::localization::set_locale(locale);
+            }
+        },
         Action::LoadFont { bytes, channel } => {
             if let Some(compositor) = compositor {
                 // TODO: Error handling (?)
@@ -2975,6 +3018,8 @@ fn run_action<'a, P, C>(
                     continue;
                 };
 
+                let _context = window.enter();
+
                 let cache = ui.into_cache();
                 let size = window.logical_size();
 
@@ -3089,6 +3134,7 @@ where
         .drain()
         .filter_map(|(id, cache)| {
             let window = window_manager.get_mut(id)?;
+            let _context = window.enter();
 
             Some((
                 id,
@@ -7,6 +7,7 @@ pub use crate::core::window::{Event, Id, RedrawRequest, Settings};
 use crate::conversion;
 use crate::core::alignment;
 use crate::core::input_method;
+use crate::core::layout_direction::{self, LayoutDirection};
 use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text;
@@ -53,6 +54,7 @@ where
         program: &program::Instance<P>,
         compositor: &mut C,
         exit_on_close_request: bool,
+        layout_direction: Option<LayoutDirection>,
         system_theme: theme::Mode,
     ) -> &mut Window<P, C> {
         let state = State::new(program, id, &window, system_theme);
@@ -70,6 +72,9 @@ where
                 raw: window,
                 state,
                 exit_on_close_request,
+                layout_direction,
+                #[cfg(feature = "accessibility")]
+                is_accessibility_active: false,
                 surface,
                 surface_version,
                 renderer,
@@ -150,6 +155,13 @@ where
     }
 }
 
+/// The per-window state entered with [`Window::enter`].
+pub struct Context {
+    _layout_direction: layout_direction::Scope,
+    #[cfg(feature = "accessibility")]
+    _accessibility: crate::core::accessibility::Scope,
+}
+
 pub struct Window<P, C>
 where
     P: Program,
@@ -159,6 +171,9 @@ where
     pub raw: Arc<winit::window::Window>,
     pub state: State<P>,
     pub exit_on_close_request: bool,
+    pub layout_direction: Option<LayoutDirection>,
+    #[cfg(feature = "accessibility")]
+    pub is_accessibility_active: bool,
     pub mouse_interaction: mouse::Interaction,
     pub surface: C::Surface,
     pub surface_version: u64,
@@ -189,6 +204,22 @@ where
         self.state.logical_size()
     }
 
+    /// Enters the layout direction and accessibility state of the window on the
+    /// current thread, until the returned [`Context`] is dropped.
+    ///
+    /// This must be held while building, updating, drawing or operating on the
+    /// user interface of the window.
+    pub fn enter(&self) -> Context {
+        Context {
+            _layout_direction: layout_direction::enter(
+                self.layout_direction
+                    .unwrap_or_else(layout_direction::default_layout_direction),
+            ),
+            #[cfg(feature = "accessibility")]
+            _accessibility: crate::core::accessibility::enter(self.is_accessibility_active),
+        }
+    }
+
     pub fn request_redraw(&mut self, redraw_request: RedrawRequest) {
         match redraw_request {
             RedrawRequest::NextFrame => {
@@ -377,6 +408,7 @@ where
                 align_y: alignment::Vertical::Top,
                 shaping: text::Shaping::Advanced,
                 wrapping: text::Wrapping::None,
+                truncation: text::Truncation::None,
                 hint_factor: renderer.scale_factor(),
             });
 
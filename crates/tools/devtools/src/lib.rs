@@ -11,6 +11,7 @@ mod time_machine;
 
 use crate::core::border;
 use crate::core::keyboard;
+use crate::core::localization;
 use crate::core::theme::{self, Theme};
 use crate::core::time::seconds;
 use crate::core::window;
@@ -336,10 +337,9 @@ where
 
         let notification = self
             .show_notification
-            .then(|| text("Press F12 to open debug metrics"))
+            .then(|| text(localization::text("devtools-open-metrics")))
             .or_else(|| {
-                debug::is_stale()
-                    .then(|| text("Types have changed. Restart to re-enable hotpatching."))
+                debug::is_stale().then(|| text(localization::text("devtools-hotpatch-stale")))
             })
             .map(|notification| {
                 bottom_right(opaque(
@@ -434,16 +434,20 @@ where
     Renderer: program::Renderer + 'static,
 {
     let controls = row![
-        button(text("Cancel").center().width(Fill))
-            .width(100)
-            .on_press(Message::CancelSetup)
-            .style(button::danger),
+        button(
+            text(localization::text("devtools-cancel"))
+                .center()
+                .width(Fill)
+        )
+        .width(100)
+        .on_press(Message::CancelSetup)
+        .style(button::danger),
         space::horizontal(),
         button(
-            text(match goal {
-                Goal::Installation => "Install",
-                Goal::Update { .. } => "Update",
-            })
+            text(localization::text(match goal {
+                Goal::Installation => "devtools-install",
+                Goal::Update { .. } => "devtools-update",
+            }))
             .center()
             .width(Fill)
         )
@@ -468,16 +472,11 @@ where
 
     Element::from(match goal {
         Goal::Installation => column![
-            text("comet is not installed!").size(20),
-            "In order to display performance \
-                metrics, the  comet debugger must \
-                be installed in your system.",
-            "The comet debugger is an official \
-                companion tool that helps you debug \
-                your iced applications.",
+            text(localization::text("devtools-comet-missing")).size(20),
+            text(localization::text("devtools-comet-missing-description")),
+            text(localization::text("devtools-comet-about")),
             column![
-                "Do you wish to install it with the \
-                    following command?",
+                text(localization::text("devtools-comet-install-prompt")),
                 command
             ]
             .spacing(10),
@@ -487,13 +486,17 @@ where
         Goal::Update { revision } => {
             let comparison = column![
                 row![
-                    "Installed revision:",
+                    text(localization::text("devtools-installed-revision")),
                     space::horizontal(),
-                    inline_code(revision.as_deref().unwrap_or("Unknown"))
+                    inline_code(
+                        revision
+                            .clone()
+                            .unwrap_or_else(|| localization::text("devtools-unknown-revision"))
+                    )
                 ]
                 .align_y(Center),
                 row![
-                    "Compatible revision:",
+                    text(localization::text("devtools-compatible-revision")),
                     space::horizontal(),
                     inline_code(comet::COMPATIBLE_REVISION),
                 ]
@@ -502,11 +505,10 @@ where
             .spacing(5);
 
             column![
-                text("comet is out of date!").size(20),
+                text(localization::text("devtools-comet-outdated")).size(20),
                 comparison,
                 column![
-                    "Do you wish to update it with the following \
-                        command?",
+                    text(localization::text("devtools-comet-update-prompt")),
                     command
                 ]
                 .spacing(10),
@@ -522,7 +524,7 @@ where
     Renderer: program::Renderer + 'a,
 {
     column![
-        text("Installing comet...").size(20),
+        text(localization::text("devtools-installing")).size(20),
         container(
             scrollable(
                 column(
@@ -0,0 +1,316 @@
+//! Inspect and lint the accessibility tree of a user interface.
+use crate::Error;
+use crate::core::Rectangle;
+use crate::core::accessibility::{Action, Node, NodeId, Role};
+use crate::core::widget::operation::accessibility::AccessibilityTree;
+
+use std::collections::HashMap;
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::path::Path;
+
+/// The accessibility tree of a user interface rendered by a
+/// [`Simulator`](crate::Simulator).
+///
+/// A [`Snapshot`] serializes the roles, names, states and bounds of every node
+/// into a stable text format that can be committed alongside your tests.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    text: String,
+    issues: Vec<Issue>,
+}
+
+impl Snapshot {
+    /// Creates a [`Snapshot`] of the given [`AccessibilityTree`].
+    pub fn new(tree: &AccessibilityTree) -> Self {
+        let nodes: HashMap<NodeId, &Node> =
+            tree.nodes.iter().map(|(id, node)| (*id, node)).collect();
+
+        let mut snapshot = Self {
+            text: String::new(),
+            issues: Vec::new(),
+        };
+
+        for id in &tree.top_level_ids {
+            snapshot.visit(&nodes, *id, 0);
+        }
+
+        snapshot
+    }
+
+    /// Returns the serialized accessibility tree of the [`Snapshot`].
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the [`Issue`]s found in the accessibility tree of the [`Snapshot`].
+    pub fn issues(&self) -> &[Issue] {
+        &self.issues
+    }
+
+    /// Fails with [`Error::AccessibilityIssues`] if the [`Snapshot`] has any
+    /// [`Issue`]s.
+    ///
+    /// This is meant to be used in CI to catch unlabeled controls.
+    pub fn lint(&self) -> Result<(), Error> {
+        if self.issues.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::AccessibilityIssues {
+                issues: self.issues.clone(),
+            })
+        }
+    }
+
+    /// Compares the [`Snapshot`] with the text file found in the given path, returning
+    /// `true` if they are identical.
+    ///
+    /// If the file does not exist, it will be created by the [`Snapshot`] for future
+    /// testing and `true` will be returned.
+    pub fn matches_file(&self, path: impl AsRef<Path>) -> Result<bool, Error> {
+        let path = path.as_ref().with_extension("a11y");
+
+        if path.exists() {
+            let saved = fs::read_to_string(&path)?;
+
+            Ok(self.text == saved)
+        } else {
+            if let Some(directory) = path.parent() {
+                fs::create_dir_all(directory)?;
+            }
+
+            fs::write(path, &self.text)?;
+            Ok(true)
+        }
+    }
+
+    fn visit(&mut self, nodes: &HashMap<NodeId, &Node>, id: NodeId, depth: usize) {
+        let Some(node) = nodes.get(&id) else {
+            return;
+        };
+
+        let bounds = node
+            .bounds()
+            .map(|rect| Rectangle {
+                x: rect.x0 as f32,
+                y: rect.y0 as f32,
+                width: (rect.x1 - rect.x0) as f32,
+                height: (rect.y1 - rect.y0) as f32,
+            })
+            .unwrap_or_default();
+
+        let _ = writeln!(
+            self.text,
+            "{:indent$}{}",
+            "",
+            Line { node, bounds },
+            indent = depth * 2
+        );
+
+        let has_name = node.label().is_some_and(|label| !label.trim().is_empty())
+            || !node.labelled_by().is_empty();
+
+        if !has_name {
+            if node.role() == Role::Button {
+                self.issues.push(Issue::UnlabeledButton { bounds });
+            } else if node.supports_action(Action::Focus) {
+                self.issues.push(Issue::UnnamedFocusable {
+                    role: node.role(),
+                    bounds,
+                });
+            }
+        }
+
+        for child in node.children() {
+            self.visit(nodes, *child, depth + 1);
+        }
+    }
+}
+
+impl fmt::Display for Snapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// An accessibility problem found in a [`Snapshot`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Issue {
+    /// A button has no accessible name.
+    UnlabeledButton {
+        /// The bounds of the button.
+        bounds: Rectangle,
+    },
+    /// A focusable node has no accessible name.
+    UnnamedFocusable {
+        /// The role of the node.
+        role: Role,
+        /// The bounds of the node.
+        bounds: Rectangle,
+    },
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnlabeledButton { bounds } => {
+                write!(f, "button at {} has no label", Bounds(*bounds))
+            }
+            Self::UnnamedFocusable { role, bounds } => {
+                write!(f, "focusable {role:?} at {} has no name", Bounds(*bounds))
+            }
+        }
+    }
+}
+
+/// A single serialized node of a [`Snapshot`].
+struct Line<'a> {
+    node: &'a Node,
+    bounds: Rectangle,
+}
+
+impl fmt::Display for Line<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let node = self.node;
+
+        write!(f, "{:?}", node.role())?;
+
+        if let Some(label) = node.label() {
+            write!(f, " {label:?}")?;
+        }
+
+        if let Some(value) = node.value() {
+            write!(f, " value={value:?}")?;
+        }
+
+        if let Some(value) = node.numeric_value() {
+            write!(f, " value={value}")?;
+
+            if let (Some(min), Some(max)) = (node.min_numeric_value(), node.max_numeric_value()) {
+                write!(f, " range={min}..={max}")?;
+            }
+        }
+
+        if let Some(description) = node.description() {
+            write!(f, " description={description:?}")?;
+        }
+
+        if let Some(placeholder) = node.placeholder() {
+            write!(f, " placeholder={placeholder:?}")?;
+        }
+
+        let mut states = Vec::new();
+
+        if node.is_disabled() {
+            states.push("disabled".to_owned());
+        }
+
+        if node.supports_action(Action::Focus) {
+            states.push("focusable".to_owned());
+        }
+
+        if node.is_required() {
+            states.push("required".to_owned());
+        }
+
+        if let Some(toggled) = node.toggled() {
+            states.push(format!("toggled={toggled:?}").to_lowercase());
+        }
+
+        if let Some(selected) = node.is_selected() {
+            states.push(format!("selected={selected}"));
+        }
+
+        if let Some(expanded) = node.is_expanded() {
+            states.push(format!("expanded={expanded}"));
+        }
+
+        if let Some(level) = node.level() {
+            states.push(format!("level={level}"));
+        }
+
+        if let Some(live) = node.live() {
+            states.push(format!("live={live:?}").to_lowercase());
+        }
+
+        if !states.is_empty() {
+            write!(f, " [{}]", states.join(", "))?;
+        }
+
+        write!(f, " @ {}", Bounds(self.bounds))
+    }
+}
+
+/// Bounds rounded to whole logical pixels, so snapshots are not affected by
+/// subpixel differences in text measurement.
+struct Bounds(Rectangle);
+
+impl fmt::Display for Bounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Rectangle {
+            x,
+            y,
+            width,
+            height,
+        } = self.0;
+
+        write!(
+            f,
+            "({}, {}) {}x{}",
+            x.round(),
+            y.round(),
+            width.round(),
+            height.round()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::accessibility::WidgetInfo;
+    use crate::core::widget::Operation;
+    use crate::core::widget::operation::{Outcome, accessibility};
+
+    #[test]
+    fn serializes_tree_and_reports_issues() {
+        let mut operation = accessibility::collect();
+
+        operation.accessibility(
+            None,
+            Rectangle::new([0.0, 0.0].into(), [80.0, 30.0].into()),
+            WidgetInfo::button("Save"),
+        );
+        operation.accessibility(
+            None,
+            Rectangle::new([0.0, 40.0].into(), [30.0, 30.0].into()),
+            WidgetInfo::button(""),
+        );
+        operation.accessibility(
+            None,
+            Rectangle::new([0.0, 80.0].into(), [200.0, 10.0].into()),
+            WidgetInfo::progress_bar(0.5, 0.0, 1.0),
+        );
+
+        let Outcome::Some(tree) = operation.finish() else {
+            panic!("collect should produce a tree");
+        };
+
+        let snapshot = Snapshot::new(&tree);
+
+        assert_eq!(
+            snapshot.as_str(),
+            "Button \"Save\" [focusable] @ (0, 0) 80x30\n\
+             Button \"\" [focusable] @ (0, 40) 30x30\n\
+             ProgressIndicator value=0.5 range=0..=1 @ (0, 80) 200x10\n"
+        );
+
+        assert_eq!(
+            snapshot.issues(),
+            [Issue::UnlabeledButton {
+                bounds: Rectangle::new([0.0, 40.0].into(), [30.0, 30.0].into()),
+            }]
+        );
+        assert!(snapshot.lint().is_err());
+    }
+}
@@ -264,6 +264,11 @@ impl<P: Program + 'static> Emulator<P> {
                     // TODO
                     dbg!(action);
                 }
+                runtime::Action::Localization(action) => match action {
+                    runtime::localization::Action::SetLocale(locale) => {
+                        core::localization::set_locale(locale);
+                    }
+                },
                 icy_ui_runtime::Action::Tick => {
                     // TODO
                 }
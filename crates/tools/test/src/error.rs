@@ -45,6 +45,13 @@ pub enum Error {
         /// The [`Instruction`] that failed.
         instruction: Instruction,
     },
+    /// The accessibility tree of a [`Simulator`](crate::Simulator) has issues.
+    #[cfg(feature = "accessibility")]
+    #[error("the accessibility tree has {} issue(s): {issues:?}", .issues.len())]
+    AccessibilityIssues {
+        /// The issues found.
+        issues: Vec<crate::accessibility::Issue>,
+    },
     /// The [`Preset`](crate::program::Preset) of a program could not be found.
     #[error("the preset \"{name}\" does not exist (available presets: {available:?})")]
     PresetNotFound {
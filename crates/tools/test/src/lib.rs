@@ -82,6 +82,10 @@
 //! [`Simulator`] contains additional operations you can use to simulate more interactions—like [`tap_key`](Simulator::tap_key) or
 //! [`typewrite`](Simulator::typewrite)—and even perform [_snapshot testing_](Simulator::snapshot)!
 //!
+//! With the `accessibility` feature enabled, [`Simulator::accessibility_snapshot`] captures the
+//! accessibility tree of the interface instead, which can be compared against a committed file
+//! and linted for unlabeled controls.
+//!
 //! [the classical counter interface]: https://book.iced.rs/architecture.html#dissecting-an-interface
 pub use icy_ui_futures as futures;
 pub use icy_ui_program as program;
@@ -91,6 +95,8 @@ pub use icy_ui_runtime::core;
 
 pub use icy_ui_selector as selector;
 
+#[cfg(feature = "accessibility")]
+pub mod accessibility;
 pub mod emulator;
 pub mod ice;
 pub mod instruction;
@@ -15,6 +15,9 @@ use crate::runtime::user_interface;
 use crate::selector::Bounded;
 use crate::{Error, Selector};
 
+#[cfg(feature = "accessibility")]
+use crate::accessibility;
+
 use std::borrow::Cow;
 use std::env;
 use std::fs;
@@ -223,6 +226,28 @@ where
         })
     }
 
+    /// Takes an [`accessibility::Snapshot`] of the interface in the [`Simulator`].
+    ///
+    /// The snapshot can be compared against a committed file with
+    /// [`accessibility::Snapshot::matches_file`] and checked for unlabeled
+    /// controls with [`accessibility::Snapshot::lint`].
+    #[cfg(feature = "accessibility")]
+    pub fn accessibility_snapshot(&mut self) -> accessibility::Snapshot {
+        use widget::Operation;
+
+        let mut operation = widget::operation::accessibility::collect();
+
+        self.raw.operate(
+            &self.renderer,
+            &mut widget::operation::black_box(&mut operation),
+        );
+
+        match operation.finish() {
+            widget::operation::Outcome::Some(tree) => accessibility::Snapshot::new(&tree),
+            _ => accessibility::Snapshot::new(&Default::default()),
+        }
+    }
+
     /// Turns the [`Simulator`] into the sequence of messages produced by any interactions.
     pub fn into_messages(self) -> impl Iterator<Item = Message> + use<Message, Theme, Renderer> {
         self.messages.into_iter()
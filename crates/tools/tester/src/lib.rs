@@ -15,6 +15,7 @@ use crate::core::Alignment::Center;
 use crate::core::Length::Fill;
 use crate::core::alignment::Horizontal::Right;
 use crate::core::border;
+use crate::core::localization;
 use crate::core::mouse;
 use crate::core::theme;
 use crate::core::window;
@@ -536,31 +537,34 @@ impl<P: Program<Theme = Theme> + 'static> Tester<P> {
     ) -> Element<'a, Tick<P>, Theme, P::Renderer> {
         let status = {
             let (icon, label) = match &self.state {
-                State::Empty | State::Idle { .. } => (text(""), "Idle"),
-                State::Recording { .. } => (icon::record(), "Recording"),
-                State::Asserting { .. } => (icon::lightbulb(), "Asserting"),
+                State::Empty | State::Idle { .. } => (text(""), "tester-idle"),
+                State::Recording { .. } => (icon::record(), "tester-recording"),
+                State::Asserting { .. } => (icon::lightbulb(), "tester-asserting"),
                 State::Playing { outcome, .. } => match outcome {
-                    Outcome::Running => (icon::play(), "Playing"),
-                    Outcome::Failed => (icon::cancel(), "Failed"),
-                    Outcome::Success => (icon::check(), "Success"),
+                    Outcome::Running => (icon::play(), "tester-playing"),
+                    Outcome::Failed => (icon::cancel(), "tester-failed"),
+                    Outcome::Success => (icon::check(), "tester-success"),
                 },
             };
 
-            container(row![icon.size(14), label].align_y(Center).spacing(8)).style(
-                |theme: &Theme| container::Style {
-                    text_color: Some(match &self.state {
-                        State::Empty | State::Idle { .. } => theme.background.on,
-                        State::Recording { .. } => theme.destructive.base,
-                        State::Asserting { .. } => theme.warning.base,
-                        State::Playing { outcome, .. } => match outcome {
-                            Outcome::Running => theme.accent.base,
-                            Outcome::Failed => theme.destructive.base,
-                            Outcome::Success => theme.success.base,
-                        },
-                    }),
-                    ..container::Style::default()
-                },
+            container(
+                row![icon.size(14), text(localization::text(label))]
+                    .align_y(Center)
+                    .spacing(8),
             )
+            .style(|theme: &Theme| container::Style {
+                text_color: Some(match &self.state {
+                    State::Empty | State::Idle { .. } => theme.background.on,
+                    State::Recording { .. } => theme.destructive.base,
+                    State::Asserting { .. } => theme.warning.base,
+                    State::Playing { outcome, .. } => match outcome {
+                        Outcome::Running => theme.accent.base,
+                        Outcome::Failed => theme.destructive.base,
+                        Outcome::Success => theme.success.base,
+                    },
+                }),
+                ..container::Style::default()
+            })
         };
 
         let view = match &self.state {
@@ -617,7 +621,7 @@ impl<P: Program<Theme = Theme> + 'static> Tester<P> {
     fn controls(&self) -> Element<'_, Event, Theme, P::Renderer> {
         let viewport = column![
             labeled_slider(
-                "Width",
+                localization::text("tester-width"),
                 100.0..=2000.0,
                 self.viewport.width,
                 |width| Event::ViewportChanged(Size {
@@ -627,7 +631,7 @@ impl<P: Program<Theme = Theme> + 'static> Tester<P> {
                 |width| format!("{width:.0}"),
             ),
             labeled_slider(
-                "Height",
+                localization::text("tester-height"),
                 100.0..=2000.0,
                 self.viewport.height,
                 |height| Event::ViewportChanged(Size {
@@ -641,7 +645,7 @@ impl<P: Program<Theme = Theme> + 'static> Tester<P> {
 
         let preset = combo_box(
             &self.presets,
-            "Default",
+            &localization::text("tester-default-preset"),
             self.preset.as_ref(),
             Event::PresetSelected,
         )
@@ -662,7 +666,7 @@ impl<P: Program<Theme = Theme> + 'static> Tester<P> {
                     .into()
             } else if self.instructions.is_empty() {
                 Element::from(center(
-                    text("No instructions recorded yet!")
+                    text(localization::text("tester-no-instructions"))
                         .size(14)
                         .font(Font::MONOSPACE)
                         .width(Fill)
@@ -763,10 +767,10 @@ impl<P: Program<Theme = Theme> + 'static> Tester<P> {
         };
 
         column![
-            labeled("Viewport", viewport),
-            labeled("Mode", mode),
-            labeled("Preset", preset),
-            labeled_with("Instructions", edit, player)
+            labeled(localization::text("tester-viewport"), viewport),
+            labeled(localization::text("tester-mode"), mode),
+            labeled(localization::text("tester-preset"), preset),
+            labeled_with(localization::text("tester-instructions"), edit, player)
         ]
         .spacing(10)
         .into()
@@ -119,6 +119,7 @@ where
             align_y: core::alignment::Vertical::Top,
             shaping: core::text::Shaping::Advanced,
             wrapping: core::text::Wrapping::None,
+            truncation: core::text::Truncation::None,
             hint_factor: renderer.scale_factor(),
         });
 
@@ -53,11 +53,12 @@ mod program;
 pub use program::Program;
 
 pub use crate::Action;
+pub use crate::core::Camera;
 pub use crate::core::event::Event;
 pub use crate::graphics::cache::Group;
 pub use crate::graphics::geometry::{
     Fill, Gradient, Image, LineCap, LineDash, LineJoin, Path, Stroke, Style, Text, fill, gradient,
-    path, stroke,
+    path, scene, stroke, svg,
 };
 
 use crate::core::event;
@@ -77,6 +78,10 @@ use std::marker::PhantomData;
 /// change or it is explicitly cleared.
 pub type Cache<Renderer = crate::Renderer> = geometry::Cache<Renderer>;
 
+/// A retained scene graph that only redraws the [`Geometry`] of the nodes
+/// that changed.
+pub type Scene<Renderer = crate::Renderer> = geometry::Scene<Renderer>;
+
 /// The geometry supported by a renderer.
 pub type Geometry<Renderer = crate::Renderer> = <Renderer as geometry::Renderer>::Geometry;
 
@@ -357,6 +357,7 @@ where
                             align_y: alignment::Vertical::Top,
                             shaping: self.text_shaping,
                             wrapping: self.text_wrapping,
+                            truncation: text::Truncation::None,
                         },
                     )
                 } else {
@@ -556,6 +557,7 @@ where
                         align_y: alignment::Vertical::Center,
                         shaping: *shaping,
                         wrapping: text::Wrapping::default(),
+                        truncation: text::Truncation::None,
                         hint_factor: None,
                     },
                     bounds.center(),
@@ -704,13 +704,37 @@ where
 
     fn operate(
         &mut self,
-        state: &mut Tree,
-        _layout: Layout<'_>,
-        _renderer: &crate::Renderer,
-        _operation: &mut dyn Operation,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &crate::Renderer,
+        operation: &mut dyn Operation,
     ) {
-        // Forward operations to children when needed.
-        let _ = state;
+        #[cfg(feature = "accessibility")]
+        if let Some(info) = self.accessibility(tree, layout) {
+            operation.accessibility(None, layout.bounds(), info);
+        }
+
+        if let Some(input_layout) = layout.children().next() {
+            Widget::<HexInputEvent, Theme, crate::Renderer>::operate(
+                &mut self.text_input,
+                &mut tree.children[0],
+                input_layout,
+                renderer,
+                operation,
+            );
+        }
+    }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+    ) -> Option<core::accessibility::WidgetInfo> {
+        Some(
+            core::accessibility::WidgetInfo::color_well(color_to_hex_string(self.hsv.to_color()))
+                .with_bounds(layout.bounds()),
+        )
     }
 }
 
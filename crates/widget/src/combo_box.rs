@@ -755,6 +755,28 @@ where
         }
     }
 
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        #[cfg(feature = "accessibility")]
+        if let Some(info) =
+            <Self as Widget<Message, Theme, Renderer>>::accessibility(self, tree, layout)
+        {
+            operation.accessibility(None, layout.bounds(), info);
+        }
+
+        let state = tree.children[0]
+            .state
+            .downcast_mut::<text_input::State<Renderer::Paragraph>>();
+
+        operation.text_input(None, layout.bounds(), state);
+        operation.focusable(None, layout.bounds(), state);
+    }
+
     fn mouse_interaction(
         &self,
         tree: &widget::Tree,
@@ -876,6 +898,39 @@ where
             None
         }
     }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+    ) -> Option<crate::core::accessibility::WidgetInfo> {
+        use crate::core::accessibility::Role;
+
+        // The combo box is exposed as its text input, with the role and
+        // expanded state of a combo box.
+        let mut info = Widget::<TextInputEvent, Theme, Renderer>::accessibility(
+            &self.text_input,
+            &tree.children[0],
+            layout,
+        )?;
+
+        let is_focused = tree.children[0]
+            .state
+            .downcast_ref::<text_input::State<Renderer::Paragraph>>()
+            .is_focused();
+
+        let menu = tree.state.downcast_ref::<Menu<T>>();
+
+        info.role = Role::ComboBox;
+        info.expanded = Some(is_focused && !menu.filtered_options.options.is_empty());
+
+        if !is_focused && !self.selection.is_empty() {
+            info.value = Some(self.selection.to_string());
+        }
+
+        Some(info)
+    }
 }
 
 impl<'a, T, Message, Theme, Renderer> From<ComboBox<'a, T, Message, Theme, Renderer>>
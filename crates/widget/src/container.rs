@@ -29,8 +29,8 @@ use crate::core::renderer;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::widget::{self, Operation};
 use crate::core::{
-    self, Background, Clipboard, Color, Element, Event, Layout, LayoutDirection, Length, Padding,
-    Pixels, Rectangle, Shadow, Shell, Size, Theme, Vector, Widget, color,
+    self, Backdrop, Background, Clipboard, Color, Element, Event, Layout, LayoutDirection, Length,
+    Padding, Pixels, Rectangle, Shadow, Shell, Size, Theme, Vector, Widget, color,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -363,23 +363,23 @@ where
         let style = theme.style(&self.class);
 
         if let Some(clipped_viewport) = bounds.intersection(viewport) {
-            draw_background(renderer, &style, bounds);
-
-            self.content.as_widget().draw(
-                tree,
-                renderer,
-                theme,
-                &renderer::Style {
-                    text_color: style.text_color.unwrap_or(renderer_style.text_color),
-                },
-                layout.children().next().unwrap(),
-                cursor,
-                if self.clip {
-                    &clipped_viewport
-                } else {
-                    viewport
-                },
-            );
+            draw_with_backdrop(renderer, &style, bounds, viewport, |renderer| {
+                self.content.as_widget().draw(
+                    tree,
+                    renderer,
+                    theme,
+                    &renderer::Style {
+                        text_color: style.text_color.unwrap_or(renderer_style.text_color),
+                    },
+                    layout.children().next().unwrap(),
+                    cursor,
+                    if self.clip {
+                        &clipped_viewport
+                    } else {
+                        viewport
+                    },
+                );
+            });
         }
     }
 
@@ -399,6 +399,11 @@ where
             translation,
         )
     }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_label(&self) -> Option<std::borrow::Cow<'_, str>> {
+        self.content.as_widget().accessibility_label()
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<Container<'a, Message, Theme, Renderer>>
@@ -475,10 +480,37 @@ pub fn layout(
 }
 
 /// Draws the background of a [`Container`] given its [`Style`] and its `bounds`.
+///
+/// Any extra shadows of the [`Style`] are stacked around the main quad:
+/// outer shadows are drawn below it and inset shadows above it.
 pub fn draw_background<Renderer>(renderer: &mut Renderer, style: &Style, bounds: Rectangle)
 where
     Renderer: core::Renderer,
 {
+    let extra_shadows = || {
+        style
+            .extra_shadows
+            .iter()
+            .rev()
+            .flatten()
+            .filter(|shadow| shadow.color.a > 0.0)
+    };
+
+    for shadow in extra_shadows().filter(|shadow| !shadow.inset) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    width: 0.0,
+                    ..style.border
+                },
+                shadow: *shadow,
+                snap: style.snap,
+            },
+            Color::TRANSPARENT,
+        );
+    }
+
     if style.background.is_some() || style.border.width > 0.0 || style.shadow.color.a > 0.0 {
         renderer.fill_quad(
             renderer::Quad {
@@ -492,6 +524,84 @@ where
                 .unwrap_or(Background::Color(Color::TRANSPARENT)),
         );
     }
+
+    for shadow in extra_shadows().filter(|shadow| shadow.inset) {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    color: Color::TRANSPARENT,
+                    ..style.border
+                },
+                shadow: *shadow,
+                snap: style.snap,
+            },
+            Color::TRANSPARENT,
+        );
+    }
+}
+
+/// Draws the background of a [`Container`] given its [`Style`] and its `bounds`,
+/// followed by the contents drawn in the given closure.
+///
+/// If the [`Style`] has a [`Backdrop`], the background and the contents are drawn
+/// in a new layer that blurs whatever was drawn behind the `bounds`. This layer
+/// clips the contents to the `bounds`.
+pub fn draw_with_backdrop<Renderer>(
+    renderer: &mut Renderer,
+    style: &Style,
+    bounds: Rectangle,
+    viewport: &Rectangle,
+    draw_content: impl FnOnce(&mut Renderer),
+) where
+    Renderer: core::Renderer,
+{
+    let Some(backdrop) = style.backdrop else {
+        draw_background(renderer, style, bounds);
+        draw_content(renderer);
+        return;
+    };
+
+    let Some(clip_bounds) = bounds.intersection(viewport) else {
+        return;
+    };
+
+    // The backdrop layer clips its contents, so outer shadows are drawn below it
+    let is_outer = |shadow: &Shadow| !shadow.inset;
+
+    let outer = Style {
+        background: None,
+        border: Border {
+            width: 0.0,
+            ..style.border
+        },
+        shadow: Some(style.shadow).filter(is_outer).unwrap_or_default(),
+        extra_shadows: style.extra_shadows.map(|shadow| shadow.filter(is_outer)),
+        ..*style
+    };
+
+    let inner = Style {
+        shadow: Some(style.shadow)
+            .filter(|shadow| shadow.inset)
+            .unwrap_or_default(),
+        extra_shadows: style
+            .extra_shadows
+            .map(|shadow| shadow.filter(|shadow| shadow.inset)),
+        ..*style
+    };
+
+    draw_background(renderer, &outer, bounds);
+
+    renderer.with_backdrop_layer(
+        clip_bounds,
+        bounds,
+        style.border.radius,
+        backdrop,
+        |renderer| {
+            draw_background(renderer, &inner, bounds);
+            draw_content(renderer);
+        },
+    );
 }
 
 /// The appearance of a container.
@@ -505,6 +615,14 @@ pub struct Style {
     pub border: Border,
     /// The [`Shadow`] of the container.
     pub shadow: Shadow,
+    /// Additional shadows of the container, stacked after [`Style::shadow`].
+    ///
+    /// Use [`Style::shadows`] to set them all at once.
+    pub extra_shadows: [Option<Shadow>; Style::MAX_EXTRA_SHADOWS],
+    /// The [`Backdrop`] of the container, blurring whatever is behind it.
+    ///
+    /// A container with a backdrop clips its contents.
+    pub backdrop: Option<Backdrop>,
     /// Whether the container should be snapped to the pixel grid.
     pub snap: bool,
 }
@@ -516,12 +634,17 @@ impl Default for Style {
             background: None,
             border: Border::default(),
             shadow: Shadow::default(),
+            extra_shadows: [None; Self::MAX_EXTRA_SHADOWS],
+            backdrop: None,
             snap: renderer::CRISP,
         }
     }
 }
 
 impl Style {
+    /// The maximum amount of [`Style::extra_shadows`].
+    pub const MAX_EXTRA_SHADOWS: usize = 4;
+
     /// Updates the text color of the [`Style`].
     pub fn color(self, color: impl Into<Color>) -> Self {
         Self {
@@ -553,6 +676,35 @@ impl Style {
             ..self
         }
     }
+
+    /// Updates the backdrop of the [`Style`].
+    pub fn backdrop(self, backdrop: Backdrop) -> Self {
+        Self {
+            backdrop: Some(backdrop),
+            ..self
+        }
+    }
+
+    /// Sets all the shadows of the [`Style`], from top to bottom.
+    ///
+    /// The first shadow becomes [`Style::shadow`] and the rest fill
+    /// [`Style::extra_shadows`]. Shadows beyond [`Style::MAX_EXTRA_SHADOWS`]
+    /// are ignored.
+    pub fn shadows(self, shadows: impl IntoIterator<Item = Shadow>) -> Self {
+        let mut shadows = shadows.into_iter();
+        let shadow = shadows.next().unwrap_or_default();
+        let mut extra_shadows = [None; Self::MAX_EXTRA_SHADOWS];
+
+        for (slot, shadow) in extra_shadows.iter_mut().zip(shadows) {
+            *slot = Some(shadow);
+        }
+
+        Self {
+            shadow,
+            extra_shadows,
+            ..self
+        }
+    }
 }
 
 impl From<Color> for Style {
@@ -640,6 +792,23 @@ pub fn bordered_box(theme: &Theme) -> Style {
     }
 }
 
+/// A translucent [`Container`] that blurs whatever is behind it.
+pub fn frosted(theme: &Theme) -> Style {
+    Style {
+        text_color: Some(theme.background.on),
+        border: Border {
+            width: 1.0,
+            radius: 5.0.into(),
+            color: theme.background.divider,
+        },
+        backdrop: Some(Backdrop {
+            blur_radius: 12.0,
+            tint: theme.background.base.scale_alpha(0.6),
+        }),
+        ..Style::default()
+    }
+}
+
 /// A [`Container`] with a dark background and white text.
 pub fn dark(_theme: &Theme) -> Style {
     Style {
@@ -699,3 +868,130 @@ pub fn danger(theme: &Theme) -> Style {
         ..Style::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A renderer recording the shadows of its quads, marking layers with
+    /// `None`.
+    #[derive(Default)]
+    struct Recorder(Vec<Option<Shadow>>);
+
+    impl core::Renderer for Recorder {
+        fn start_layer(&mut self, _bounds: Rectangle) {
+            self.0.push(None);
+        }
+
+        fn end_layer(&mut self) {
+            self.0.push(None);
+        }
+
+        fn start_transformation(&mut self, _transformation: core::Transformation) {}
+
+        fn end_transformation(&mut self) {}
+
+        fn fill_quad(&mut self, quad: renderer::Quad, _background: impl Into<Background>) {
+            self.0.push(Some(quad.shadow));
+        }
+
+        fn allocate_image(
+            &mut self,
+            _handle: &core::image::Handle,
+            _callback: impl FnOnce(Result<core::image::Allocation, core::image::Error>) + Send + 'static,
+        ) {
+        }
+
+        fn hint(&mut self, _scale_factor: f32) {}
+
+        fn scale_factor(&self) -> Option<f32> {
+            None
+        }
+
+        fn reset(&mut self, _new_bounds: Rectangle) {}
+    }
+
+    fn shadow(blur_radius: f32, inset: bool) -> Shadow {
+        Shadow {
+            color: Color::BLACK,
+            blur_radius,
+            inset,
+            ..Shadow::default()
+        }
+    }
+
+    #[test]
+    fn shadows_fill_the_main_shadow_first() {
+        let shadows = (1..=6).map(|blur_radius| shadow(blur_radius as f32, false));
+        let style = Style::default().shadows(shadows);
+
+        assert_eq!(style.shadow, shadow(1.0, false));
+        assert_eq!(
+            style.extra_shadows,
+            [2.0, 3.0, 4.0, 5.0].map(|blur_radius| Some(shadow(blur_radius, false)))
+        );
+
+        let style = style.shadows([]);
+
+        assert_eq!(style.shadow, Shadow::default());
+        assert_eq!(style.extra_shadows, [None; Style::MAX_EXTRA_SHADOWS]);
+    }
+
+    #[test]
+    fn shadows_are_drawn_from_bottom_to_top() {
+        let style = Style::default().background(Color::WHITE).shadows([
+            shadow(1.0, false),
+            shadow(2.0, true),
+            shadow(3.0, false),
+            shadow(4.0, true),
+            shadow(5.0, false),
+        ]);
+
+        let mut renderer = Recorder::default();
+        draw_background(
+            &mut renderer,
+            &style,
+            Rectangle::with_size(Size::new(10.0, 10.0)),
+        );
+
+        // Outer shadows go below the main quad and inset ones above it, with
+        // the first shadows on top
+        assert_eq!(
+            renderer.0,
+            [
+                shadow(5.0, false),
+                shadow(3.0, false),
+                shadow(1.0, false),
+                shadow(4.0, true),
+                shadow(2.0, true),
+            ]
+            .map(Some)
+        );
+    }
+
+    #[test]
+    fn backdrop_layer_holds_the_inset_shadows() {
+        let style = Style::default()
+            .backdrop(Backdrop {
+                blur_radius: 4.0,
+                tint: Color::TRANSPARENT,
+            })
+            .shadows([shadow(1.0, true), shadow(2.0, false), shadow(3.0, true)]);
+
+        let bounds = Rectangle::with_size(Size::new(10.0, 10.0));
+
+        let mut renderer = Recorder::default();
+        draw_with_backdrop(&mut renderer, &style, bounds, &bounds, |_| {});
+
+        assert_eq!(
+            renderer.0,
+            [
+                Some(shadow(2.0, false)),
+                None,
+                Some(shadow(1.0, true)),
+                Some(shadow(3.0, true)),
+                None,
+            ]
+        );
+    }
+}
@@ -32,9 +32,16 @@
 use crate::button;
 use crate::container;
 use crate::core::alignment::Horizontal;
+use crate::core::localization;
 use crate::core::{Alignment, Element, Length, Padding};
 use crate::{Column, Row, text};
 
+#[cfg(feature = "accessibility")]
+use crate::core::{
+    self, Clipboard, Event, Layout, Rectangle, Shell, Size, Vector, layout, mouse, overlay,
+    renderer, widget,
+};
+
 /// A simple date representation (year, month, day).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Date {
@@ -148,23 +155,37 @@ impl Date {
         }
     }
 
-    /// Get the month name.
-    pub fn month_name(&self) -> &'static str {
-        match self.month {
-            1 => "January",
-            2 => "February",
-            3 => "March",
-            4 => "April",
-            5 => "May",
-            6 => "June",
-            7 => "July",
-            8 => "August",
-            9 => "September",
-            10 => "October",
-            11 => "November",
-            12 => "December",
-            _ => "Unknown",
-        }
+    /// Get the month name, translated into the current [`Locale`].
+    ///
+    /// [`Locale`]: crate::core::Locale
+    pub fn month_name(&self) -> String {
+        let key = match self.month {
+            1 => "date-picker-january",
+            2 => "date-picker-february",
+            3 => "date-picker-march",
+            4 => "date-picker-april",
+            5 => "date-picker-may",
+            6 => "date-picker-june",
+            7 => "date-picker-july",
+            8 => "date-picker-august",
+            9 => "date-picker-september",
+            10 => "date-picker-october",
+            11 => "date-picker-november",
+            12 => "date-picker-december",
+            _ => return String::from("Unknown"),
+        };
+
+        localization::text(key)
+    }
+
+    /// Get the month name and the year, translated into the current [`Locale`].
+    ///
+    /// [`Locale`]: crate::core::Locale
+    pub fn month_and_year(&self) -> String {
+        localization::format(
+            "date-picker-month-year",
+            &[("month", &self.month_name()), ("year", &self.year)],
+        )
     }
 }
 
@@ -240,23 +261,30 @@ impl<'a, Message: Clone + 'static> From<DatePicker<'a, Message>>
     for Element<'a, Message, crate::Theme, crate::Renderer>
 {
     fn from(picker: DatePicker<'a, Message>) -> Self {
+        let weekdays = [
+            "date-picker-monday",
+            "date-picker-tuesday",
+            "date-picker-wednesday",
+            "date-picker-thursday",
+            "date-picker-friday",
+            "date-picker-saturday",
+            "date-picker-sunday",
+        ];
+
         let weekday_names = match picker.first_day_of_week {
-            FirstDayOfWeek::Monday => ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"],
-            FirstDayOfWeek::Sunday => ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+            FirstDayOfWeek::Monday => weekdays,
+            FirstDayOfWeek::Sunday => {
+                let mut weekdays = weekdays;
+                weekdays.rotate_right(1);
+                weekdays
+            }
         };
 
         // Header with month/year and navigation
         let header = Row::new()
             .spacing(8)
             .align_y(Alignment::Center)
-            .push(
-                text(format!(
-                    "{} {}",
-                    picker.visible.month_name(),
-                    picker.visible.year
-                ))
-                .size(16),
-            )
+            .push(text(picker.visible.month_and_year()).size(16))
             .push(crate::Space::new().width(Length::Fill))
             .push(
                 button::Button::new(text("◀"))
@@ -274,7 +302,7 @@ impl<'a, Message: Clone + 'static> From<DatePicker<'a, Message>>
             weekday_names
                 .iter()
                 .map(|name| {
-                    text(*name)
+                    text(localization::text(name))
                         .size(12)
                         .width(Length::Fixed(36.0))
                         .align_x(Horizontal::Center)
@@ -384,8 +412,154 @@ impl<'a, Message: Clone + 'static> From<DatePicker<'a, Message>>
             .push(weekday_row)
             .push(calendar_grid);
 
-        container::Container::new(content)
-            .style(container::rounded_box)
-            .into()
+        let calendar = container::Container::new(content).style(container::rounded_box);
+
+        #[cfg(feature = "accessibility")]
+        let calendar = Calendar {
+            content: calendar.into(),
+            selected: picker.selected,
+            visible: picker.visible,
+        };
+
+        calendar.into()
+    }
+}
+
+/// The calendar of a [`DatePicker`], exposed to assistive technologies as a
+/// date input.
+#[cfg(feature = "accessibility")]
+struct Calendar<'a, Message> {
+    content: Element<'a, Message, crate::Theme, crate::Renderer>,
+    selected: Date,
+    visible: Date,
+}
+
+#[cfg(feature = "accessibility")]
+impl<Message> core::Widget<Message, crate::Theme, crate::Renderer> for Calendar<'_, Message> {
+    fn tag(&self) -> widget::tree::Tag {
+        self.content.as_widget().tag()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        self.content.as_widget().state()
+    }
+
+    fn children(&self) -> Vec<widget::Tree> {
+        self.content.as_widget().children()
+    }
+
+    fn diff(&self, tree: &mut widget::Tree) {
+        self.content.as_widget().diff(tree);
+    }
+
+    fn size(&self) -> Size<Length> {
+        self.content.as_widget().size()
+    }
+
+    fn size_hint(&self) -> Size<Length> {
+        self.content.as_widget().size_hint()
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut widget::Tree,
+        renderer: &crate::Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.as_widget_mut().layout(tree, renderer, limits)
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        renderer: &crate::Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        if let Some(info) = self.accessibility(tree, layout) {
+            operation.accessibility(None, layout.bounds(), info);
+        }
+
+        self.content
+            .as_widget_mut()
+            .operate(tree, layout, renderer, operation);
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &crate::Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        self.content.as_widget_mut().update(
+            tree, event, layout, cursor, renderer, clipboard, shell, viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &crate::Renderer,
+    ) -> mouse::Interaction {
+        self.content
+            .as_widget()
+            .mouse_interaction(tree, layout, cursor, viewport, renderer)
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut crate::Renderer,
+        theme: &crate::Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .as_widget()
+            .draw(tree, renderer, theme, style, layout, cursor, viewport);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut widget::Tree,
+        layout: Layout<'b>,
+        renderer: &crate::Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, crate::Theme, crate::Renderer>> {
+        self.content
+            .as_widget_mut()
+            .overlay(tree, layout, renderer, viewport, translation)
+    }
+
+    fn accessibility(
+        &self,
+        _tree: &widget::Tree,
+        layout: Layout<'_>,
+    ) -> Option<core::accessibility::WidgetInfo> {
+        Some(
+            core::accessibility::WidgetInfo::date_input(self.selected.to_string())
+                .with_label(self.visible.month_and_year())
+                .with_bounds(layout.bounds()),
+        )
+    }
+}
+
+#[cfg(feature = "accessibility")]
+impl<'a, Message: 'a> From<Calendar<'a, Message>>
+    for Element<'a, Message, crate::Theme, crate::Renderer>
+{
+    fn from(calendar: Calendar<'a, Message>) -> Self {
+        Element::new(calendar)
     }
 }
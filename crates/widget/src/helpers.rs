@@ -14,6 +14,7 @@ use crate::pane_grid::{self, PaneGrid};
 use crate::pick_list::{self, PickList};
 use crate::progress_bar::{self, ProgressBar};
 use crate::radio::{self, Radio};
+use crate::rich_editor::{self, RichEditor};
 use crate::scrolling::scroll_area::ScrollArea;
 use crate::scrolling::scrollable::{self, Scrollable};
 use crate::slider::{self, Slider};
@@ -24,7 +25,8 @@ use crate::toggler::{self, Toggler};
 use crate::tooltip::{self, Tooltip};
 use crate::vertical_slider::{self, VerticalSlider};
 use crate::{
-    Column, Draggable, DropTarget, Grid, MouseArea, Pin, Responsive, Row, Sensor, Space, Stack,
+    Column, Draggable, DropTarget, Grid, MouseArea, Opacity, Pin, Responsive, Row, Sensor, Space,
+    Stack, Sticky,
 };
 
 use std::borrow::Borrow;
@@ -458,6 +460,36 @@ where
     Pin::new(content)
 }
 
+/// Creates a new [`Sticky`] section with the given header and content.
+///
+/// While the section is visible inside a [`Scrollable`], its header stays pinned
+/// to the top of the viewport until the end of the section pushes it out.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use icy_ui_widget::*; } }
+/// # pub type Element<'a, Message> = icy_ui_widget::core::Element<'a, Message, icy_ui_widget::Theme, icy_ui_widget::Renderer>;
+/// use icy_ui::widget::{column, scrollable, sticky, text};
+///
+/// enum Message {}
+///
+/// fn view<'a>(groups: &'a [(String, Vec<String>)]) -> Element<'a, Message> {
+///     scrollable(column(groups.iter().map(|(title, items)| {
+///         sticky(text(title), column(items.iter().map(|item| text(item).into()))).into()
+///     })))
+///     .into()
+/// }
+/// ```
+pub fn sticky<'a, Message, Theme, Renderer>(
+    header: impl Into<Element<'a, Message, Theme, Renderer>>,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Sticky<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Sticky::new(header, content)
+}
+
 /// Creates a new [`Column`] with the given children.
 ///
 /// Columns distribute their children vertically.
@@ -1476,6 +1508,45 @@ where
     crate::RadioGroup::new(options, selected, on_select)
 }
 
+/// Creates a new [`Tabs`] bar.
+///
+/// Tabs let users switch between documents or views sharing the same space.
+///
+/// [`Tabs`]: crate::Tabs
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use icy_ui_widget::*; } pub use icy_ui_widget::Renderer; pub use icy_ui_widget::core::*; }
+/// # pub type Element<'a, Message> = icy_ui_widget::core::Element<'a, Message, icy_ui_widget::Theme, icy_ui_widget::Renderer>;
+/// #
+/// use icy_ui::widget::tabs;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Selected(String),
+///     Closed(String),
+/// }
+///
+/// fn view<'a>(documents: &'a [String], active: Option<String>) -> Element<'a, Message> {
+///     tabs(documents.iter().cloned(), active, Message::Selected)
+///         .on_close(Message::Closed)
+///         .into()
+/// }
+/// ```
+pub fn tabs<'a, T, Message, Theme, Renderer, F>(
+    tabs: impl IntoIterator<Item = T>,
+    selected: Option<T>,
+    on_select: F,
+) -> crate::Tabs<'a, T, Message, Theme, Renderer>
+where
+    T: Clone + PartialEq + ToString,
+    Theme: crate::tabs::Catalog + 'a,
+    Renderer: core::text::Renderer,
+    F: Fn(T) -> Message + 'a,
+{
+    crate::Tabs::new(tabs, selected, on_select)
+}
+
 /// Creates a new [`Toggler`].
 ///
 /// Togglers let users make binary choices by toggling a switch.
@@ -1612,6 +1683,52 @@ where
     TextEditor::new(content)
 }
 
+/// Creates a new [`RichEditor`].
+///
+/// Rich text editors display a multi-line input for formatted text, with
+/// headings, lists, links and inline images.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use icy_ui_widget::*; } pub use icy_ui_widget::Renderer; pub use icy_ui_widget::core::*; }
+/// # pub type Element<'a, Message> = icy_ui_widget::core::Element<'a, Message, icy_ui_widget::Theme, icy_ui_widget::Renderer>;
+/// #
+/// use icy_ui::widget::rich_editor;
+///
+/// struct State {
+///    content: rich_editor::Content,
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Edit(rich_editor::Action)
+/// }
+///
+/// fn view(state: &State) -> Element<'_, Message> {
+///     rich_editor(&state.content)
+///         .placeholder("Type something here...")
+///         .on_action(Message::Edit)
+///         .into()
+/// }
+///
+/// fn update(state: &mut State, message: Message) {
+///     match message {
+///         Message::Edit(action) => {
+///             state.content.perform(action);
+///         }
+///     }
+/// }
+/// ```
+pub fn rich_editor<'a, Message, Theme, Renderer>(
+    content: &'a rich_editor::Content<Renderer>,
+) -> RichEditor<'a, Message, Theme, Renderer>
+where
+    Theme: rich_editor::Catalog + 'a,
+    Renderer: rich_editor::Renderer,
+{
+    RichEditor::new(content)
+}
+
 /// Creates a new [`Slider`].
 ///
 /// Sliders let users set a value by moving an indicator.
@@ -1963,6 +2080,17 @@ pub fn image<Handle>(handle: impl Into<Handle>) -> crate::Image<Handle> {
     crate::Image::new(handle.into())
 }
 
+/// Creates a new [`Animated`] image widget that plays the given [`Animation`].
+///
+/// [`Animated`]: crate::image::Animated
+/// [`Animation`]: crate::image::Animation
+#[cfg(feature = "animated-image")]
+pub fn animated_image<Message>(
+    animation: &crate::image::Animation,
+) -> crate::image::Animated<Message> {
+    crate::image::Animated::new(animation)
+}
+
 /// Creates a new [`Svg`] widget from the given [`Handle`].
 ///
 /// Svg widgets display vector graphics in your application.
@@ -2262,6 +2390,20 @@ where
     Float::new(content)
 }
 
+/// Creates a new [`Opacity`] widget with the given opacity and content.
+///
+/// The contents are composited as a whole, so fading a subtree does not
+/// require changing the style of every widget in it.
+pub fn opacity<'a, Message, Theme, Renderer>(
+    opacity: f32,
+    content: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> Opacity<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    Opacity::new(opacity, content)
+}
+
 /// Creates a new [`Responsive`] widget with a closure that produces its
 /// contents.
 ///
@@ -19,6 +19,11 @@
 pub mod viewer;
 pub use viewer::Viewer;
 
+#[cfg(feature = "animated-image")]
+pub mod animated;
+#[cfg(feature = "animated-image")]
+pub use animated::{Animated, Animation};
+
 use crate::core::border;
 use crate::core::image;
 use crate::core::layout;
@@ -0,0 +1,640 @@
+//! Play animated images (GIF, APNG, WebP).
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use icy_ui_widget::*; } }
+//! # pub type Element<'a, Message> = icy_ui_widget::core::Element<'a, Message, icy_ui_widget::Theme, icy_ui_widget::Renderer>;
+//! use icy_ui::widget::animated_image;
+//! use icy_ui::widget::image::Animation;
+//!
+//! struct State {
+//!     animation: Animation,
+//!     is_playing: bool,
+//! }
+//!
+//! enum Message {
+//!     // ...
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     animated_image(&state.animation)
+//!         .playing(state.is_playing)
+//!         .into()
+//! }
+//! ```
+use crate::core::border;
+use crate::core::image;
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::window;
+use crate::core::{
+    Clipboard, ContentFit, Element, Event, Layout, Length, Rectangle, Rotation, Shell, Size, Widget,
+};
+use crate::graphics;
+
+pub use graphics::image::Frame;
+
+use std::sync::Arc;
+use std::sync::atomic::{self, AtomicU64};
+
+/// The shortest delay honored between frames.
+///
+/// Like web browsers, shorter delays are treated as [`DEFAULT_DELAY`],
+/// since many images rely on this behavior.
+const MIN_DELAY: Duration = Duration::from_millis(10);
+
+/// The delay used for frames with a delay shorter than [`MIN_DELAY`].
+const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+
+/// The most decoded bytes of an [`Animation`] kept uploaded at once.
+///
+/// Smaller animations keep all of their frames uploaded, so each frame is
+/// only uploaded once. Larger ones only keep their current and next frames,
+/// letting the renderer evict the rest and upload them again when needed.
+const MAX_UPLOADED_BYTES: usize = 32 * 1024 * 1024;
+
+/// The decoded frames of an animated image.
+///
+/// Decoding happens once, when the [`Animation`] is loaded. Cloning an
+/// [`Animation`] is cheap.
+#[derive(Debug, Clone)]
+pub struct Animation {
+    id: u64,
+    frames: Arc<[Frame]>,
+    size: Size<u32>,
+    duration: Duration,
+    bytes: usize,
+}
+
+impl Animation {
+    /// Loads and decodes all the frames of the animated image with the
+    /// given [`image::Handle`].
+    ///
+    /// GIF, APNG, and animated WebP images are supported. Any other image
+    /// produces an [`Animation`] with a single frame.
+    ///
+    /// This method blocks while decoding. Consider calling it in a
+    /// background task.
+    pub fn load(handle: impl Into<image::Handle>) -> Result<Self, image::Error> {
+        graphics::image::load_frames(&handle.into()).map(Self::from_frames)
+    }
+
+    /// Creates an [`Animation`] from a list of already decoded frames.
+    pub fn from_frames(frames: impl Into<Arc<[Frame]>>) -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        let frames = frames.into();
+
+        let size = frames
+            .first()
+            .and_then(|frame| match &frame.handle {
+                image::Handle::Rgba { width, height, .. } => Some(Size::new(*width, *height)),
+                _ => None,
+            })
+            .unwrap_or(Size::new(0, 0));
+
+        let duration = frames.iter().map(delay).sum();
+
+        let bytes = frames
+            .iter()
+            .map(|frame| match &frame.handle {
+                image::Handle::Rgba { pixels, .. } => pixels.len(),
+                _ => 0,
+            })
+            .sum();
+
+        Self {
+            id: NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed),
+            frames,
+            size,
+            duration,
+            bytes,
+        }
+    }
+
+    /// Returns the frames of the [`Animation`].
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// Returns the size of the [`Animation`], if its frames are decoded.
+    pub fn size(&self) -> Size<u32> {
+        self.size
+    }
+
+    /// Returns the total duration of a single loop of the [`Animation`].
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+/// A widget that plays an [`Animation`].
+///
+/// Playback is driven by redraw requests, so it only consumes resources
+/// while the animation is visible and playing.
+pub struct Animated<Message> {
+    animation: Animation,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    filter_method: image::FilterMethod,
+    border_radius: border::Radius,
+    opacity: f32,
+    is_playing: bool,
+    is_looping: bool,
+    on_finish: Option<Message>,
+}
+
+impl<Message> Animated<Message> {
+    /// Creates a new [`Animated`] widget that plays the given [`Animation`].
+    pub fn new(animation: &Animation) -> Self {
+        Self {
+            animation: animation.clone(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::default(),
+            filter_method: image::FilterMethod::default(),
+            border_radius: border::Radius::default(),
+            opacity: 1.0,
+            is_playing: true,
+            is_looping: true,
+            on_finish: None,
+        }
+    }
+
+    /// Sets the width of the [`Animated`] boundaries.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`Animated`] boundaries.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`Animated`].
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`](image::FilterMethod) of the [`Animated`].
+    pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets the [`border::Radius`] of the [`Animated`].
+    pub fn border_radius(mut self, border_radius: impl Into<border::Radius>) -> Self {
+        self.border_radius = border_radius.into();
+        self
+    }
+
+    /// Sets the opacity of the [`Animated`].
+    ///
+    /// It should be in the [0.0, 1.0] range—`0.0` meaning completely transparent,
+    /// and `1.0` meaning completely opaque.
+    pub fn opacity(mut self, opacity: impl Into<f32>) -> Self {
+        self.opacity = opacity.into();
+        self
+    }
+
+    /// Sets whether the [`Animated`] is playing.
+    ///
+    /// A paused animation keeps displaying its current frame and resumes
+    /// from it once playing again, while a finished animation starts over.
+    /// Animations play by default.
+    pub fn playing(mut self, is_playing: bool) -> Self {
+        self.is_playing = is_playing;
+        self
+    }
+
+    /// Sets whether the [`Animated`] starts over after its last frame.
+    ///
+    /// Animations loop by default. Otherwise, the last frame is kept once
+    /// the animation finishes, until it loops or is played again.
+    pub fn looping(mut self, is_looping: bool) -> Self {
+        self.is_looping = is_looping;
+        self
+    }
+
+    /// Sets the message that will be produced when a non-looping
+    /// [`Animated`] finishes.
+    pub fn on_finish(mut self, message: Message) -> Self {
+        self.on_finish = Some(message);
+        self
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    animation: Option<u64>,
+    frame: usize,
+    next_frame_at: Option<Instant>,
+    is_playing: bool,
+    is_finished: bool,
+    allocations: Vec<Option<image::Allocation>>,
+}
+
+impl State {
+    fn new<Message>(animated: &Animated<Message>) -> Self {
+        Self {
+            animation: Some(animated.animation.id),
+            is_playing: animated.is_playing,
+            allocations: vec![None; animated.animation.frames.len()],
+            ..Self::default()
+        }
+    }
+
+    fn sync<Message>(&mut self, animated: &Animated<Message>) {
+        if self.animation != Some(animated.animation.id) {
+            *self = Self::new(animated);
+            return;
+        }
+
+        // A finished animation goes on once it loops, or starts over once it
+        // is played again
+        if self.is_finished && (animated.is_looping || (animated.is_playing && !self.is_playing)) {
+            if !animated.is_looping {
+                self.frame = 0;
+            }
+
+            self.is_finished = false;
+            self.next_frame_at = None;
+        }
+
+        self.is_playing = animated.is_playing;
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer> for Animated<Message>
+where
+    Message: Clone,
+    Renderer: image::Renderer<Handle = image::Handle>,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::new(self))
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.state.downcast_mut::<State>().sync(self);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let Some(frame) = self.animation.frames.first() else {
+            return layout::Node::new(limits.resolve(self.width, self.height, Size::ZERO));
+        };
+
+        super::layout(
+            renderer,
+            limits,
+            &frame.handle,
+            self.width,
+            self.height,
+            None,
+            self.content_fit,
+            Rotation::default(),
+            false,
+        )
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        _layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Event::Window(window::Event::RedrawRequested(now)) = event else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+        let frames = &self.animation.frames;
+
+        if frames.is_empty() {
+            return;
+        }
+
+        if !self.is_playing || state.is_finished || frames.len() == 1 {
+            state.next_frame_at = None;
+        } else {
+            let next_frame_at = state
+                .next_frame_at
+                .get_or_insert_with(|| *now + delay(&frames[state.frame]));
+
+            // Skip ahead instead of catching up after long stalls
+            // (e.g. while the window was hidden)
+            if *now > *next_frame_at + self.animation.duration() {
+                *next_frame_at = *now;
+            }
+
+            while *now >= *next_frame_at {
+                if state.frame + 1 == frames.len() && !self.is_looping {
+                    state.is_finished = true;
+
+                    if let Some(on_finish) = self.on_finish.clone() {
+                        shell.publish(on_finish);
+                    }
+
+                    break;
+                }
+
+                state.frame = (state.frame + 1) % frames.len();
+                *next_frame_at += delay(&frames[state.frame]);
+            }
+
+            if !state.is_finished {
+                shell.request_redraw_at(*next_frame_at);
+            }
+        }
+
+        // Frames stay uploaded for as long as we hold their allocations.
+        // The next frame is uploaded ahead of time to be ready when shown.
+        let next = (state.frame + 1) % frames.len();
+        let keeps_every_frame = self.animation.bytes <= MAX_UPLOADED_BYTES;
+
+        for (index, allocation) in state.allocations.iter_mut().enumerate() {
+            if index == state.frame || index == next {
+                if allocation.is_none() {
+                    *allocation = renderer.load_image(&frames[index].handle).ok();
+                }
+            } else if !keeps_every_frame {
+                *allocation = None;
+            }
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State>();
+
+        let Some(frame) = self.animation.frames.get(state.frame) else {
+            return;
+        };
+
+        super::draw(
+            renderer,
+            layout,
+            &frame.handle,
+            None,
+            self.border_radius,
+            self.content_fit,
+            self.filter_method,
+            Rotation::default(),
+            self.opacity,
+            1.0,
+        );
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Animated<Message>> for Element<'a, Message, Theme, Renderer>
+where
+    Message: Clone + 'a,
+    Renderer: image::Renderer<Handle = image::Handle>,
+{
+    fn from(animated: Animated<Message>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(animated)
+    }
+}
+
+fn delay(frame: &Frame) -> Duration {
+    if frame.delay < MIN_DELAY {
+        DEFAULT_DELAY
+    } else {
+        frame.delay
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clipboard;
+
+    fn animation() -> Animation {
+        let frame = |delay| Frame {
+            handle: image::Handle::from_rgba(1, 1, vec![0; 4]),
+            delay: Duration::from_millis(delay),
+        };
+
+        Animation::from_frames(vec![frame(20), frame(30), frame(40)])
+    }
+
+    /// Requests a redraw of the [`Animated`] at the given time, returning
+    /// its current frame and the produced messages.
+    fn redraw(
+        animated: &mut Animated<()>,
+        tree: &mut Tree,
+        now: Instant,
+    ) -> (usize, window::RedrawRequest, Vec<()>) {
+        let node = layout::Node::new(Size::ZERO);
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        Widget::<(), (), ()>::update(
+            animated,
+            tree,
+            &Event::Window(window::Event::RedrawRequested(now)),
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::default(),
+        );
+
+        let redraw_request = shell.redraw_request();
+
+        (
+            tree.state.downcast_ref::<State>().frame,
+            redraw_request,
+            messages,
+        )
+    }
+
+    #[test]
+    fn caches_duration() {
+        assert_eq!(animation().duration(), Duration::from_millis(90));
+    }
+
+    #[test]
+    fn advances_frames() {
+        let mut animated = Animated::new(&animation());
+        let mut tree = Tree::new(&animated as &dyn Widget<(), (), ()>);
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+
+        assert_eq!(
+            redraw(&mut animated, &mut tree, start),
+            (0, window::RedrawRequest::At(at(20)), vec![])
+        );
+        assert_eq!(redraw(&mut animated, &mut tree, at(19)).0, 0);
+        assert_eq!(
+            redraw(&mut animated, &mut tree, at(20)),
+            (1, window::RedrawRequest::At(at(50)), vec![])
+        );
+        assert_eq!(redraw(&mut animated, &mut tree, at(50)).0, 2);
+    }
+
+    #[test]
+    fn loops_back_to_the_first_frame() {
+        let mut animated = Animated::new(&animation());
+        let mut tree = Tree::new(&animated as &dyn Widget<(), (), ()>);
+        let start = Instant::now();
+
+        let _ = redraw(&mut animated, &mut tree, start);
+
+        assert_eq!(
+            redraw(&mut animated, &mut tree, start + Duration::from_millis(90)).0,
+            0
+        );
+        assert_eq!(
+            redraw(&mut animated, &mut tree, start + Duration::from_millis(110)).0,
+            1
+        );
+    }
+
+    #[test]
+    fn stops_at_the_last_frame() {
+        let mut animated = Animated::new(&animation()).looping(false).on_finish(());
+        let mut tree = Tree::new(&animated as &dyn Widget<(), (), ()>);
+        let start = Instant::now();
+
+        let _ = redraw(&mut animated, &mut tree, start);
+
+        assert_eq!(
+            redraw(&mut animated, &mut tree, start + Duration::from_millis(90)),
+            (2, window::RedrawRequest::Wait, vec![()])
+        );
+        assert_eq!(
+            redraw(&mut animated, &mut tree, start + Duration::from_millis(200)),
+            (2, window::RedrawRequest::Wait, vec![])
+        );
+    }
+
+    #[test]
+    fn starts_over_when_played_again_after_finishing() {
+        let mut animated = Animated::new(&animation()).looping(false);
+        let mut tree = Tree::new(&animated as &dyn Widget<(), (), ()>);
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+
+        let _ = redraw(&mut animated, &mut tree, start);
+        assert_eq!(redraw(&mut animated, &mut tree, at(90)).0, 2);
+
+        // Staying in play does not restart a finished animation
+        tree.diff(&animated as &dyn Widget<(), (), ()>);
+        assert_eq!(redraw(&mut animated, &mut tree, at(100)).0, 2);
+
+        animated = animated.playing(false);
+        tree.diff(&animated as &dyn Widget<(), (), ()>);
+        assert_eq!(redraw(&mut animated, &mut tree, at(110)).0, 2);
+
+        animated = animated.playing(true);
+        tree.diff(&animated as &dyn Widget<(), (), ()>);
+
+        assert_eq!(
+            redraw(&mut animated, &mut tree, at(120)),
+            (0, window::RedrawRequest::At(at(140)), vec![])
+        );
+        assert_eq!(redraw(&mut animated, &mut tree, at(140)).0, 1);
+    }
+
+    #[test]
+    fn goes_on_when_looping_again_after_finishing() {
+        let mut animated = Animated::new(&animation()).looping(false);
+        let mut tree = Tree::new(&animated as &dyn Widget<(), (), ()>);
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+
+        let _ = redraw(&mut animated, &mut tree, start);
+        assert_eq!(redraw(&mut animated, &mut tree, at(90)).0, 2);
+
+        animated = animated.looping(true);
+        tree.diff(&animated as &dyn Widget<(), (), ()>);
+
+        // The last frame is shown for its whole delay before looping
+        assert_eq!(
+            redraw(&mut animated, &mut tree, at(100)),
+            (2, window::RedrawRequest::At(at(140)), vec![])
+        );
+        assert_eq!(redraw(&mut animated, &mut tree, at(140)).0, 0);
+    }
+
+    fn uploaded_frames(tree: &Tree) -> Vec<bool> {
+        tree.state
+            .downcast_ref::<State>()
+            .allocations
+            .iter()
+            .map(Option::is_some)
+            .collect()
+    }
+
+    #[test]
+    fn keeps_every_frame_of_small_animations_uploaded() {
+        let mut animated = Animated::new(&animation());
+        let mut tree = Tree::new(&animated as &dyn Widget<(), (), ()>);
+        let start = Instant::now();
+
+        let _ = redraw(&mut animated, &mut tree, start);
+        assert_eq!(uploaded_frames(&tree), [true, true, false]);
+
+        let _ = redraw(&mut animated, &mut tree, start + Duration::from_millis(50));
+        assert_eq!(uploaded_frames(&tree), [true, true, true]);
+    }
+
+    #[test]
+    fn only_keeps_the_current_and_next_frames_of_large_animations() {
+        let mut large = animation();
+        large.bytes = MAX_UPLOADED_BYTES + 1;
+
+        let mut animated = Animated::new(&large);
+        let mut tree = Tree::new(&animated as &dyn Widget<(), (), ()>);
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+
+        let _ = redraw(&mut animated, &mut tree, start);
+        assert_eq!(uploaded_frames(&tree), [true, true, false]);
+
+        let _ = redraw(&mut animated, &mut tree, at(20));
+        assert_eq!(uploaded_frames(&tree), [false, true, true]);
+
+        let _ = redraw(&mut animated, &mut tree, at(50));
+        assert_eq!(uploaded_frames(&tree), [true, false, true]);
+    }
+}
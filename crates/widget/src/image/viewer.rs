@@ -6,8 +6,8 @@ use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
-    Clipboard, ContentFit, Element, Event, Image, Layout, Length, Pixels, Point, Radians,
-    Rectangle, Shell, Size, Vector, Widget,
+    Camera, Clipboard, ContentFit, Element, Event, Image, Layout, Length, Pixels, Radians,
+    Rectangle, Shell, Size, Widget,
 };
 
 /// A frame that displays an image with the ability to zoom in/out and pan.
@@ -105,7 +105,23 @@ where
     }
 
     fn state(&self) -> tree::State {
-        tree::State::new(State::new())
+        tree::State::new(State {
+            camera: Camera::new()
+                .pan_button(mouse::Button::Left)
+                .min_scale(self.min_scale)
+                .max_scale(self.max_scale)
+                .scale_step(self.scale_step),
+            size: Size::ZERO,
+        })
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        let camera = &mut tree.state.downcast_mut::<State>().camera;
+
+        *camera = std::mem::take(camera)
+            .min_scale(self.min_scale)
+            .max_scale(self.max_scale)
+            .scale_step(self.scale_step);
     }
 
     fn size(&self) -> Size<Length> {
@@ -159,120 +175,26 @@ where
         _viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
+        let state = tree.state.downcast_mut::<State>();
+
+        let is_handled = state.camera.update(event, bounds, cursor);
+
+        // Keep the image in view when the camera moves or the bounds change
+        if is_handled || state.size != bounds.size() {
+            let image_size =
+                fitted_image_size(renderer, &self.handle, bounds.size(), self.content_fit);
+
+            state.size = bounds.size();
+            state
+                .camera
+                .constrain(Rectangle::with_size(image_size), bounds.size());
+        }
 
-        match event {
-            Event::Mouse(mouse::Event::WheelScrolled { delta, .. }) => {
-                let Some(cursor_position) = cursor.position_over(bounds) else {
-                    return;
-                };
-
-                match *delta {
-                    mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
-                        let state = tree.state.downcast_mut::<State>();
-                        let previous_scale = state.scale;
-
-                        if y < 0.0 && previous_scale > self.min_scale
-                            || y > 0.0 && previous_scale < self.max_scale
-                        {
-                            state.scale = (if y > 0.0 {
-                                state.scale * (1.0 + self.scale_step)
-                            } else {
-                                state.scale / (1.0 + self.scale_step)
-                            })
-                            .clamp(self.min_scale, self.max_scale);
-
-                            let scaled_size = scaled_image_size(
-                                renderer,
-                                &self.handle,
-                                state,
-                                bounds.size(),
-                                self.content_fit,
-                            );
-
-                            let factor = state.scale / previous_scale - 1.0;
-
-                            let cursor_to_center = cursor_position - bounds.center();
-
-                            let adjustment =
-                                cursor_to_center * factor + state.current_offset * factor;
-
-                            state.current_offset = Vector::new(
-                                if scaled_size.width > bounds.width {
-                                    state.current_offset.x + adjustment.x
-                                } else {
-                                    0.0
-                                },
-                                if scaled_size.height > bounds.height {
-                                    state.current_offset.y + adjustment.y
-                                } else {
-                                    0.0
-                                },
-                            );
-                        }
-                    }
-                }
-
-                shell.request_redraw();
-                shell.capture_event();
-            }
-            Event::Mouse(mouse::Event::ButtonPressed {
-                button: mouse::Button::Left,
-                ..
-            }) => {
-                let Some(cursor_position) = cursor.position_over(bounds) else {
-                    return;
-                };
-
-                let state = tree.state.downcast_mut::<State>();
-
-                state.cursor_grabbed_at = Some(cursor_position);
-                state.starting_offset = state.current_offset;
-
-                shell.capture_event();
-            }
-            Event::Mouse(mouse::Event::ButtonReleased {
-                button: mouse::Button::Left,
-                ..
-            }) => {
-                let state = tree.state.downcast_mut::<State>();
-
-                state.cursor_grabbed_at = None;
-            }
-            Event::Mouse(mouse::Event::CursorMoved { position, .. }) => {
-                let state = tree.state.downcast_mut::<State>();
-
-                if let Some(origin) = state.cursor_grabbed_at {
-                    let scaled_size = scaled_image_size(
-                        renderer,
-                        &self.handle,
-                        state,
-                        bounds.size(),
-                        self.content_fit,
-                    );
-                    let hidden_width = (scaled_size.width - bounds.width / 2.0).max(0.0).round();
-
-                    let hidden_height = (scaled_size.height - bounds.height / 2.0).max(0.0).round();
-
-                    let delta = *position - origin;
-
-                    let x = if bounds.width < scaled_size.width {
-                        (state.starting_offset.x - delta.x).clamp(-hidden_width, hidden_width)
-                    } else {
-                        0.0
-                    };
-
-                    let y = if bounds.height < scaled_size.height {
-                        (state.starting_offset.y - delta.y).clamp(-hidden_height, hidden_height)
-                    } else {
-                        0.0
-                    };
-
-                    state.current_offset = Vector::new(x, y);
-                    shell.request_redraw();
-                    shell.capture_event();
-                }
-            }
-            _ => {}
+        if is_handled {
+            shell.request_redraw();
+            shell.capture_event();
+        } else if state.camera.is_animating() {
+            shell.request_redraw();
         }
     }
 
@@ -318,17 +240,7 @@ where
             self.content_fit,
         );
 
-        let translation = {
-            let diff_w = bounds.width - final_size.width;
-            let diff_h = bounds.height - final_size.height;
-
-            let image_top_left = match self.content_fit {
-                ContentFit::None => Vector::new(diff_w.max(0.0) / 2.0, diff_h.max(0.0) / 2.0),
-                _ => Vector::new(diff_w / 2.0, diff_h / 2.0),
-            };
-
-            image_top_left - state.offset(bounds, final_size)
-        };
+        let translation = state.camera.translation();
 
         let drawing_bounds = Rectangle::new(bounds.position(), final_size);
 
@@ -354,21 +266,19 @@ where
 }
 
 /// The local state of a [`Viewer`].
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct State {
-    scale: f32,
-    starting_offset: Vector,
-    current_offset: Vector,
-    cursor_grabbed_at: Option<Point>,
+    camera: Camera,
+    size: Size,
 }
 
 impl Default for State {
     fn default() -> Self {
         Self {
-            scale: 1.0,
-            starting_offset: Vector::default(),
-            current_offset: Vector::default(),
-            cursor_grabbed_at: None,
+            camera: Camera::new()
+                .pan_button(mouse::Button::Left)
+                .min_scale(0.25),
+            size: Size::ZERO,
         }
     }
 }
@@ -379,22 +289,14 @@ impl State {
         State::default()
     }
 
-    /// Returns the current offset of the [`State`], given the bounds
-    /// of the [`Viewer`] and its image.
-    fn offset(&self, bounds: Rectangle, image_size: Size) -> Vector {
-        let hidden_width = (image_size.width - bounds.width / 2.0).max(0.0).round();
-
-        let hidden_height = (image_size.height - bounds.height / 2.0).max(0.0).round();
-
-        Vector::new(
-            self.current_offset.x.clamp(-hidden_width, hidden_width),
-            self.current_offset.y.clamp(-hidden_height, hidden_height),
-        )
+    /// Returns the [`Camera`] used to zoom and pan the image.
+    pub fn camera(&self) -> &Camera {
+        &self.camera
     }
 
     /// Returns if the cursor is currently grabbed by the [`Viewer`].
     pub fn is_cursor_grabbed(&self) -> bool {
-        self.cursor_grabbed_at.is_some()
+        self.camera.is_panning()
     }
 }
 
@@ -423,14 +325,21 @@ pub fn scaled_image_size<Renderer>(
 where
     Renderer: image::Renderer,
 {
-    let Size { width, height } = renderer.measure_image(handle).unwrap_or_default();
-
-    let image_size = Size::new(width as f32, height as f32);
+    fitted_image_size(renderer, handle, bounds, content_fit) * state.camera.scale()
+}
 
-    let adjusted_fit = content_fit.fit(image_size, bounds);
+/// Returns the size of the underlying image fit to the bounds of the
+/// [`Viewer`], before any zoom is applied.
+fn fitted_image_size<Renderer>(
+    renderer: &Renderer,
+    handle: &<Renderer as image::Renderer>::Handle,
+    bounds: Size,
+    content_fit: ContentFit,
+) -> Size
+where
+    Renderer: image::Renderer,
+{
+    let Size { width, height } = renderer.measure_image(handle).unwrap_or_default();
 
-    Size::new(
-        adjusted_fit.width * state.scale,
-        adjusted_fit.height * state.scale,
-    )
+    content_fit.fit(Size::new(width as f32, height as f32), bounds)
 }
@@ -28,12 +28,14 @@ pub mod focus;
 pub mod grid;
 pub mod keyed;
 pub mod menu;
+pub mod opacity;
 pub mod overlay;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
 pub mod radio_group;
+pub mod rich_editor;
 pub mod row;
 pub mod rule;
 pub mod scrolling;
@@ -42,6 +44,7 @@ pub mod slider;
 pub mod space;
 pub mod spin_button;
 pub mod table;
+pub mod tabs;
 pub mod text;
 pub mod text_editor;
 pub mod text_input;
@@ -53,6 +56,7 @@ pub mod vertical_slider;
 // Re-export scrolling modules at the top level for backwards compatibility
 pub use scrolling::scroll_area;
 pub use scrolling::scrollable;
+pub use scrolling::sticky;
 
 mod helpers;
 
@@ -91,6 +95,8 @@ pub use menu::MenuBar;
 #[doc(no_inline)]
 pub use mouse_area::MouseArea;
 #[doc(no_inline)]
+pub use opacity::Opacity;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -103,6 +109,8 @@ pub use radio::Radio;
 #[doc(no_inline)]
 pub use radio_group::RadioGroup;
 #[doc(no_inline)]
+pub use rich_editor::RichEditor;
+#[doc(no_inline)]
 pub use responsive::Responsive;
 #[doc(no_inline)]
 pub use row::Row;
@@ -113,6 +121,8 @@ pub use scrolling::ScrollArea;
 #[doc(no_inline)]
 pub use scrolling::Scrollable;
 #[doc(no_inline)]
+pub use scrolling::Sticky;
+#[doc(no_inline)]
 pub use sensor::Sensor;
 #[doc(no_inline)]
 pub use slider::Slider;
@@ -123,6 +133,8 @@ pub use spin_button::SpinButton;
 #[doc(no_inline)]
 pub use stack::Stack;
 #[doc(no_inline)]
+pub use tabs::Tabs;
+#[doc(no_inline)]
 pub use text::Text;
 #[doc(no_inline)]
 pub use text_editor::TextEditor;
@@ -48,6 +48,7 @@ use crate::core::border;
 use crate::core::font::{self, Font};
 use crate::core::padding;
 use crate::core::theme;
+use crate::core::widget;
 use crate::core::{self, Color, Element, Length, Padding, Pixels, Theme, color};
 use crate::{checkbox, column, container, rich_text, row, rule, scrollable, span, text};
 
@@ -67,6 +68,32 @@ pub use pulldown_cmark::HeadingLevel;
 /// [URI]: https://en.wikipedia.org/wiki/Uniform_Resource_Identifier
 pub type Uri = String;
 
+/// The renderer of Markdown.
+///
+/// With the `image` feature, it must also draw the images returned by
+/// [`Viewer::load_image`].
+#[cfg(feature = "image")]
+pub trait Renderer:
+    core::text::Renderer<Font = Font> + core::image::Renderer<Handle = core::image::Handle>
+{
+}
+
+#[cfg(feature = "image")]
+impl<T> Renderer for T where
+    T: core::text::Renderer<Font = Font> + core::image::Renderer<Handle = core::image::Handle>
+{
+}
+
+/// The renderer of Markdown.
+///
+/// With the `image` feature, it must also draw the images returned by
+/// `Viewer::load_image`.
+#[cfg(not(feature = "image"))]
+pub trait Renderer: core::text::Renderer<Font = Font> {}
+
+#[cfg(not(feature = "image"))]
+impl<T> Renderer for T where T: core::text::Renderer<Font = Font> {}
+
 /// A bunch of Markdown that has been parsed.
 #[derive(Debug, Default)]
 pub struct Content {
@@ -114,7 +141,9 @@ impl Content {
         };
 
         // Pop the last item
-        let _ = self.items.pop();
+        if let Some(item) = self.items.pop() {
+            self.state.forget(&item);
+        }
 
         // Re-parse last item and new text
         for (item, source, broken_links) in parse_with(&mut self.state, input) {
@@ -147,10 +176,23 @@ impl Content {
                     .retain(|link| !self.state.references.contains_key(link));
 
                 if broken_links_before != section.broken_links.len() {
+                    // Keep the task indices and anchors of the section
+                    let mut anchors = self.state.anchors.clone();
+                    self.items[*index].visit(&mut |item| {
+                        if let Item::Heading(_, text) = item
+                            && let Some(anchor) = &text.anchor
+                        {
+                            let _ = anchors.remove(anchor);
+                        }
+                    });
+
                     let mut state = State {
                         leftover: String::new(),
                         references: self.state.references.clone(),
                         images: HashSet::new(),
+                        tasks: self.items[..*index].iter().map(Item::tasks).sum(),
+                        anchors,
+                        footnotes: self.state.footnotes.clone(),
                         #[cfg(feature = "highlighter")]
                         highlighter: None,
                     };
@@ -162,6 +204,8 @@ impl Content {
                     }
 
                     self.state.images.extend(state.images.drain());
+                    self.state.anchors = mem::take(&mut state.anchors);
+                    self.state.footnotes = mem::take(&mut state.footnotes);
                     drop(state);
                 }
 
@@ -181,6 +225,42 @@ impl Content {
     pub fn images(&self) -> &HashSet<Uri> {
         &self.state.images
     }
+
+    /// Returns the headings of the [`Content`] in order; e.g. to build a
+    /// table of contents.
+    pub fn headings(&self) -> Vec<Heading> {
+        let mut headings = Vec::new();
+
+        for item in &self.items {
+            item.visit(&mut |item| {
+                if let Item::Heading(level, text) = item
+                    && let Some(anchor) = &text.anchor
+                {
+                    headings.push(Heading {
+                        level: *level,
+                        title: text.plain(),
+                        anchor: anchor.clone(),
+                    });
+                }
+            });
+        }
+
+        headings
+    }
+}
+
+/// A heading of some [`Content`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heading {
+    /// The level of the heading.
+    pub level: HeadingLevel,
+    /// The plain text of the heading.
+    pub title: String,
+    /// The anchor of the heading.
+    ///
+    /// It is the [`widget::Id`] of the heading when displayed, so it can be
+    /// scrolled to with `scroll_to_widget`.
+    pub anchor: String,
 }
 
 /// A Markdown item.
@@ -228,6 +308,55 @@ pub enum Item {
         /// The rows of the table.
         rows: Vec<Row>,
     },
+    /// A footnote definition.
+    Footnote {
+        /// The number of the footnote, in order of first appearance.
+        number: usize,
+        /// The anchor of the footnote.
+        ///
+        /// References to the footnote link to it, prefixed with `#`.
+        anchor: String,
+        /// The contents of the footnote.
+        items: Vec<Item>,
+    },
+}
+
+impl Item {
+    /// Calls the given function with the [`Item`] and all of its nested
+    /// items.
+    fn visit(&self, f: &mut impl FnMut(&Item)) {
+        f(self);
+
+        match self {
+            Item::Quote(items) | Item::Footnote { items, .. } => {
+                for item in items {
+                    item.visit(f);
+                }
+            }
+            Item::List { bullets, .. } => {
+                for item in bullets.iter().flat_map(Bullet::items) {
+                    item.visit(f);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the amount of tasks in the [`Item`].
+    fn tasks(&self) -> usize {
+        let mut tasks = 0;
+
+        self.visit(&mut |item| {
+            if let Item::List { bullets, .. } = item {
+                tasks += bullets
+                    .iter()
+                    .filter(|bullet| matches!(bullet, Bullet::Task { .. }))
+                    .count();
+            }
+        });
+
+        tasks
+    }
 }
 
 /// The column of a table.
@@ -250,6 +379,7 @@ pub struct Row {
 #[derive(Debug, Clone)]
 pub struct Text {
     spans: Vec<Span>,
+    anchor: Option<String>,
     last_style: Cell<Option<Style>>,
     last_styled_spans: RefCell<Arc<[text::Span<'static, Uri>]>>,
 }
@@ -258,6 +388,7 @@ impl Text {
     fn new(spans: Vec<Span>) -> Self {
         Self {
             spans,
+            anchor: None,
             last_style: Cell::default(),
             last_styled_spans: RefCell::default(),
         }
@@ -277,6 +408,26 @@ impl Text {
 
         self.last_styled_spans.borrow().clone()
     }
+
+    /// Returns the anchor of the [`Text`], if it is the text of a heading.
+    ///
+    /// Anchors are unique in a document and derived from the text itself;
+    /// e.g. `## Getting started` is anchored as `getting-started`. Links to
+    /// `#getting-started` are reported through [`Viewer::on_link_click`].
+    pub fn anchor(&self) -> Option<&str> {
+        self.anchor.as_deref()
+    }
+
+    fn plain(&self) -> String {
+        self.spans
+            .iter()
+            .map(|span| match span {
+                Span::Standard { text, .. } => text.as_str(),
+                #[cfg(feature = "highlighter")]
+                Span::Highlight { text, .. } => text.as_str(),
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -362,6 +513,8 @@ pub enum Bullet {
         items: Vec<Item>,
         /// Whether the task is done or not.
         done: bool,
+        /// The index of the task in the document, counting from zero.
+        index: usize,
     },
 }
 
@@ -428,10 +581,92 @@ struct State {
     leftover: String,
     references: HashMap<String, String>,
     images: HashSet<Uri>,
+    tasks: usize,
+    anchors: HashSet<String>,
+    footnotes: HashMap<String, (usize, String)>,
     #[cfg(feature = "highlighter")]
     highlighter: Option<Highlighter>,
 }
 
+impl State {
+    /// Reserves a unique anchor for the heading with the given title.
+    fn anchor(&mut self, title: &str) -> String {
+        let slug = slug(title);
+
+        self.reserve(if slug.is_empty() {
+            String::from("section")
+        } else {
+            slug
+        })
+    }
+
+    /// Reserves the given slug, or the first free `{slug}-{n}`.
+    fn reserve(&mut self, slug: String) -> String {
+        let mut anchor = slug.clone();
+        let mut n = 0;
+
+        while self.anchors.contains(&anchor) {
+            n += 1;
+            anchor = format!("{slug}-{n}");
+        }
+
+        let _ = self.anchors.insert(anchor.clone());
+
+        anchor
+    }
+
+    /// Returns the number and the anchor of the footnote with the given
+    /// label, reserving them the first time the label is seen.
+    fn footnote(&mut self, label: &str) -> (usize, String) {
+        if let Some(footnote) = self.footnotes.get(label) {
+            return footnote.clone();
+        }
+
+        let slug = slug(label);
+        let anchor = self.reserve(format!(
+            "fn-{}",
+            if slug.is_empty() { "note" } else { &slug }
+        ));
+
+        let footnote = (self.footnotes.len() + 1, anchor);
+        let _ = self.footnotes.insert(label.to_owned(), footnote.clone());
+
+        footnote
+    }
+
+    /// Releases the tasks and anchors of an [`Item`] that will be parsed
+    /// again.
+    fn forget(&mut self, item: &Item) {
+        self.tasks = self.tasks.saturating_sub(item.tasks());
+
+        item.visit(&mut |item| {
+            if let Item::Heading(_, text) = item
+                && let Some(anchor) = &text.anchor
+            {
+                let _ = self.anchors.remove(anchor);
+            }
+        });
+    }
+}
+
+/// Turns a title into an anchor, like GitHub does.
+fn slug(title: &str) -> String {
+    title
+        .trim()
+        .chars()
+        .filter_map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                Some(c)
+            } else if c.is_whitespace() {
+                Some('-')
+            } else {
+                None
+            }
+        })
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
 #[cfg(feature = "highlighter")]
 #[derive(Debug)]
 struct Highlighter {
@@ -522,6 +757,10 @@ fn parse_with<'a>(
             rows: Vec<Row>,
             current: Vec<Item>,
         },
+        Footnote {
+            label: String,
+            items: Vec<Item>,
+        },
     }
 
     struct List {
@@ -553,7 +792,8 @@ fn parse_with<'a>(
             | pulldown_cmark::Options::ENABLE_PLUSES_DELIMITED_METADATA_BLOCKS
             | pulldown_cmark::Options::ENABLE_TABLES
             | pulldown_cmark::Options::ENABLE_STRIKETHROUGH
-            | pulldown_cmark::Options::ENABLE_TASKLISTS,
+            | pulldown_cmark::Options::ENABLE_TASKLISTS
+            | pulldown_cmark::Options::ENABLE_FOOTNOTES,
         {
             let references = state.borrow().references.clone();
             let broken_links = broken_links.clone();
@@ -586,7 +826,7 @@ fn parse_with<'a>(
                 Scope::List(list) => {
                     list.bullets.last_mut().expect("item context").push(item);
                 }
-                Scope::Quote(items) => {
+                Scope::Quote(items) | Scope::Footnote { items, .. } => {
                     items.push(item);
                 }
                 Scope::Table { current, .. } => {
@@ -676,6 +916,25 @@ fn parse_with<'a>(
 
                 prev
             }
+            pulldown_cmark::Tag::FootnoteDefinition(label) if !metadata => {
+                let prev = if spans.is_empty() {
+                    None
+                } else {
+                    produce(
+                        state.borrow_mut(),
+                        &mut stack,
+                        Item::Paragraph(Text::new(spans.drain(..).collect())),
+                        source,
+                    )
+                };
+
+                stack.push(Scope::Footnote {
+                    label: label.into_string(),
+                    items: Vec::new(),
+                });
+
+                prev
+            }
             pulldown_cmark::Tag::CodeBlock(pulldown_cmark::CodeBlockKind::Fenced(language))
                 if !metadata =>
             {
@@ -740,12 +999,14 @@ fn parse_with<'a>(
             _ => None,
         },
         pulldown_cmark::Event::End(tag) => match tag {
-            pulldown_cmark::TagEnd::Heading(level) if !metadata => produce(
-                state.borrow_mut(),
-                &mut stack,
-                Item::Heading(level, Text::new(spans.drain(..).collect())),
-                source,
-            ),
+            pulldown_cmark::TagEnd::Heading(level) if !metadata => {
+                let mut text = Text::new(spans.drain(..).collect());
+
+                let state = state.borrow_mut();
+                text.anchor = Some(state.anchor(&text.plain()));
+
+                produce(state, &mut stack, Item::Heading(level, text), source)
+            }
             pulldown_cmark::TagEnd::Strong if !metadata => {
                 strong = false;
                 None
@@ -812,6 +1073,27 @@ fn parse_with<'a>(
 
                 produce(state.borrow_mut(), &mut stack, Item::Quote(quote), source)
             }
+            pulldown_cmark::TagEnd::FootnoteDefinition if !metadata => {
+                let scope = stack.pop()?;
+
+                let Scope::Footnote { label, items } = scope else {
+                    return None;
+                };
+
+                let state = state.borrow_mut();
+                let (number, anchor) = state.footnote(&label);
+
+                produce(
+                    state,
+                    &mut stack,
+                    Item::Footnote {
+                        number,
+                        anchor,
+                        items,
+                    },
+                    source,
+                )
+            }
             pulldown_cmark::TagEnd::Image if !metadata => {
                 let (url, title) = image.take()?;
                 let alt = Text::new(spans.drain(..).collect());
@@ -972,16 +1254,35 @@ fn parse_with<'a>(
             });
             None
         }
+        pulldown_cmark::Event::FootnoteReference(label) if !metadata => {
+            let (number, anchor) = state.borrow_mut().footnote(&label);
+
+            spans.push(Span::Standard {
+                text: format!("[{number}]"),
+                strikethrough,
+                strong,
+                emphasis,
+                link: Some(format!("#{anchor}")),
+                code: false,
+            });
+
+            None
+        }
         pulldown_cmark::Event::Rule => produce(state.borrow_mut(), &mut stack, Item::Rule, source),
         pulldown_cmark::Event::TaskListMarker(done) => {
             if let Some(Scope::List(list)) = stack.last_mut()
                 && let Some(item) = list.bullets.last_mut()
                 && let Bullet::Point { items } = item
             {
+                let state = state.borrow_mut();
+
                 *item = Bullet::Task {
                     items: std::mem::take(items),
                     done,
+                    index: state.tasks,
                 };
+
+                state.tasks += 1;
             }
 
             None
@@ -1013,6 +1314,8 @@ pub struct Settings {
     pub spacing: Pixels,
     /// The styling of the Markdown.
     pub style: Style,
+    /// Whether the text of the Markdown can be selected and copied.
+    pub selectable: bool,
 }
 
 impl Settings {
@@ -1040,8 +1343,17 @@ impl Settings {
             code_size: text_size * 0.75,
             spacing: text_size * 0.875,
             style: style.into(),
+            selectable: false,
         }
     }
+
+    /// Sets whether the text of the Markdown can be selected and copied.
+    ///
+    /// Every block of text keeps its own selection.
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
 }
 
 impl From<&Theme> for Settings {
@@ -1159,7 +1471,7 @@ pub fn view<'a, Theme, Renderer>(
 ) -> Element<'a, Uri, Theme, Renderer>
 where
     Theme: Catalog + 'a,
-    Renderer: core::text::Renderer<Font = Font> + 'a,
+    Renderer: self::Renderer + 'a,
 {
     view_with(items, settings, &DefaultViewer)
 }
@@ -1177,7 +1489,7 @@ pub fn view_with<'a, Message, Theme, Renderer>(
 where
     Message: 'a,
     Theme: Catalog + 'a,
-    Renderer: core::text::Renderer<Font = Font> + 'a,
+    Renderer: self::Renderer + 'a,
 {
     let settings = settings.into();
 
@@ -1199,7 +1511,7 @@ pub fn item<'a, Message, Theme, Renderer>(
 where
     Message: 'a,
     Theme: Catalog + 'a,
-    Renderer: core::text::Renderer<Font = Font> + 'a,
+    Renderer: self::Renderer + 'a,
 {
     match item {
         Item::Image { url, title, alt } => viewer.image(settings, url, title, alt),
@@ -1221,6 +1533,11 @@ where
         Item::Quote(quote) => viewer.quote(settings, quote),
         Item::Rule => viewer.rule(settings),
         Item::Table { columns, rows } => viewer.table(settings, columns, rows),
+        Item::Footnote {
+            number,
+            anchor,
+            items,
+        } => viewer.footnote(settings, *number, anchor, items),
     }
 }
 
@@ -1235,7 +1552,7 @@ pub fn heading<'a, Message, Theme, Renderer>(
 where
     Message: 'a,
     Theme: Catalog + 'a,
-    Renderer: core::text::Renderer<Font = Font> + 'a,
+    Renderer: self::Renderer + 'a,
 {
     let Settings {
         h1_size,
@@ -1248,9 +1565,18 @@ where
         ..
     } = settings;
 
-    container(
+    let heading = container(
         rich_text(text.spans(settings.style))
             .on_link_click(on_link_click)
+            .selectable(settings.selectable)
+            .a11y_heading(match level {
+                pulldown_cmark::HeadingLevel::H1 => 1,
+                pulldown_cmark::HeadingLevel::H2 => 2,
+                pulldown_cmark::HeadingLevel::H3 => 3,
+                pulldown_cmark::HeadingLevel::H4 => 4,
+                pulldown_cmark::HeadingLevel::H5 => 5,
+                pulldown_cmark::HeadingLevel::H6 => 6,
+            })
             .size(match level {
                 pulldown_cmark::HeadingLevel::H1 => h1_size,
                 pulldown_cmark::HeadingLevel::H2 => h2_size,
@@ -1264,8 +1590,12 @@ where
         text_size / 2.0
     } else {
         Pixels::ZERO
-    }))
-    .into()
+    }));
+
+    match text.anchor() {
+        Some(anchor) => heading.id(widget::Id::from(anchor.to_owned())).into(),
+        None => heading.into(),
+    }
 }
 
 /// Displays a paragraph using the default look.
@@ -1277,11 +1607,12 @@ pub fn paragraph<'a, Message, Theme, Renderer>(
 where
     Message: 'a,
     Theme: Catalog + 'a,
-    Renderer: core::text::Renderer<Font = Font> + 'a,
+    Renderer: self::Renderer + 'a,
 {
     rich_text(text.spans(settings.style))
         .size(settings.text_size)
         .on_link_click(on_link_click)
+        .selectable(settings.selectable)
         .into()
 }
 
@@ -1295,7 +1626,7 @@ pub fn unordered_list<'a, Message, Theme, Renderer>(
 where
     Message: 'a,
     Theme: Catalog + 'a,
-    Renderer: core::text::Renderer<Font = Font> + 'a,
+    Renderer: self::Renderer + 'a,
 {
     column(bullets.iter().map(|bullet| {
         row![
@@ -1303,10 +1634,18 @@ where
                 Bullet::Point { .. } => {
                     text("•").size(settings.text_size).into()
                 }
-                Bullet::Task { done, .. } => {
+                Bullet::Task { done, index, .. } => {
+                    let index = *index;
+
                     Element::from(
-                        container(checkbox(*done).size(settings.text_size))
-                            .center_y(text::LineHeight::default().to_absolute(settings.text_size)),
+                        container(
+                            checkbox(*done).size(settings.text_size).on_toggle_maybe(
+                                viewer
+                                    .on_task_toggle()
+                                    .map(|on_toggle| move |checked| on_toggle(index, checked)),
+                            ),
+                        )
+                        .center_y(text::LineHeight::default().to_absolute(settings.text_size)),
                     )
                 }
             },
@@ -1338,7 +1677,7 @@ pub fn ordered_list<'a, Message, Theme, Renderer>(
 where
     Message: 'a,
     Theme: Catalog + 'a,
-    Renderer: core::text::Renderer<Font = Font> + 'a,
+    Renderer: self::Renderer + 'a,
 {
     let digits = ((start + bullets.len() as u64).max(1) as f32)
         .log10()
@@ -1375,13 +1714,14 @@ pub fn code_block<'a, Message, Theme, Renderer>(
 where
     Message: 'a,
     Theme: Catalog + 'a,
-    Renderer: core::text::Renderer<Font = Font> + 'a,
+    Renderer: self::Renderer + 'a,
 {
     container(
         scrollable(
             container(column(lines.iter().map(|line| {
                 rich_text(line.spans(settings.style))
                     .on_link_click(on_link_click.clone())
+                    .selectable(settings.selectable)
                     .font(settings.style.code_block_font)
                     .size(settings.code_size)
                     .into()
@@ -1409,7 +1749,7 @@ pub fn quote<'a, Message, Theme, Renderer>(
 where
     Message: 'a,
     Theme: Catalog + 'a,
-    Renderer: core::text::Renderer<Font = Font> + 'a,
+    Renderer: self::Renderer + 'a,
 {
     row![
         rule::vertical(4),
@@ -1431,7 +1771,7 @@ pub fn rule<'a, Message, Theme, Renderer>() -> Element<'a, Message, Theme, Rende
 where
     Message: 'a,
     Theme: Catalog + 'a,
-    Renderer: core::text::Renderer<Font = Font> + 'a,
+    Renderer: self::Renderer + 'a,
 {
     rule::horizontal(2).into()
 }
@@ -1446,7 +1786,7 @@ pub fn table<'a, Message, Theme, Renderer>(
 where
     Message: 'a,
     Theme: Catalog + 'a,
-    Renderer: core::text::Renderer<Font = Font> + 'a,
+    Renderer: self::Renderer + 'a,
 {
     use crate::table;
 
@@ -1481,6 +1821,55 @@ where
         .into()
 }
 
+/// Displays a footnote using the default look.
+///
+/// Its contents are smaller, numbered and anchored, so references can be
+/// scrolled to.
+pub fn footnote<'a, Message, Theme, Renderer>(
+    viewer: &impl Viewer<'a, Message, Theme, Renderer>,
+    settings: Settings,
+    number: usize,
+    anchor: &'a str,
+    items: &'a [Item],
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: self::Renderer + 'a,
+{
+    let settings = Settings {
+        text_size: settings.text_size * 0.875,
+        spacing: settings.spacing * 0.6,
+        ..settings
+    };
+
+    container(
+        row![
+            text!("{number}.")
+                .size(settings.text_size)
+                .align_x(alignment::Horizontal::Right)
+                .width(settings.text_size * 1.5),
+            view_with(items, settings, viewer),
+        ]
+        .spacing(settings.spacing),
+    )
+    .id(widget::Id::from(anchor.to_owned()))
+    .into()
+}
+
+/// Displays a loaded image using the default look.
+#[cfg(feature = "image")]
+pub fn image<'a, Message, Theme, Renderer>(
+    handle: core::image::Handle,
+) -> Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: self::Renderer + 'a,
+{
+    crate::Image::new(handle).into()
+}
+
 /// Displays a column of items with the default look.
 pub fn items<'a, Message, Theme, Renderer>(
     viewer: &impl Viewer<'a, Message, Theme, Renderer>,
@@ -1490,7 +1879,7 @@ pub fn items<'a, Message, Theme, Renderer>(
 where
     Message: 'a,
     Theme: Catalog + 'a,
-    Renderer: core::text::Renderer<Font = Font> + 'a,
+    Renderer: self::Renderer + 'a,
 {
     column(
         items
@@ -1508,14 +1897,35 @@ where
     Self: Sized + 'a,
     Message: 'a,
     Theme: Catalog + 'a,
-    Renderer: core::text::Renderer<Font = Font> + 'a,
+    Renderer: self::Renderer + 'a,
 {
     /// Produces a message when a link is clicked with the given [`Uri`].
     fn on_link_click(url: Uri) -> Message;
 
+    /// Returns the function producing a message when a task is toggled,
+    /// given its [`Bullet::Task`] index and whether it is now checked.
+    ///
+    /// By default, tasks cannot be toggled.
+    fn on_task_toggle(&self) -> Option<fn(usize, bool) -> Message> {
+        None
+    }
+
+    /// Loads the image with the given [`Uri`]; e.g. from the handles of the
+    /// [`Content::images`] fetched by the application.
+    ///
+    /// By default, images are not loaded.
+    #[cfg(feature = "image")]
+    fn load_image(&self, url: &Uri) -> Option<core::image::Handle> {
+        let _url = url;
+
+        None
+    }
+
     /// Displays an image.
     ///
-    /// By default, it will show a container with the image title.
+    /// By default, it calls [`image`](self::image()) with the handle returned by
+    /// [`Viewer::load_image`] or, if the image is not loaded, shows a
+    /// container with the image title.
     fn image(
         &self,
         settings: Settings,
@@ -1523,6 +1933,11 @@ where
         title: &'a str,
         alt: &Text,
     ) -> Element<'a, Message, Theme, Renderer> {
+        #[cfg(feature = "image")]
+        if let Some(handle) = self.load_image(url) {
+            return image(handle);
+        }
+
         let _url = url;
         let _title = title;
 
@@ -1620,6 +2035,19 @@ where
     ) -> Element<'a, Message, Theme, Renderer> {
         table(self, settings, columns, rows)
     }
+
+    /// Displays a footnote.
+    ///
+    /// By default, it calls [`footnote`].
+    fn footnote(
+        &self,
+        settings: Settings,
+        number: usize,
+        anchor: &'a str,
+        items: &'a [Item],
+    ) -> Element<'a, Message, Theme, Renderer> {
+        footnote(self, settings, number, anchor, items)
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -1628,7 +2056,7 @@ struct DefaultViewer;
 impl<'a, Theme, Renderer> Viewer<'a, Uri, Theme, Renderer> for DefaultViewer
 where
     Theme: Catalog + 'a,
-    Renderer: core::text::Renderer<Font = Font> + 'a,
+    Renderer: self::Renderer + 'a,
 {
     fn on_link_click(url: Uri) -> Uri {
         url
@@ -1653,3 +2081,107 @@ impl Catalog for Theme {
         Box::new(container::dark)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tasks(content: &Content) -> Vec<(usize, bool)> {
+        let mut tasks = Vec::new();
+
+        for item in content.items() {
+            item.visit(&mut |item| {
+                if let Item::List { bullets, .. } = item {
+                    for bullet in bullets {
+                        if let Bullet::Task { index, done, .. } = bullet {
+                            tasks.push((*index, *done));
+                        }
+                    }
+                }
+            });
+        }
+
+        tasks
+    }
+
+    #[test]
+    fn anchors_and_tasks_survive_incremental_parsing() {
+        let markdown = "# Intro\n\nSee the note[^a].\n\n## Intro\n\n- [ ] one\n- [x] two\n\n\
+            > - [ ] three\n\n[^a]: A **note**.\n";
+
+        let content = Content::parse(markdown);
+
+        let anchors: Vec<_> = content
+            .headings()
+            .into_iter()
+            .map(|heading| heading.anchor)
+            .collect();
+
+        assert_eq!(anchors, ["intro", "intro-1"]);
+        assert_eq!(tasks(&content), [(0, false), (1, true), (2, false)]);
+
+        assert!(content.items().iter().any(|item| matches!(
+            item,
+            Item::Footnote { number: 1, anchor, .. } if anchor == "fn-a"
+        )));
+
+        let mut streamed = Content::new();
+
+        for chunk in markdown.as_bytes().chunks(3) {
+            streamed.push_str(std::str::from_utf8(chunk).expect("ASCII"));
+        }
+
+        assert_eq!(streamed.headings(), content.headings());
+        assert_eq!(tasks(&streamed), tasks(&content));
+    }
+
+    #[test]
+    fn footnote_anchors_are_unique() {
+        let markdown = "# Fn xy\n\nOne[^x.y], two[^xy], three[^!!] and one again[^x.y].\n\n\
+            [^x.y]: First.\n\n[^xy]: Second.\n\n[^!!]: Third.\n";
+
+        let content = Content::parse(markdown);
+
+        let headings: Vec<_> = content
+            .headings()
+            .into_iter()
+            .map(|heading| heading.anchor)
+            .collect();
+
+        let footnotes: Vec<_> = content
+            .items()
+            .iter()
+            .filter_map(|item| match item {
+                Item::Footnote { number, anchor, .. } => Some((*number, anchor.as_str())),
+                _ => None,
+            })
+            .collect();
+
+        let mut links = Vec::new();
+
+        for item in content.items() {
+            item.visit(&mut |item| {
+                if let Item::Paragraph(text) = item {
+                    links.extend(text.spans.iter().filter_map(|span| match span {
+                        Span::Standard {
+                            link: Some(link), ..
+                        } => Some(link.clone()),
+                        _ => None,
+                    }));
+                }
+            });
+        }
+
+        assert_eq!(headings, ["fn-xy"]);
+        assert_eq!(footnotes, [(1, "fn-xy-1"), (2, "fn-xy-2"), (3, "fn-note")]);
+        assert_eq!(links, ["#fn-xy-1", "#fn-xy-2", "#fn-note", "#fn-xy-1"]);
+
+        let mut streamed = Content::new();
+
+        for chunk in markdown.as_bytes().chunks(5) {
+            streamed.push_str(std::str::from_utf8(chunk).expect("ASCII"));
+        }
+
+        assert_eq!(streamed.headings(), content.headings());
+    }
+}
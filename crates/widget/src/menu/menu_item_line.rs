@@ -1,7 +1,8 @@
 use crate::core::layout::{Limits, Node};
 use crate::core::renderer::{self, Renderer as _};
 use crate::core::text::{
-    Alignment as TextAlignment, LineHeight, Shaping, Span as CoreSpan, Text as CoreText, Wrapping,
+    Alignment as TextAlignment, LineHeight, Shaping, Span as CoreSpan, Text as CoreText,
+    Truncation, Wrapping,
 };
 use crate::core::widget::{Tree, tree};
 use crate::core::{
@@ -196,6 +197,7 @@ impl<Message> MenuItemLine<Message> {
                 align_y: crate::core::alignment::Vertical::Top,
                 shaping: Shaping::default(),
                 wrapping: Wrapping::None,
+                truncation: Truncation::None,
                 hint_factor: None,
             });
 
@@ -666,6 +668,7 @@ where
                             align_y: crate::core::alignment::Vertical::Center,
                             shaping: Shaping::Basic,
                             wrapping: Wrapping::None,
+                            truncation: Truncation::None,
                             hint_factor: None,
                         },
                         bounds.center(),
@@ -605,6 +605,7 @@ where
                     align_y: alignment::Vertical::Center,
                     shaping: self.text_shaping,
                     wrapping: text::Wrapping::default(),
+                    truncation: text::Truncation::None,
                     hint_factor: renderer.scale_factor(),
                 },
                 Point::new(text_x, bounds.center_y()),
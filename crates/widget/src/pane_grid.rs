@@ -12,6 +12,8 @@
 //! * Tracking of the last active pane
 //! * Mouse-based resizing
 //! * Drag and drop to reorganize panes
+//! * Tabbed panes with a [`TabStack`], whose tabs can be dragged between panes
+//!   and windows
 //! * Hotkey support
 //! * Configurable modifier keys
 //! * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, etc.)
@@ -62,12 +64,13 @@ mod draggable;
 mod node;
 mod pane;
 mod split;
+mod tab_stack;
 mod title_bar;
 
 pub mod state;
 
 pub use axis::Axis;
-pub use configuration::Configuration;
+pub use configuration::{Configuration, Snapshot};
 pub use content::Content;
 pub use controls::Controls;
 pub use direction::Direction;
@@ -76,6 +79,7 @@ pub use node::Node;
 pub use pane::Pane;
 pub use split::Split;
 pub use state::State;
+pub use tab_stack::TabStack;
 pub use title_bar::TitleBar;
 
 use crate::container;
@@ -95,6 +99,13 @@ use crate::core::{
 const DRAG_DEADBAND_DISTANCE: f32 = 10.0;
 const THICKNESS_RATIO: f32 = 25.0;
 
+/// The drag and drop format used to move tabs between the panes and windows
+/// of a [`PaneGrid`].
+///
+/// Start a drag with this format (e.g. using `dnd::start_drag`) and enable
+/// [`PaneGrid::on_tab_drop`] to let users dock the dragged tab back.
+pub const TAB_DRAG_FORMAT: &str = "application/x-icy-ui-pane-tab";
+
 /// A collection of panes distributed using either vertical or horizontal splits
 /// to completely fill the space available.
 ///
@@ -110,6 +121,8 @@ const THICKNESS_RATIO: f32 = 25.0;
 /// * Tracking of the last active pane
 /// * Mouse-based resizing
 /// * Drag and drop to reorganize panes
+/// * Tabbed panes with a [`TabStack`], whose tabs can be dragged between panes
+///   and windows
 /// * Hotkey support
 /// * Configurable modifier keys
 /// * [`State`] API to perform actions programmatically (`split`, `swap`, `resize`, etc.)
@@ -162,6 +175,7 @@ where
     on_click: Option<Box<dyn Fn(Pane) -> Message + 'a>>,
     on_drag: Option<Box<dyn Fn(DragEvent) -> Message + 'a>>,
     on_resize: Option<(f32, Box<dyn Fn(ResizeEvent) -> Message + 'a>)>,
+    on_tab_drop: Option<Box<dyn Fn(TabDrop) -> Message + 'a>>,
     class: <Theme as Catalog>::Class<'a>,
     last_mouse_interaction: Option<mouse::Interaction>,
 }
@@ -200,6 +214,7 @@ where
             on_click: None,
             on_drag: None,
             on_resize: None,
+            on_tab_drop: None,
             class: <Theme as Catalog>::default(),
             last_mouse_interaction: None,
         }
@@ -270,6 +285,22 @@ where
         self
     }
 
+    /// Makes the [`PaneGrid`] accept tabs dragged with the [`TAB_DRAG_FORMAT`],
+    /// using the provided function to produce a message when one is dropped.
+    ///
+    /// The [`Target`] under the drag is highlighted while hovering, just like
+    /// when dragging a [`Pane`]. Use [`State::dock_tab`] to handle the
+    /// resulting [`TabDrop`].
+    pub fn on_tab_drop<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(TabDrop) -> Message,
+    {
+        if self.internal.maximized().is_none() {
+            self.on_tab_drop = Some(Box::new(f));
+        }
+        self
+    }
+
     /// Sets the style of the [`PaneGrid`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
@@ -339,6 +370,12 @@ where
 struct Memory {
     action: state::Action,
     order: Vec<Pane>,
+    tab_drag: Option<TabDrag>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TabDrag {
+    position: Option<Point>,
 }
 
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -473,9 +510,55 @@ where
         shell: &mut Shell<'_, Message>,
         viewport: &Rectangle,
     ) {
-        let Memory { action, .. } = tree.state.downcast_mut();
+        let Memory {
+            action, tab_drag, ..
+        } = tree.state.downcast_mut();
         let node = self.internal.layout();
 
+        if let Some(on_tab_drop) = &self.on_tab_drop {
+            let bounds = layout.bounds();
+
+            match event {
+                Event::Window(window::Event::DragEntered { position, formats })
+                    if formats.iter().any(|format| format == TAB_DRAG_FORMAT) =>
+                {
+                    *tab_drag = Some(TabDrag {
+                        position: bounds.contains(*position).then_some(*position),
+                    });
+
+                    shell.request_redraw();
+                }
+                Event::Window(window::Event::DragMoved { position, .. }) => {
+                    if let Some(tab_drag) = tab_drag {
+                        tab_drag.position = bounds.contains(*position).then_some(*position);
+
+                        shell.request_redraw();
+                    }
+                }
+                Event::Window(window::Event::DragDropped {
+                    position,
+                    data,
+                    format,
+                    ..
+                }) if tab_drag.take().is_some() && format == TAB_DRAG_FORMAT => {
+                    if let Some(target) = drop_target(layout, &self.panes, *position, self.internal)
+                    {
+                        shell.publish(on_tab_drop(TabDrop {
+                            target,
+                            data: data.clone(),
+                        }));
+                        shell.capture_event();
+                    }
+
+                    shell.request_redraw();
+                }
+                Event::Window(window::Event::DragLeft) if tab_drag.take().is_some() => {
+                    shell.request_redraw();
+                }
+                _ => {}
+            }
+        }
+
         let on_drag = if self.drag_enabled() {
             &self.on_drag
         } else {
@@ -723,7 +806,9 @@ where
         cursor: mouse::Cursor,
         viewport: &Rectangle,
     ) {
-        let Memory { action, .. } = tree.state.downcast_ref();
+        let Memory {
+            action, tab_drag, ..
+        } = tree.state.downcast_ref();
         let node = self.internal.layout();
         let resize_leeway = self.on_resize.as_ref().map(|(leeway, _)| *leeway);
 
@@ -857,6 +942,31 @@ where
             );
         }
 
+        if let Some(position) = tab_drag.and_then(|tab_drag| tab_drag.position)
+            && let Some(target) = drop_target(layout, &self.panes, position, self.internal)
+        {
+            let bounds = match target {
+                Target::Edge(edge) => Some(edge_bounds(layout, edge)),
+                Target::Pane(target, region) => self
+                    .panes
+                    .iter()
+                    .zip(layout.children())
+                    .find(|(pane, _)| **pane == target)
+                    .map(|(_, pane_layout)| layout_region_bounds(pane_layout, region)),
+            };
+
+            if let Some(bounds) = bounds {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border: style.hovered_region.border,
+                        ..renderer::Quad::default()
+                    },
+                    style.hovered_region.background,
+                );
+            }
+        }
+
         // Render picked pane last
         if let Some(((content, tree), origin, layout)) = render_picked_pane
             && let Some(cursor_position) = cursor.position()
@@ -982,6 +1092,30 @@ fn layout_region(layout: Layout<'_>, cursor_position: Point) -> Option<Region> {
     Some(region)
 }
 
+fn drop_target(
+    layout: Layout<'_>,
+    panes: &[Pane],
+    position: Point,
+    internal: &state::Internal,
+) -> Option<Target> {
+    if let Some(edge) = in_edge(layout, position) {
+        return Some(Target::Edge(edge));
+    }
+
+    panes
+        .iter()
+        .copied()
+        .zip(layout.children())
+        .filter(|(pane, _)| {
+            internal
+                .maximized()
+                .is_none_or(|maximized| *pane == maximized)
+        })
+        .find_map(|(pane, layout)| {
+            layout_region(layout, position).map(|region| Target::Pane(pane, region))
+        })
+}
+
 fn click_pane<'a, Message, T>(
     action: &mut state::Action,
     layout: Layout<'_>,
@@ -1119,6 +1253,18 @@ pub enum DragEvent {
     },
 }
 
+/// A tab dropped on a [`PaneGrid`] with the [`TAB_DRAG_FORMAT`].
+///
+/// See [`PaneGrid::on_tab_drop`].
+#[derive(Debug, Clone)]
+pub struct TabDrop {
+    /// The [`Target`] where the tab was dropped on.
+    pub target: Target,
+
+    /// The data of the drag operation, as provided when it was started.
+    pub data: Vec<u8>,
+}
+
 /// The [`Target`] area a pane can be dropped on.
 #[derive(Debug, Clone, Copy)]
 pub enum Target {
@@ -2,8 +2,15 @@ use crate::pane_grid::Axis;
 
 /// The arrangement of a [`PaneGrid`].
 ///
+/// It can be saved from a [`State`] with [`State::to_configuration`] and
+/// (de)serialized with the `serde` feature to persist layouts. Ratios out of
+/// range are clamped when a [`State`] is created from it.
+///
 /// [`PaneGrid`]: super::PaneGrid
-#[derive(Debug, Clone)]
+/// [`State`]: super::State
+/// [`State::to_configuration`]: super::State::to_configuration
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Configuration<T> {
     /// A split of the available space.
     Split {
@@ -24,3 +31,32 @@ pub enum Configuration<T> {
     /// [`Pane`]: super::Pane
     Pane(T),
 }
+
+/// A saved [`State`], made of its [`Configuration`] and its maximized pane.
+///
+/// It can be obtained with [`State::snapshot`] and restored with
+/// [`State::with_snapshot`].
+///
+/// [`State`]: super::State
+/// [`State::snapshot`]: super::State::snapshot
+/// [`State::with_snapshot`]: super::State::with_snapshot
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Snapshot<T> {
+    /// The arrangement of the panes.
+    pub configuration: Configuration<T>,
+
+    /// The position of the maximized pane among the panes of the
+    /// [`Configuration`], in depth-first order, if any.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub maximized: Option<usize>,
+}
+
+impl<T> From<Configuration<T>> for Snapshot<T> {
+    fn from(configuration: Configuration<T>) -> Self {
+        Self {
+            configuration,
+            maximized: None,
+        }
+    }
+}
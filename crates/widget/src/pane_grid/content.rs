@@ -197,6 +197,21 @@ where
         renderer: &Renderer,
         operation: &mut dyn widget::Operation,
     ) {
+        #[cfg(feature = "accessibility")]
+        {
+            let mut info = core::accessibility::WidgetInfo::pane().with_bounds(layout.bounds());
+
+            if let Some(label) = self
+                .title_bar
+                .as_ref()
+                .and_then(TitleBar::accessibility_label)
+            {
+                info = info.with_label(label);
+            }
+
+            operation.accessibility(None, layout.bounds(), info);
+        }
+
         let body_layout = if let Some(title_bar) = &mut self.title_bar {
             let mut children = layout.children();
 
@@ -1,8 +1,13 @@
 //! The state of a [`PaneGrid`].
 //!
 //! [`PaneGrid`]: super::PaneGrid
+use crate::core::window;
 use crate::core::{Point, Size};
-use crate::pane_grid::{Axis, Configuration, Direction, Edge, Node, Pane, Region, Split, Target};
+use crate::pane_grid::{
+    Axis, Configuration, Direction, Edge, Node, Pane, Region, Snapshot, Split, TabStack, Target,
+};
+
+use icy_ui_runtime::Task;
 
 use std::borrow::Cow;
 use std::collections::BTreeMap;
@@ -53,6 +58,143 @@ impl<T> State<T> {
         State { panes, internal }
     }
 
+    /// Creates a new [`State`] from a [`Snapshot`], maximizing its maximized
+    /// pane again.
+    pub fn with_snapshot(snapshot: Snapshot<T>) -> Self {
+        let Snapshot {
+            configuration,
+            maximized,
+        } = snapshot;
+
+        let mut state = Self::with_configuration(configuration);
+
+        // Pane identifiers are assigned in depth-first order, so the n-th pane
+        // of the configuration is the n-th key of the map.
+        state.internal.maximized =
+            maximized.and_then(|index| state.panes.keys().nth(index).copied());
+
+        state
+    }
+
+    /// Returns the [`Configuration`] of the [`State`], cloning the state of
+    /// each [`Pane`].
+    ///
+    /// This is the inverse of [`with_configuration`](Self::with_configuration)
+    /// and can be used to persist a layout; see
+    /// [`to_configuration_with`](Self::to_configuration_with).
+    ///
+    /// # Panics
+    /// If none of the panes of the layout have a state in
+    /// [`panes`](Self::panes).
+    pub fn to_configuration(&self) -> Configuration<T>
+    where
+        T: Clone,
+    {
+        self.to_configuration_with(|_pane, state| state.clone())
+    }
+
+    /// Returns the [`Configuration`] of the [`State`], mapping the state of
+    /// each [`Pane`] with the given function.
+    ///
+    /// The [`Configuration`] keeps the whole layout tree and the ratio of every
+    /// [`Split`], including the panes hidden by a maximized one. [`Pane`]
+    /// identifiers are not kept; a [`State`] created from the [`Configuration`]
+    /// assigns new ones. Use [`snapshot_with`](Self::snapshot_with) to save
+    /// the maximized pane as well.
+    ///
+    /// A [`Pane`] of the layout without a state in [`panes`](Self::panes) is
+    /// left out, giving its space to its sibling.
+    ///
+    /// # Panics
+    /// If none of the panes of the layout have a state in
+    /// [`panes`](Self::panes).
+    pub fn to_configuration_with<U>(&self, f: impl FnMut(Pane, &T) -> U) -> Configuration<U> {
+        self.snapshot_with(f).configuration
+    }
+
+    /// Returns a [`Snapshot`] of the [`State`], cloning the state of each
+    /// [`Pane`].
+    ///
+    /// Unlike a [`Configuration`], a [`Snapshot`] also keeps the maximized
+    /// [`Pane`], which is restored by [`with_snapshot`](Self::with_snapshot).
+    ///
+    /// # Panics
+    /// If none of the panes of the layout have a state in
+    /// [`panes`](Self::panes).
+    pub fn snapshot(&self) -> Snapshot<T>
+    where
+        T: Clone,
+    {
+        self.snapshot_with(|_pane, state| state.clone())
+    }
+
+    /// Returns a [`Snapshot`] of the [`State`], mapping the state of each
+    /// [`Pane`] with the given function.
+    ///
+    /// See [`to_configuration_with`](Self::to_configuration_with).
+    ///
+    /// # Panics
+    /// If none of the panes of the layout have a state in
+    /// [`panes`](Self::panes).
+    pub fn snapshot_with<U>(&self, mut f: impl FnMut(Pane, &T) -> U) -> Snapshot<U> {
+        fn configuration<T, U>(
+            node: &Node,
+            panes: &BTreeMap<Pane, T>,
+            maximized: Option<Pane>,
+            count: &mut usize,
+            index: &mut Option<usize>,
+            f: &mut impl FnMut(Pane, &T) -> U,
+        ) -> Option<Configuration<U>> {
+            match node {
+                Node::Split {
+                    axis, ratio, a, b, ..
+                } => {
+                    let a = configuration(a, panes, maximized, count, index, f);
+                    let b = configuration(b, panes, maximized, count, index, f);
+
+                    match (a, b) {
+                        (Some(a), Some(b)) => Some(Configuration::Split {
+                            axis: *axis,
+                            ratio: *ratio,
+                            a: Box::new(a),
+                            b: Box::new(b),
+                        }),
+                        (a, b) => a.or(b),
+                    }
+                }
+                Node::Pane(pane) => {
+                    let state = panes.get(pane)?;
+
+                    if maximized == Some(*pane) {
+                        *index = Some(*count);
+                    }
+
+                    *count += 1;
+
+                    Some(Configuration::Pane(f(*pane, state)))
+                }
+            }
+        }
+
+        let mut maximized = None;
+
+        let Some(configuration) = configuration(
+            &self.internal.layout,
+            &self.panes,
+            self.internal.maximized,
+            &mut 0,
+            &mut maximized,
+            &mut f,
+        ) else {
+            panic!("None of the panes of the layout have a state");
+        };
+
+        Snapshot {
+            configuration,
+            maximized,
+        }
+    }
+
     /// Returns the total amount of panes in the [`State`].
     pub fn len(&self) -> usize {
         self.panes.len()
@@ -124,7 +266,7 @@ impl<T> State<T> {
     /// Splits the given [`Pane`] into two in the given [`Axis`] and
     /// initializing the new [`Pane`] with the provided internal state.
     pub fn split(&mut self, axis: Axis, pane: Pane, state: T) -> Option<(Pane, Split)> {
-        self.split_node(axis, Some(pane), state, false)
+        self.split_node(axis, Some(pane), state, false).ok()
     }
 
     /// Split a target [`Pane`] with a given [`Pane`] on a given [`Region`].
@@ -160,31 +302,34 @@ impl<T> State<T> {
         }
     }
 
+    /// Splits the given [`Pane`] (or the major node) with a new [`Pane`] for
+    /// the provided state, giving the state back if the split fails.
     fn split_node(
         &mut self,
         axis: Axis,
         pane: Option<Pane>,
         state: T,
         inverse: bool,
-    ) -> Option<(Pane, Split)> {
+    ) -> Result<(Pane, Split), T> {
         let node = if let Some(pane) = pane {
-            self.internal.layout.find(pane)?
+            let Some(node) = self.internal.layout.find(pane) else {
+                return Err(state);
+            };
+
+            node
         } else {
             // Major node
             &mut self.internal.layout
         };
 
-        let new_pane = {
-            self.internal.last_id = self.internal.last_id.checked_add(1)?;
-
-            Pane(self.internal.last_id)
+        let Some(last_id) = self.internal.last_id.checked_add(2) else {
+            return Err(state);
         };
 
-        let new_split = {
-            self.internal.last_id = self.internal.last_id.checked_add(1)?;
+        let new_pane = Pane(last_id - 1);
+        let new_split = Split(last_id);
 
-            Split(self.internal.last_id)
-        };
+        self.internal.last_id = last_id;
 
         if inverse {
             node.split_inverse(new_split, axis, new_pane);
@@ -195,7 +340,7 @@ impl<T> State<T> {
         let _ = self.panes.insert(new_pane, state);
         let _ = self.internal.maximized.take();
 
-        Some((new_pane, new_split))
+        Ok((new_pane, new_split))
     }
 
     fn split_and_swap(&mut self, axis: Axis, target: Pane, pane: Pane, swap: bool) {
@@ -233,7 +378,7 @@ impl<T> State<T> {
 
     fn split_major_node_and_swap(&mut self, axis: Axis, pane: Pane, inverse: bool) {
         if let Some((state, _)) = self.close(pane)
-            && let Some((new_pane, _)) = self.split_node(axis, None, state, inverse)
+            && let Ok((new_pane, _)) = self.split_node(axis, None, state, inverse)
         {
             // Ensure new node corresponds to original closed `Pane` for state continuity
             self.relabel(new_pane, pane);
@@ -321,6 +466,180 @@ impl<T> State<T> {
     }
 }
 
+impl<T> State<TabStack<T>> {
+    /// Moves the tab at the given index of a [`Pane`] to the provided
+    /// [`Target`], returning the [`Pane`] that holds it afterwards.
+    ///
+    /// Dropping a tab on the [`Region::Center`] of a [`Pane`] adds it to its
+    /// [`TabStack`], while dropping it on an [`Edge`] splits a new [`Pane`]
+    /// for it. A [`Pane`] left without tabs is closed. Dropping a tab on the
+    /// [`Region::Center`] of its own [`Pane`] leaves it in place.
+    ///
+    /// Nothing is moved and `None` is returned if the tab or the [`Target`]
+    /// do not exist; for instance, if the target [`Pane`] was closed.
+    ///
+    /// If you want to move tabs on drag and drop in your [`PaneGrid`], you
+    /// will need to call this method when handling a [`DragEvent`] for the
+    /// tab.
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    /// [`DragEvent`]: super::DragEvent
+    pub fn move_tab(&mut self, pane: Pane, index: usize, target: Target) -> Option<Pane> {
+        if let Target::Pane(target, _) = target
+            && !self.panes.contains_key(&target)
+        {
+            return None;
+        }
+
+        let stack = self.panes.get_mut(&pane)?;
+
+        if index >= stack.len() {
+            return None;
+        }
+
+        if matches!(target, Target::Pane(target, Region::Center) if target == pane) {
+            return Some(pane);
+        }
+
+        if stack.len() > 1 {
+            let active = stack.active_index();
+            let tab = stack.remove(index)?;
+
+            return match self.dock_tab(tab, target) {
+                Ok(pane) => Some(pane),
+                Err(tab) => {
+                    // The tab could not be docked, so we put it back in place
+                    if let Some(stack) = self.panes.get_mut(&pane) {
+                        stack.insert(index, tab);
+                        stack.activate(active);
+                    }
+
+                    None
+                }
+            };
+        }
+
+        // The tab is alone in its pane, so we move the whole pane if possible
+        // to keep its identity.
+        match target {
+            Target::Pane(target, _) if target == pane => Some(pane),
+            Target::Pane(target, Region::Center) => {
+                let tab = self.take_tab(pane, index)?;
+
+                // The target exists, so docking the tab into it cannot fail
+                self.dock_tab(tab, Target::Pane(target, Region::Center))
+                    .ok()
+            }
+            Target::Pane(target, region) => {
+                self.split_with(target, pane, region);
+
+                Some(pane)
+            }
+            Target::Edge(edge) => {
+                self.move_to_edge(pane, edge);
+
+                Some(pane)
+            }
+        }
+    }
+
+    /// Takes the tab at the given index out of a [`Pane`]; for instance, to
+    /// open it in a new window.
+    ///
+    /// The [`Pane`] is closed if it is left without tabs, unless it is the
+    /// last one of the [`State`].
+    pub fn take_tab(&mut self, pane: Pane, index: usize) -> Option<T> {
+        let stack = self.panes.get_mut(&pane)?;
+        let tab = stack.remove(index)?;
+
+        if stack.is_empty() {
+            let _ = self.close(pane);
+        }
+
+        Some(tab)
+    }
+
+    /// Takes the tab at the given index out of a [`Pane`] and opens a new
+    /// window for it; for instance, when a tab is dragged out of its
+    /// [`Tabs`] bar with [`Tabs::on_drag_out`].
+    ///
+    /// Alongside the tab, it returns the [`window::Id`] of the new window and
+    /// the [`Task`] opening it. Keep the tab by [`window::Id`] to show it in
+    /// the view of the new window, and [`dock_tab`](Self::dock_tab) it back
+    /// when it is dropped on the [`PaneGrid`] again.
+    ///
+    /// [`Tabs`]: crate::Tabs
+    /// [`Tabs::on_drag_out`]: crate::Tabs::on_drag_out
+    /// [`PaneGrid`]: super::PaneGrid
+    pub fn open_tab_in_window(
+        &mut self,
+        pane: Pane,
+        index: usize,
+        settings: window::Settings,
+    ) -> Option<(T, window::Id, Task<window::Id>)> {
+        let tab = self.take_tab(pane, index)?;
+        let (id, task) = icy_ui_runtime::window::open(settings);
+
+        Some((tab, id, task))
+    }
+
+    /// Docks a tab into the provided [`Target`], returning the [`Pane`] that
+    /// holds it.
+    ///
+    /// This is the counterpart of [`take_tab`](Self::take_tab) and can be
+    /// used to handle a [`TabDrop`] of a [`PaneGrid`].
+    ///
+    /// The tab is given back if it cannot be docked; for instance, if the
+    /// target [`Pane`] was closed in the meantime.
+    ///
+    /// [`PaneGrid`]: super::PaneGrid
+    /// [`TabDrop`]: super::TabDrop
+    pub fn dock_tab(&mut self, tab: T, target: Target) -> Result<Pane, T> {
+        let (axis, pane, inverse) = match target {
+            Target::Pane(pane, Region::Center) => {
+                let Some(stack) = self.panes.get_mut(&pane) else {
+                    return Err(tab);
+                };
+
+                stack.push(tab);
+
+                return Ok(pane);
+            }
+            Target::Pane(pane, Region::Edge(edge)) => {
+                if !self.panes.contains_key(&pane) {
+                    return Err(tab);
+                }
+
+                let (axis, inverse) = edge_split(edge);
+
+                (axis, Some(pane), inverse)
+            }
+            Target::Edge(edge) => {
+                let (axis, inverse) = edge_split(edge);
+
+                (axis, None, inverse)
+            }
+        };
+
+        self.split_node(axis, pane, TabStack::new(tab), inverse)
+            .map(|(pane, _)| pane)
+            .map_err(|mut stack| {
+                stack
+                    .remove(0)
+                    .expect("The tab stack of an undocked tab holds it")
+            })
+    }
+}
+
+fn edge_split(edge: Edge) -> (Axis, bool) {
+    match edge {
+        Edge::Top => (Axis::Horizontal, true),
+        Edge::Bottom => (Axis::Horizontal, false),
+        Edge::Left => (Axis::Vertical, true),
+        Edge::Right => (Axis::Vertical, false),
+    }
+}
+
 /// The internal state of a [`PaneGrid`].
 ///
 /// [`PaneGrid`]: super::PaneGrid
@@ -355,6 +674,14 @@ impl Internal {
                     ..
                 } = Self::from_configuration(panes, *b, next_id);
 
+                // Configurations may be deserialized, so their ratios are
+                // brought back in range
+                let ratio = if ratio.is_finite() {
+                    ratio.clamp(0.0, 1.0)
+                } else {
+                    0.5
+                };
+
                 (
                     Node::Split {
                         id: Split(next_id),
@@ -440,3 +767,208 @@ impl Action {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_configuration_round_trip() {
+        let (mut state, first) = State::new("first");
+        let (second, split) = state.split(Axis::Vertical, first, "second").unwrap();
+        let _ = state.split(Axis::Horizontal, second, "third").unwrap();
+        state.resize(split, 0.3);
+
+        let configuration = state.to_configuration();
+        let restored = State::with_configuration(configuration.clone());
+
+        assert_eq!(restored.to_configuration(), configuration);
+        assert_eq!(restored.len(), 3);
+
+        let Configuration::Split { axis, ratio, a, b } = configuration else {
+            panic!("expected a split");
+        };
+
+        assert_eq!(axis, Axis::Vertical);
+        assert_eq!(ratio, 0.3);
+        assert_eq!(*a, Configuration::Pane("first"));
+        assert!(matches!(
+            *b,
+            Configuration::Split {
+                axis: Axis::Horizontal,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn snapshot_keeps_the_maximized_pane() {
+        let (mut state, first) = State::new("first");
+        let (second, _) = state.split(Axis::Vertical, first, "second").unwrap();
+        let _ = state.split(Axis::Horizontal, second, "third").unwrap();
+        let (fourth, _) = state.split(Axis::Vertical, second, "fourth").unwrap();
+        state.maximize(fourth);
+
+        let snapshot = state.snapshot();
+        let restored = State::with_snapshot(snapshot.clone());
+
+        assert_eq!(snapshot.configuration, state.to_configuration());
+        assert_eq!(snapshot.maximized, Some(2));
+        assert_eq!(restored.snapshot(), snapshot);
+        assert_eq!(
+            restored.maximized().and_then(|pane| restored.get(pane)),
+            Some(&"fourth")
+        );
+
+        let out_of_bounds = State::with_snapshot(Snapshot {
+            maximized: Some(4),
+            ..snapshot
+        });
+
+        assert_eq!(out_of_bounds.maximized(), None);
+    }
+
+    #[test]
+    fn to_configuration_skips_panes_without_a_state() {
+        let (mut state, first) = State::new("first");
+        let (second, _) = state.split(Axis::Vertical, first, "second").unwrap();
+        let (third, _) = state.split(Axis::Horizontal, second, "third").unwrap();
+
+        let _ = state.panes.remove(&second);
+        state.maximize(third);
+
+        let snapshot = state.snapshot();
+
+        assert_eq!(snapshot.maximized, Some(1));
+        assert_eq!(
+            snapshot.configuration,
+            Configuration::Split {
+                axis: Axis::Vertical,
+                ratio: 0.5,
+                a: Box::new(Configuration::Pane("first")),
+                b: Box::new(Configuration::Pane("third")),
+            }
+        );
+    }
+
+    #[test]
+    fn with_configuration_clamps_ratios() {
+        let split = |ratio| Configuration::Split {
+            axis: Axis::Horizontal,
+            ratio,
+            a: Box::new(Configuration::Pane(())),
+            b: Box::new(Configuration::Pane(())),
+        };
+
+        for (ratio, expected) in [(-1.0, 0.0), (0.25, 0.25), (2.0, 1.0), (f32::NAN, 0.5)] {
+            let state = State::with_configuration(split(ratio));
+
+            assert_eq!(state.to_configuration(), split(expected));
+        }
+    }
+
+    #[test]
+    fn move_tab_to_own_center_keeps_order() {
+        let (mut state, pane) = State::new(TabStack::with_tabs(["a", "b", "c"]));
+
+        let moved = state.move_tab(pane, 0, Target::Pane(pane, Region::Center));
+
+        assert_eq!(moved, Some(pane));
+        assert_eq!(state.len(), 1);
+        assert_eq!(
+            state.get(pane).unwrap().iter().copied().collect::<Vec<_>>(),
+            ["a", "b", "c"]
+        );
+    }
+
+    #[test]
+    fn move_tab_to_a_closed_pane_keeps_the_tab() {
+        let (mut state, pane) = State::new(TabStack::with_tabs(["a", "b", "c"]));
+        let (other, _) = state
+            .split(Axis::Vertical, pane, TabStack::new("d"))
+            .unwrap();
+        let _ = state.close(other);
+
+        state.panes.get_mut(&pane).unwrap().activate(2);
+
+        for region in [Region::Center, Region::Edge(Edge::Left)] {
+            assert_eq!(state.move_tab(pane, 1, Target::Pane(other, region)), None);
+        }
+
+        let stack = state.get(pane).unwrap();
+
+        assert_eq!(state.len(), 1);
+        assert_eq!(stack.iter().copied().collect::<Vec<_>>(), ["a", "b", "c"]);
+        assert_eq!(stack.active(), Some(&"c"));
+    }
+
+    #[test]
+    fn move_last_tab_to_a_closed_pane_keeps_its_pane() {
+        let (mut state, pane) = State::new(TabStack::new("a"));
+        let (other, _) = state
+            .split(Axis::Vertical, pane, TabStack::new("b"))
+            .unwrap();
+        let (closed, _) = state
+            .split(Axis::Vertical, other, TabStack::new("c"))
+            .unwrap();
+        let _ = state.close(closed);
+
+        assert_eq!(
+            state.move_tab(other, 0, Target::Pane(closed, Region::Center)),
+            None
+        );
+        assert_eq!(state.get(other).and_then(TabStack::active), Some(&"b"));
+    }
+
+    #[test]
+    fn move_tab_to_another_pane() {
+        let (mut state, pane) = State::new(TabStack::with_tabs(["a", "b"]));
+        let (other, _) = state
+            .split(Axis::Vertical, pane, TabStack::new("c"))
+            .unwrap();
+
+        assert_eq!(
+            state.move_tab(pane, 0, Target::Pane(other, Region::Center)),
+            Some(other)
+        );
+        assert_eq!(
+            state
+                .get(other)
+                .unwrap()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            ["c", "a"]
+        );
+
+        // A lone tab moves its whole pane along
+        assert_eq!(
+            state.move_tab(pane, 0, Target::Edge(Edge::Bottom)),
+            Some(pane)
+        );
+        assert_eq!(state.get(pane).and_then(TabStack::active), Some(&"b"));
+        assert_eq!(state.len(), 2);
+    }
+
+    #[test]
+    fn dock_tab_gives_the_tab_back_when_the_pane_is_closed() {
+        let (mut state, pane) = State::new(TabStack::new("a"));
+        let (other, _) = state
+            .split(Axis::Vertical, pane, TabStack::new("b"))
+            .unwrap();
+        let _ = state.close(other);
+
+        assert_eq!(
+            state.dock_tab("c", Target::Pane(other, Region::Center)),
+            Err("c")
+        );
+        assert_eq!(
+            state.dock_tab("c", Target::Pane(other, Region::Edge(Edge::Top))),
+            Err("c")
+        );
+        assert_eq!(
+            state.dock_tab("c", Target::Pane(pane, Region::Center)),
+            Ok(pane)
+        );
+    }
+}
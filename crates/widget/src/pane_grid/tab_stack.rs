@@ -0,0 +1,229 @@
+/// A stack of tabs held by a single [`Pane`].
+///
+/// Using a [`TabStack`] as the state of each [`Pane`] turns a [`PaneGrid`]
+/// into a tabbed workspace: [`State`] gains methods to move tabs between panes
+/// ([`State::move_tab`]), take them out to open them elsewhere (e.g. in a new
+/// window, [`State::take_tab`]), and dock them back ([`State::dock_tab`]).
+///
+/// [`Pane`]: super::Pane
+/// [`PaneGrid`]: super::PaneGrid
+/// [`State`]: super::State
+/// [`State::move_tab`]: super::State::move_tab
+/// [`State::take_tab`]: super::State::take_tab
+/// [`State::dock_tab`]: super::State::dock_tab
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Unchecked<T>"))]
+pub struct TabStack<T> {
+    tabs: Vec<T>,
+    active: usize,
+}
+
+/// A deserialized [`TabStack`] whose active tab has not been validated yet.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct Unchecked<T> {
+    tabs: Vec<T>,
+    active: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<Unchecked<T>> for TabStack<T> {
+    type Error = String;
+
+    fn try_from(Unchecked { tabs, active }: Unchecked<T>) -> Result<Self, Self::Error> {
+        if active >= tabs.len().max(1) {
+            return Err(format!(
+                "active tab {active} is out of bounds for {} tabs",
+                tabs.len()
+            ));
+        }
+
+        Ok(Self { tabs, active })
+    }
+}
+
+impl<T> TabStack<T> {
+    /// Creates a new [`TabStack`] holding a single tab.
+    pub fn new(tab: T) -> Self {
+        Self {
+            tabs: vec![tab],
+            active: 0,
+        }
+    }
+
+    /// Creates a new [`TabStack`] with the given tabs, activating the first one.
+    pub fn with_tabs(tabs: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            tabs: tabs.into_iter().collect(),
+            active: 0,
+        }
+    }
+
+    /// Returns the amount of tabs in the [`TabStack`].
+    pub fn len(&self) -> usize {
+        self.tabs.len()
+    }
+
+    /// Returns `true` if the [`TabStack`] has no tabs.
+    pub fn is_empty(&self) -> bool {
+        self.tabs.is_empty()
+    }
+
+    /// Returns the index of the active tab.
+    pub fn active_index(&self) -> usize {
+        self.active
+    }
+
+    /// Returns the active tab, if any.
+    pub fn active(&self) -> Option<&T> {
+        self.tabs.get(self.active)
+    }
+
+    /// Returns the active tab with mutability, if any.
+    pub fn active_mut(&mut self) -> Option<&mut T> {
+        self.tabs.get_mut(self.active)
+    }
+
+    /// Activates the tab at the given index, if it exists.
+    pub fn activate(&mut self, index: usize) {
+        if index < self.tabs.len() {
+            self.active = index;
+        }
+    }
+
+    /// Returns the tab at the given index, if it exists.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.tabs.get(index)
+    }
+
+    /// Returns the tab at the given index with mutability, if it exists.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.tabs.get_mut(index)
+    }
+
+    /// Returns an iterator over the tabs of the [`TabStack`].
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.tabs.iter()
+    }
+
+    /// Returns a mutable iterator over the tabs of the [`TabStack`].
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.tabs.iter_mut()
+    }
+
+    /// Adds a tab at the end of the [`TabStack`] and activates it.
+    pub fn push(&mut self, tab: T) {
+        self.tabs.push(tab);
+        self.active = self.tabs.len() - 1;
+    }
+
+    /// Inserts a tab at the given index and activates it.
+    ///
+    /// The index is clamped to the amount of tabs.
+    pub fn insert(&mut self, index: usize, tab: T) {
+        let index = index.min(self.tabs.len());
+
+        self.tabs.insert(index, tab);
+        self.active = index;
+    }
+
+    /// Removes the tab at the given index, if it exists.
+    ///
+    /// The active tab is kept if possible; otherwise its neighbor is activated.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.tabs.len() {
+            return None;
+        }
+
+        let tab = self.tabs.remove(index);
+
+        if index < self.active || self.active >= self.tabs.len() {
+            self.active = self.active.saturating_sub(1);
+        }
+
+        Some(tab)
+    }
+
+    /// Moves the tab at `from` to the index `to`, keeping the active tab.
+    pub fn reorder(&mut self, from: usize, to: usize) {
+        if from >= self.tabs.len() || to >= self.tabs.len() || from == to {
+            return;
+        }
+
+        let is_active = self.active == from;
+        let tab = self.tabs.remove(from);
+        self.tabs.insert(to, tab);
+
+        if is_active {
+            self.active = to;
+        } else if from < self.active && to >= self.active {
+            self.active -= 1;
+        } else if from > self.active && to <= self.active {
+            self.active += 1;
+        }
+    }
+}
+
+impl<T> FromIterator<T> for TabStack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(tabs: I) -> Self {
+        Self::with_tabs(tabs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn remove_keeps_the_active_tab() {
+        let mut stack = TabStack::with_tabs(["a", "b", "c"]);
+        stack.activate(2);
+
+        assert_eq!(stack.remove(0), Some("a"));
+        assert_eq!(stack.active(), Some(&"c"));
+
+        assert_eq!(stack.remove(1), Some("c"));
+        assert_eq!(stack.active(), Some(&"b"));
+
+        assert_eq!(stack.remove(1), None);
+    }
+
+    #[test]
+    fn reorder_follows_the_active_tab() {
+        let mut stack = TabStack::with_tabs(["a", "b", "c"]);
+        stack.activate(1);
+
+        stack.reorder(0, 2);
+
+        assert_eq!(stack.iter().copied().collect::<Vec<_>>(), ["b", "c", "a"]);
+        assert_eq!(stack.active(), Some(&"b"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialization_validates_the_active_tab() {
+        let valid = TabStack::try_from(Unchecked {
+            tabs: vec!["a", "b"],
+            active: 1,
+        });
+
+        assert_eq!(valid.map(|stack| stack.active().copied()), Ok(Some("b")));
+
+        assert!(
+            TabStack::try_from(Unchecked {
+                tabs: vec!["a", "b"],
+                active: 2,
+            })
+            .is_err()
+        );
+
+        assert!(
+            TabStack::<&str>::try_from(Unchecked {
+                tabs: Vec::new(),
+                active: 0,
+            })
+            .is_ok()
+        );
+    }
+}
@@ -337,6 +337,11 @@ where
         layout::Node::container(node, self.padding)
     }
 
+    #[cfg(feature = "accessibility")]
+    pub(crate) fn accessibility_label(&self) -> Option<std::borrow::Cow<'_, str>> {
+        self.content.as_widget().accessibility_label()
+    }
+
     pub(crate) fn operate(
         &mut self,
         tree: &mut Tree,
@@ -167,6 +167,7 @@ where
     text_size: Option<Pixels>,
     text_line_height: text::LineHeight,
     text_shaping: text::Shaping,
+    text_truncation: text::Truncation,
     font: Option<Renderer::Font>,
     handle: Handle<Renderer::Font>,
     class: <Theme as Catalog>::Class<'a>,
@@ -200,6 +201,7 @@ where
             text_size: None,
             text_line_height: text::LineHeight::default(),
             text_shaping: text::Shaping::default(),
+            text_truncation: text::Truncation::default(),
             font: None,
             handle: Handle::default(),
             class: <Theme as Catalog>::default(),
@@ -257,6 +259,15 @@ where
         self
     }
 
+    /// Sets the [`text::Truncation`] strategy of the selected label of the
+    /// [`PickList`].
+    ///
+    /// The full label remains available to assistive technologies.
+    pub fn text_truncation(mut self, truncation: text::Truncation) -> Self {
+        self.text_truncation = truncation;
+        self
+    }
+
     /// Sets the font of the [`PickList`].
     pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
         self.font = Some(font.into());
@@ -370,6 +381,7 @@ where
             align_y: alignment::Vertical::Center,
             shaping: self.text_shaping,
             wrapping: text::Wrapping::default(),
+            truncation: text::Truncation::None,
             hint_factor: renderer.scale_factor(),
         };
 
@@ -787,6 +799,12 @@ where
             Handle::None => None,
         };
 
+        // The horizontal space taken by the handle, including the gap
+        // separating it from the label
+        let handle_width = handle.map_or(0.0, |(_, _, size, _, _)| {
+            size.unwrap_or_else(|| renderer.default_size()).0 + self.padding.left
+        });
+
         if let Some((font, code_point, size, line_height, shaping)) = handle {
             let size = size.unwrap_or_else(|| renderer.default_size());
             let is_rtl = crate::core::layout_direction().is_rtl();
@@ -812,6 +830,7 @@ where
                     align_y: alignment::Vertical::Center,
                     shaping,
                     wrapping: text::Wrapping::default(),
+                    truncation: text::Truncation::None,
                     hint_factor: None,
                 },
                 Point::new(handle_x, bounds.center_y()),
@@ -843,13 +862,18 @@ where
                     line_height: self.text_line_height,
                     font,
                     bounds: Size::new(
-                        bounds.width - self.padding.x(),
+                        if self.text_truncation == text::Truncation::None {
+                            bounds.width - self.padding.x()
+                        } else {
+                            bounds.width - self.padding.x() - handle_width
+                        },
                         f32::from(self.text_line_height.to_absolute(text_size)),
                     ),
                     align_x: label_align,
                     align_y: alignment::Vertical::Center,
                     shaping: self.text_shaping,
                     wrapping: text::Wrapping::default(),
+                    truncation: self.text_truncation,
                     hint_factor: renderer.scale_factor(),
                 },
                 Point::new(label_x, bounds.center_y()),
@@ -249,6 +249,39 @@ where
             );
         }
     }
+
+    #[cfg(feature = "accessibility")]
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn crate::core::widget::Operation,
+    ) {
+        if let Some(info) =
+            <Self as Widget<Message, Theme, Renderer>>::accessibility(self, tree, layout)
+        {
+            operation.accessibility(None, layout.bounds(), info);
+        }
+    }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+    ) -> Option<crate::core::accessibility::WidgetInfo> {
+        let (start, end) = self.range.clone().into_inner();
+
+        Some(
+            crate::core::accessibility::WidgetInfo::progress_bar(
+                self.value.into(),
+                start.into(),
+                end.into(),
+            )
+            .with_bounds(layout.bounds()),
+        )
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<ProgressBar<'a, Theme>>
@@ -335,3 +368,46 @@ fn styled(background: impl Into<Background>, bar: impl Into<Background>) -> Styl
         border: border::rounded(2),
     }
 }
+
+#[cfg(all(test, feature = "accessibility"))]
+mod tests {
+    use super::*;
+    use crate::core::accessibility::Role;
+    use crate::core::widget::operation::{self, Operation, Outcome, accessibility};
+
+    #[test]
+    fn exposes_value_and_range() {
+        let mut progress_bar: ProgressBar<'_> = ProgressBar::new(0.0..=10.0, 2.5);
+        let mut tree = Tree::new(&progress_bar as &dyn Widget<(), Theme, ()>);
+
+        let node = Widget::<(), Theme, ()>::layout(
+            &mut progress_bar,
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(200.0, 100.0)),
+        );
+
+        let mut operation = accessibility::collect();
+
+        Widget::<(), Theme, ()>::operate(
+            &mut progress_bar,
+            &mut tree,
+            Layout::new(&node),
+            &(),
+            &mut operation::black_box(&mut operation),
+        );
+
+        let Outcome::Some(tree) = operation.finish() else {
+            panic!("collect should produce a tree");
+        };
+
+        let [(_, node)] = tree.nodes.as_slice() else {
+            panic!("expected a single node, got {:?}", tree.nodes);
+        };
+
+        assert_eq!(node.role(), Role::ProgressIndicator);
+        assert_eq!(node.numeric_value(), Some(2.5));
+        assert_eq!(node.min_numeric_value(), Some(0.0));
+        assert_eq!(node.max_numeric_value(), Some(10.0));
+    }
+}
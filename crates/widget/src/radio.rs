@@ -376,6 +376,7 @@ where
                         align_y: alignment::Vertical::Top,
                         shaping: self.text_shaping,
                         wrapping: self.text_wrapping,
+                        truncation: text::Truncation::None,
                     },
                 )
             },
@@ -362,6 +362,7 @@ where
                     align_y: alignment::Vertical::Center,
                     shaping: text::Shaping::default(),
                     wrapping: text::Wrapping::default(),
+                    truncation: text::Truncation::None,
                 },
             );
 
@@ -0,0 +1,2399 @@
+//! Rich text editors display a multi-line input for formatted text.
+//!
+//! Its [`Content`] is a [`Document`] of paragraphs, headings and list items,
+//! made of bold, italic, underlined, colored and linked runs of text, and
+//! inline images. Documents can be converted from and to HTML and RTF, the
+//! same formats written by [`clipboard::write_html`] and
+//! [`clipboard::write_rich_text`].
+//!
+//! [`clipboard::write_html`]: icy_ui_runtime::clipboard::write_html
+//! [`clipboard::write_rich_text`]: icy_ui_runtime::clipboard::write_rich_text
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use icy_ui_widget::*; } pub use icy_ui_widget::Renderer; pub use icy_ui_widget::core::*; }
+//! # pub type Element<'a, Message> = icy_ui_widget::core::Element<'a, Message, icy_ui_widget::Theme, icy_ui_widget::Renderer>;
+//! #
+//! use icy_ui::widget::{button, column, rich_editor, row};
+//!
+//! struct State {
+//!    content: rich_editor::Content,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Edit(rich_editor::Action),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     column![
+//!         row![
+//!             button("B").on_press(Message::Edit(rich_editor::Action::Format(
+//!                 rich_editor::Format::Bold
+//!             ))),
+//!             button("H1").on_press(Message::Edit(rich_editor::Action::Format(
+//!                 rich_editor::Format::Block(rich_editor::document::Kind::Heading(1))
+//!             ))),
+//!         ],
+//!         rich_editor(&state.content)
+//!             .placeholder("Take a note...")
+//!             .on_action(Message::Edit),
+//!     ]
+//!     .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Edit(action) => {
+//!             state.content.perform(action);
+//!         }
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::clipboard::{self, Clipboard};
+use crate::core::font::{self, Font};
+use crate::core::input_method;
+use crate::core::keyboard;
+use crate::core::keyboard::key;
+use crate::core::layout::{self, Layout};
+use crate::core::mouse;
+use crate::core::renderer;
+use crate::core::text::{self, LineHeight, Paragraph as _, Span, Text, Wrapping};
+use crate::core::theme;
+use crate::core::time::{Duration, Instant};
+use crate::core::widget::operation;
+use crate::core::widget::{self, Widget};
+use crate::core::window;
+use crate::core::{
+    Background, Border, Color, Element, Event, InputMethod, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Theme, Vector,
+};
+
+pub use crate::core::text::document::{self, Document, Position};
+pub use crate::core::text::editor::Motion;
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::fmt;
+use std::ops::Range;
+use std::sync::Arc;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The font size of each heading level, relative to the text size.
+const HEADING_SCALES: [f32; 6] = [2.0, 1.5, 1.25, 1.1, 1.0, 0.9];
+
+/// The renderer of a [`RichEditor`].
+///
+/// With the `image` feature, inline images are drawn with the handles
+/// returned by [`RichEditor::image`]; otherwise, they are drawn as
+/// placeholders.
+#[cfg(feature = "image")]
+pub trait Renderer:
+    text::Renderer<Font = Font> + crate::core::image::Renderer<Handle = crate::core::image::Handle>
+{
+}
+
+#[cfg(feature = "image")]
+impl<T> Renderer for T where
+    T: text::Renderer<Font = Font>
+        + crate::core::image::Renderer<Handle = crate::core::image::Handle>
+{
+}
+
+/// The renderer of a [`RichEditor`].
+///
+/// With the `image` feature, inline images are drawn with the handles
+/// returned by `RichEditor::image`; otherwise, they are drawn as
+/// placeholders.
+#[cfg(not(feature = "image"))]
+pub trait Renderer: text::Renderer<Font = Font> {}
+
+#[cfg(not(feature = "image"))]
+impl<T> Renderer for T where T: text::Renderer<Font = Font> {}
+
+/// A multi-line editor of rich text.
+#[allow(missing_debug_implementations)]
+pub struct RichEditor<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Theme: Catalog,
+    Renderer: self::Renderer,
+{
+    id: Option<widget::Id>,
+    content: &'a Content<Renderer>,
+    placeholder: Option<text::Fragment<'a>>,
+    font: Option<Font>,
+    text_size: Option<Pixels>,
+    line_height: LineHeight,
+    width: Length,
+    height: Length,
+    padding: Padding,
+    class: Theme::Class<'a>,
+    on_edit: Option<Box<dyn Fn(Action) -> Message + 'a>>,
+    on_link_click: Option<Box<dyn Fn(String) -> Message + 'a>>,
+    #[cfg(feature = "image")]
+    image: Option<Box<dyn Fn(&document::Image) -> Option<crate::core::image::Handle> + 'a>>,
+    last_status: Option<Status>,
+}
+
+impl<'a, Message, Theme, Renderer> RichEditor<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: self::Renderer,
+{
+    /// Creates new [`RichEditor`] with the given [`Content`].
+    pub fn new(content: &'a Content<Renderer>) -> Self {
+        Self {
+            id: None,
+            content,
+            placeholder: None,
+            font: None,
+            text_size: None,
+            line_height: LineHeight::default(),
+            width: Length::Fill,
+            height: Length::Shrink,
+            padding: Padding::new(5.0),
+            class: <Theme as Catalog>::default(),
+            on_edit: None,
+            on_link_click: None,
+            #[cfg(feature = "image")]
+            image: None,
+            last_status: None,
+        }
+    }
+
+    /// Sets the [`Id`](widget::Id) of the [`RichEditor`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets the placeholder of the [`RichEditor`].
+    pub fn placeholder(mut self, placeholder: impl text::IntoFragment<'a>) -> Self {
+        self.placeholder = Some(placeholder.into_fragment());
+        self
+    }
+
+    /// Sets the width of the [`RichEditor`].
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height of the [`RichEditor`].
+    ///
+    /// By default, it grows with its [`Content`]; place it in a
+    /// [`Scrollable`](crate::Scrollable) to scroll long documents.
+    pub fn height(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the message that should be produced when some action is performed in
+    /// the [`RichEditor`].
+    ///
+    /// If this method is not called, the [`RichEditor`] will be disabled.
+    pub fn on_action(mut self, on_edit: impl Fn(Action) -> Message + 'a) -> Self {
+        self.on_edit = Some(Box::new(on_edit));
+        self
+    }
+
+    /// Sets the message that should be produced when a link is clicked while
+    /// holding the command key.
+    pub fn on_link_click(mut self, on_link_click: impl Fn(String) -> Message + 'a) -> Self {
+        self.on_link_click = Some(Box::new(on_link_click));
+        self
+    }
+
+    /// Sets the function used to load the inline images of the
+    /// [`RichEditor`] from their [`document::Image`] description.
+    ///
+    /// Images without a handle are drawn as placeholders.
+    #[cfg(feature = "image")]
+    pub fn image(
+        mut self,
+        image: impl Fn(&document::Image) -> Option<crate::core::image::Handle> + 'a,
+    ) -> Self {
+        self.image = Some(Box::new(image));
+        self
+    }
+
+    /// Sets the base [`Font`] of the [`RichEditor`].
+    pub fn font(mut self, font: impl Into<Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the base text size of the [`RichEditor`].
+    ///
+    /// Headings are scaled relative to it.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the [`text::LineHeight`] of the [`RichEditor`].
+    pub fn line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.line_height = line_height.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`RichEditor`].
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the style of the [`RichEditor`].
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`RichEditor`].
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    fn metrics(&self, renderer: &Renderer, link: Option<Color>) -> Metrics {
+        Metrics {
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: self.line_height,
+            link,
+        }
+    }
+}
+
+/// The content of a [`RichEditor`].
+pub struct Content<R = crate::Renderer>(RefCell<Internal<R>>)
+where
+    R: text::Renderer;
+
+struct Internal<R>
+where
+    R: text::Renderer,
+{
+    document: Document,
+    cursor: Position,
+    anchor: Option<Position>,
+    pending: Option<document::Style>,
+    preferred_x: Option<f32>,
+    blocks: Vec<BlockLayout<R::Paragraph>>,
+    metrics: Option<Metrics>,
+    width: f32,
+    scale_factor: Option<f32>,
+    is_dirty: bool,
+}
+
+/// The layout of a block of the [`Document`].
+struct BlockLayout<P> {
+    paragraph: P,
+    /// The position of the paragraph, relative to the text bounds.
+    position: Point,
+    marker: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Metrics {
+    font: Font,
+    size: Pixels,
+    line_height: LineHeight,
+    link: Option<Color>,
+}
+
+impl<R> Content<R>
+where
+    R: text::Renderer<Font = Font>,
+{
+    /// Creates an empty [`Content`].
+    pub fn new() -> Self {
+        Self::with_document(Document::new())
+    }
+
+    /// Creates a [`Content`] with the given plain text, one paragraph per
+    /// line.
+    pub fn with_text(text: &str) -> Self {
+        Self::with_document(Document::with_text(text))
+    }
+
+    /// Creates a [`Content`] with the given [`Document`].
+    pub fn with_document(document: Document) -> Self {
+        Self(RefCell::new(Internal {
+            document,
+            cursor: Position::default(),
+            anchor: None,
+            pending: None,
+            preferred_x: None,
+            blocks: Vec::new(),
+            metrics: None,
+            width: 0.0,
+            scale_factor: None,
+            is_dirty: true,
+        }))
+    }
+
+    /// Creates a [`Content`] from HTML.
+    pub fn from_html(html: &str) -> Self {
+        Self::with_document(Document::from_html(html))
+    }
+
+    /// Creates a [`Content`] from RTF.
+    pub fn from_rtf(rtf: &str) -> Self {
+        Self::with_document(Document::from_rtf(rtf))
+    }
+
+    /// Performs an [`Action`] on the [`Content`].
+    pub fn perform(&mut self, action: Action) {
+        self.0.get_mut().perform(action);
+    }
+
+    /// Returns a copy of the [`Document`] of the [`Content`].
+    pub fn document(&self) -> Document {
+        self.0.borrow().document.clone()
+    }
+
+    /// Returns the plain text of the [`Content`].
+    pub fn text(&self) -> String {
+        self.0.borrow().document.text()
+    }
+
+    /// Serializes the [`Content`] as HTML.
+    pub fn to_html(&self) -> String {
+        self.0.borrow().document.to_html()
+    }
+
+    /// Serializes the [`Content`] as RTF.
+    pub fn to_rtf(&self) -> String {
+        self.0.borrow().document.to_rtf()
+    }
+
+    /// Returns whether the [`Content`] contains no text at all.
+    pub fn is_empty(&self) -> bool {
+        self.0.borrow().document.is_empty()
+    }
+
+    /// Returns the selected part of the [`Document`], if any.
+    pub fn selection(&self) -> Option<Document> {
+        self.0.borrow().selected()
+    }
+
+    /// Returns the current [`Position`] of the cursor.
+    pub fn cursor(&self) -> Position {
+        self.0.borrow().cursor
+    }
+
+    /// Returns the [`document::Style`] that typed text will have, e.g. to
+    /// highlight the active buttons of a toolbar.
+    pub fn style(&self) -> document::Style {
+        self.0.borrow().typing_style()
+    }
+
+    /// Returns the [`document::Kind`] of the block containing the cursor.
+    pub fn kind(&self) -> document::Kind {
+        let internal = self.0.borrow();
+
+        internal.document.blocks()[internal.cursor.block].kind
+    }
+}
+
+impl<R> Default for Content<R>
+where
+    R: text::Renderer<Font = Font>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R> Clone for Content<R>
+where
+    R: text::Renderer<Font = Font>,
+{
+    fn clone(&self) -> Self {
+        Self::with_document(self.document())
+    }
+}
+
+impl<R> fmt::Debug for Content<R>
+where
+    R: text::Renderer,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let internal = self.0.borrow();
+
+        f.debug_struct("Content")
+            .field("document", &internal.document)
+            .field("cursor", &internal.cursor)
+            .field("anchor", &internal.anchor)
+            .finish()
+    }
+}
+
+impl<R> Internal<R>
+where
+    R: text::Renderer<Font = Font>,
+{
+    fn selection(&self) -> Option<Range<Position>> {
+        let anchor = self.anchor.filter(|anchor| *anchor != self.cursor)?;
+
+        Some(anchor.min(self.cursor)..anchor.max(self.cursor))
+    }
+
+    fn selected(&self) -> Option<Document> {
+        self.selection().map(|range| self.document.slice(range))
+    }
+
+    fn typing_style(&self) -> document::Style {
+        self.pending
+            .clone()
+            .unwrap_or_else(|| match self.selection() {
+                Some(range) => self.document.style_at(Position {
+                    offset: range.start.offset + 1,
+                    ..range.start
+                }),
+                None => self.document.style_at(self.cursor),
+            })
+    }
+
+    /// Lays out every block again if the [`Document`] or the [`Metrics`]
+    /// changed.
+    fn update(&mut self, width: f32, metrics: Metrics, scale_factor: Option<f32>) {
+        if !self.is_dirty
+            && self.metrics == Some(metrics)
+            && self.width == width
+            && self.scale_factor == scale_factor
+        {
+            return;
+        }
+
+        let size = f32::from(metrics.size);
+        let spacing = size * 0.5;
+
+        let mut blocks = Vec::with_capacity(self.document.blocks().len());
+        let mut y = 0.0;
+        let mut number = 0;
+
+        for block in self.document.blocks() {
+            if block.kind != document::Kind::Numbered {
+                number = 0;
+            }
+
+            let (scale, marker) = match block.kind {
+                document::Kind::Paragraph => (1.0, None),
+                document::Kind::Heading(level) => {
+                    (HEADING_SCALES[usize::from(level.clamp(1, 6) - 1)], None)
+                }
+                document::Kind::Bullet => (1.0, Some("•".to_owned())),
+                document::Kind::Numbered => {
+                    number += 1;
+
+                    (1.0, Some(format!("{number}.")))
+                }
+            };
+
+            let indent = if marker.is_some() { size * 2.0 } else { 0.0 };
+            let block_size = Pixels(size * scale);
+
+            let base = Font {
+                weight: if matches!(block.kind, document::Kind::Heading(_)) {
+                    font::Weight::Bold
+                } else {
+                    metrics.font.weight
+                },
+                ..metrics.font
+            };
+
+            let spans: Vec<Span<'_, (), Font>> = block
+                .runs
+                .iter()
+                .map(|run| span(run, base, block_size, metrics))
+                .collect();
+
+            let paragraph = R::Paragraph::with_spans(Text {
+                content: spans.as_slice(),
+                bounds: Size::new((width - indent).max(0.0), f32::INFINITY),
+                size: block_size,
+                line_height: metrics.line_height,
+                font: base,
+                align_x: text::Alignment::Default,
+                align_y: alignment::Vertical::Top,
+                shaping: text::Shaping::Advanced,
+                wrapping: Wrapping::WordOrGlyph,
+                truncation: text::Truncation::None,
+                hint_factor: scale_factor,
+            });
+
+            let height = paragraph
+                .min_height()
+                .max(f32::from(metrics.line_height.to_absolute(block_size)));
+
+            blocks.push(BlockLayout {
+                paragraph,
+                position: Point::new(indent, y),
+                marker,
+            });
+
+            y += height + spacing;
+        }
+
+        self.blocks = blocks;
+        self.metrics = Some(metrics);
+        self.width = width;
+        self.scale_factor = scale_factor;
+        self.is_dirty = false;
+    }
+
+    fn height(&self) -> f32 {
+        self.blocks
+            .iter()
+            .map(|block| block.position.y + block_height(block))
+            .fold(0.0, f32::max)
+    }
+
+    /// Returns the bounds of the caret at the given [`Position`], relative
+    /// to the text bounds.
+    fn caret(&self, position: Position) -> Rectangle {
+        let Some(block) = self.blocks.get(position.block) else {
+            return Rectangle::new(Point::ORIGIN, Size::new(1.0, 0.0));
+        };
+
+        let text = self.document.blocks()[position.block].text();
+        let offset = position.offset.min(text.len());
+
+        let next = text[offset..]
+            .graphemes(true)
+            .next()
+            .map(|grapheme| offset..offset + grapheme.len());
+
+        let previous = text[..offset]
+            .graphemes(true)
+            .next_back()
+            .map(|grapheme| offset - grapheme.len()..offset);
+
+        let bounds = next
+            .and_then(|range| block.paragraph.range_bounds(range).first().copied())
+            .map(|bounds| Rectangle::new(bounds.position(), Size::new(1.0, bounds.height)))
+            .or_else(|| {
+                previous
+                    .and_then(|range| block.paragraph.range_bounds(range).last().copied())
+                    .map(|bounds| {
+                        Rectangle::new(
+                            Point::new(bounds.x + bounds.width, bounds.y),
+                            Size::new(1.0, bounds.height),
+                        )
+                    })
+            })
+            .unwrap_or_else(|| {
+                Rectangle::new(Point::ORIGIN, Size::new(1.0, block_line_height(block)))
+            });
+
+        bounds + (block.position - Point::ORIGIN)
+    }
+
+    /// Returns the [`Position`] nearest to the given point, relative to the
+    /// text bounds.
+    ///
+    /// Points between two blocks belong to the next one when `prefer_next`
+    /// is set, and to the previous one otherwise.
+    fn hit(&self, point: Point, prefer_next: bool) -> Position {
+        let Some(first) = self.blocks.first() else {
+            return Position::default();
+        };
+
+        if point.y < first.position.y {
+            return Position::default();
+        }
+
+        let index = if prefer_next {
+            self.blocks
+                .iter()
+                .position(|block| point.y < block.position.y + block_height(block))
+                .unwrap_or(self.blocks.len() - 1)
+        } else {
+            self.blocks
+                .iter()
+                .rposition(|block| point.y >= block.position.y)
+                .unwrap_or(0)
+        };
+
+        let block = &self.blocks[index];
+        let height = block_height(block);
+
+        if point.y > block.position.y + height && index == self.blocks.len() - 1 {
+            return self.document.end();
+        }
+
+        let local = Point::new(
+            point.x - block.position.x,
+            (point.y - block.position.y).clamp(0.0, (height - 1.0).max(0.0)),
+        );
+
+        let offset = block
+            .paragraph
+            .hit_offset(local)
+            .unwrap_or_else(|| self.document.blocks()[index].len());
+
+        self.document.clamp(Position::new(index, offset))
+    }
+
+    fn perform(&mut self, action: Action) {
+        match action {
+            Action::Move(motion) => {
+                if let Some(range) = self.selection()
+                    && matches!(motion, Motion::Left | Motion::Right)
+                {
+                    self.cursor = if motion == Motion::Left {
+                        range.start
+                    } else {
+                        range.end
+                    };
+                } else {
+                    self.cursor = self.motion(motion);
+                }
+
+                self.anchor = None;
+                self.pending = None;
+            }
+            Action::Select(motion) => {
+                let anchor = self.anchor.unwrap_or(self.cursor);
+
+                self.cursor = self.motion(motion);
+                self.anchor = Some(anchor);
+                self.pending = None;
+            }
+            Action::SelectWord => {
+                let text = self.document.blocks()[self.cursor.block].text();
+
+                if let Some((start, word)) = text
+                    .split_word_bound_indices()
+                    .find(|(start, word)| self.cursor.offset < start + word.len())
+                    .or_else(|| text.split_word_bound_indices().next_back())
+                {
+                    self.anchor = Some(Position::new(self.cursor.block, start));
+                    self.cursor = Position::new(self.cursor.block, start + word.len());
+                }
+
+                self.pending = None;
+            }
+            Action::SelectLine => {
+                let block = self.cursor.block;
+
+                self.anchor = Some(Position::new(block, 0));
+                self.cursor = Position::new(block, self.document.blocks()[block].len());
+                self.pending = None;
+            }
+            Action::SelectAll => {
+                self.anchor = Some(Position::default());
+                self.cursor = self.document.end();
+                self.pending = None;
+            }
+            Action::Click(point) => {
+                self.cursor = self.hit(point, false);
+                self.anchor = None;
+                self.pending = None;
+                self.preferred_x = None;
+            }
+            Action::Drag(point) => {
+                let anchor = self.anchor.unwrap_or(self.cursor);
+
+                self.cursor = self.hit(point, false);
+                self.anchor = Some(anchor);
+                self.pending = None;
+                self.preferred_x = None;
+            }
+            Action::Edit(edit) => {
+                self.edit(edit);
+                self.preferred_x = None;
+                self.is_dirty = true;
+            }
+            Action::Format(format) => {
+                self.format(format);
+                self.is_dirty = true;
+            }
+        }
+    }
+
+    fn motion(&mut self, motion: Motion) -> Position {
+        let cursor = self.cursor;
+        let text = self.document.blocks()[cursor.block].text();
+        let blocks = self.document.blocks().len();
+
+        if !matches!(
+            motion,
+            Motion::Up | Motion::Down | Motion::PageUp | Motion::PageDown
+        ) {
+            self.preferred_x = None;
+        }
+
+        match motion {
+            Motion::Left => match text[..cursor.offset].graphemes(true).next_back() {
+                Some(grapheme) => Position::new(cursor.block, cursor.offset - grapheme.len()),
+                None if cursor.block > 0 => Position::new(
+                    cursor.block - 1,
+                    self.document.blocks()[cursor.block - 1].len(),
+                ),
+                None => cursor,
+            },
+            Motion::Right => match text[cursor.offset..].graphemes(true).next() {
+                Some(grapheme) => Position::new(cursor.block, cursor.offset + grapheme.len()),
+                None if cursor.block + 1 < blocks => Position::new(cursor.block + 1, 0),
+                None => cursor,
+            },
+            Motion::WordLeft => {
+                if cursor.offset == 0 {
+                    return self.motion(Motion::Left);
+                }
+
+                let start = text
+                    .split_word_bound_indices()
+                    .rfind(|(start, word)| *start < cursor.offset && !word.trim().is_empty())
+                    .map_or(0, |(start, _)| start);
+
+                Position::new(cursor.block, start)
+            }
+            Motion::WordRight => {
+                if cursor.offset == text.len() {
+                    return self.motion(Motion::Right);
+                }
+
+                let end = text
+                    .split_word_bound_indices()
+                    .find(|(start, word)| {
+                        start + word.len() > cursor.offset && !word.trim().is_empty()
+                    })
+                    .map_or(text.len(), |(start, word)| start + word.len());
+
+                Position::new(cursor.block, end)
+            }
+            Motion::Home | Motion::End => {
+                let caret = self.caret(cursor);
+                let x = if motion == Motion::Home {
+                    0.0
+                } else {
+                    f32::MAX
+                };
+
+                self.hit(Point::new(x, caret.center_y()), false)
+            }
+            Motion::Up | Motion::Down | Motion::PageUp | Motion::PageDown => {
+                let caret = self.caret(cursor);
+                let x = *self.preferred_x.get_or_insert(caret.x);
+
+                let distance = match motion {
+                    Motion::PageUp | Motion::PageDown => caret.height * 20.0,
+                    _ => 1.0,
+                };
+
+                match motion {
+                    Motion::Up | Motion::PageUp => {
+                        if caret.y - distance < 0.0 {
+                            Position::default()
+                        } else {
+                            self.hit(Point::new(x, caret.y - distance), false)
+                        }
+                    }
+                    _ => {
+                        let y = caret.y + caret.height + distance;
+
+                        if y >= self.height() {
+                            self.document.end()
+                        } else {
+                            self.hit(Point::new(x, y), true)
+                        }
+                    }
+                }
+            }
+            Motion::DocumentStart => Position::default(),
+            Motion::DocumentEnd => self.document.end(),
+        }
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        let Some(range) = self.selection() else {
+            return false;
+        };
+
+        self.document.delete(range.clone());
+        self.cursor = range.start;
+        self.anchor = None;
+
+        true
+    }
+
+    fn edit(&mut self, edit: Edit) {
+        match edit {
+            Edit::Insert(c) => {
+                let style = self.typing_style();
+
+                let _ = self.delete_selection();
+                self.cursor = self
+                    .document
+                    .insert(self.cursor, c.encode_utf8(&mut [0; 4]), &style);
+            }
+            Edit::Paste(document) => {
+                let _ = self.delete_selection();
+                self.cursor = self.document.insert_document(self.cursor, &document);
+                self.pending = None;
+            }
+            Edit::Enter => {
+                let _ = self.delete_selection();
+
+                let block = &self.document.blocks()[self.cursor.block];
+
+                // Enter on an empty list item ends the list
+                if block.is_empty()
+                    && matches!(
+                        block.kind,
+                        document::Kind::Bullet | document::Kind::Numbered
+                    )
+                {
+                    let index = self.cursor.block;
+
+                    self.document
+                        .set_kind(index..index + 1, document::Kind::Paragraph);
+                } else {
+                    self.cursor = self.document.split(self.cursor);
+                }
+            }
+            Edit::Backspace => {
+                if self.delete_selection() {
+                    return;
+                }
+
+                let block = &self.document.blocks()[self.cursor.block];
+
+                // Backspace at the start of a list item or heading turns it
+                // into a paragraph first
+                if self.cursor.offset == 0 && block.kind != document::Kind::Paragraph {
+                    let index = self.cursor.block;
+
+                    self.document
+                        .set_kind(index..index + 1, document::Kind::Paragraph);
+
+                    return;
+                }
+
+                let start = self.motion(Motion::Left);
+
+                self.document.delete(start..self.cursor);
+                self.cursor = start;
+            }
+            Edit::Delete => {
+                if self.delete_selection() {
+                    return;
+                }
+
+                let end = self.motion(Motion::Right);
+
+                self.document.delete(self.cursor..end);
+            }
+        }
+
+        self.anchor = None;
+    }
+
+    fn format(&mut self, format: Format) {
+        let selection = self.selection();
+
+        let toggle = |internal: &mut Self,
+                      get: fn(&document::Style) -> bool,
+                      set: fn(&mut document::Style, bool)| {
+            match internal.selection() {
+                Some(range) => {
+                    let value = !internal.document.is_styled(range.clone(), get);
+
+                    internal
+                        .document
+                        .update_style(range, |style| set(style, value));
+                }
+                None => {
+                    let mut style = internal.typing_style();
+                    let value = !get(&style);
+
+                    set(&mut style, value);
+                    internal.pending = Some(style);
+                }
+            }
+        };
+
+        match format {
+            Format::Bold => toggle(self, |style| style.bold, |style, value| style.bold = value),
+            Format::Italic => toggle(
+                self,
+                |style| style.italic,
+                |style, value| style.italic = value,
+            ),
+            Format::Underline => toggle(
+                self,
+                |style| style.underline,
+                |style, value| style.underline = value,
+            ),
+            Format::Strikethrough => toggle(
+                self,
+                |style| style.strikethrough,
+                |style, value| style.strikethrough = value,
+            ),
+            Format::Color(color) => match selection {
+                Some(range) => self
+                    .document
+                    .update_style(range, |style| style.color = color),
+                None => {
+                    let mut style = self.typing_style();
+                    style.color = color;
+
+                    self.pending = Some(style);
+                }
+            },
+            Format::Link(link) => match selection {
+                Some(range) => self
+                    .document
+                    .update_style(range, |style| style.link.clone_from(&link)),
+                None => {
+                    let mut style = self.typing_style();
+                    style.link = link;
+
+                    self.pending = Some(style);
+                }
+            },
+            Format::Block(kind) => {
+                let range = selection.unwrap_or(self.cursor..self.cursor);
+                let blocks = range.start.block..range.end.block + 1;
+
+                let is_applied = self.document.blocks()[blocks.clone()]
+                    .iter()
+                    .all(|block| block.kind == kind);
+
+                self.document.set_kind(
+                    blocks,
+                    if is_applied {
+                        document::Kind::Paragraph
+                    } else {
+                        kind
+                    },
+                );
+            }
+            Format::Image(image) => {
+                let style = self.typing_style();
+
+                let _ = self.delete_selection();
+                self.cursor = self.document.insert_image(self.cursor, image, &style);
+                self.anchor = None;
+            }
+        }
+    }
+}
+
+fn span(run: &document::Run, font: Font, size: Pixels, metrics: Metrics) -> Span<'_, (), Font> {
+    if let Some(image) = &run.image {
+        // An em space is as wide as its font size, reserving a square
+        // where the image is drawn
+        return Span::new("\u{2003}")
+            .size(image_size(image, metrics))
+            .line_height(LineHeight::Relative(1.0))
+            .color(Color::TRANSPARENT);
+    }
+
+    let style = &run.style;
+
+    let font = Font {
+        weight: if style.bold {
+            font::Weight::Bold
+        } else {
+            font.weight
+        },
+        style: if style.italic {
+            font::Style::Italic
+        } else {
+            font.style
+        },
+        ..font
+    };
+
+    let color = style.color.or(style.link.as_ref().and(metrics.link));
+
+    Span::new(run.text.as_str())
+        .font(font)
+        .size(size)
+        .color_maybe(color)
+        .underline(style.underline || style.link.is_some())
+        .strikethrough(style.strikethrough)
+}
+
+fn image_size(image: &document::Image, metrics: Metrics) -> f32 {
+    image
+        .height
+        .or(image.width)
+        .unwrap_or(f32::from(metrics.size) * 4.0)
+}
+
+fn block_height<P: text::Paragraph>(block: &BlockLayout<P>) -> f32 {
+    block.paragraph.min_height().max(block_line_height(block))
+}
+
+fn block_line_height<P: text::Paragraph>(block: &BlockLayout<P>) -> f32 {
+    f32::from(
+        block
+            .paragraph
+            .line_height()
+            .to_absolute(block.paragraph.size()),
+    )
+}
+
+/// An interaction with a [`RichEditor`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    /// Apply a [`Motion`].
+    Move(Motion),
+    /// Select text with a given [`Motion`].
+    Select(Motion),
+    /// Select the word at the current cursor.
+    SelectWord,
+    /// Select the block at the current cursor.
+    SelectLine,
+    /// Select the entire document.
+    SelectAll,
+    /// Perform an [`Edit`].
+    Edit(Edit),
+    /// Apply a [`Format`] to the selection, or to the text typed next.
+    Format(Format),
+    /// Click the [`RichEditor`] at the given [`Point`].
+    Click(Point),
+    /// Drag the mouse on the [`RichEditor`] to the given [`Point`].
+    Drag(Point),
+}
+
+impl Action {
+    /// Returns whether the [`Action`] may change the [`Document`].
+    pub fn is_edit(&self) -> bool {
+        matches!(self, Self::Edit(_) | Self::Format(_))
+    }
+}
+
+/// An edition in a [`RichEditor`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Edit {
+    /// Insert the given character.
+    Insert(char),
+    /// Paste the given [`Document`].
+    Paste(Arc<Document>),
+    /// Break the current block.
+    Enter,
+    /// Delete the previous character.
+    Backspace,
+    /// Delete the next character.
+    Delete,
+}
+
+/// A formatting change in a [`RichEditor`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Format {
+    /// Toggle bold text.
+    Bold,
+    /// Toggle italic text.
+    Italic,
+    /// Toggle underlined text.
+    Underline,
+    /// Toggle struck through text.
+    Strikethrough,
+    /// Set the [`Color`] of the text, or reset it.
+    Color(Option<Color>),
+    /// Link the text to the given URL, or remove its link.
+    Link(Option<String>),
+    /// Turn the selected blocks into the given [`document::Kind`], or back
+    /// into paragraphs if they already are.
+    Block(document::Kind),
+    /// Insert an inline [`document::Image`].
+    Image(document::Image),
+}
+
+/// The state of a [`RichEditor`].
+#[derive(Debug, Default)]
+pub struct State {
+    focus: Option<Focus>,
+    preedit: Option<input_method::Preedit>,
+    last_click: Option<mouse::Click>,
+    is_dragging: bool,
+}
+
+#[derive(Debug, Clone)]
+struct Focus {
+    updated_at: Instant,
+    now: Instant,
+    is_window_focused: bool,
+}
+
+impl Focus {
+    const CURSOR_BLINK_INTERVAL_MILLIS: u128 = 500;
+
+    fn now() -> Self {
+        let now = Instant::now();
+
+        Self {
+            updated_at: now,
+            now,
+            is_window_focused: true,
+        }
+    }
+
+    fn is_cursor_visible(&self) -> bool {
+        self.is_window_focused
+            && ((self.now - self.updated_at).as_millis() / Self::CURSOR_BLINK_INTERVAL_MILLIS)
+                .is_multiple_of(2)
+    }
+}
+
+impl State {
+    /// Returns whether the [`RichEditor`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.focus.is_some()
+    }
+}
+
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        self.focus.is_some()
+    }
+
+    fn focus(&mut self) {
+        self.focus = Some(Focus::now());
+    }
+
+    fn unfocus(&mut self) {
+        self.focus = None;
+    }
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for RichEditor<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: self::Renderer,
+{
+    fn tag(&self) -> widget::tree::Tag {
+        widget::tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> widget::tree::State {
+        widget::tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut widget::Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let mut internal = self.content.0.borrow_mut();
+        let limits = limits.width(self.width).height(self.height);
+
+        let link = internal.metrics.and_then(|metrics| metrics.link);
+
+        internal.update(
+            limits.shrink(self.padding).max().width,
+            self.metrics(renderer, link),
+            renderer.scale_factor(),
+        );
+
+        match self.height {
+            Length::Fill | Length::FillPortion(_) | Length::Fixed(_) => {
+                layout::Node::new(limits.max())
+            }
+            Length::Shrink => layout::Node::new(
+                limits
+                    .height(internal.height())
+                    .max()
+                    .expand(Size::new(0.0, self.padding.y())),
+            ),
+        }
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut widget::Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let Some(on_edit) = self.on_edit.as_ref() else {
+            return;
+        };
+
+        let state = tree.state.downcast_mut::<State>();
+        let bounds = layout.bounds();
+        let text_bounds = bounds.shrink(self.padding);
+
+        match event {
+            Event::Window(window::Event::Unfocused) => {
+                if let Some(focus) = &mut state.focus {
+                    focus.is_window_focused = false;
+                }
+            }
+            Event::Window(window::Event::Focused) => {
+                if let Some(focus) = &mut state.focus {
+                    focus.is_window_focused = true;
+                    focus.updated_at = Instant::now();
+
+                    shell.request_redraw();
+                }
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if let Some(focus) = &mut state.focus
+                    && focus.is_window_focused
+                {
+                    focus.now = *now;
+
+                    let millis_until_redraw = Focus::CURSOR_BLINK_INTERVAL_MILLIS
+                        - (focus.now - focus.updated_at).as_millis()
+                            % Focus::CURSOR_BLINK_INTERVAL_MILLIS;
+
+                    shell.request_redraw_at(
+                        focus.now + Duration::from_millis(millis_until_redraw as u64),
+                    );
+                }
+
+                shell.request_input_method(&self.input_method(state, layout));
+            }
+            Event::Mouse(mouse::Event::ButtonPressed {
+                button: mouse::Button::Left,
+                modifiers,
+                ..
+            }) => {
+                let Some(position) = cursor.position_in(bounds) else {
+                    state.focus = None;
+                    state.is_dragging = false;
+                    return;
+                };
+
+                let point = position - Vector::new(self.padding.left, self.padding.top);
+                let click = mouse::Click::new(point, mouse::Button::Left, state.last_click);
+
+                if modifiers.command()
+                    && let Some(on_link_click) = &self.on_link_click
+                    && let Some(link) = self.link_at(point)
+                {
+                    shell.publish(on_link_click(link));
+                    shell.capture_event();
+                    return;
+                }
+
+                match click.kind() {
+                    mouse::click::Kind::Single if modifiers.shift() => {
+                        shell.publish(on_edit(Action::Drag(point)));
+                    }
+                    mouse::click::Kind::Single => {
+                        shell.publish(on_edit(Action::Click(point)));
+                    }
+                    mouse::click::Kind::Double => {
+                        shell.publish(on_edit(Action::Click(point)));
+                        shell.publish(on_edit(Action::SelectWord));
+                    }
+                    mouse::click::Kind::Triple => {
+                        shell.publish(on_edit(Action::SelectLine));
+                    }
+                }
+
+                state.focus = Some(Focus::now());
+                state.last_click = Some(click);
+                state.is_dragging = click.kind() == mouse::click::Kind::Single;
+
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonReleased {
+                button: mouse::Button::Left,
+                ..
+            }) => {
+                state.is_dragging = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) if state.is_dragging => {
+                if let Some(position) = cursor.position() {
+                    let point = position - (text_bounds.position() - Point::ORIGIN);
+
+                    shell.publish(on_edit(Action::Drag(point)));
+                    shell.capture_event();
+                }
+            }
+            Event::InputMethod(event) => match event {
+                input_method::Event::Opened | input_method::Event::Closed => {
+                    state.preedit = matches!(event, input_method::Event::Opened)
+                        .then(input_method::Preedit::new);
+
+                    shell.request_redraw();
+                }
+                input_method::Event::Preedit(content, selection) if state.focus.is_some() => {
+                    state.preedit = Some(input_method::Preedit {
+                        content: content.clone(),
+                        selection: selection.clone(),
+                        text_size: self.text_size,
+                    });
+
+                    shell.request_redraw();
+                }
+                input_method::Event::Commit(text) if state.focus.is_some() => {
+                    let mut characters = text.chars();
+
+                    let edit = match (characters.next(), characters.next()) {
+                        (None, _) => None,
+                        (Some(c), None) => Some(Edit::Insert(c)),
+                        _ => Some(Edit::Paste(Arc::new(Document::with_text(text)))),
+                    };
+
+                    if let Some(edit) = edit {
+                        shell.publish(on_edit(Action::Edit(edit)));
+                    }
+
+                    shell.capture_event();
+                }
+                _ => {}
+            },
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key,
+                modified_key,
+                physical_key,
+                modifiers,
+                text,
+                ..
+            }) if state.focus.is_some() => {
+                if state
+                    .preedit
+                    .as_ref()
+                    .is_some_and(|preedit| !preedit.content.is_empty())
+                {
+                    return;
+                }
+
+                let actions = match key.to_latin(*physical_key) {
+                    Some('c') if modifiers.command() => {
+                        let _ = self.copy(clipboard);
+                        Vec::new()
+                    }
+                    Some('x') if modifiers.command() => {
+                        if self.copy(clipboard) {
+                            vec![Action::Edit(Edit::Delete)]
+                        } else {
+                            Vec::new()
+                        }
+                    }
+                    Some('v') if modifiers.command() && !modifiers.alt() => paste(clipboard)
+                        .map(|document| vec![Action::Edit(Edit::Paste(Arc::new(document)))])
+                        .unwrap_or_default(),
+                    Some('a') if modifiers.command() => vec![Action::SelectAll],
+                    Some('b') if modifiers.command() => vec![Action::Format(Format::Bold)],
+                    Some('i') if modifiers.command() => vec![Action::Format(Format::Italic)],
+                    Some('u') if modifiers.command() => vec![Action::Format(Format::Underline)],
+                    _ => key_press(key, modified_key, *modifiers, text.as_deref()),
+                };
+
+                if actions.is_empty() {
+                    if matches!(
+                        modified_key.as_ref(),
+                        keyboard::Key::Named(key::Named::Escape)
+                    ) {
+                        state.focus = None;
+                        state.is_dragging = false;
+                    }
+
+                    return;
+                }
+
+                for action in actions {
+                    shell.publish(on_edit(action));
+                }
+
+                if let Some(focus) = &mut state.focus {
+                    focus.updated_at = Instant::now();
+                }
+
+                shell.capture_event();
+            }
+            _ => {}
+        }
+
+        let status = if self.on_edit.is_none() {
+            Status::Disabled
+        } else if state.focus.is_some() {
+            Status::Focused {
+                is_hovered: cursor.is_over(bounds),
+            }
+        } else if cursor.is_over(bounds) {
+            Status::Hovered
+        } else {
+            Status::Active
+        };
+
+        if let Event::Window(window::Event::RedrawRequested(_)) = event {
+            self.last_status = Some(status);
+        } else if self
+            .last_status
+            .is_some_and(|last_status| status != last_status)
+        {
+            shell.request_redraw();
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &widget::Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state = tree.state.downcast_ref::<State>();
+        let style = theme.style(&self.class, self.last_status.unwrap_or(Status::Active));
+
+        let mut internal = self.content.0.borrow_mut();
+        let text_bounds = bounds.shrink(self.padding);
+
+        // Links are colored by the theme
+        internal.update(
+            text_bounds.width,
+            self.metrics(renderer, Some(style.link)),
+            renderer.scale_factor(),
+        );
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: style.border,
+                ..renderer::Quad::default()
+            },
+            style.background,
+        );
+
+        let Some(clip_bounds) = text_bounds.intersection(viewport) else {
+            return;
+        };
+
+        let translation = text_bounds.position() - Point::ORIGIN;
+        let selection = internal.selection();
+
+        if internal.document.is_empty()
+            && internal.document.blocks().len() == 1
+            && let Some(placeholder) = &self.placeholder
+        {
+            let metrics = self.metrics(renderer, None);
+
+            renderer.fill_text(
+                Text {
+                    content: placeholder.to_string(),
+                    bounds: text_bounds.size(),
+                    size: metrics.size,
+                    line_height: self.line_height,
+                    font: metrics.font,
+                    align_x: text::Alignment::Default,
+                    align_y: alignment::Vertical::Top,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: Wrapping::WordOrGlyph,
+                    truncation: text::Truncation::None,
+                    hint_factor: renderer.scale_factor(),
+                },
+                text_bounds.position(),
+                style.placeholder,
+                clip_bounds,
+            );
+        }
+
+        for (index, (block, layout)) in internal
+            .document
+            .blocks()
+            .iter()
+            .zip(&internal.blocks)
+            .enumerate()
+        {
+            let position = layout.position + translation;
+            let height = block_height(layout);
+
+            if position.y > clip_bounds.y + clip_bounds.height
+                || position.y + height < clip_bounds.y
+            {
+                continue;
+            }
+
+            if let Some(range) = &selection
+                && (range.start.block..=range.end.block).contains(&index)
+            {
+                let start = if index == range.start.block {
+                    range.start.offset
+                } else {
+                    0
+                };
+
+                let end = if index == range.end.block {
+                    range.end.offset
+                } else {
+                    block.len()
+                };
+
+                let mut regions = layout.paragraph.range_bounds(start..end);
+
+                // Show selected line breaks
+                if index != range.end.block {
+                    let caret = internal.caret(Position::new(index, block.len()));
+
+                    regions.push(Rectangle::new(
+                        caret.position() - (layout.position - Point::ORIGIN),
+                        Size::new(layout.paragraph.size().0 / 3.0, caret.height),
+                    ));
+                }
+
+                for region in regions {
+                    if let Some(region) =
+                        clip_bounds.intersection(&(region + (position - Point::ORIGIN)))
+                    {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: region,
+                                ..renderer::Quad::default()
+                            },
+                            style.selection,
+                        );
+                    }
+                }
+            }
+
+            if let Some(marker) = &layout.marker {
+                renderer.fill_text(
+                    Text {
+                        content: marker.clone(),
+                        bounds: Size::new(layout.position.x, f32::INFINITY),
+                        size: layout.paragraph.size(),
+                        line_height: layout.paragraph.line_height(),
+                        font: layout.paragraph.font(),
+                        align_x: text::Alignment::Right,
+                        align_y: alignment::Vertical::Top,
+                        shaping: text::Shaping::Advanced,
+                        wrapping: Wrapping::None,
+                        truncation: text::Truncation::None,
+                        hint_factor: renderer.scale_factor(),
+                    },
+                    Point::new(
+                        text_bounds.x + layout.position.x - layout.paragraph.size().0 * 0.5,
+                        position.y,
+                    ),
+                    style.value,
+                    clip_bounds,
+                );
+            }
+
+            for (run_index, run) in block.runs.iter().enumerate() {
+                let regions = layout.paragraph.span_bounds(run_index);
+
+                if let Some(image) = &run.image {
+                    for region in regions {
+                        self.draw_image(
+                            renderer,
+                            image,
+                            region + (position - Point::ORIGIN),
+                            clip_bounds,
+                            &style,
+                        );
+                    }
+
+                    continue;
+                }
+
+                let run_style = &run.style;
+
+                if !(run_style.underline || run_style.strikethrough || run_style.link.is_some()) {
+                    continue;
+                }
+
+                let size = layout.paragraph.size();
+                let line_height = layout.paragraph.line_height().to_absolute(size);
+                let color = run_style
+                    .color
+                    .or(run_style.link.as_ref().map(|_| style.link))
+                    .unwrap_or(style.value);
+
+                let baseline = Vector::new(0.0, size.0 + (line_height.0 - size.0) / 2.0);
+
+                for region in regions {
+                    let origin = region.position() + (position - Point::ORIGIN) + baseline;
+
+                    let mut lines = Vec::new();
+
+                    if run_style.underline || run_style.link.is_some() {
+                        lines.push(origin - Vector::new(0.0, size.0 * 0.08));
+                    }
+
+                    if run_style.strikethrough {
+                        lines.push(origin - Vector::new(0.0, size.0 / 2.0 - size.0 * 0.1));
+                    }
+
+                    for line in lines {
+                        if let Some(line) = clip_bounds
+                            .intersection(&Rectangle::new(line, Size::new(region.width, 1.0)))
+                        {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: line,
+                                    ..renderer::Quad::default()
+                                },
+                                color,
+                            );
+                        }
+                    }
+                }
+            }
+
+            renderer.fill_paragraph(&layout.paragraph, position, style.value, clip_bounds);
+        }
+
+        if let Some(focus) = &state.focus
+            && selection.is_none()
+            && focus.is_cursor_visible()
+        {
+            let width = if renderer::CRISP {
+                (1.0 / renderer.scale_factor().unwrap_or(1.0)).max(1.0)
+            } else {
+                1.0
+            };
+
+            let caret = internal.caret(internal.cursor) + translation;
+
+            if let Some(caret) = clip_bounds.intersection(&Rectangle::new(
+                caret.position(),
+                Size::new(width, caret.height),
+            )) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: caret,
+                        ..renderer::Quad::default()
+                    },
+                    style.value,
+                );
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &widget::Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let is_disabled = self.on_edit.is_none();
+
+        if cursor.is_over(layout.bounds()) {
+            if is_disabled {
+                mouse::Interaction::NotAllowed
+            } else {
+                mouse::Interaction::Text
+            }
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut widget::Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        #[cfg(feature = "accessibility")]
+        if let Some(info) =
+            <Self as Widget<Message, Theme, Renderer>>::accessibility(self, tree, layout)
+        {
+            operation.accessibility(self.id.as_ref(), layout.bounds(), info);
+        }
+
+        let state = tree.state.downcast_mut::<State>();
+
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+    }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility(
+        &self,
+        _tree: &widget::Tree,
+        layout: Layout<'_>,
+    ) -> Option<crate::core::accessibility::WidgetInfo> {
+        use crate::core::accessibility::WidgetInfo;
+
+        let internal = self.content.0.borrow();
+
+        // Map positions to character indices of the plain text, where every
+        // block is followed by a line break
+        let character_index = |position: Position| {
+            let blocks = internal.document.blocks();
+
+            let before: usize = blocks[..position.block]
+                .iter()
+                .map(|block| block.text().chars().count() + 1)
+                .sum();
+
+            before
+                + blocks[position.block]
+                    .text()
+                    .get(..position.offset)
+                    .map_or(0, |text| text.chars().count())
+        };
+
+        let value = internal
+            .document
+            .blocks()
+            .iter()
+            .map(document::Block::text)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let end = character_index(internal.cursor);
+        let start = internal.anchor.map_or(end, character_index);
+
+        let mut info = WidgetInfo::text_editor(value)
+            .with_bounds(layout.bounds())
+            .with_enabled(self.on_edit.is_some())
+            .with_selection(start, end);
+
+        if let Some(placeholder) = &self.placeholder {
+            info = info.with_placeholder(placeholder.as_ref());
+        }
+
+        Some(info)
+    }
+}
+
+impl<Message, Theme, Renderer> RichEditor<'_, Message, Theme, Renderer>
+where
+    Theme: Catalog,
+    Renderer: self::Renderer,
+{
+    fn input_method<'b>(&self, state: &'b State, layout: Layout<'_>) -> InputMethod<&'b str> {
+        let Some(Focus {
+            is_window_focused: true,
+            ..
+        }) = &state.focus
+        else {
+            return InputMethod::Disabled;
+        };
+
+        let internal = self.content.0.borrow();
+        let text_bounds = layout.bounds().shrink(self.padding);
+
+        let caret = internal.caret(internal.cursor) + (text_bounds.position() - Point::ORIGIN);
+
+        InputMethod::Enabled {
+            cursor: caret,
+            purpose: input_method::Purpose::Normal,
+            preedit: state.preedit.as_ref().map(input_method::Preedit::as_ref),
+        }
+    }
+
+    /// Returns the link under the given point, relative to the text bounds.
+    fn link_at(&self, point: Point) -> Option<String> {
+        let internal = self.content.0.borrow();
+        let position = internal.hit(point, false);
+
+        let block = internal.blocks.get(position.block)?;
+        let runs = &internal.document.blocks()[position.block].runs;
+
+        let local = point - (block.position - Point::ORIGIN);
+
+        let run = block.paragraph.hit_span(local)?;
+
+        runs.get(run)?.style.link.clone()
+    }
+
+    /// Writes the selection to the clipboard as HTML, RTF and plain text.
+    ///
+    /// Returns whether anything was selected.
+    fn copy(&self, clipboard: &mut dyn Clipboard) -> bool {
+        let Some(selection) = self.content.0.borrow().selected() else {
+            return false;
+        };
+
+        let html = selection.to_html();
+        let rtf = selection.to_rtf();
+        let text = selection.text();
+
+        clipboard.write_multi(
+            clipboard::Kind::Standard,
+            &[
+                (
+                    Cow::Borrowed(html.as_bytes()),
+                    clipboard::Format::Html.formats(),
+                ),
+                (
+                    Cow::Borrowed(rtf.as_bytes()),
+                    clipboard::Format::Rtf.formats(),
+                ),
+                (
+                    Cow::Borrowed(text.as_bytes()),
+                    clipboard::Format::Text.formats(),
+                ),
+            ],
+        );
+
+        true
+    }
+
+    fn draw_image(
+        &self,
+        renderer: &mut Renderer,
+        image: &document::Image,
+        bounds: Rectangle,
+        clip_bounds: Rectangle,
+        style: &Style,
+    ) {
+        #[cfg(feature = "image")]
+        if let Some(handle) = self.image.as_ref().and_then(|image_fn| image_fn(image)) {
+            let size = crate::core::image::Renderer::measure_image(renderer, &handle)
+                .map_or(bounds.size(), |size| {
+                    Size::new(size.width as f32, size.height as f32)
+                });
+
+            let fitted = crate::core::ContentFit::Contain.fit(size, bounds.size());
+
+            let drawing = Rectangle::new(
+                bounds.center() - Vector::new(fitted.width / 2.0, fitted.height / 2.0),
+                fitted,
+            );
+
+            crate::core::image::Renderer::draw_image(
+                renderer,
+                crate::core::image::Image::new(handle),
+                drawing,
+                clip_bounds,
+            );
+
+            return;
+        }
+
+        let _ = image;
+
+        if let Some(bounds) = clip_bounds.intersection(&bounds) {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border: Border {
+                        color: style.placeholder,
+                        width: 1.0,
+                        radius: 2.0.into(),
+                    },
+                    ..renderer::Quad::default()
+                },
+                style.selection,
+            );
+        }
+    }
+}
+
+/// Reads a [`Document`] from the clipboard, preferring HTML and RTF over
+/// plain text.
+fn paste(clipboard: &dyn Clipboard) -> Option<Document> {
+    if let Some(html) = clipboard.read_html(clipboard::Kind::Standard) {
+        return Some(Document::from_html(&html));
+    }
+
+    if let Some(rtf) = clipboard
+        .read(clipboard::Kind::Standard, clipboard::Format::Rtf.formats())
+        .and_then(clipboard::ClipboardData::into_text)
+    {
+        return Some(Document::from_rtf(&rtf));
+    }
+
+    clipboard
+        .read_text(clipboard::Kind::Standard)
+        .map(|text| Document::with_text(&text))
+}
+
+fn key_press(
+    key: &keyboard::Key,
+    modified_key: &keyboard::Key,
+    modifiers: keyboard::Modifiers,
+    text: Option<&str>,
+) -> Vec<Action> {
+    #[cfg(target_os = "macos")]
+    let modified_key = &crate::text_editor::convert_macos_shortcut(key, modifiers)
+        .unwrap_or_else(|| modified_key.clone());
+
+    match modified_key.as_ref() {
+        keyboard::Key::Named(key::Named::Enter) => vec![Action::Edit(Edit::Enter)],
+        keyboard::Key::Named(key::Named::Backspace) if modifiers.jump() => vec![
+            Action::Select(Motion::WordLeft),
+            Action::Edit(Edit::Backspace),
+        ],
+        keyboard::Key::Named(key::Named::Backspace) => vec![Action::Edit(Edit::Backspace)],
+        keyboard::Key::Named(key::Named::Delete) if modifiers.jump() => vec![
+            Action::Select(Motion::WordRight),
+            Action::Edit(Edit::Delete),
+        ],
+        keyboard::Key::Named(key::Named::Delete) if text.is_none() || text == Some("\u{7f}") => {
+            vec![Action::Edit(Edit::Delete)]
+        }
+        keyboard::Key::Named(key::Named::Escape) => Vec::new(),
+        _ => {
+            if let Some(c) = text.and_then(|text| text.chars().find(|c| !c.is_control())) {
+                return vec![Action::Edit(Edit::Insert(c))];
+            }
+
+            let keyboard::Key::Named(named) = key.as_ref() else {
+                return Vec::new();
+            };
+
+            let Some(motion) = motion(named) else {
+                return Vec::new();
+            };
+
+            let motion = if modifiers.macos_command() {
+                match motion {
+                    Motion::Left => Motion::Home,
+                    Motion::Right => Motion::End,
+                    _ => motion,
+                }
+            } else {
+                motion
+            };
+
+            let motion = if modifiers.jump() {
+                motion.widen()
+            } else {
+                motion
+            };
+
+            vec![if modifiers.shift() {
+                Action::Select(motion)
+            } else {
+                Action::Move(motion)
+            }]
+        }
+    }
+}
+
+fn motion(key: key::Named) -> Option<Motion> {
+    match key {
+        key::Named::ArrowLeft => Some(Motion::Left),
+        key::Named::ArrowRight => Some(Motion::Right),
+        key::Named::ArrowUp => Some(Motion::Up),
+        key::Named::ArrowDown => Some(Motion::Down),
+        key::Named::Home => Some(Motion::Home),
+        key::Named::End => Some(Motion::End),
+        key::Named::PageUp => Some(Motion::PageUp),
+        key::Named::PageDown => Some(Motion::PageDown),
+        _ => None,
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<RichEditor<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: Catalog + 'a,
+    Renderer: self::Renderer + 'a,
+{
+    fn from(rich_editor: RichEditor<'a, Message, Theme, Renderer>) -> Self {
+        Self::new(rich_editor)
+    }
+}
+
+/// The possible status of a [`RichEditor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The [`RichEditor`] can be interacted with.
+    Active,
+    /// The [`RichEditor`] is being hovered.
+    Hovered,
+    /// The [`RichEditor`] is focused.
+    Focused {
+        /// Whether the [`RichEditor`] is hovered, while focused.
+        is_hovered: bool,
+    },
+    /// The [`RichEditor`] cannot be interacted with.
+    Disabled,
+}
+
+/// The appearance of a rich text editor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the rich text editor.
+    pub background: Background,
+    /// The [`Border`] of the rich text editor.
+    pub border: Border,
+    /// The [`Color`] of the placeholder of the rich text editor.
+    pub placeholder: Color,
+    /// The default [`Color`] of the text of the rich text editor.
+    pub value: Color,
+    /// The [`Color`] of the selection of the rich text editor.
+    pub selection: Color,
+    /// The [`Color`] of the links of the rich text editor.
+    pub link: Color,
+}
+
+/// The theme catalog of a [`RichEditor`].
+pub trait Catalog: theme::Base {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style;
+}
+
+/// A styling function for a [`RichEditor`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>, status: Status) -> Style {
+        class(self, status)
+    }
+}
+
+/// The default style of a [`RichEditor`].
+pub fn default(theme: &Theme, status: Status) -> Style {
+    let active = Style {
+        background: Background::Color(theme.background.base),
+        border: Border {
+            radius: 2.0.into(),
+            width: 1.0,
+            color: theme.background.divider,
+        },
+        placeholder: theme.background.on.scale_alpha(0.5),
+        value: theme.background.on,
+        selection: theme.accent.base.scale_alpha(0.3),
+        link: theme.accent.base,
+    };
+
+    match status {
+        Status::Active => active,
+        Status::Hovered => Style {
+            border: Border {
+                color: theme.background.on,
+                ..active.border
+            },
+            ..active
+        },
+        Status::Focused { .. } => Style {
+            border: Border {
+                color: theme.accent.base,
+                ..active.border
+            },
+            ..active
+        },
+        Status::Disabled => Style {
+            background: Background::Color(theme.background.small_widget),
+            value: active.placeholder,
+            placeholder: theme.background.on.scale_alpha(0.3),
+            ..active
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::path::PathBuf;
+
+    /// A [`Clipboard`] keeping every written format in memory.
+    #[derive(Default)]
+    struct Memory {
+        formats: Vec<(String, Vec<u8>)>,
+    }
+
+    impl Clipboard for Memory {
+        fn read_text(&self, kind: clipboard::Kind) -> Option<String> {
+            self.read(kind, clipboard::Format::Text.formats())
+                .and_then(clipboard::ClipboardData::into_text)
+        }
+
+        fn write_text(&mut self, kind: clipboard::Kind, contents: String) {
+            self.write(
+                kind,
+                Cow::Owned(contents.into_bytes()),
+                clipboard::Format::Text.formats(),
+            );
+        }
+
+        fn read(
+            &self,
+            _kind: clipboard::Kind,
+            mime_types: &[&str],
+        ) -> Option<clipboard::ClipboardData> {
+            mime_types.iter().find_map(|mime_type| {
+                self.formats
+                    .iter()
+                    .find(|(format, _)| format == mime_type)
+                    .map(|(format, data)| clipboard::ClipboardData::new(format, data.clone()))
+            })
+        }
+
+        fn write(&mut self, kind: clipboard::Kind, data: Cow<'_, [u8]>, mime_types: &[&str]) {
+            self.write_multi(kind, &[(data, mime_types)]);
+        }
+
+        fn write_multi(&mut self, _kind: clipboard::Kind, formats: &[(Cow<'_, [u8]>, &[&str])]) {
+            self.formats = formats
+                .iter()
+                .flat_map(|(data, mime_types)| {
+                    mime_types
+                        .iter()
+                        .map(|mime_type| ((*mime_type).to_owned(), data.to_vec()))
+                })
+                .collect();
+        }
+
+        fn available_mime_types(&self, _kind: clipboard::Kind) -> Vec<String> {
+            self.formats
+                .iter()
+                .map(|(format, _)| format.clone())
+                .collect()
+        }
+
+        fn read_files(&self, _kind: clipboard::Kind) -> Option<Vec<PathBuf>> {
+            None
+        }
+
+        fn write_files(&mut self, _kind: clipboard::Kind, _paths: &[PathBuf]) {}
+
+        fn clear(&mut self, _kind: clipboard::Kind) {
+            self.formats.clear();
+        }
+    }
+
+    fn content(text: &str) -> Content<()> {
+        Content::with_text(text)
+    }
+
+    fn select(content: &mut Content<()>, range: Range<usize>) {
+        content.perform(Action::Move(Motion::DocumentStart));
+
+        for _ in 0..range.start {
+            content.perform(Action::Move(Motion::Right));
+        }
+
+        for _ in range {
+            content.perform(Action::Select(Motion::Right));
+        }
+    }
+
+    #[test]
+    fn word_motion_skips_whole_words() {
+        let mut content = content("hello big  world");
+
+        content.perform(Action::Move(Motion::DocumentEnd));
+        content.perform(Action::Move(Motion::WordLeft));
+        assert_eq!(content.cursor(), Position::new(0, 11));
+
+        content.perform(Action::Move(Motion::WordLeft));
+        assert_eq!(content.cursor(), Position::new(0, 6));
+
+        content.perform(Action::Move(Motion::WordLeft));
+        assert_eq!(content.cursor(), Position::new(0, 0));
+
+        content.perform(Action::Move(Motion::WordRight));
+        assert_eq!(content.cursor(), Position::new(0, 5));
+
+        content.perform(Action::Move(Motion::WordRight));
+        assert_eq!(content.cursor(), Position::new(0, 9));
+    }
+
+    #[test]
+    fn word_motion_crosses_blocks() {
+        let mut content = content("one\ntwo");
+
+        content.perform(Action::Move(Motion::Right));
+        content.perform(Action::Move(Motion::WordRight));
+        assert_eq!(content.cursor(), Position::new(0, 3));
+
+        content.perform(Action::Move(Motion::WordRight));
+        assert_eq!(content.cursor(), Position::new(1, 0));
+
+        content.perform(Action::Move(Motion::WordLeft));
+        assert_eq!(content.cursor(), Position::new(0, 3));
+    }
+
+    #[test]
+    fn selects_words_and_lines() {
+        let mut content = content("hello world\nagain");
+
+        select(&mut content, 7..7);
+        content.perform(Action::SelectWord);
+        assert_eq!(
+            content.selection().map(|selection| selection.text()),
+            Some("world".to_owned())
+        );
+
+        content.perform(Action::SelectLine);
+        assert_eq!(
+            content.selection().map(|selection| selection.text()),
+            Some("hello world".to_owned())
+        );
+    }
+
+    #[test]
+    fn toggles_style_on_selection() {
+        let mut content = content("hello world");
+
+        select(&mut content, 0..5);
+        content.perform(Action::Format(Format::Bold));
+
+        let document = content.document();
+        let is_bold = |range: Range<usize>| {
+            document.is_styled(
+                Position::new(0, range.start)..Position::new(0, range.end),
+                |style| style.bold,
+            )
+        };
+
+        assert!(is_bold(0..5));
+        assert!(!is_bold(5..11));
+        assert!(content.style().bold);
+
+        content.perform(Action::Format(Format::Bold));
+
+        assert!(
+            !content
+                .document()
+                .is_styled(Position::new(0, 0)..Position::new(0, 5), |style| style.bold)
+        );
+    }
+
+    #[test]
+    fn toggles_style_at_caret() {
+        let mut content = content("ab");
+
+        content.perform(Action::Move(Motion::Right));
+        content.perform(Action::Format(Format::Italic));
+        assert!(content.style().italic);
+
+        content.perform(Action::Edit(Edit::Insert('x')));
+        assert_eq!(content.text(), "axb");
+
+        let document = content.document();
+
+        assert!(
+            document.is_styled(Position::new(0, 1)..Position::new(0, 2), |style| style
+                .italic)
+        );
+        assert!(
+            !document.is_styled(Position::new(0, 2)..Position::new(0, 3), |style| style
+                .italic)
+        );
+
+        // Moving the caret drops the pending style
+        content.perform(Action::Move(Motion::DocumentEnd));
+        assert!(!content.style().italic);
+    }
+
+    #[test]
+    fn copies_html_rtf_and_text() {
+        let mut content = content("hello world");
+
+        select(&mut content, 0..5);
+        content.perform(Action::Format(Format::Bold));
+
+        let mut clipboard = Memory::default();
+
+        assert!(RichEditor::<(), crate::Theme, ()>::new(&content).copy(&mut clipboard));
+
+        let selection = content.selection().expect("selection");
+
+        assert_eq!(
+            clipboard.read_html(clipboard::Kind::Standard),
+            Some(selection.to_html())
+        );
+        assert_eq!(
+            clipboard
+                .read(clipboard::Kind::Standard, clipboard::Format::Rtf.formats())
+                .and_then(clipboard::ClipboardData::into_text),
+            Some(selection.to_rtf())
+        );
+        assert_eq!(
+            clipboard.read_text(clipboard::Kind::Standard),
+            Some("hello".to_owned())
+        );
+
+        content.perform(Action::Move(Motion::DocumentEnd));
+
+        assert!(!RichEditor::<(), crate::Theme, ()>::new(&content).copy(&mut clipboard));
+    }
+
+    #[test]
+    fn pastes_formatted_text() {
+        let mut source = content("bold plain");
+
+        select(&mut source, 0..4);
+        source.perform(Action::Format(Format::Bold));
+        source.perform(Action::SelectAll);
+
+        let copied = source.selection().expect("selection");
+
+        for data in [
+            (copied.to_html(), clipboard::Format::Html),
+            (copied.to_rtf(), clipboard::Format::Rtf),
+        ] {
+            let mut clipboard = Memory::default();
+            clipboard.write(
+                clipboard::Kind::Standard,
+                Cow::Owned(data.0.into_bytes()),
+                data.1.formats(),
+            );
+
+            let pasted = paste(&clipboard).expect("pasted document");
+
+            let mut content = content("> ");
+            content.perform(Action::Move(Motion::DocumentEnd));
+            content.perform(Action::Edit(Edit::Paste(Arc::new(pasted))));
+
+            let document = content.document();
+
+            assert_eq!(content.text(), "> bold plain");
+            assert!(
+                document.is_styled(Position::new(0, 2)..Position::new(0, 6), |style| style.bold)
+            );
+            assert!(
+                !document.is_styled(Position::new(0, 6)..Position::new(0, 12), |style| style
+                    .bold)
+            );
+        }
+    }
+
+    #[test]
+    fn pastes_plain_text() {
+        let mut clipboard = Memory::default();
+        clipboard.write_text(clipboard::Kind::Standard, "one\ntwo".to_owned());
+
+        let mut content = content("");
+        content.perform(Action::Edit(Edit::Paste(Arc::new(
+            paste(&clipboard).expect("pasted document"),
+        ))));
+
+        assert_eq!(content.text(), "one\ntwo");
+        assert_eq!(content.cursor(), Position::new(1, 3));
+    }
+}
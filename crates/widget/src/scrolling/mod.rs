@@ -5,6 +5,7 @@
 //! - [`scrollable`] - Traditional scrolling with fully measured content
 //! - [`scroll_area`] - Unified builder API for both regular and virtual scrolling
 //! - `virtual_scrollable` (internal) - Virtual scrolling implementation for large content
+//! - [`sticky`] - Section headers that stay pinned while their content scrolls
 //!
 //! # Quick Start
 //!
@@ -45,7 +46,9 @@
 
 pub mod scroll_area;
 pub mod scrollable;
+pub mod sticky;
 pub(crate) mod virtual_scrollable;
 
 pub use scroll_area::ScrollArea;
 pub use scrollable::Scrollable;
+pub use sticky::Sticky;
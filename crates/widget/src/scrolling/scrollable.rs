@@ -110,6 +110,8 @@ where
     auto_scroll: bool,
     content: Element<'a, Message, Theme, Renderer>,
     on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
+    markers: Vec<Marker>,
+    on_marker_press: Option<Box<dyn Fn(usize) -> Message + 'a>>,
     class: Theme::Class<'a>,
     last_status: Option<Status>,
 }
@@ -137,6 +139,8 @@ where
             auto_scroll: false,
             content: content.into(),
             on_scroll: None,
+            markers: Vec::new(),
+            on_marker_press: None,
             class: Theme::default(),
             last_status: None,
         }
@@ -194,6 +198,23 @@ where
         self
     }
 
+    /// Sets the [`Marker`]s drawn into the track of the vertical [`Scrollbar`].
+    ///
+    /// Clicking a [`Marker`] scrolls its position into the center of the
+    /// [`Viewport`].
+    pub fn markers(mut self, markers: impl IntoIterator<Item = Marker>) -> Self {
+        self.markers = markers.into_iter().collect();
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Marker`] is pressed.
+    ///
+    /// The function takes the index of the pressed [`Marker`].
+    pub fn on_marker_press(mut self, f: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_marker_press = Some(Box::new(f));
+        self
+    }
+
     /// Anchors the vertical [`Scrollable`] direction to the top.
     pub fn anchor_top(self) -> Self {
         self.anchor_y(Anchor::Start)
@@ -485,6 +506,144 @@ pub enum Anchor {
     End,
 }
 
+/// A marker drawn into the track of the vertical [`Scrollbar`], turning it into
+/// an overview ruler.
+///
+/// Markers can be used to highlight interesting spots of long contents (e.g.
+/// search hits, errors, or diff hunks). Clicking a marker scrolls its position
+/// into the center of the [`Viewport`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Marker {
+    /// The relative position of the marker in the contents, from `0.0` (top)
+    /// to `1.0` (bottom).
+    pub position: f32,
+    /// The relative length of the marked range in the contents.
+    ///
+    /// A length of `0.0` marks a single spot.
+    pub length: f32,
+    /// The [`Color`] of the marker.
+    pub color: Color,
+}
+
+impl Marker {
+    /// The minimum height of a [`Marker`] in the track, in logical pixels.
+    const MIN_HEIGHT: f32 = 2.0;
+
+    /// The extra distance around a [`Marker`] that still counts as a click on it.
+    const TOLERANCE: f32 = 2.0;
+
+    /// Creates a new [`Marker`] at the given relative position with the given [`Color`].
+    pub fn new(position: f32, color: impl Into<Color>) -> Self {
+        Self {
+            position: position.clamp(0.0, 1.0),
+            length: 0.0,
+            color: color.into(),
+        }
+    }
+
+    /// Creates a new [`Marker`] at the given absolute offset of contents with the
+    /// given total height.
+    pub fn at_offset(offset: f32, content_height: f32, color: impl Into<Color>) -> Self {
+        Self::new(offset / content_height.max(1.0), color)
+    }
+
+    /// Sets the relative length of the [`Marker`].
+    pub fn length(mut self, length: f32) -> Self {
+        self.length = length.clamp(0.0, 1.0 - self.position);
+        self
+    }
+
+    /// Returns the bounds of the [`Marker`] inside the given track.
+    ///
+    /// The bounds span the marked range, or are centered on it if it is
+    /// shorter than [`Self::MIN_HEIGHT`].
+    fn bounds(&self, track: Rectangle) -> Rectangle {
+        let height = (self.length * track.height).max(Self::MIN_HEIGHT);
+        let center = (self.position + self.length / 2.0) * track.height;
+
+        Rectangle {
+            y: (track.y + center - height / 2.0)
+                .clamp(track.y, (track.y + track.height - height).max(track.y)),
+            height,
+            ..track
+        }
+    }
+
+    /// Returns the relative scroll offset that centers the [`Marker`] in a
+    /// viewport of the given bounds.
+    fn scroll_percentage(&self, bounds: Rectangle, content_bounds: Rectangle) -> f32 {
+        let scrollable = content_bounds.height - bounds.height;
+
+        if scrollable <= 0.0 {
+            return 0.0;
+        }
+
+        let center = (self.position + self.length / 2.0) * content_bounds.height;
+
+        ((center - bounds.height / 2.0) / scrollable).clamp(0.0, 1.0)
+    }
+}
+
+/// Returns the index of the topmost [`Marker`] under the cursor, if any.
+fn marker_at(markers: &[Marker], track: Rectangle, cursor_position: Point) -> Option<usize> {
+    markers.iter().rposition(|marker| {
+        let bounds = marker.bounds(track);
+
+        cursor_position.y >= bounds.y - Marker::TOLERANCE
+            && cursor_position.y <= bounds.y + bounds.height + Marker::TOLERANCE
+    })
+}
+
+/// Draws the given markers into the track of a vertical scrollbar.
+pub(super) fn draw_markers<Renderer>(renderer: &mut Renderer, markers: &[Marker], track: Rectangle)
+where
+    Renderer: core::Renderer,
+{
+    if track.width <= 0.0 || track.height <= 0.0 {
+        return;
+    }
+
+    for marker in markers {
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: marker.bounds(track),
+                ..renderer::Quad::default()
+            },
+            Background::Color(marker.color),
+        );
+    }
+}
+
+/// Handles a press on the track of a vertical scrollbar with markers.
+///
+/// Returns the index of the pressed [`Marker`] and the relative scroll offset
+/// that centers it, if any. Presses on the scroller are ignored, so that it
+/// can still be dragged where it overlaps a marker.
+pub(super) fn press_marker(
+    markers: &[Marker],
+    track: Rectangle,
+    scroller: Option<Rectangle>,
+    alignment: Anchor,
+    cursor_position: Point,
+    bounds: Rectangle,
+    content_bounds: Rectangle,
+) -> Option<(usize, f32)> {
+    if scroller.is_some_and(|scroller| scroller.contains(cursor_position)) {
+        return None;
+    }
+
+    let index = marker_at(markers, track, cursor_position)?;
+    let percentage = markers[index].scroll_percentage(bounds, content_bounds);
+
+    Some((
+        index,
+        match alignment {
+            Anchor::Start => percentage,
+            Anchor::End => 1.0 - percentage,
+        },
+    ))
+}
+
 impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
     for Scrollable<'_, Message, Theme, Renderer>
 where
@@ -712,11 +871,18 @@ where
         renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
-        let state = tree.state.downcast_mut::<State>();
-
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
+
+        #[cfg(feature = "accessibility")]
+        if let Some(info) =
+            <Self as Widget<Message, Theme, Renderer>>::accessibility(self, tree, layout)
+        {
+            operation.accessibility(self.id.as_ref(), bounds, info);
+        }
+
+        let state = tree.state.downcast_mut::<State>();
         let translation = state.translation(self.direction, bounds, content_bounds);
 
         operation.scrollable(self.id.as_ref(), bounds, content_bounds, translation, state);
@@ -816,7 +982,31 @@ where
                             return;
                         };
 
-                        if let (Some(scroller_grabbed_at), Some(scrollbar)) =
+                        if let Some((index, percentage)) = scrollbars.y.and_then(|scrollbar| {
+                            press_marker(
+                                &self.markers,
+                                scrollbar.bounds,
+                                scrollbar.scroller.map(|scroller| scroller.bounds),
+                                scrollbar.alignment,
+                                cursor_position,
+                                bounds,
+                                content_bounds,
+                            )
+                        }) {
+                            state.scroll_y_to(percentage, bounds, content_bounds);
+
+                            let _ = notify_scroll(
+                                state,
+                                &self.on_scroll,
+                                bounds,
+                                content_bounds,
+                                shell,
+                            );
+
+                            if let Some(on_marker_press) = &self.on_marker_press {
+                                shell.publish(on_marker_press(index));
+                            }
+                        } else if let (Some(scroller_grabbed_at), Some(scrollbar)) =
                             (scrollbars.grab_y_scroller(cursor_position), scrollbars.y)
                         {
                             state.scroll_y_to(
@@ -1452,6 +1642,11 @@ where
                     }
                 }
 
+                // Draw overview markers below the handle, so it stays on top
+                if is_vertical {
+                    draw_markers(renderer, &self.markers, scrollbar_bounds);
+                }
+
                 // Draw handle/scroller
                 if let Some(bounds) = scroller_bounds
                     && bounds.width > 0.0
@@ -1645,6 +1840,37 @@ where
             ))),
         }
     }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+    ) -> Option<crate::core::accessibility::WidgetInfo> {
+        let state = tree.state.downcast_ref::<State>();
+
+        let bounds = layout.bounds();
+        let content_bounds = layout.children().next().unwrap().bounds();
+        let translation = state.translation(self.direction, bounds, content_bounds);
+
+        let mut info = crate::core::accessibility::WidgetInfo::scroll_view().with_bounds(bounds);
+
+        if self.direction.horizontal().is_some() {
+            info = info.with_scroll_x(
+                f64::from(translation.x),
+                f64::from((content_bounds.width - bounds.width).max(0.0)),
+            );
+        }
+
+        if self.direction.vertical().is_some() {
+            info = info.with_scroll_y(
+                f64::from(translation.y),
+                f64::from((content_bounds.height - bounds.height).max(0.0)),
+            );
+        }
+
+        Some(info)
+    }
 }
 
 struct AutoScrollIcon<'a, Class> {
@@ -1725,6 +1951,7 @@ where
                 align_y: alignment::Vertical::Center,
                 shaping: text::Shaping::Basic,
                 wrapping: text::Wrapping::None,
+                truncation: text::Truncation::None,
                 hint_factor: None,
             };
 
@@ -1944,6 +2171,17 @@ impl operation::Scrollable for State {
         State::snap_to(self, offset);
     }
 
+    fn absolute_offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Option<AbsoluteOffset> {
+        Some(AbsoluteOffset {
+            x: self.offset_x.absolute(bounds.width, content_bounds.width),
+            y: self.offset_y.absolute(bounds.height, content_bounds.height),
+        })
+    }
+
     fn scroll_to(&mut self, offset: AbsoluteOffset<Option<f32>>) {
         State::scroll_to(self, offset);
     }
@@ -2968,6 +3206,7 @@ fn apply_theme_and_status(mut scroll_style: ScrollStyle, theme: &Theme, status:
             color: Color::BLACK.scale_alpha(0.7),
             offset: Vector::ZERO,
             blur_radius: 2.0,
+            ..Shadow::default()
         },
         icon: theme.background.on.scale_alpha(0.8),
     };
@@ -3033,3 +3272,227 @@ pub fn solid(theme: &Theme, status: Status) -> Style {
         status,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRACK: Rectangle = Rectangle {
+        x: 90.0,
+        y: 0.0,
+        width: 10.0,
+        height: 100.0,
+    };
+
+    const VIEWPORT: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 90.0,
+        height: 100.0,
+    };
+
+    const CONTENT: Rectangle = Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: 90.0,
+        height: 1000.0,
+    };
+
+    #[test]
+    fn marker_bounds_are_centered_on_their_position() {
+        let bounds = Marker::new(0.5, Color::BLACK).bounds(TRACK);
+
+        assert_eq!(bounds.x, TRACK.x);
+        assert_eq!(bounds.width, TRACK.width);
+        assert_eq!(bounds.height, Marker::MIN_HEIGHT);
+        assert_eq!(bounds.y, 50.0 - Marker::MIN_HEIGHT / 2.0);
+    }
+
+    #[test]
+    fn marker_bounds_span_their_length() {
+        let bounds = Marker::new(0.25, Color::BLACK).length(0.5).bounds(TRACK);
+
+        assert_eq!(bounds.height, 50.0);
+        assert_eq!(bounds.y, 25.0);
+    }
+
+    #[test]
+    fn marker_bounds_stay_inside_the_track() {
+        let top = Marker::new(0.0, Color::BLACK).bounds(TRACK);
+        let bottom = Marker::new(1.0, Color::BLACK).bounds(TRACK);
+
+        assert_eq!(top.y, TRACK.y);
+        assert_eq!(bottom.y + bottom.height, TRACK.y + TRACK.height);
+    }
+
+    #[test]
+    fn scroll_percentage_centers_the_marker() {
+        let marker = Marker::new(0.5, Color::BLACK);
+
+        assert_eq!(marker.scroll_percentage(VIEWPORT, CONTENT), 0.5);
+        assert_eq!(
+            Marker::new(0.2, Color::BLACK)
+                .length(0.1)
+                .scroll_percentage(VIEWPORT, CONTENT),
+            200.0 / 900.0
+        );
+    }
+
+    #[test]
+    fn scroll_percentage_is_clamped() {
+        assert_eq!(
+            Marker::new(0.0, Color::BLACK).scroll_percentage(VIEWPORT, CONTENT),
+            0.0
+        );
+        assert_eq!(
+            Marker::new(1.0, Color::BLACK).scroll_percentage(VIEWPORT, CONTENT),
+            1.0
+        );
+    }
+
+    #[test]
+    fn scroll_percentage_is_zero_when_everything_fits() {
+        assert_eq!(
+            Marker::new(0.8, Color::BLACK).scroll_percentage(VIEWPORT, VIEWPORT),
+            0.0
+        );
+    }
+
+    #[test]
+    fn press_marker_picks_the_topmost_marker() {
+        let markers = [
+            Marker::new(0.5, Color::BLACK),
+            Marker::new(0.51, Color::WHITE),
+        ];
+
+        let pressed = press_marker(
+            &markers,
+            TRACK,
+            None,
+            Anchor::Start,
+            Point::new(95.0, 50.0),
+            VIEWPORT,
+            CONTENT,
+        );
+
+        assert_eq!(pressed.map(|(index, _)| index), Some(1));
+    }
+
+    #[test]
+    fn press_marker_is_mirrored_for_end_anchors() {
+        let markers = [Marker::new(0.2, Color::BLACK).length(0.1)];
+
+        let pressed = press_marker(
+            &markers,
+            TRACK,
+            None,
+            Anchor::End,
+            Point::new(95.0, 25.0),
+            VIEWPORT,
+            CONTENT,
+        );
+
+        assert_eq!(pressed, Some((0, 1.0 - 200.0 / 900.0)));
+    }
+
+    #[test]
+    fn press_marker_ignores_markers_beneath_the_scroller() {
+        let markers = [Marker::new(0.05, Color::BLACK)];
+        let scroller = Rectangle {
+            height: 10.0,
+            ..TRACK
+        };
+        let cursor = Point::new(95.0, 5.0);
+
+        assert_eq!(
+            press_marker(
+                &markers,
+                TRACK,
+                Some(scroller),
+                Anchor::Start,
+                cursor,
+                VIEWPORT,
+                CONTENT
+            ),
+            None
+        );
+        assert_eq!(
+            press_marker(
+                &markers,
+                TRACK,
+                None,
+                Anchor::Start,
+                cursor,
+                VIEWPORT,
+                CONTENT
+            )
+            .map(|(index, _)| index),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn press_marker_misses_outside_of_the_tolerance() {
+        let markers = [Marker::new(0.5, Color::BLACK)];
+
+        assert_eq!(
+            press_marker(
+                &markers,
+                TRACK,
+                None,
+                Anchor::Start,
+                Point::new(95.0, 60.0),
+                VIEWPORT,
+                CONTENT
+            ),
+            None
+        );
+    }
+
+    #[cfg(feature = "accessibility")]
+    #[test]
+    fn scroll_view_exposes_its_scroll_range() {
+        use crate::core::accessibility::Role;
+        use crate::core::widget::operation::{self, Outcome, accessibility};
+
+        let mut scrollable: Scrollable<'_, (), crate::Theme, ()> =
+            Scrollable::new(crate::Space::new().width(Length::Fill).height(1000))
+                .width(90)
+                .height(100);
+
+        let mut tree = Tree::new(&scrollable as &dyn Widget<(), crate::Theme, ()>);
+        let node = Widget::<(), crate::Theme, ()>::layout(
+            &mut scrollable,
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(400.0, 400.0)),
+        );
+
+        tree.state.downcast_mut::<State>().offset_y = Offset::Absolute(250.0);
+
+        let mut operation = accessibility::collect();
+
+        Widget::<(), crate::Theme, ()>::operate(
+            &mut scrollable,
+            &mut tree,
+            Layout::new(&node),
+            &(),
+            &mut operation::black_box(&mut operation),
+        );
+
+        let Outcome::Some(tree) = operation.finish() else {
+            panic!("collect should produce a tree");
+        };
+
+        let (_, node) = tree
+            .nodes
+            .iter()
+            .find(|(_, node)| node.role() == Role::ScrollView)
+            .expect("scroll view node");
+
+        assert_eq!(node.scroll_y(), Some(250.0));
+        assert_eq!(node.scroll_y_min(), Some(0.0));
+        assert_eq!(node.scroll_y_max(), Some(900.0));
+        assert_eq!(node.scroll_x(), None);
+    }
+}
@@ -0,0 +1,412 @@
+//! Pin section headers to the top of a scrolling viewport.
+//!
+//! A [`Sticky`] section is made of a header and some content laid out below it.
+//! While the section is visible inside a [`Scrollable`](super::Scrollable), its
+//! header stays pinned to the top of the viewport and is pushed out by the end
+//! of the section, just like the section headers of a grouped list.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use icy_ui_widget::*; } }
+//! # pub type Element<'a, Message> = icy_ui_widget::core::Element<'a, Message, icy_ui_widget::Theme, icy_ui_widget::Renderer>;
+//! use icy_ui::widget::{column, container, scrollable, sticky, text};
+//!
+//! enum Message {}
+//!
+//! fn view<'a>(groups: &'a [(String, Vec<String>)]) -> Element<'a, Message> {
+//!     scrollable(column(groups.iter().map(|(title, items)| {
+//!         sticky(
+//!             container(text(title)).style(container::rounded_box),
+//!             column(items.iter().map(|item| text(item).into())),
+//!         )
+//!         .into()
+//!     })))
+//!     .into()
+//! }
+//! ```
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::widget::Operation;
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    self, Clipboard, Element, Event, Layout, Length, Pixels, Rectangle, Shell, Size, Vector, Widget,
+};
+
+/// A section whose header sticks to the top of the visible viewport while its
+/// content scrolls beneath it.
+///
+/// The header keeps receiving events and is reported to operations (e.g. focus
+/// and accessibility) at its pinned position.
+pub struct Sticky<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    Renderer: core::Renderer,
+{
+    header: Element<'a, Message, Theme, Renderer>,
+    content: Element<'a, Message, Theme, Renderer>,
+    width: Length,
+    spacing: f32,
+}
+
+impl<'a, Message, Theme, Renderer> Sticky<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    /// Creates a new [`Sticky`] section with the given header and content.
+    pub fn new(
+        header: impl Into<Element<'a, Message, Theme, Renderer>>,
+        content: impl Into<Element<'a, Message, Theme, Renderer>>,
+    ) -> Self {
+        Self {
+            header: header.into(),
+            content: content.into(),
+            width: Length::Fill,
+            spacing: 0.0,
+        }
+    }
+
+    /// Sets the width of the [`Sticky`] section.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the vertical spacing between the header and the content.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+}
+
+/// The local state of a [`Sticky`] section.
+#[derive(Debug, Clone, Copy, Default)]
+struct State {
+    /// The offset of the pinned header, as of the last processed event.
+    offset: f32,
+}
+
+/// Computes how far the header must be moved down to stay inside the viewport.
+fn pinned_offset(bounds: Rectangle, header: Rectangle, viewport: &Rectangle) -> f32 {
+    (viewport.y - bounds.y).clamp(0.0, (bounds.height - header.height).max(0.0))
+}
+
+impl<Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Sticky<'_, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![Tree::new(&self.header), Tree::new(&self.content)]
+    }
+
+    fn diff(&self, tree: &mut Tree) {
+        tree.diff_children(&[&self.header, &self.content]);
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let child_limits = limits.loose();
+
+        let header =
+            self.header
+                .as_widget_mut()
+                .layout(&mut tree.children[0], renderer, &child_limits);
+
+        let offset = header.size().height + self.spacing;
+
+        let content = self
+            .content
+            .as_widget_mut()
+            .layout(
+                &mut tree.children[1],
+                renderer,
+                &child_limits.shrink(Size::new(0.0, offset)),
+            )
+            .move_to((0.0, offset));
+
+        let size = limits.resolve(
+            self.width,
+            Length::Shrink,
+            Size::new(
+                header.size().width.max(content.size().width),
+                offset + content.size().height,
+            ),
+        );
+
+        layout::Node::with_children(size, vec![header, content])
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let offset = tree.state.downcast_ref::<State>().offset;
+        let (header_tree, content_tree) = tree.children.split_at_mut(1);
+
+        operation.container(None, layout.bounds());
+        operation.traverse(&mut |operation| {
+            self.header.as_widget_mut().operate(
+                &mut header_tree[0],
+                layout.child(0).translate(Vector::new(0.0, offset)),
+                renderer,
+                operation,
+            );
+
+            self.content.as_widget_mut().operate(
+                &mut content_tree[0],
+                layout.child(1),
+                renderer,
+                operation,
+            );
+        });
+        operation.leave_container();
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        viewport: &Rectangle,
+    ) {
+        let offset = pinned_offset(layout.bounds(), layout.child(0).bounds(), viewport);
+        tree.state.downcast_mut::<State>().offset = offset;
+
+        let header_layout = layout.child(0).translate(Vector::new(0.0, offset));
+
+        self.header.as_widget_mut().update(
+            &mut tree.children[0],
+            event,
+            header_layout,
+            cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+
+        if shell.is_event_captured() {
+            return;
+        }
+
+        let content_cursor = if cursor.is_over(header_layout.bounds()) {
+            cursor.levitate()
+        } else {
+            cursor
+        };
+
+        self.content.as_widget_mut().update(
+            &mut tree.children[1],
+            event,
+            layout.child(1),
+            content_cursor,
+            renderer,
+            clipboard,
+            shell,
+            viewport,
+        );
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let offset = pinned_offset(layout.bounds(), layout.child(0).bounds(), viewport);
+        let header_layout = layout.child(0).translate(Vector::new(0.0, offset));
+
+        if cursor.is_over(header_layout.bounds()) {
+            return self.header.as_widget().mouse_interaction(
+                &tree.children[0],
+                header_layout,
+                cursor,
+                viewport,
+                renderer,
+            );
+        }
+
+        self.content.as_widget().mouse_interaction(
+            &tree.children[1],
+            layout.child(1),
+            cursor,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let offset = pinned_offset(layout.bounds(), layout.child(0).bounds(), viewport);
+        let header_layout = layout.child(0).translate(Vector::new(0.0, offset));
+
+        let content_cursor = if cursor.is_over(header_layout.bounds()) {
+            cursor.levitate()
+        } else {
+            cursor
+        };
+
+        self.content.as_widget().draw(
+            &tree.children[1],
+            renderer,
+            theme,
+            style,
+            layout.child(1),
+            content_cursor,
+            viewport,
+        );
+
+        let Some(header_bounds) = header_layout.bounds().intersection(viewport) else {
+            return;
+        };
+
+        // The header is drawn in its own layer, so that the content scrolling
+        // beneath it (including its text) never bleeds through.
+        renderer.with_layer(header_bounds, |renderer| {
+            self.header.as_widget().draw(
+                &tree.children[0],
+                renderer,
+                theme,
+                style,
+                header_layout,
+                cursor,
+                viewport,
+            );
+        });
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let offset = pinned_offset(layout.bounds(), layout.child(0).bounds(), viewport);
+        let (header_tree, content_tree) = tree.children.split_at_mut(1);
+
+        let children = [
+            self.header.as_widget_mut().overlay(
+                &mut header_tree[0],
+                layout.child(0).translate(Vector::new(0.0, offset)),
+                renderer,
+                viewport,
+                translation,
+            ),
+            self.content.as_widget_mut().overlay(
+                &mut content_tree[0],
+                layout.child(1),
+                renderer,
+                viewport,
+                translation,
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        (!children.is_empty()).then(|| overlay::Group::with_children(children).overlay())
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<Sticky<'a, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    Message: 'a,
+    Theme: 'a,
+    Renderer: core::Renderer + 'a,
+{
+    fn from(sticky: Sticky<'a, Message, Theme, Renderer>) -> Self {
+        Element::new(sticky)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECTION: Rectangle = Rectangle {
+        x: 0.0,
+        y: 100.0,
+        width: 200.0,
+        height: 300.0,
+    };
+
+    const HEADER: Rectangle = Rectangle {
+        x: 0.0,
+        y: 100.0,
+        width: 200.0,
+        height: 40.0,
+    };
+
+    fn viewport(y: f32) -> Rectangle {
+        Rectangle::new(core::Point::new(0.0, y), Size::new(200.0, 200.0))
+    }
+
+    #[test]
+    fn header_stays_in_place_before_the_section_is_reached() {
+        assert_eq!(pinned_offset(SECTION, HEADER, &viewport(0.0)), 0.0);
+        assert_eq!(pinned_offset(SECTION, HEADER, &viewport(100.0)), 0.0);
+    }
+
+    #[test]
+    fn header_is_pinned_to_the_top_of_the_viewport() {
+        assert_eq!(pinned_offset(SECTION, HEADER, &viewport(150.0)), 50.0);
+        assert_eq!(pinned_offset(SECTION, HEADER, &viewport(360.0)), 260.0);
+    }
+
+    #[test]
+    fn header_is_pushed_out_by_the_end_of_the_section() {
+        assert_eq!(pinned_offset(SECTION, HEADER, &viewport(380.0)), 260.0);
+        assert_eq!(pinned_offset(SECTION, HEADER, &viewport(1000.0)), 260.0);
+    }
+
+    #[test]
+    fn header_taller_than_its_section_is_never_moved() {
+        let header = Rectangle {
+            height: 400.0,
+            ..HEADER
+        };
+
+        assert_eq!(pinned_offset(SECTION, header, &viewport(200.0)), 0.0);
+    }
+}
@@ -80,7 +80,8 @@ use crate::core::{
 };
 
 use super::scrollable::{
-    Anchor, Catalog, Direction, ScrollStyle, Scrollbar, Status, Style, StyleFn, Viewport,
+    Anchor, Catalog, Direction, Marker, ScrollStyle, Scrollbar, Status, Style, StyleFn, Viewport,
+    draw_markers, press_marker,
 };
 
 pub use super::scrollable::{AbsoluteOffset, RelativeOffset};
@@ -136,6 +137,10 @@ where
     VirtualScrollable::with_rows(row_height, total_rows, view)
 }
 
+/// Produces the sticky header of a [`VirtualScrollable`] for the first visible row.
+type StickyHeaderFn<'a, Message, Theme, Renderer> =
+    Box<dyn Fn(usize) -> Option<Element<'a, Message, Theme, Renderer>> + 'a>;
+
 /// A scrollable that only renders visible content for efficient large content display.
 ///
 /// Use [`show_viewport`] or [`show_rows`] to create instances.
@@ -151,6 +156,8 @@ where
     content_size: Size,
     view: Box<dyn Fn(Rectangle) -> Element<'a, Message, Theme, Renderer> + 'a>,
     on_scroll: Option<Box<dyn Fn(Viewport) -> Message + 'a>>,
+    markers: Vec<Marker>,
+    on_marker_press: Option<Box<dyn Fn(usize) -> Message + 'a>>,
     auto_scroll: bool,
     class: Theme::Class<'a>,
     last_status: Option<Status>,
@@ -163,6 +170,10 @@ where
     /// Cached content element and viewport to avoid rebuilding on every draw.
     /// The tuple is (viewport, element).
     cached_content: RefCell<Option<(Rectangle, Element<'a, Message, Theme, Renderer>)>>,
+    /// Produces the header pinned to the top of the viewport for the first visible row.
+    sticky_header: Option<StickyHeaderFn<'a, Message, Theme, Renderer>>,
+    /// The current sticky header, rebuilt whenever the first visible row changes.
+    sticky_content: Option<Element<'a, Message, Theme, Renderer>>,
 }
 
 impl<'a, Message, Theme, Renderer> VirtualScrollable<'a, Message, Theme, Renderer>
@@ -183,12 +194,16 @@ where
             content_size,
             view: Box::new(view),
             on_scroll: None,
+            markers: Vec::new(),
+            on_marker_press: None,
             auto_scroll: false,
             class: Theme::default(),
             last_status: None,
             row_height: None,
             cache_key: 0,
             cached_content: RefCell::new(None),
+            sticky_header: None,
+            sticky_content: None,
         }
     }
 
@@ -217,12 +232,16 @@ where
                 view(visible_range)
             }),
             on_scroll: None,
+            markers: Vec::new(),
+            on_marker_press: None,
             auto_scroll: false,
             class: Theme::default(),
             last_status: None,
             row_height: Some(row_height),
             cache_key: 0,
             cached_content: RefCell::new(None),
+            sticky_header: None,
+            sticky_content: None,
         }
     }
 
@@ -270,6 +289,23 @@ where
         self
     }
 
+    /// Sets the [`Marker`]s drawn into the track of the vertical [`Scrollbar`].
+    ///
+    /// Clicking a [`Marker`] scrolls its position into the center of the
+    /// [`Viewport`].
+    pub fn markers(mut self, markers: impl IntoIterator<Item = Marker>) -> Self {
+        self.markers = markers.into_iter().collect();
+        self
+    }
+
+    /// Sets the message that should be produced when a [`Marker`] is pressed.
+    ///
+    /// The function takes the index of the pressed [`Marker`].
+    pub fn on_marker_press(mut self, f: impl Fn(usize) -> Message + 'a) -> Self {
+        self.on_marker_press = Some(Box::new(f));
+        self
+    }
+
     /// Anchors the vertical [`VirtualScrollable`] direction to the top.
     pub fn anchor_top(self) -> Self {
         self.anchor_y(Anchor::Start)
@@ -352,6 +388,127 @@ where
         self.cache_key = key;
         self
     }
+
+    /// Sets a header that sticks to the top of the viewport while the rows
+    /// scroll beneath it.
+    ///
+    /// The function receives the index of the first visible row and returns the
+    /// header to display for it, if any. This makes it easy to pin the header of
+    /// the section the visible rows belong to in a grouped list. For scrollables
+    /// created with [`VirtualScrollable::new`] the index is always `0`.
+    ///
+    /// The header is drawn above the rows, keeps receiving events and takes part
+    /// in widget operations (e.g. focus and accessibility).
+    ///
+    /// # Example
+    /// ```ignore
+    /// scroll_area()
+    ///     .show_rows(30.0, items.len(), |range| { ... })
+    ///     .sticky_header(|first_row| {
+    ///         let group = group_of(first_row)?;
+    ///         Some(text(&group.title).into())
+    ///     })
+    /// ```
+    #[must_use]
+    pub fn sticky_header(
+        mut self,
+        header: impl Fn(usize) -> Option<Element<'a, Message, Theme, Renderer>> + 'a,
+    ) -> Self {
+        self.sticky_header = Some(Box::new(header));
+        self
+    }
+
+    /// Returns the index of the first row visible with the given translation.
+    fn first_visible_row(&self, translation: Vector) -> usize {
+        self.row_height
+            .filter(|row_height| *row_height > 0.0)
+            .map(|row_height| (translation.y / row_height).floor().max(0.0) as usize)
+            .unwrap_or(0)
+    }
+
+    /// Rebuilds and lays out the sticky header if the first visible row, the
+    /// cache key or the available width have changed.
+    fn sync_sticky_header(
+        &mut self,
+        children: &mut Vec<Tree>,
+        state: &mut State,
+        renderer: &Renderer,
+        first_row: usize,
+        width: f32,
+    ) {
+        let Some(sticky_header) = &self.sticky_header else {
+            return;
+        };
+
+        let is_current = state.sticky_header.as_ref().is_some_and(|sticky| {
+            sticky.row == first_row
+                && sticky.cache_key == self.cache_key
+                && (sticky.width - width).abs() <= f32::EPSILON
+        });
+
+        if is_current && self.sticky_content.is_some() {
+            return;
+        }
+
+        let Some(mut header) = sticky_header(first_row) else {
+            self.sticky_content = None;
+            state.sticky_header = None;
+            children.truncate(STICKY_HEADER);
+            return;
+        };
+
+        if children.is_empty() {
+            children.push(Tree::empty());
+        }
+
+        if let Some(header_tree) = children.get_mut(STICKY_HEADER) {
+            header_tree.diff(&header);
+        } else {
+            children.push(Tree::new(&header));
+        }
+
+        let node = header.as_widget_mut().layout(
+            &mut children[STICKY_HEADER],
+            renderer,
+            &layout::Limits::new(Size::ZERO, Size::new(width, f32::INFINITY)),
+        );
+
+        state.sticky_header = Some(StickyHeader {
+            row: first_row,
+            cache_key: self.cache_key,
+            width,
+            node,
+        });
+        self.sticky_content = Some(header);
+    }
+}
+
+/// The index of the tree of the visible content in the children of a
+/// [`VirtualScrollable`].
+///
+/// Both children are created lazily: the content on its first layout and the
+/// sticky header on its first sync. When the header comes first, an empty tree
+/// holds the place of the content until it is built.
+const CONTENT: usize = 0;
+
+/// The index of the tree of the sticky header in the children of a
+/// [`VirtualScrollable`].
+const STICKY_HEADER: usize = 1;
+
+/// The layout of the sticky header of a [`VirtualScrollable`].
+#[derive(Debug, Clone)]
+struct StickyHeader {
+    row: usize,
+    cache_key: u64,
+    width: f32,
+    node: layout::Node,
+}
+
+impl StickyHeader {
+    /// Returns the [`Layout`] of the header pinned to the top of the given bounds.
+    fn layout(&self, bounds: Rectangle) -> Layout<'_> {
+        Layout::with_offset(Vector::new(bounds.x, bounds.y), &self.node)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -390,6 +547,9 @@ struct State {
     /// Whether a pointer is currently pressed (mouse button down or touch active).
     /// Used to avoid rebuilding/updating viewport content on every hover move.
     is_pointer_down: bool,
+
+    /// Layout of the sticky header, if any.
+    sticky_header: Option<StickyHeader>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -432,6 +592,8 @@ impl Default for State {
             cached_key: 0,
 
             is_pointer_down: false,
+
+            sticky_header: None,
         }
     }
 }
@@ -441,6 +603,17 @@ impl operation::Scrollable for State {
         State::snap_to(self, offset);
     }
 
+    fn absolute_offset(
+        &self,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+    ) -> Option<AbsoluteOffset> {
+        Some(AbsoluteOffset {
+            x: self.offset_x.absolute(bounds.width, content_bounds.width),
+            y: self.offset_y.absolute(bounds.height, content_bounds.height),
+        })
+    }
+
     fn scroll_to(&mut self, offset: AbsoluteOffset<Option<f32>>) {
         State::scroll_to(self, offset);
     }
@@ -1005,14 +1178,14 @@ where
                 if tree.children.is_empty() {
                     tree.children.push(Tree::new(content.as_widget()));
                 } else {
-                    tree.children[0].diff(content.as_widget());
+                    tree.children[CONTENT].diff(content.as_widget());
                 }
 
                 // Layout the visible content within the visible bounds
                 let content_limits =
                     layout::Limits::new(Size::ZERO, Size::new(viewport_width, viewport_height));
                 let content_node = content.as_widget_mut().layout(
-                    &mut tree.children[0],
+                    &mut tree.children[CONTENT],
                     renderer,
                     &content_limits,
                 );
@@ -1031,6 +1204,15 @@ where
                 state.cached_content_layout.clone().unwrap()
             };
 
+        let first_row = self.first_visible_row(translation);
+        self.sync_sticky_header(
+            &mut tree.children,
+            state,
+            renderer,
+            first_row,
+            viewport_width,
+        );
+
         // The main node has the outer bounds, with content as child (shrunken by right/bottom padding)
         layout::Node::with_children(bounds, vec![content_node])
     }
@@ -1039,7 +1221,7 @@ where
         &mut self,
         tree: &mut Tree,
         layout: Layout<'_>,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         operation: &mut dyn Operation,
     ) {
         let state = tree.state.downcast_mut::<State>();
@@ -1070,6 +1252,40 @@ where
 
         let translation = state.translation(self.direction, viewport_bounds, content_bounds);
 
+        #[cfg(feature = "accessibility")]
+        {
+            let mut info =
+                crate::core::accessibility::WidgetInfo::scroll_view().with_bounds(bounds);
+
+            if self.direction.horizontal().is_some() {
+                info = info.with_scroll_x(
+                    f64::from(translation.x),
+                    f64::from((content_bounds.width - viewport_bounds.width).max(0.0)),
+                );
+            }
+
+            if self.direction.vertical().is_some() {
+                info = info.with_scroll_y(
+                    f64::from(translation.y),
+                    f64::from((content_bounds.height - viewport_bounds.height).max(0.0)),
+                );
+            }
+
+            operation.accessibility(self.id.as_ref(), bounds, info);
+        }
+
+        // The sticky header is not translated by the scroll offset, so it is
+        // operated on before the scrollable itself.
+        if let (Some(header), Some(sticky), Some(header_tree)) = (
+            &mut self.sticky_content,
+            &state.sticky_header,
+            tree.children.get_mut(STICKY_HEADER),
+        ) {
+            header
+                .as_widget_mut()
+                .operate(header_tree, sticky.layout(bounds), renderer, operation);
+        }
+
         operation.scrollable(self.id.as_ref(), bounds, content_bounds, translation, state);
     }
 
@@ -1212,7 +1428,31 @@ where
                             return;
                         };
 
-                        if let (Some(scroller_grabbed_at), Some(scrollbar)) =
+                        if let Some((index, percentage)) = scrollbars.y.and_then(|scrollbar| {
+                            press_marker(
+                                &self.markers,
+                                scrollbar.bounds,
+                                scrollbar.scroller.map(|scroller| scroller.bounds),
+                                scrollbar.alignment,
+                                cursor_position,
+                                bounds,
+                                content_bounds,
+                            )
+                        }) {
+                            state.scroll_y_to(percentage, bounds, content_bounds);
+
+                            let _ = notify_scroll(
+                                state,
+                                &self.on_scroll,
+                                bounds,
+                                content_bounds,
+                                shell,
+                            );
+
+                            if let Some(on_marker_press) = &self.on_marker_press {
+                                shell.publish(on_marker_press(index));
+                            }
+                        } else if let (Some(scroller_grabbed_at), Some(scrollbar)) =
                             (scrollbars.grab_y_scroller(cursor_position), scrollbars.y)
                         {
                             state.scroll_y_to(
@@ -1343,6 +1583,43 @@ where
                         .max(0.0),
                 };
 
+                // The sticky header sits above the content, so it gets the event first.
+                let first_row = self.first_visible_row(translation);
+                self.sync_sticky_header(
+                    &mut tree.children,
+                    state,
+                    renderer,
+                    first_row,
+                    viewport_bounds.width,
+                );
+
+                let cursor = if let (Some(header), Some(sticky), Some(header_tree)) = (
+                    &mut self.sticky_content,
+                    &state.sticky_header,
+                    tree.children.get_mut(STICKY_HEADER),
+                ) {
+                    let header_layout = sticky.layout(bounds);
+
+                    header.as_widget_mut().update(
+                        header_tree,
+                        event,
+                        header_layout,
+                        cursor,
+                        renderer,
+                        clipboard,
+                        shell,
+                        &bounds,
+                    );
+
+                    if cursor.is_over(header_layout.bounds()) {
+                        cursor.levitate()
+                    } else {
+                        cursor
+                    }
+                } else {
+                    cursor
+                };
+
                 // Try to use cached content instead of regenerating
                 let mut cached = self.cached_content.borrow_mut();
                 let content_from_cache = cached
@@ -1366,7 +1643,7 @@ where
 
                     if let Some(content_layout) = layout.children().next() {
                         content.as_widget_mut().update(
-                            &mut tree.children[0],
+                            &mut tree.children[CONTENT],
                             event,
                             content_layout,
                             cursor,
@@ -1385,12 +1662,12 @@ where
                     if tree.children.is_empty() {
                         tree.children.push(Tree::new(content.as_widget()));
                     } else {
-                        tree.children[0].diff(content.as_widget());
+                        tree.children[CONTENT].diff(content.as_widget());
                     }
 
                     if let Some(content_layout) = layout.children().next() {
                         content.as_widget_mut().update(
-                            &mut tree.children[0],
+                            &mut tree.children[CONTENT],
                             event,
                             content_layout,
                             cursor,
@@ -1926,6 +2203,45 @@ where
             _ => mouse::Cursor::Unavailable,
         };
 
+        let sticky_header = self
+            .sticky_content
+            .as_ref()
+            .zip(state.sticky_header.as_ref())
+            .zip(tree.children.get(STICKY_HEADER));
+
+        let header_cursor = cursor;
+        let cursor = match sticky_header {
+            Some(((_, sticky), _)) if cursor.is_over(sticky.layout(bounds).bounds()) => {
+                cursor.levitate()
+            }
+            _ => cursor,
+        };
+
+        let draw_sticky_header = |renderer: &mut Renderer| {
+            let Some(((header, sticky), header_tree)) = sticky_header else {
+                return;
+            };
+
+            let header_layout = sticky.layout(bounds);
+
+            let Some(header_bounds) = header_layout.bounds().intersection(&visible_bounds) else {
+                return;
+            };
+
+            // Drawn in its own layer so the rows scrolling beneath never bleed through.
+            renderer.with_layer(header_bounds, |renderer| {
+                header.as_widget().draw(
+                    header_tree,
+                    renderer,
+                    theme,
+                    defaults,
+                    header_layout,
+                    header_cursor,
+                    &visible_bounds,
+                );
+            });
+        };
+
         let status = self.last_status.unwrap_or(Status::Active {
             hover_factor: 0.0,
             is_horizontal_scrollbar_disabled: false,
@@ -1975,7 +2291,7 @@ where
                 let (_, content) = cached.as_ref().unwrap();
 
                 // Get the tree for the content - must exist after layout()
-                let Some(content_tree) = tree.children.first() else {
+                let Some(content_tree) = tree.children.get(CONTENT) else {
                     return;
                 };
 
@@ -2012,7 +2328,7 @@ where
                 let content = (self.view)(visible_viewport);
 
                 // Get the tree for the content - must exist after layout()
-                let Some(content_tree) = tree.children.first() else {
+                let Some(content_tree) = tree.children.get(CONTENT) else {
                     return;
                 };
 
@@ -2044,6 +2360,8 @@ where
                 });
             }
 
+            draw_sticky_header(renderer);
+
             // Draw scrollbars
             let scroll_style = &style.scroll;
             let corner_radius = crate::core::border::rounded(scroll_style.corner_radius as u32);
@@ -2120,6 +2438,11 @@ where
                     }
                 }
 
+                // Draw overview markers below the handle, so it stays on top
+                if is_vertical {
+                    draw_markers(renderer, &self.markers, scrollbar_bounds);
+                }
+
                 // Draw handle/scroller
                 if let Some(bounds) = scroller_bounds
                     && bounds.width > 0.0
@@ -2248,7 +2571,7 @@ where
                 let cached = self.cached_content.borrow();
                 let (_, content) = cached.as_ref().unwrap();
 
-                let Some(content_tree) = tree.children.first() else {
+                let Some(content_tree) = tree.children.get(CONTENT) else {
                     return;
                 };
 
@@ -2267,7 +2590,7 @@ where
                 drop(cached);
                 let content = (self.view)(visible_viewport);
 
-                let Some(content_tree) = tree.children.first() else {
+                let Some(content_tree) = tree.children.get(CONTENT) else {
                     return;
                 };
 
@@ -2283,6 +2606,8 @@ where
                     );
                 }
             }
+
+            draw_sticky_header(renderer);
         }
     }
 
@@ -2345,6 +2670,24 @@ where
             _ => cursor.levitate(),
         };
 
+        if let (Some(header), Some(sticky), Some(header_tree)) = (
+            &self.sticky_content,
+            &state.sticky_header,
+            tree.children.get(STICKY_HEADER),
+        ) {
+            let header_layout = sticky.layout(bounds);
+
+            if cursor.is_over(header_layout.bounds()) {
+                return header.as_widget().mouse_interaction(
+                    header_tree,
+                    header_layout,
+                    cursor,
+                    &bounds,
+                    renderer,
+                );
+            }
+        }
+
         // Get mouse interaction from content
         let visible_viewport = Rectangle {
             x: translation.x,
@@ -2378,7 +2721,7 @@ where
             let cached = self.cached_content.borrow();
             let (_, content) = cached.as_ref().unwrap();
 
-            let Some(content_tree) = tree.children.first() else {
+            let Some(content_tree) = tree.children.get(CONTENT) else {
                 return mouse::Interaction::None;
             };
 
@@ -2397,7 +2740,7 @@ where
             drop(cached);
             let content = (self.view)(visible_viewport);
 
-            let Some(content_tree) = tree.children.first() else {
+            let Some(content_tree) = tree.children.get(CONTENT) else {
                 return mouse::Interaction::None;
             };
 
@@ -2543,6 +2886,7 @@ where
                 align_y: alignment::Vertical::Center,
                 shaping: text::Shaping::Basic,
                 wrapping: text::Wrapping::None,
+                truncation: text::Truncation::None,
                 hint_factor: None,
             };
 
@@ -3027,3 +3371,65 @@ fn status_fields_changed(a: Status, b: Status) -> bool {
         _ => true,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grouped_list<'a>() -> VirtualScrollable<'a, (), crate::Theme, ()> {
+        show_rows(20.0, 100, |_| crate::Column::new().into()).sticky_header(|row| {
+            (row < 50).then(|| crate::Space::new().width(Length::Fill).height(10).into())
+        })
+    }
+
+    #[test]
+    fn first_visible_row_follows_the_translation() {
+        let list = grouped_list();
+
+        assert_eq!(list.first_visible_row(Vector::new(0.0, 0.0)), 0);
+        assert_eq!(list.first_visible_row(Vector::new(0.0, 45.0)), 2);
+        assert_eq!(list.first_visible_row(Vector::new(0.0, -10.0)), 0);
+    }
+
+    #[test]
+    fn sticky_header_is_rebuilt_for_the_first_visible_row() {
+        let mut list = grouped_list();
+        let mut state = State::new();
+        let mut children = Vec::new();
+
+        list.sync_sticky_header(&mut children, &mut state, &(), 3, 200.0);
+
+        assert_eq!(children.len(), STICKY_HEADER + 1);
+        assert_eq!(
+            state.sticky_header.as_ref().map(|sticky| sticky.row),
+            Some(3)
+        );
+        assert_eq!(
+            state.sticky_header.as_ref().map(|sticky| sticky
+                .layout(Rectangle::with_size(Size::new(200.0, 100.0)))
+                .bounds()),
+            Some(Rectangle::new(Point::ORIGIN, Size::new(200.0, 10.0)))
+        );
+
+        list.sync_sticky_header(&mut children, &mut state, &(), 4, 200.0);
+
+        assert_eq!(
+            state.sticky_header.as_ref().map(|sticky| sticky.row),
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn sticky_header_is_removed_when_the_row_has_none() {
+        let mut list = grouped_list();
+        let mut state = State::new();
+        let mut children = Vec::new();
+
+        list.sync_sticky_header(&mut children, &mut state, &(), 3, 200.0);
+        list.sync_sticky_header(&mut children, &mut state, &(), 60, 200.0);
+
+        assert!(state.sticky_header.is_none());
+        assert!(list.sticky_content.is_none());
+        assert_eq!(children.len(), CONTENT + 1);
+    }
+}
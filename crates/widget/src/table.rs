@@ -8,7 +8,7 @@ use crate::core::layout;
 use crate::core::mouse;
 use crate::core::overlay;
 use crate::core::renderer;
-use crate::core::text;
+use crate::core::text::{self, Truncation};
 use crate::core::widget;
 use crate::core::{
     Alignment, Background, Element, Layout, LayoutDirection, Length, Pixels, Rectangle, Size,
@@ -87,6 +87,53 @@ where
     virtual_scrollable::show_rows(row_height, total_rows, view)
 }
 
+/// Places a header above the body of a table, keeping it pinned while the body scrolls.
+///
+/// The result is a [`Sticky`] section: inside a [`Scrollable`], the header stays
+/// pinned to the top of the viewport until the end of the table pushes it out.
+/// Together with [`virtual_table`], whose rows are scrolled inside their own
+/// viewport, the header simply stays above that viewport. Either way, the header
+/// keeps receiving events and focus like any other widget.
+///
+/// For grouped rows, [`VirtualScrollable::sticky_header`] can additionally pin the
+/// header of the section the first visible row belongs to.
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use icy_ui_widget::*; } }
+/// # pub type Element<'a, Message> = icy_ui_widget::core::Element<'a, Message, icy_ui_widget::Theme, icy_ui_widget::Renderer>;
+/// use icy_ui::widget::{column, row, text, table};
+///
+/// enum Message {}
+///
+/// fn view(items: &[(String, i32)]) -> Element<'_, Message> {
+///     table::with_header(
+///         row![text("Name"), text("Value")],
+///         table::virtual_table(30.0, items.len(), |visible_range| {
+///             column(visible_range.map(|i| {
+///                 let (name, value) = &items[i];
+///                 row![text(name), text(value.to_string())].into()
+///             }))
+///             .into()
+///         }),
+///     )
+///     .into()
+/// }
+/// ```
+///
+/// [`Sticky`]: crate::Sticky
+/// [`Scrollable`]: crate::Scrollable
+/// [`VirtualScrollable::sticky_header`]: virtual_scrollable::VirtualScrollable::sticky_header
+pub fn with_header<'a, Message, Theme, Renderer>(
+    header: impl Into<Element<'a, Message, Theme, Renderer>>,
+    body: impl Into<Element<'a, Message, Theme, Renderer>>,
+) -> crate::Sticky<'a, Message, Theme, Renderer>
+where
+    Renderer: core::Renderer,
+{
+    crate::Sticky::new(header, body)
+}
+
 /// Creates a new [`Column`] with the given header and view function.
 ///
 /// The view function will be called for each row in a [`Table`] and it must
@@ -108,6 +155,30 @@ where
     }
 }
 
+/// Creates a cell that displays the given text on a single line, truncated
+/// with the given [`Truncation`] when it does not fit its [`Column`].
+///
+/// While a cell is truncated, hovering it shows the full text in a
+/// [`Tooltip`](crate::Tooltip). Its accessibility label is always the full
+/// text.
+pub fn cell<'a, Message, Theme, Renderer>(
+    content: impl text::IntoFragment<'a>,
+    truncation: Truncation,
+) -> crate::Tooltip<'a, Message, Theme, Renderer>
+where
+    Theme: crate::container::Catalog + crate::text::Catalog + 'a,
+    Renderer: text::Renderer + 'a,
+{
+    let content = content.into_fragment();
+
+    crate::Tooltip::new(
+        crate::Text::new(content.clone()).truncation(truncation),
+        crate::Text::new(content),
+        crate::tooltip::Position::FollowCursor,
+    )
+    .when_truncated(true)
+}
+
 /// A grid-like visual representation of data distributed in columns and rows.
 pub struct Table<'a, Message, Theme = crate::Theme, Renderer = crate::Renderer>
 where
@@ -646,6 +717,13 @@ where
         renderer: &Renderer,
         operation: &mut dyn widget::Operation,
     ) {
+        #[cfg(feature = "accessibility")]
+        if let Some(info) =
+            <Self as Widget<Message, Theme, Renderer>>::accessibility(self, tree, layout)
+        {
+            operation.accessibility(None, layout.bounds(), info);
+        }
+
         for ((cell, state), layout) in self
             .cells
             .iter_mut()
@@ -674,6 +752,43 @@ where
             translation,
         )
     }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility(
+        &self,
+        _tree: &widget::Tree,
+        layout: Layout<'_>,
+    ) -> Option<core::accessibility::WidgetInfo> {
+        use core::accessibility::WidgetInfo;
+
+        let columns = self.columns.len().max(1);
+        let rows = self.cells.len().div_ceil(columns);
+
+        // The cells are exposed as generated children of the table, so
+        // screen readers can navigate them by row and column.
+        let cells =
+            self.cells
+                .iter()
+                .zip(layout.children())
+                .enumerate()
+                .map(|(i, (cell, layout))| {
+                    let (row, column) = (i / columns, i % columns);
+                    let label = cell.as_widget().accessibility_label().unwrap_or_default();
+
+                    let info = if row == 0 {
+                        WidgetInfo::column_header(label, column)
+                    } else {
+                        WidgetInfo::cell(row, column).with_label(label)
+                    };
+
+                    info.with_bounds(layout.bounds())
+                });
+
+        Some(cells.fold(
+            WidgetInfo::table(rows, columns).with_bounds(layout.bounds()),
+            WidgetInfo::with_extra_child,
+        ))
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<Table<'a, Message, Theme, Renderer>>
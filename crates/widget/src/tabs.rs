@@ -0,0 +1,1721 @@
+//! Tabs let users switch between documents or views sharing the same space.
+//!
+//! A [`Tabs`] bar shows one tab per item. Tabs can be closed (with their close
+//! button, a middle click, or the Delete key while the bar is focused),
+//! reordered by dragging them, and cycled through with Ctrl+Tab, even while the
+//! focus is in their content. Tabs can also be dragged out of the bar (see
+//! [`Tabs::on_drag_out`]); for instance, to move them to another pane of a
+//! [`PaneGrid`] or to a new window.
+//!
+//! [`PaneGrid`]: crate::PaneGrid
+//!
+//! Labels support mnemonics like the menus: `"&Files"` selects the tab with
+//! Alt+F and underlines the `F` while Alt is held.
+//!
+//! When the tabs do not fit, the bar scrolls horizontally with the mouse wheel
+//! or its arrow buttons, and a menu button lists every tab. The selected tab is
+//! always revealed.
+//!
+//! # Example
+//! ```no_run
+//! # mod iced { pub mod widget { pub use icy_ui_widget::*; } pub use icy_ui_widget::Renderer; pub use icy_ui_widget::core::*; }
+//! # pub type Element<'a, Message> = icy_ui_widget::core::Element<'a, Message, icy_ui_widget::Theme, icy_ui_widget::Renderer>;
+//! #
+//! use icy_ui::widget::{column, tabs, text};
+//! use icy_ui::widget::tabs::Reorder;
+//!
+//! struct State {
+//!     documents: Vec<String>,
+//!     active: Option<String>,
+//! }
+//!
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Selected(String),
+//!     Closed(String),
+//!     Reordered(Reorder),
+//! }
+//!
+//! fn view(state: &State) -> Element<'_, Message> {
+//!     column![
+//!         tabs(state.documents.clone(), state.active.clone(), Message::Selected)
+//!             .on_close(Message::Closed)
+//!             .on_reorder(Message::Reordered),
+//!         text(state.active.clone().unwrap_or_default()),
+//!     ]
+//!     .into()
+//! }
+//!
+//! fn update(state: &mut State, message: Message) {
+//!     match message {
+//!         Message::Selected(document) => state.active = Some(document),
+//!         Message::Closed(document) => state.documents.retain(|d| *d != document),
+//!         Message::Reordered(reorder) => reorder.apply(&mut state.documents),
+//!     }
+//! }
+//! ```
+use crate::core::alignment;
+use crate::core::border::{self, Border};
+use crate::core::keyboard;
+use crate::core::keyboard::key::{self, Key};
+use crate::core::layout;
+use crate::core::mouse;
+use crate::core::overlay;
+use crate::core::renderer;
+use crate::core::text::{self, Paragraph as _, Text};
+use crate::core::touch;
+use crate::core::widget;
+use crate::core::widget::Id as WidgetId;
+use crate::core::widget::operation::{self, Operation};
+use crate::core::widget::tree::{self, Tree};
+use crate::core::{
+    Background, Clipboard, Color, Element, Event, Layout, Length, Padding, Pixels, Point,
+    Rectangle, Shell, Size, Theme, Vector, Widget,
+};
+use crate::focus::FocusRing;
+use crate::menu::{mnemonics_enabled, parse_mnemonic};
+use crate::overlay::menu::{self, Menu};
+
+/// A strip of tabs that allows selecting, closing and reordering items.
+///
+/// This widget handles keyboard navigation internally:
+/// - **Tab**: Focus enters/leaves the bar (single tab stop)
+/// - **Arrow Left/Right, Home/End**: Select another tab while focused
+/// - **Delete**: Close the selected tab while focused
+/// - **Ctrl+Tab / Ctrl+Shift+Tab**: Select the next/previous tab, unless another
+///   widget handles it
+/// - **Alt+mnemonic**: Select the tab with the matching mnemonic, unless another
+///   widget handles it
+///
+/// # Example
+/// ```no_run
+/// # mod iced { pub mod widget { pub use icy_ui_widget::*; } pub use icy_ui_widget::Renderer; pub use icy_ui_widget::core::*; }
+/// # pub type Element<'a, Message> = icy_ui_widget::core::Element<'a, Message, icy_ui_widget::Theme, icy_ui_widget::Renderer>;
+/// #
+/// use icy_ui::widget::tabs;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// enum Page {
+///     General,
+///     Advanced,
+/// }
+///
+/// impl std::fmt::Display for Page {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         match self {
+///             Page::General => write!(f, "&General"),
+///             Page::Advanced => write!(f, "&Advanced"),
+///         }
+///     }
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     Selected(Page),
+/// }
+///
+/// fn view(page: Page) -> Element<'static, Message> {
+///     tabs([Page::General, Page::Advanced], Some(page), Message::Selected).into()
+/// }
+/// ```
+pub struct Tabs<'a, T, Message, Theme = crate::Theme, Renderer = crate::Renderer>
+where
+    T: Clone + PartialEq + ToString,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    id: Option<WidgetId>,
+    tabs: Vec<T>,
+    labels: Vec<Label>,
+    selected: Option<T>,
+    on_select: Box<dyn Fn(T) -> Message + 'a>,
+    on_close: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    on_reorder: Option<Box<dyn Fn(Reorder) -> Message + 'a>>,
+    on_drag_out: Option<Box<dyn Fn(T) -> Message + 'a>>,
+    width: Length,
+    padding: Padding,
+    spacing: f32,
+    text_size: Option<Pixels>,
+    text_line_height: text::LineHeight,
+    font: Option<Renderer::Font>,
+    class: <Theme as Catalog>::Class<'a>,
+    menu_class: <Theme as menu::Catalog>::Class<'a>,
+}
+
+impl<'a, T, Message, Theme, Renderer> Tabs<'a, T, Message, Theme, Renderer>
+where
+    T: Clone + PartialEq + ToString,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    /// The default padding of a tab.
+    pub const DEFAULT_PADDING: Padding = Padding {
+        top: 6.0,
+        bottom: 6.0,
+        right: 12.0,
+        left: 12.0,
+    };
+
+    /// The default spacing between tabs.
+    pub const DEFAULT_SPACING: f32 = 2.0;
+
+    /// The size of the close button of a tab.
+    const CLOSE_SIZE: f32 = 16.0;
+
+    /// The spacing between the label and the close button of a tab.
+    const CLOSE_SPACING: f32 = 6.0;
+
+    /// The width of each of the scroll and menu buttons shown when the tabs
+    /// overflow.
+    const ARROW_WIDTH: f32 = 20.0;
+
+    /// The distance the cursor must travel before a press becomes a drag.
+    const DRAG_THRESHOLD: f32 = 4.0;
+
+    /// The vertical distance from the bar at which a dragged tab is dragged out.
+    const DRAG_OUT_DISTANCE: f32 = 24.0;
+
+    /// Creates a new [`Tabs`] bar.
+    ///
+    /// It expects:
+    ///   * the list of tabs
+    ///   * the currently selected tab (if any)
+    ///   * a function that will be called when a tab is selected
+    pub fn new<F>(tabs: impl IntoIterator<Item = T>, selected: Option<T>, on_select: F) -> Self
+    where
+        F: Fn(T) -> Message + 'a,
+    {
+        let tabs: Vec<T> = tabs.into_iter().collect();
+        let labels = tabs
+            .iter()
+            .enumerate()
+            .map(|(index, tab)| Label::new(index, &tab.to_string()))
+            .collect();
+
+        Tabs {
+            id: None,
+            tabs,
+            labels,
+            selected,
+            on_select: Box::new(on_select),
+            on_close: None,
+            on_reorder: None,
+            on_drag_out: None,
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            spacing: Self::DEFAULT_SPACING,
+            text_size: None,
+            text_line_height: text::LineHeight::default(),
+            font: None,
+            class: <Theme as Catalog>::default(),
+            menu_class: <Theme as menu::Catalog>::default(),
+        }
+    }
+
+    /// Sets the unique identifier of the [`Tabs`] bar.
+    pub fn id(mut self, id: impl Into<WidgetId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Makes the tabs closable, producing the given message when a tab is closed.
+    pub fn on_close(mut self, on_close: impl Fn(T) -> Message + 'a) -> Self {
+        self.on_close = Some(Box::new(on_close));
+        self
+    }
+
+    /// Makes the tabs reorderable by dragging, producing the given message when
+    /// a tab is dropped at a new position.
+    pub fn on_reorder(mut self, on_reorder: impl Fn(Reorder) -> Message + 'a) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+
+    /// Lets tabs be dragged out of the bar, producing the given message when a
+    /// pressed tab is dragged away from it vertically.
+    ///
+    /// The tab is not removed. The message is a good place to start a drag
+    /// and drop operation, like moving the tab to another [`PaneGrid`] with the
+    /// [`TAB_DRAG_FORMAT`], or opening it in a new window when the drop is
+    /// cancelled (see [`State::open_tab_in_window`]).
+    ///
+    /// [`PaneGrid`]: crate::PaneGrid
+    /// [`TAB_DRAG_FORMAT`]: crate::pane_grid::TAB_DRAG_FORMAT
+    /// [`State::open_tab_in_window`]: crate::pane_grid::State::open_tab_in_window
+    pub fn on_drag_out(mut self, on_drag_out: impl Fn(T) -> Message + 'a) -> Self {
+        self.on_drag_out = Some(Box::new(on_drag_out));
+        self
+    }
+
+    /// Sets the width of the [`Tabs`] bar.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the [`Padding`] of each tab.
+    pub fn padding(mut self, padding: impl Into<Padding>) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the spacing between tabs.
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Sets the text size of the labels.
+    pub fn text_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.text_size = Some(size.into());
+        self
+    }
+
+    /// Sets the text line height of the labels.
+    pub fn text_line_height(mut self, line_height: impl Into<text::LineHeight>) -> Self {
+        self.text_line_height = line_height.into();
+        self
+    }
+
+    /// Sets the font of the labels.
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = Some(font.into());
+        self
+    }
+
+    /// Sets the style of the [`Tabs`] bar.
+    #[must_use]
+    pub fn style(mut self, style: impl Fn(&Theme, Status, bool) -> Style + 'a) -> Self
+    where
+        <Theme as Catalog>::Class<'a>: From<StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style of the overflow menu of the [`Tabs`] bar.
+    #[must_use]
+    pub fn menu_style(mut self, style: impl Fn(&Theme) -> menu::Style + 'a) -> Self
+    where
+        <Theme as menu::Catalog>::Class<'a>: From<menu::StyleFn<'a, Theme>>,
+    {
+        self.menu_class = (Box::new(style) as menu::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Tabs`] bar.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn class(mut self, class: impl Into<<Theme as Catalog>::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Sets the style class of the overflow menu of the [`Tabs`] bar.
+    #[cfg(feature = "advanced")]
+    #[must_use]
+    pub fn menu_class(mut self, class: impl Into<<Theme as menu::Catalog>::Class<'a>>) -> Self {
+        self.menu_class = class.into();
+        self
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+
+        self.tabs.iter().position(|tab| tab == selected)
+    }
+
+    fn select(&self, index: usize, shell: &mut Shell<'_, Message>) {
+        if let Some(tab) = self.tabs.get(index)
+            && self.selected.as_ref() != Some(tab)
+        {
+            shell.publish((self.on_select)(tab.clone()));
+        }
+    }
+
+    fn close(&self, index: usize, shell: &mut Shell<'_, Message>) {
+        if let (Some(on_close), Some(tab)) = (&self.on_close, self.tabs.get(index)) {
+            shell.publish(on_close(tab.clone()));
+        }
+    }
+}
+
+/// A request to move a tab to a new position, produced by dragging it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reorder {
+    /// The index of the dragged tab.
+    pub from: usize,
+    /// The index the dragged tab should have after moving it.
+    pub to: usize,
+}
+
+impl Reorder {
+    /// Applies the [`Reorder`] to the given items.
+    pub fn apply<T>(&self, items: &mut Vec<T>) {
+        if self.from < items.len() && self.to < items.len() {
+            let item = items.remove(self.from);
+            items.insert(self.to, item);
+        }
+    }
+}
+
+/// The label of a tab, with its mnemonic parsed.
+#[derive(Debug, Clone)]
+struct Label {
+    /// The index of the tab.
+    index: usize,
+    text: String,
+    mnemonic: Option<char>,
+    /// The grapheme index of the mnemonic character in `text`.
+    underline: Option<usize>,
+}
+
+impl Label {
+    fn new(index: usize, label: &str) -> Self {
+        let parsed = parse_mnemonic(label);
+
+        Self {
+            index,
+            underline: parsed
+                .underline_index
+                .map(|index| parsed.display_text[..index].chars().count()),
+            mnemonic: parsed.mnemonic_char,
+            text: parsed.display_text.into_owned(),
+        }
+    }
+}
+
+impl std::fmt::Display for Label {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// Returns the index of the tab with the given mnemonic, if any.
+fn mnemonic_index(labels: &[Label], c: char) -> Option<usize> {
+    let c = c.to_ascii_lowercase();
+
+    labels
+        .iter()
+        .position(|label| label.mnemonic.is_some_and(|mnemonic| mnemonic == c))
+}
+
+/// Returns the index of the tab after (or before) the `current` one, wrapping
+/// around at both ends.
+fn cycle(current: Option<usize>, count: usize, backwards: bool) -> usize {
+    let current = current.unwrap_or(0);
+
+    if backwards {
+        (current + count - 1) % count
+    } else {
+        (current + 1) % count
+    }
+}
+
+/// Internal state of a [`Tabs`] bar.
+pub struct State<P: text::Paragraph> {
+    is_focused: bool,
+    show_mnemonics: bool,
+    /// The horizontal scroll offset of the tabs, measured from the start edge.
+    scroll: f32,
+    /// The selected tab last revealed by scrolling.
+    revealed: Option<usize>,
+    /// The tab whose close button is being pressed.
+    closing: Option<usize>,
+    drag: Option<Drag>,
+    paragraphs: Vec<widget::text::State<P>>,
+    menu: menu::State,
+    is_menu_open: bool,
+    hovered_option: Option<usize>,
+}
+
+/// A tab being pressed or dragged.
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    index: usize,
+    origin: f32,
+    cursor: f32,
+    is_dragging: bool,
+}
+
+impl<P: text::Paragraph> Default for State<P> {
+    fn default() -> Self {
+        Self {
+            is_focused: false,
+            show_mnemonics: false,
+            scroll: 0.0,
+            revealed: None,
+            closing: None,
+            drag: None,
+            paragraphs: Vec::new(),
+            menu: menu::State::default(),
+            is_menu_open: false,
+            hovered_option: None,
+        }
+    }
+}
+
+impl<P: text::Paragraph> operation::Focusable for State<P> {
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+        self.show_mnemonics = false;
+    }
+
+    fn focus_tier(&self) -> operation::FocusTier {
+        operation::FocusTier::Control
+    }
+}
+
+/// The visible part of the tabs, given the current scroll offset.
+struct Strip {
+    /// The area showing the tabs.
+    viewport: Rectangle,
+    /// The translation from layout to screen coordinates of the tabs.
+    translation: Vector,
+    /// The scroll buttons, if the tabs overflow.
+    arrows: Option<(Rectangle, Rectangle)>,
+    /// The button opening the menu listing every tab, if the tabs overflow.
+    menu: Option<Rectangle>,
+    /// The maximum scroll offset.
+    max_scroll: f32,
+}
+
+impl Strip {
+    fn new(layout: Layout<'_>, scroll: f32, arrow_width: f32) -> Self {
+        let bounds = layout.bounds();
+        let is_rtl = crate::core::layout_direction().is_rtl();
+
+        let content_width = layout
+            .children()
+            .map(|tab| tab.bounds().x + tab.bounds().width - bounds.x)
+            .fold(0.0, f32::max);
+
+        let overflows = content_width > bounds.width;
+
+        // The scroll buttons are followed by the menu button at the end edge
+        let viewport_width = if overflows {
+            (bounds.width - 3.0 * arrow_width).max(0.0)
+        } else {
+            bounds.width
+        };
+
+        let viewport = Rectangle {
+            x: if overflows && is_rtl {
+                bounds.x + 3.0 * arrow_width
+            } else {
+                bounds.x
+            },
+            width: viewport_width,
+            ..bounds
+        };
+
+        let max_scroll = (content_width - viewport_width).max(0.0);
+        let scroll = scroll.clamp(0.0, max_scroll);
+
+        // In RTL the tabs are mirrored in layout, so their end edge is
+        // aligned with the end of the viewport when not scrolled.
+        let translation = if is_rtl {
+            Vector::new(
+                viewport.x + viewport.width - content_width - bounds.x + scroll,
+                0.0,
+            )
+        } else {
+            Vector::new(viewport.x - bounds.x - scroll, 0.0)
+        };
+
+        let button = |x| Rectangle {
+            x,
+            width: arrow_width,
+            ..bounds
+        };
+
+        let (arrows, menu) = if overflows {
+            let x = if is_rtl {
+                bounds.x
+            } else {
+                bounds.x + bounds.width - 3.0 * arrow_width
+            };
+
+            // The first arrow always scrolls back towards the start.
+            if is_rtl {
+                (
+                    Some((button(x + 2.0 * arrow_width), button(x + arrow_width))),
+                    Some(button(x)),
+                )
+            } else {
+                (
+                    Some((button(x), button(x + arrow_width))),
+                    Some(button(x + 2.0 * arrow_width)),
+                )
+            }
+        } else {
+            (None, None)
+        };
+
+        Self {
+            viewport,
+            translation,
+            arrows,
+            menu,
+            max_scroll,
+        }
+    }
+
+    fn tab<'a>(&self, layout: Layout<'a>, index: usize) -> Layout<'a> {
+        layout.child(index).translate(self.translation)
+    }
+
+    fn tab_at(&self, layout: Layout<'_>, position: Point) -> Option<usize> {
+        if !self.viewport.contains(position) {
+            return None;
+        }
+
+        (0..layout.children().len())
+            .find(|index| self.tab(layout, *index).bounds().contains(position))
+    }
+}
+
+impl<T, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
+    for Tabs<'_, T, Message, Theme, Renderer>
+where
+    T: Clone + PartialEq + ToString,
+    Theme: Catalog,
+    Renderer: text::Renderer,
+{
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Renderer::Paragraph>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Renderer::Paragraph>::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.width,
+            height: Length::Shrink,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        tree: &mut Tree,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        state
+            .paragraphs
+            .resize_with(self.tabs.len(), widget::text::State::default);
+
+        let close_width = if self.on_close.is_some() {
+            Self::CLOSE_SIZE + Self::CLOSE_SPACING
+        } else {
+            0.0
+        };
+
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let text_limits = layout::Limits::new(Size::ZERO, Size::INFINITE);
+
+        let mut tabs = Vec::with_capacity(self.tabs.len());
+
+        for (label, paragraph) in self.labels.iter().zip(&mut state.paragraphs) {
+            let text = widget::text::layout(
+                paragraph,
+                renderer,
+                &text_limits,
+                &label.text,
+                widget::text::Format {
+                    width: Length::Shrink,
+                    height: Length::Shrink,
+                    line_height: self.text_line_height,
+                    size: self.text_size,
+                    font: self.font,
+                    align_x: text::Alignment::Default,
+                    align_y: alignment::Vertical::Center,
+                    shaping: text::Shaping::Advanced,
+                    wrapping: text::Wrapping::None,
+                    truncation: text::Truncation::None,
+                },
+            );
+
+            tabs.push(text);
+        }
+
+        let content_height = tabs
+            .iter()
+            .map(|text| text.size().height)
+            .fold(0.0, f32::max)
+            .max(if close_width > 0.0 {
+                Self::CLOSE_SIZE
+            } else {
+                0.0
+            });
+
+        let height = content_height + self.padding.y();
+        let is_rtl = crate::core::layout_direction().is_rtl();
+
+        let mut x = 0.0;
+
+        let mut tabs: Vec<layout::Node> = tabs
+            .into_iter()
+            .map(|text| {
+                let width = self.padding.x() + text.size().width + close_width;
+                let text_x = if is_rtl {
+                    self.padding.right + close_width
+                } else {
+                    self.padding.left
+                };
+                let close_x = if is_rtl {
+                    self.padding.right
+                } else {
+                    width - self.padding.right - Self::CLOSE_SIZE
+                };
+
+                let close = layout::Node::new(if close_width > 0.0 {
+                    Size::new(Self::CLOSE_SIZE, Self::CLOSE_SIZE)
+                } else {
+                    Size::ZERO
+                })
+                .move_to(Point::new(close_x, (height - Self::CLOSE_SIZE) / 2.0));
+
+                let text_y = (height - text.size().height) / 2.0;
+
+                let tab = layout::Node::with_children(
+                    Size::new(width, height),
+                    vec![text.move_to(Point::new(text_x, text_y)), close],
+                )
+                .move_to(Point::new(x, 0.0));
+
+                x += width + self.spacing;
+
+                tab
+            })
+            .collect();
+
+        let content_width = (x - self.spacing).max(0.0);
+
+        // Tabs start at the end edge in RTL
+        if is_rtl {
+            for tab in &mut tabs {
+                let bounds = tab.bounds();
+
+                tab.move_to_mut(Point::new(content_width - bounds.x - bounds.width, 0.0));
+            }
+        }
+
+        let size = limits.resolve(self.width, Length::Shrink, Size::new(content_width, height));
+
+        let viewport_width = if content_width > size.width {
+            (size.width - 3.0 * Self::ARROW_WIDTH).max(0.0)
+        } else {
+            size.width
+        };
+
+        // Reveal the selected tab whenever the selection changes
+        let selected = self.selected_index();
+
+        if selected != state.revealed {
+            state.revealed = selected;
+
+            if let Some(tab) = selected.and_then(|index| tabs.get(index)) {
+                let bounds = tab.bounds();
+
+                let (start, end) = if is_rtl {
+                    (
+                        content_width - bounds.x - bounds.width,
+                        content_width - bounds.x,
+                    )
+                } else {
+                    (bounds.x, bounds.x + bounds.width)
+                };
+
+                if start < state.scroll {
+                    state.scroll = start;
+                } else if end > state.scroll + viewport_width {
+                    state.scroll = end - viewport_width;
+                }
+            }
+        }
+
+        state.scroll = state
+            .scroll
+            .clamp(0.0, (content_width - viewport_width).max(0.0));
+
+        layout::Node::with_children(size, tabs)
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        let strip = Strip::new(layout, state.scroll, Self::ARROW_WIDTH);
+        let count = self.tabs.len();
+        let is_rtl = crate::core::layout_direction().is_rtl();
+
+        // Mnemonics are underlined while Alt is held, like in the menus
+        if let Event::Keyboard(
+            keyboard::Event::KeyPressed { modifiers, .. }
+            | keyboard::Event::KeyReleased { modifiers, .. },
+        ) = event
+        {
+            let show_mnemonics = mnemonics_enabled() && modifiers.alt();
+
+            if show_mnemonics != state.show_mnemonics {
+                state.show_mnemonics = show_mnemonics;
+                shell.request_redraw();
+            }
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed {
+                button: mouse::Button::Left,
+                ..
+            })
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if state.is_menu_open {
+                    // The overlay did not process the event, so the press is
+                    // either outside of the menu or on its button.
+                    state.is_menu_open = false;
+
+                    shell.request_redraw();
+                    shell.capture_event();
+
+                    return;
+                }
+
+                let Some(position) = cursor.position_over(layout.bounds()) else {
+                    if state.is_focused {
+                        state.is_focused = false;
+                        state.show_mnemonics = false;
+                        shell.request_redraw();
+                    }
+
+                    return;
+                };
+
+                if let Some((back, forward)) = strip.arrows {
+                    let step = strip.viewport.width / 2.0;
+
+                    if back.contains(position) {
+                        state.scroll = (state.scroll - step).max(0.0);
+                    } else if forward.contains(position) {
+                        state.scroll = (state.scroll + step).min(strip.max_scroll);
+                    }
+                }
+
+                if strip.menu.is_some_and(|menu| menu.contains(position)) {
+                    state.is_menu_open = true;
+                    state.hovered_option = self.selected_index();
+                }
+
+                if let Some(index) = strip.tab_at(layout, position) {
+                    let close = strip.tab(layout, index).child(1).bounds();
+
+                    if self.on_close.is_some() && close.contains(position) {
+                        state.closing = Some(index);
+                    } else {
+                        self.select(index, shell);
+
+                        state.drag = Some(Drag {
+                            index,
+                            origin: position.x,
+                            cursor: position.x,
+                            is_dragging: false,
+                        });
+                    }
+                }
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Mouse(mouse::Event::ButtonPressed {
+                button: mouse::Button::Middle,
+                ..
+            }) => {
+                if let Some(index) = cursor
+                    .position_over(layout.bounds())
+                    .and_then(|position| strip.tab_at(layout, position))
+                    && self.on_close.is_some()
+                {
+                    self.close(index, shell);
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position, .. })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(drag) = &mut state.drag {
+                    drag.cursor = position.x;
+
+                    let bounds = layout.bounds();
+
+                    if let Some(on_drag_out) = &self.on_drag_out
+                        && (position.y < bounds.y - Self::DRAG_OUT_DISTANCE
+                            || position.y > bounds.y + bounds.height + Self::DRAG_OUT_DISTANCE)
+                    {
+                        if let Some(tab) = self.tabs.get(drag.index) {
+                            shell.publish(on_drag_out(tab.clone()));
+                        }
+
+                        state.drag = None;
+
+                        shell.request_redraw();
+                        shell.capture_event();
+
+                        return;
+                    }
+
+                    if !drag.is_dragging
+                        && self.on_reorder.is_some()
+                        && (drag.cursor - drag.origin).abs() > Self::DRAG_THRESHOLD
+                    {
+                        drag.is_dragging = true;
+                    }
+
+                    if drag.is_dragging {
+                        shell.request_redraw();
+                        shell.capture_event();
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased {
+                button: mouse::Button::Left,
+                ..
+            })
+            | Event::Touch(touch::Event::FingerLifted { .. } | touch::Event::FingerLost { .. }) => {
+                if let Some(index) = state.closing.take() {
+                    let close = strip.tab(layout, index).child(1).bounds();
+
+                    if cursor.is_over(close) {
+                        self.close(index, shell);
+                    }
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+
+                if let Some(drag) = state.drag.take()
+                    && drag.is_dragging
+                {
+                    let to = drop_index(layout, &strip, drag, is_rtl);
+
+                    if let Some(on_reorder) = &self.on_reorder
+                        && to != drag.index
+                    {
+                        shell.publish(on_reorder(Reorder {
+                            from: drag.index,
+                            to,
+                        }));
+                    }
+
+                    shell.request_redraw();
+                    shell.capture_event();
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta, .. })
+                if strip.max_scroll > 0.0 && cursor.is_over(layout.bounds()) =>
+            {
+                let delta = match *delta {
+                    mouse::ScrollDelta::Lines { x, y } => (if x == 0.0 { y } else { x }) * 40.0,
+                    mouse::ScrollDelta::Pixels { x, y } => {
+                        if x == 0.0 {
+                            y
+                        } else {
+                            x
+                        }
+                    }
+                };
+
+                state.scroll = (state.scroll - delta).clamp(0.0, strip.max_scroll);
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key: Key::Named(key::Named::Escape),
+                ..
+            }) if state.is_menu_open => {
+                state.is_menu_open = false;
+
+                shell.request_redraw();
+                shell.capture_event();
+            }
+            // Ctrl+Tab and mnemonics work while the focus is in the content of
+            // the tabs, unless the focused widget handles them first.
+            Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. })
+                if count > 0 && !shell.is_event_captured() =>
+            {
+                let selected = self.selected_index();
+
+                let target = match key {
+                    Key::Named(key::Named::Tab) if modifiers.control() => {
+                        Some(cycle(selected, count, modifiers.shift()))
+                    }
+                    Key::Character(c) if modifiers.alt() && mnemonics_enabled() => c
+                        .chars()
+                        .next()
+                        .and_then(|c| mnemonic_index(&self.labels, c)),
+                    Key::Named(named) if state.is_focused => {
+                        let current = selected.unwrap_or(0);
+                        let (previous, next) = if is_rtl {
+                            (key::Named::ArrowRight, key::Named::ArrowLeft)
+                        } else {
+                            (key::Named::ArrowLeft, key::Named::ArrowRight)
+                        };
+
+                        match named {
+                            named if *named == previous => Some(current.saturating_sub(1)),
+                            named if *named == next => Some((current + 1).min(count - 1)),
+                            key::Named::Home => Some(0),
+                            key::Named::End => Some(count - 1),
+                            key::Named::Delete => {
+                                if let Some(index) = selected
+                                    && self.on_close.is_some()
+                                {
+                                    self.close(index, shell);
+                                    shell.capture_event();
+                                }
+
+                                None
+                            }
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                };
+
+                if let Some(index) = target {
+                    self.select(index, shell);
+                    shell.capture_event();
+                }
+            }
+            #[cfg(feature = "accessibility")]
+            Event::Accessibility(accessibility_event) => {
+                use crate::core::accessibility::{derived_node_id, node_id_from_widget_id};
+
+                let Some(list_id) = self.id.as_ref().map(node_id_from_widget_id) else {
+                    return;
+                };
+
+                if let Some(index) = (0..count).find(|index| {
+                    derived_node_id(list_id, *index as u64) == accessibility_event.target
+                }) {
+                    if accessibility_event.is_click() || accessibility_event.is_focus() {
+                        state.is_focused = true;
+                        self.select(index, shell);
+                        shell.request_redraw();
+                        shell.capture_event();
+                    }
+                } else if list_id == accessibility_event.target {
+                    if accessibility_event.is_focus() {
+                        state.is_focused = true;
+                        shell.request_redraw();
+                        shell.capture_event();
+                    }
+
+                    if accessibility_event.is_blur() {
+                        state.is_focused = false;
+                        shell.request_redraw();
+                        shell.capture_event();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+
+        if state.drag.is_some_and(|drag| drag.is_dragging) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        defaults: &renderer::Style,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        viewport: &Rectangle,
+    ) {
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let strip = Strip::new(layout, state.scroll, Self::ARROW_WIDTH);
+        let bounds = layout.bounds();
+        let selected = self.selected_index();
+        let is_rtl = crate::core::layout_direction().is_rtl();
+
+        let bar = Catalog::style(theme, &self.class, Status::Active, false);
+
+        if let Some(background) = bar.bar_background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    ..renderer::Quad::default()
+                },
+                background,
+            );
+        }
+
+        let dragged = state.drag.filter(|drag| drag.is_dragging);
+        let hovered = cursor
+            .position()
+            .filter(|_| dragged.is_none())
+            .and_then(|position| strip.tab_at(layout, position));
+
+        let draw_tab = |renderer: &mut Renderer, index: usize, tab: Layout<'_>, status: Status| {
+            let is_selected = selected == Some(index);
+            let style = Catalog::style(theme, &self.class, status, is_selected);
+            let tab_bounds = tab.bounds();
+
+            if style.background.is_some() || style.border.width > 0.0 {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: tab_bounds,
+                        border: style.border,
+                        ..renderer::Quad::default()
+                    },
+                    style
+                        .background
+                        .unwrap_or(Background::Color(Color::TRANSPARENT)),
+                );
+            }
+
+            if let Some(indicator) = style.indicator {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            y: tab_bounds.y + tab_bounds.height - 2.0,
+                            height: 2.0,
+                            ..tab_bounds
+                        },
+                        ..renderer::Quad::default()
+                    },
+                    indicator,
+                );
+            }
+
+            let text_bounds = tab.child(0).bounds();
+
+            if let Some(paragraph) = state.paragraphs.get(index) {
+                crate::text::draw(
+                    renderer,
+                    defaults,
+                    text_bounds,
+                    paragraph.raw(),
+                    crate::text::Style {
+                        color: Some(style.text_color),
+                    },
+                    viewport,
+                );
+
+                if state.show_mnemonics
+                    && let Some(underline) = self.labels[index].underline
+                    && let (Some(start), Some(end)) = (
+                        paragraph.raw().grapheme_position(0, underline),
+                        paragraph.raw().grapheme_position(0, underline + 1),
+                    )
+                {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: Rectangle {
+                                x: text_bounds.x + start.x.min(end.x),
+                                y: text_bounds.y + text_bounds.height - 1.0,
+                                width: (end.x - start.x).abs().max(1.0),
+                                height: 1.0,
+                            },
+                            ..renderer::Quad::default()
+                        },
+                        style.text_color,
+                    );
+                }
+            }
+
+            if self.on_close.is_some() {
+                let close = tab.child(1).bounds();
+
+                if state.closing == Some(index) || cursor.is_over(close) {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds: close,
+                            border: border::rounded(close.width / 2.0),
+                            ..renderer::Quad::default()
+                        },
+                        style.close_color.scale_alpha(0.2),
+                    );
+                }
+
+                renderer.fill_text(
+                    Text {
+                        content: String::from("×"),
+                        size: Pixels(Self::CLOSE_SIZE - 2.0),
+                        line_height: text::LineHeight::Absolute(Pixels(Self::CLOSE_SIZE)),
+                        font: renderer.default_font(),
+                        bounds: close.size(),
+                        align_x: text::Alignment::Center,
+                        align_y: alignment::Vertical::Center,
+                        shaping: text::Shaping::Basic,
+                        wrapping: text::Wrapping::None,
+                        truncation: text::Truncation::None,
+                        hint_factor: renderer.scale_factor(),
+                    },
+                    close.center(),
+                    style.close_color,
+                    *viewport,
+                );
+            }
+
+            if state.is_focused && is_selected {
+                FocusRing::default().draw(renderer, tab_bounds);
+            }
+        };
+
+        if let Some(tabs_viewport) = strip.viewport.intersection(viewport) {
+            renderer.with_layer(tabs_viewport, |renderer| {
+                for index in 0..self.tabs.len() {
+                    if dragged.is_some_and(|drag| drag.index == index) {
+                        continue;
+                    }
+
+                    let status = if hovered == Some(index) {
+                        Status::Hovered
+                    } else {
+                        Status::Active
+                    };
+
+                    draw_tab(renderer, index, strip.tab(layout, index), status);
+                }
+
+                if let Some(drag) = dragged {
+                    let tab = strip
+                        .tab(layout, drag.index)
+                        .translate(Vector::new(drag.cursor - drag.origin, 0.0));
+
+                    renderer.with_layer(tabs_viewport, |renderer| {
+                        draw_tab(renderer, drag.index, tab, Status::Dragged);
+                    });
+                }
+            });
+        }
+
+        if let Some((back, forward)) = strip.arrows {
+            let (back_icon, forward_icon) = if is_rtl {
+                (Renderer::SCROLL_RIGHT_ICON, Renderer::SCROLL_LEFT_ICON)
+            } else {
+                (Renderer::SCROLL_LEFT_ICON, Renderer::SCROLL_RIGHT_ICON)
+            };
+
+            let buttons = [
+                (back, back_icon, state.scroll > 0.0),
+                (forward, forward_icon, state.scroll < strip.max_scroll),
+            ]
+            .into_iter()
+            .chain(
+                strip
+                    .menu
+                    .map(|menu| (menu, Renderer::ARROW_DOWN_ICON, true)),
+            );
+
+            for (arrow, icon, is_enabled) in buttons {
+                let style = Catalog::style(
+                    theme,
+                    &self.class,
+                    if cursor.is_over(arrow) {
+                        Status::Hovered
+                    } else {
+                        Status::Active
+                    },
+                    false,
+                );
+
+                renderer.fill_text(
+                    Text {
+                        content: icon.to_string(),
+                        size: renderer.default_size(),
+                        line_height: text::LineHeight::default(),
+                        font: Renderer::ICON_FONT,
+                        bounds: arrow.size(),
+                        align_x: text::Alignment::Center,
+                        align_y: alignment::Vertical::Center,
+                        shaping: text::Shaping::Basic,
+                        wrapping: text::Wrapping::None,
+                        truncation: text::Truncation::None,
+                        hint_factor: None,
+                    },
+                    arrow.center(),
+                    if is_enabled {
+                        style.text_color
+                    } else {
+                        style.text_color.scale_alpha(0.3)
+                    },
+                    *viewport,
+                );
+            }
+        }
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        #[cfg(feature = "accessibility")]
+        if let Some(info) = self.accessibility(tree, layout) {
+            operation.accessibility(self.id.as_ref(), layout.bounds(), info);
+        }
+
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+    }
+
+    fn overlay<'b>(
+        &'b mut self,
+        tree: &'b mut Tree,
+        layout: Layout<'b>,
+        renderer: &Renderer,
+        viewport: &Rectangle,
+        translation: Vector,
+    ) -> Option<overlay::Element<'b, Message, Theme, Renderer>> {
+        let state = tree.state.downcast_mut::<State<Renderer::Paragraph>>();
+
+        if !state.is_menu_open {
+            return None;
+        }
+
+        let button = Strip::new(layout, state.scroll, Self::ARROW_WIDTH).menu?;
+        let bounds = layout.bounds();
+
+        let width = state
+            .paragraphs
+            .iter()
+            .map(|paragraph| paragraph.raw().min_width())
+            .fold(0.0, f32::max)
+            + self.padding.x();
+
+        // The menu is aligned with the end edge of its button
+        let x = if crate::core::layout_direction().is_rtl() {
+            button.x
+        } else {
+            (button.x + button.width - width).max(bounds.x)
+        };
+
+        let tabs = &self.tabs;
+        let on_select = &self.on_select;
+        let is_menu_open = &mut state.is_menu_open;
+
+        let mut menu = Menu::new(
+            &mut state.menu,
+            &self.labels,
+            &mut state.hovered_option,
+            move |label: Label| {
+                *is_menu_open = false;
+
+                on_select(tabs[label.index].clone())
+            },
+            None,
+            &self.menu_class,
+        )
+        .width(width)
+        .padding(self.padding)
+        .font(self.font.unwrap_or_else(|| renderer.default_font()))
+        .text_line_height(self.text_line_height);
+
+        if let Some(text_size) = self.text_size {
+            menu = menu.text_size(text_size);
+        }
+
+        Some(menu.overlay(
+            Point::new(x, bounds.y) + translation,
+            *viewport,
+            bounds.height,
+            Length::Shrink,
+        ))
+    }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility(
+        &self,
+        tree: &crate::core::widget::Tree,
+        layout: crate::core::Layout<'_>,
+    ) -> Option<crate::core::accessibility::WidgetInfo> {
+        use crate::core::accessibility::WidgetInfo;
+
+        let state = tree.state.downcast_ref::<State<Renderer::Paragraph>>();
+        let strip = Strip::new(layout, state.scroll, Self::ARROW_WIDTH);
+        let selected = self.selected_index();
+
+        let mut list_info = WidgetInfo::tab_list().with_bounds(layout.bounds());
+
+        for (index, label) in self.labels.iter().enumerate() {
+            let tab_info = WidgetInfo::tab(label.text.clone(), selected == Some(index))
+                .with_bounds(strip.tab(layout, index).bounds());
+
+            list_info = list_info.with_extra_child(tab_info);
+        }
+
+        Some(list_info)
+    }
+}
+
+/// Computes the index a dragged tab would have if it were dropped now.
+fn drop_index(layout: Layout<'_>, strip: &Strip, drag: Drag, is_rtl: bool) -> usize {
+    let dragged = strip.tab(layout, drag.index).bounds();
+    let center = dragged.center_x() + drag.cursor - drag.origin;
+
+    (0..layout.children().len())
+        .filter(|index| *index != drag.index)
+        .filter(|index| {
+            let other = strip.tab(layout, *index).bounds().center_x();
+
+            if is_rtl {
+                other > center
+            } else {
+                other < center
+            }
+        })
+        .count()
+}
+
+impl<'a, T, Message, Theme, Renderer> From<Tabs<'a, T, Message, Theme, Renderer>>
+    for Element<'a, Message, Theme, Renderer>
+where
+    T: 'a + Clone + PartialEq + ToString,
+    Message: 'a,
+    Theme: 'a + Catalog,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(tabs: Tabs<'a, T, Message, Theme, Renderer>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(tabs)
+    }
+}
+
+/// The possible status of a tab.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The tab can be interacted with.
+    Active,
+    /// The tab is being hovered.
+    Hovered,
+    /// The tab is being dragged.
+    Dragged,
+}
+
+/// The appearance of a tab.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    /// The [`Background`] of the whole bar.
+    pub bar_background: Option<Background>,
+    /// The [`Background`] of the tab.
+    pub background: Option<Background>,
+    /// The [`Border`] of the tab.
+    pub border: Border,
+    /// The text [`Color`] of the label.
+    pub text_color: Color,
+    /// The [`Color`] of the line drawn under the tab, if any.
+    pub indicator: Option<Color>,
+    /// The [`Color`] of the close button.
+    pub close_color: Color,
+}
+
+/// The theme catalog of a [`Tabs`] bar.
+pub trait Catalog: menu::Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> <Self as Catalog>::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(
+        &self,
+        class: &<Self as Catalog>::Class<'_>,
+        status: Status,
+        is_selected: bool,
+    ) -> Style;
+}
+
+/// A styling function for a [`Tabs`] bar.
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme, Status, bool) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> StyleFn<'a, Self> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &StyleFn<'_, Self>, status: Status, is_selected: bool) -> Style {
+        class(self, status, is_selected)
+    }
+}
+
+/// The default style of a [`Tabs`] bar.
+pub fn default(theme: &Theme, status: Status, is_selected: bool) -> Style {
+    let active = Style {
+        bar_background: Some(theme.background.base.into()),
+        background: None,
+        border: Border {
+            radius: border::top(4.0),
+            ..Border::default()
+        },
+        text_color: theme.background.on.scale_alpha(0.7),
+        indicator: None,
+        close_color: theme.background.on,
+    };
+
+    let base = if is_selected {
+        Style {
+            background: Some(theme.primary.base.into()),
+            text_color: theme.primary.on,
+            indicator: Some(theme.accent.base),
+            close_color: theme.primary.on,
+            ..active
+        }
+    } else {
+        active
+    };
+
+    match status {
+        Status::Active => base,
+        Status::Hovered if is_selected => base,
+        Status::Hovered => Style {
+            background: Some(theme.background.component.hover.into()),
+            text_color: theme.background.on,
+            ..base
+        },
+        Status::Dragged => Style {
+            background: Some(theme.primary.base.scale_alpha(0.9).into()),
+            border: Border {
+                color: theme.accent.base,
+                width: 1.0,
+                ..base.border
+            },
+            ..base
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::clipboard;
+
+    fn tabs<'a>(selected: &'a str) -> Tabs<'a, &'a str, &'a str, crate::Theme, ()> {
+        Tabs::new(["&Files", "&Edit", "View"], Some(selected), |tab| tab)
+    }
+
+    /// Presses the given key on an unfocused [`Tabs`] bar, returning the
+    /// produced messages.
+    fn press<'a>(
+        tabs: &mut Tabs<'a, &'a str, &'a str, crate::Theme, ()>,
+        key: Key,
+        modifiers: keyboard::Modifiers,
+    ) -> Vec<&'a str> {
+        let mut tree = Tree::new(&*tabs as &dyn Widget<&str, crate::Theme, ()>);
+        let node = Widget::<&str, crate::Theme, ()>::layout(
+            tabs,
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(400.0, 100.0)),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        Widget::<&str, crate::Theme, ()>::update(
+            tabs,
+            &mut tree,
+            &Event::Keyboard(keyboard::Event::KeyPressed {
+                modified_key: key.clone(),
+                key,
+                physical_key: key::Physical::Unidentified(key::NativeCode::Unidentified),
+                location: keyboard::Location::Standard,
+                modifiers,
+                text: None,
+                repeat: false,
+            }),
+            Layout::new(&node),
+            mouse::Cursor::Unavailable,
+            &(),
+            &mut clipboard::Null,
+            &mut shell,
+            &Rectangle::default(),
+        );
+
+        messages
+    }
+
+    /// Lays out tabs of the given width, one after the other.
+    fn strip(count: usize, width: f32) -> layout::Node {
+        layout::Node::with_children(
+            Size::new(400.0, 20.0),
+            (0..count)
+                .map(|index| {
+                    layout::Node::new(Size::new(width, 20.0))
+                        .move_to(Point::new(index as f32 * width, 0.0))
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn reorder_moves_items() {
+        let mut items = vec!['a', 'b', 'c', 'd'];
+
+        Reorder { from: 0, to: 2 }.apply(&mut items);
+        assert_eq!(items, ['b', 'c', 'a', 'd']);
+
+        Reorder { from: 3, to: 0 }.apply(&mut items);
+        assert_eq!(items, ['d', 'b', 'c', 'a']);
+    }
+
+    #[test]
+    fn reorder_ignores_indices_out_of_range() {
+        let mut items = vec!['a', 'b'];
+
+        Reorder { from: 2, to: 0 }.apply(&mut items);
+        Reorder { from: 0, to: 2 }.apply(&mut items);
+
+        assert_eq!(items, ['a', 'b']);
+    }
+
+    #[test]
+    fn drop_index_follows_the_center_of_the_dragged_tab() {
+        let node = strip(4, 50.0);
+        let layout = Layout::new(&node);
+        let strip = Strip::new(layout, 0.0, 20.0);
+
+        let drag = |index, offset: f32| Drag {
+            index,
+            origin: 10.0,
+            cursor: 10.0 + offset,
+            is_dragging: true,
+        };
+
+        assert_eq!(drop_index(layout, &strip, drag(0, 0.0), false), 0);
+        assert_eq!(drop_index(layout, &strip, drag(0, 80.0), false), 1);
+        assert_eq!(drop_index(layout, &strip, drag(0, 110.0), false), 2);
+        assert_eq!(drop_index(layout, &strip, drag(0, 500.0), false), 3);
+        assert_eq!(drop_index(layout, &strip, drag(3, -120.0), false), 1);
+        assert_eq!(drop_index(layout, &strip, drag(3, -500.0), false), 0);
+    }
+
+    #[test]
+    fn cycle_wraps_around() {
+        assert_eq!(cycle(Some(0), 3, false), 1);
+        assert_eq!(cycle(Some(2), 3, false), 0);
+        assert_eq!(cycle(Some(0), 3, true), 2);
+        assert_eq!(cycle(None, 3, false), 1);
+    }
+
+    #[test]
+    fn mnemonics_are_case_insensitive() {
+        let labels: Vec<_> = ["&Files", "&Edit", "View"]
+            .into_iter()
+            .enumerate()
+            .map(|(index, label)| Label::new(index, label))
+            .collect();
+
+        assert_eq!(labels[0].text, "Files");
+        assert_eq!(mnemonic_index(&labels, 'e'), Some(1));
+        assert_eq!(mnemonic_index(&labels, 'F'), Some(0));
+        assert_eq!(mnemonic_index(&labels, 'v'), None);
+    }
+
+    #[test]
+    fn ctrl_tab_works_without_focus() {
+        let mut bar = tabs("View");
+
+        assert_eq!(
+            press(
+                &mut bar,
+                Key::Named(key::Named::Tab),
+                keyboard::Modifiers::CTRL
+            ),
+            ["&Files"]
+        );
+        assert_eq!(
+            press(
+                &mut bar,
+                Key::Named(key::Named::Tab),
+                keyboard::Modifiers::CTRL | keyboard::Modifiers::SHIFT
+            ),
+            ["&Edit"]
+        );
+    }
+
+    #[test]
+    fn mnemonics_work_without_focus() {
+        if !mnemonics_enabled() {
+            return;
+        }
+
+        let mut bar = tabs("&Files");
+
+        assert_eq!(
+            press(
+                &mut bar,
+                Key::Character("e".into()),
+                keyboard::Modifiers::ALT
+            ),
+            ["&Edit"]
+        );
+        assert!(
+            press(
+                &mut bar,
+                Key::Character("e".into()),
+                keyboard::Modifiers::empty()
+            )
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn arrows_need_focus() {
+        let mut bar = tabs("&Files");
+
+        assert!(
+            press(
+                &mut bar,
+                Key::Named(key::Named::ArrowRight),
+                keyboard::Modifiers::empty()
+            )
+            .is_empty()
+        );
+    }
+}
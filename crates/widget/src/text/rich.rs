@@ -2,9 +2,10 @@ use crate::core::alignment;
 use crate::core::layout;
 use crate::core::mouse;
 use crate::core::renderer;
-use crate::core::text::{Paragraph, Span};
+use crate::core::text::{Paragraph, Selection, Span};
+use crate::core::widget;
 use crate::core::widget::text::{
-    self, Alignment, Catalog, LineHeight, Shaping, Style, StyleFn, Wrapping,
+    self, Alignment, Catalog, LineHeight, Shaping, Style, StyleFn, Truncation, Wrapping,
 };
 use crate::core::widget::tree::{self, Tree};
 use crate::core::{
@@ -28,9 +29,13 @@ where
     align_x: Alignment,
     align_y: alignment::Vertical,
     wrapping: Wrapping,
+    truncation: Truncation,
+    is_selectable: bool,
     class: Theme::Class<'a>,
     hovered_link: Option<usize>,
     on_link_click: Option<Box<dyn Fn(Link) -> Message + 'a>>,
+    #[cfg(feature = "accessibility")]
+    a11y_heading_level: Option<usize>,
 }
 
 impl<'a, Link, Message, Theme, Renderer> Rich<'a, Link, Message, Theme, Renderer>
@@ -52,9 +57,13 @@ where
             align_x: Alignment::Default,
             align_y: alignment::Vertical::Top,
             wrapping: Wrapping::default(),
+            truncation: Truncation::default(),
+            is_selectable: false,
             class: Theme::default(),
             hovered_link: None,
             on_link_click: None,
+            #[cfg(feature = "accessibility")]
+            a11y_heading_level: None,
         }
     }
 
@@ -120,6 +129,45 @@ where
         self
     }
 
+    /// Sets the [`Truncation`] strategy of the [`Rich`] text.
+    ///
+    /// Truncated text is laid out in a single line.
+    pub fn truncation(mut self, truncation: Truncation) -> Self {
+        self.truncation = truncation;
+        self
+    }
+
+    /// Sets whether the [`Rich`] text can be selected and copied by the user.
+    ///
+    /// The selection can span across any number of spans. Links can still be
+    /// clicked.
+    pub fn selectable(mut self, is_selectable: bool) -> Self {
+        self.is_selectable = is_selectable;
+        self
+    }
+
+    /// Exposes the [`Rich`] text as a heading of the given level to assistive
+    /// technologies, starting at 1.
+    ///
+    /// Screen readers let users jump between headings, so set this on titles
+    /// and section headers.
+    #[must_use]
+    pub fn a11y_heading(self, level: usize) -> Self {
+        #[cfg(feature = "accessibility")]
+        {
+            Self {
+                a11y_heading_level: Some(level),
+                ..self
+            }
+        }
+
+        #[cfg(not(feature = "accessibility"))]
+        {
+            let _ = level;
+            self
+        }
+    }
+
     /// Sets the message that will be produced when a link of the [`Rich`] text
     /// is clicked.
     ///
@@ -182,8 +230,10 @@ where
 
 struct State<Link, P: Paragraph> {
     spans: Vec<Span<'static, Link, P::Font>>,
+    content: String,
     span_pressed: Option<usize>,
     paragraph: P,
+    selection: Selection,
 }
 
 impl<Link, Message, Theme, Renderer> Widget<Message, Theme, Renderer>
@@ -200,8 +250,10 @@ where
     fn state(&self) -> tree::State {
         tree::State::new(State::<Link, _> {
             spans: Vec::new(),
+            content: String::new(),
             span_pressed: None,
             paragraph: Renderer::Paragraph::default(),
+            selection: Selection::new(),
         })
     }
 
@@ -232,6 +284,7 @@ where
             self.align_x,
             self.align_y,
             self.wrapping,
+            self.truncation,
         )
     }
 
@@ -255,6 +308,16 @@ where
 
         let style = theme.style(&self.class);
 
+        if self.is_selectable && !state.paragraph.is_truncated() {
+            state.selection.draw(
+                renderer,
+                &state.paragraph,
+                text::anchor(layout.bounds(), &state.paragraph),
+                theme.selection(),
+                viewport,
+            );
+        }
+
         for (index, span) in self.spans.as_ref().as_ref().iter().enumerate() {
             let is_hovered_link = self.on_link_click.is_some() && Some(index) == self.hovered_link;
 
@@ -345,10 +408,39 @@ where
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
+        clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) {
+        if self.is_selectable {
+            let is_link_pressed = self.on_link_click.is_some()
+                && self.hovered_link.is_some()
+                && matches!(
+                    event,
+                    Event::Mouse(mouse::Event::ButtonPressed {
+                        button: mouse::Button::Left,
+                        ..
+                    })
+                );
+
+            let state = tree
+                .state
+                .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+            if !is_link_pressed && !state.paragraph.is_truncated() {
+                state.selection.update(
+                    event,
+                    layout.bounds(),
+                    text::anchor(layout.bounds(), &state.paragraph),
+                    cursor,
+                    &state.paragraph,
+                    &state.content,
+                    clipboard,
+                    shell,
+                );
+            }
+        }
+
         let Some(on_link_clicked) = &self.on_link_click else {
             return;
         };
@@ -420,17 +512,118 @@ where
 
     fn mouse_interaction(
         &self,
-        _tree: &Tree,
-        _layout: Layout<'_>,
-        _cursor: mouse::Cursor,
+        tree: &Tree,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
         _renderer: &Renderer,
     ) -> mouse::Interaction {
         if self.hovered_link.is_some() {
-            mouse::Interaction::Pointer
-        } else {
-            mouse::Interaction::None
+            return mouse::Interaction::Pointer;
         }
+
+        if self.is_selectable {
+            let state = tree
+                .state
+                .downcast_ref::<State<Link, Renderer::Paragraph>>();
+
+            if !state.paragraph.is_truncated()
+                && (state.selection.is_dragging() || cursor.is_over(layout.bounds()))
+            {
+                return mouse::Interaction::Text;
+            }
+        }
+
+        mouse::Interaction::None
+    }
+
+    fn operate(
+        &mut self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn widget::Operation,
+    ) {
+        // Plain text is announced through the label of its parent; only
+        // headings and text with links get a node of their own.
+        #[cfg(feature = "accessibility")]
+        if (self.a11y_heading_level.is_some()
+            || self
+                .spans
+                .as_ref()
+                .as_ref()
+                .iter()
+                .any(|span| span.link.is_some()))
+            && let Some(info) = self.accessibility(tree, layout)
+        {
+            operation.accessibility(None, layout.bounds(), info);
+        }
+
+        let state = tree
+            .state
+            .downcast_mut::<State<Link, Renderer::Paragraph>>();
+
+        operation.text(None, layout.bounds(), &state.content);
+
+        if self.is_selectable && !state.paragraph.is_truncated() {
+            operation.custom(None, layout.bounds(), &mut state.selection);
+        }
+    }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility(
+        &self,
+        tree: &Tree,
+        layout: Layout<'_>,
+    ) -> Option<core::accessibility::WidgetInfo> {
+        use core::accessibility::WidgetInfo;
+
+        let label = self.accessibility_label()?;
+        let bounds = layout.bounds();
+
+        let mut info = match self.a11y_heading_level {
+            Some(level) => WidgetInfo::heading(label, level),
+            None => WidgetInfo::label(label),
+        }
+        .with_bounds(bounds);
+
+        let state = tree
+            .state
+            .downcast_ref::<State<Link, Renderer::Paragraph>>();
+
+        for (index, span) in self.spans.as_ref().as_ref().iter().enumerate() {
+            if span.link.is_none() {
+                continue;
+            }
+
+            let link_bounds = state
+                .paragraph
+                .span_bounds(index)
+                .into_iter()
+                .reduce(|a, b| a.union(&b))
+                .unwrap_or_default()
+                + Vector::new(bounds.x, bounds.y);
+
+            info = info.with_extra_child(
+                WidgetInfo::link(span.text.as_ref())
+                    .with_enabled(self.on_link_click.is_some())
+                    .with_bounds(link_bounds),
+            );
+        }
+
+        Some(info)
+    }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_label(&self) -> Option<std::borrow::Cow<'_, str>> {
+        Some(std::borrow::Cow::Owned(
+            self.spans
+                .as_ref()
+                .as_ref()
+                .iter()
+                .map(|span| span.text.as_ref())
+                .collect(),
+        ))
     }
 }
 
@@ -447,6 +640,7 @@ fn layout<Link, Renderer>(
     align_x: Alignment,
     align_y: alignment::Vertical,
     wrapping: Wrapping,
+    truncation: Truncation,
 ) -> layout::Node
 where
     Link: Clone,
@@ -468,12 +662,15 @@ where
             align_y,
             shaping: Shaping::Advanced,
             wrapping,
+            truncation,
             hint_factor: renderer.scale_factor(),
         };
 
         if state.spans != spans {
             state.paragraph = Renderer::Paragraph::with_spans(text_with_spans());
             state.spans = spans.iter().cloned().map(Span::to_static).collect();
+            state.content = spans.iter().map(|span| span.text.as_ref()).collect();
+            state.selection.clear();
         } else {
             match state.paragraph.compare(core::Text {
                 content: (),
@@ -485,6 +682,7 @@ where
                 align_y,
                 shaping: Shaping::Advanced,
                 wrapping,
+                truncation,
                 hint_factor: renderer.scale_factor(),
             }) {
                 core::text::Difference::None => {}
@@ -497,6 +695,12 @@ where
             }
         }
 
+        // The hit offsets of a truncated paragraph point into the ellipsized
+        // text instead of `content`, so it cannot be selected
+        if state.paragraph.is_truncated() {
+            state.selection.clear();
+        }
+
         state.paragraph.min_bounds()
     })
 }
@@ -41,7 +41,7 @@ use crate::core::mouse;
 use crate::core::renderer;
 use crate::core::text::editor::Editor as _;
 use crate::core::text::highlighter::{self, Highlighter};
-use crate::core::text::{self, LineHeight, Text, Wrapping};
+use crate::core::text::{self, LineHeight, Paragraph as _, Text, Wrapping};
 use crate::core::theme;
 use crate::core::time::{Duration, Instant};
 use crate::core::widget::operation;
@@ -313,7 +313,7 @@ where
 
     fn input_method<'b>(
         &self,
-        state: &'b State<Highlighter>,
+        state: &'b State<Highlighter, Renderer::Paragraph>,
         renderer: &Renderer,
         layout: Layout<'_>,
     ) -> InputMethod<&'b str> {
@@ -331,23 +331,82 @@ where
         let text_bounds = bounds.shrink(self.padding);
         let translation = text_bounds.position() - Point::ORIGIN;
 
-        let cursor = match internal.editor.selection() {
-            Selection::Caret(position) => position,
-            Selection::Range(ranges) => ranges.first().cloned().unwrap_or_default().position(),
-        };
+        let line_height = f32::from(
+            self.line_height
+                .to_absolute(self.text_size.unwrap_or_else(|| renderer.default_size())),
+        );
 
-        let line_height = self
-            .line_height
-            .to_absolute(self.text_size.unwrap_or_else(|| renderer.default_size()));
+        let mut position = composition_origin(&internal.editor) + translation;
 
-        let position = cursor + translation;
+        // Keep the candidate window next to the clause being converted
+        if let Some(preedit) = state
+            .preedit
+            .as_ref()
+            .filter(|preedit| !preedit.content.is_empty())
+        {
+            let index = preedit
+                .selection
+                .as_ref()
+                .map_or(preedit.content.len(), |selection| selection.start);
+
+            position.x += preedit_offset(&state.preedit_paragraph, index);
+        }
+
+        // Follow the caret as it scrolls, without leaving the editor
+        let position = Point::new(
+            position.x.clamp(
+                text_bounds.x,
+                text_bounds.x.max(text_bounds.x + text_bounds.width - 1.0),
+            ),
+            position.y.clamp(
+                text_bounds.y,
+                text_bounds
+                    .y
+                    .max(text_bounds.y + text_bounds.height - line_height),
+            ),
+        );
 
         InputMethod::Enabled {
-            cursor: Rectangle::new(position, Size::new(1.0, f32::from(line_height))),
+            cursor: Rectangle::new(position, Size::new(1.0, line_height)),
             purpose: input_method::Purpose::Normal,
-            preedit: state.preedit.as_ref().map(input_method::Preedit::as_ref),
+            // The preedit is drawn on-the-spot
+            preedit: None,
         }
     }
+
+    fn preedit_paragraph(&self, content: &str, renderer: &Renderer) -> Renderer::Paragraph {
+        Renderer::Paragraph::with_text(Text {
+            content,
+            bounds: Size::INFINITE,
+            size: self.text_size.unwrap_or_else(|| renderer.default_size()),
+            line_height: self.line_height,
+            font: self.font.unwrap_or_else(|| renderer.default_font()),
+            align_x: text::Alignment::Default,
+            align_y: alignment::Vertical::Top,
+            shaping: text::Shaping::Advanced,
+            wrapping: Wrapping::None,
+            truncation: text::Truncation::None,
+            hint_factor: renderer.scale_factor(),
+        })
+    }
+}
+
+/// Returns the position where the text being composed by an input method
+/// will be inserted: the caret, or the start of the selection it replaces.
+fn composition_origin<E: text::Editor>(editor: &E) -> Point {
+    match editor.selection() {
+        Selection::Caret(position) => position,
+        Selection::Range(ranges) => ranges.first().cloned().unwrap_or_default().position(),
+    }
+}
+
+/// Returns the horizontal offset of the given byte `index` in a preedit.
+fn preedit_offset<P: text::Paragraph>(paragraph: &P, index: usize) -> f32 {
+    paragraph
+        .range_bounds(0..index)
+        .iter()
+        .map(|bounds| bounds.x + bounds.width)
+        .fold(0.0, f32::max)
 }
 
 /// The content of a [`TextEditor`].
@@ -491,9 +550,10 @@ where
 
 /// The state of a [`TextEditor`].
 #[derive(Debug)]
-pub struct State<Highlighter: text::Highlighter> {
+pub struct State<Highlighter: text::Highlighter, P: text::Paragraph> {
     focus: Option<Focus>,
     preedit: Option<input_method::Preedit>,
+    preedit_paragraph: P,
     last_click: Option<mouse::Click>,
     drag_click: Option<mouse::click::Kind>,
     partial_scroll: f32,
@@ -530,14 +590,16 @@ impl Focus {
     }
 }
 
-impl<Highlighter: text::Highlighter> State<Highlighter> {
+impl<Highlighter: text::Highlighter, P: text::Paragraph> State<Highlighter, P> {
     /// Returns whether the [`TextEditor`] is currently focused or not.
     pub fn is_focused(&self) -> bool {
         self.focus.is_some()
     }
 }
 
-impl<Highlighter: text::Highlighter> operation::Focusable for State<Highlighter> {
+impl<Highlighter: text::Highlighter, P: text::Paragraph> operation::Focusable
+    for State<Highlighter, P>
+{
     fn is_focused(&self) -> bool {
         self.focus.is_some()
     }
@@ -559,13 +621,14 @@ where
     Renderer: text::Renderer,
 {
     fn tag(&self) -> widget::tree::Tag {
-        widget::tree::Tag::of::<State<Highlighter>>()
+        widget::tree::Tag::of::<State<Highlighter, Renderer::Paragraph>>()
     }
 
     fn state(&self) -> widget::tree::State {
         widget::tree::State::new(State {
             focus: None,
             preedit: None,
+            preedit_paragraph: Renderer::Paragraph::default(),
             last_click: None,
             drag_click: None,
             partial_scroll: 0.0,
@@ -590,7 +653,9 @@ where
         limits: &layout::Limits,
     ) -> icy_ui_renderer::core::layout::Node {
         let mut internal = self.content.0.borrow_mut();
-        let state = tree.state.downcast_mut::<State<Highlighter>>();
+        let state = tree
+            .state
+            .downcast_mut::<State<Highlighter, Renderer::Paragraph>>();
 
         if state.highlighter_format_address != self.highlighter_format as usize {
             state.highlighter.borrow_mut().change_line(0);
@@ -655,7 +720,9 @@ where
             return;
         };
 
-        let state = tree.state.downcast_mut::<State<Highlighter>>();
+        let state = tree
+            .state
+            .downcast_mut::<State<Highlighter, Renderer::Paragraph>>();
         let is_redraw = matches!(event, Event::Window(window::Event::RedrawRequested(_now)),);
 
         match event {
@@ -741,6 +808,7 @@ where
                         shell.request_redraw();
                     }
                     Ime::Preedit { content, selection } => {
+                        state.preedit_paragraph = self.preedit_paragraph(&content, renderer);
                         state.preedit = Some(input_method::Preedit {
                             content,
                             selection,
@@ -750,14 +818,30 @@ where
                         shell.request_redraw();
                     }
                     Ime::Commit(text) => {
-                        shell.publish(on_edit(Action::Edit(Edit::Paste(Arc::new(text)))));
+                        if let Some(preedit) = &mut state.preedit {
+                            *preedit = input_method::Preedit::new();
+                        }
+
+                        let mut characters = text.chars();
+
+                        let edit = match (characters.next(), characters.next()) {
+                            (None, _) => None,
+                            (Some(c), None) => Some(Edit::Insert(c)),
+                            _ => Some(Edit::Paste(Arc::new(text))),
+                        };
+
+                        if let Some(edit) = edit {
+                            shell.publish(on_edit(Action::Edit(edit)));
+                        }
+
+                        shell.request_redraw();
                     }
                 },
                 Update::Binding(binding) => {
                     fn apply_binding<H: text::Highlighter, R: text::Renderer, Message>(
                         binding: Binding<Message>,
                         content: &Content<R>,
-                        state: &mut State<H>,
+                        state: &mut State<H, R::Paragraph>,
                         on_edit: &dyn Fn(Action) -> Message,
                         clipboard: &mut dyn Clipboard,
                         shell: &mut Shell<'_, Message>,
@@ -881,7 +965,9 @@ where
         let bounds = layout.bounds();
 
         let mut internal = self.content.0.borrow_mut();
-        let state = tree.state.downcast_ref::<State<Highlighter>>();
+        let state = tree
+            .state
+            .downcast_ref::<State<Highlighter, Renderer::Paragraph>>();
 
         let font = self.font.unwrap_or_else(|| renderer.default_font());
 
@@ -915,6 +1001,17 @@ where
         );
 
         let text_bounds = bounds.shrink(self.padding);
+        let translation = text_bounds.position() - Point::ORIGIN;
+
+        let preedit = state
+            .preedit
+            .as_ref()
+            .filter(|preedit| state.focus.is_some() && !preedit.content.is_empty());
+
+        let line_height = f32::from(
+            self.line_height
+                .to_absolute(self.text_size.unwrap_or_else(|| renderer.default_size())),
+        );
 
         if internal.editor.is_empty() {
             if let Some(placeholder) = self.placeholder.clone() {
@@ -929,6 +1026,7 @@ where
                         align_y: alignment::Vertical::Top,
                         shaping: text::Shaping::Advanced,
                         wrapping: self.wrapping,
+                        truncation: text::Truncation::None,
                         hint_factor: renderer.scale_factor(),
                     },
                     text_bounds.position(),
@@ -936,6 +1034,49 @@ where
                     text_bounds,
                 );
             }
+        } else if preedit.is_some() {
+            // Make room for the preedit by pushing the rest of its line
+            // to the right
+            let origin = composition_origin(&internal.editor) + translation;
+            let width = state.preedit_paragraph.min_width();
+
+            let line = Rectangle::new(
+                Point::new(text_bounds.x, origin.y),
+                Size::new(text_bounds.width, line_height),
+            );
+
+            let above = Rectangle {
+                height: origin.y - text_bounds.y,
+                ..text_bounds
+            };
+
+            let below = Rectangle {
+                y: line.y + line.height,
+                height: text_bounds.y + text_bounds.height - line.y - line.height,
+                ..text_bounds
+            };
+
+            let before = Rectangle {
+                width: origin.x - line.x,
+                ..line
+            };
+
+            let after = Rectangle {
+                x: origin.x + width,
+                width: line.x + line.width - origin.x - width,
+                ..line
+            };
+
+            for (offset, clip) in [(0.0, above), (0.0, below), (0.0, before), (width, after)] {
+                if let Some(clip) = text_bounds.intersection(&clip) {
+                    renderer.fill_editor(
+                        &internal.editor,
+                        text_bounds.position() + Vector::new(offset, 0.0),
+                        style.value,
+                        clip,
+                    );
+                }
+            }
         } else {
             renderer.fill_editor(
                 &internal.editor,
@@ -945,31 +1086,39 @@ where
             );
         }
 
-        let translation = text_bounds.position() - Point::ORIGIN;
-
         if let Some(focus) = state.focus.as_ref() {
             match internal.editor.selection() {
-                Selection::Caret(position) if focus.is_cursor_visible() => {
-                    let cursor = Rectangle::new(
-                        position + translation,
-                        Size::new(
-                            if renderer::CRISP {
-                                (1.0 / renderer.scale_factor().unwrap_or(1.0)).max(1.0)
-                            } else {
-                                1.0
-                            },
-                            self.line_height
-                                .to_absolute(
-                                    self.text_size.unwrap_or_else(|| renderer.default_size()),
-                                )
-                                .into(),
-                        ),
-                    );
+                Selection::Caret(position) if focus.is_cursor_visible() && preedit.is_none() => {
+                    let width = if renderer::CRISP {
+                        (1.0 / renderer.scale_factor().unwrap_or(1.0)).max(1.0)
+                    } else {
+                        1.0
+                    };
 
-                    if let Some(clipped_cursor) = text_bounds.intersection(&cursor) {
+                    let height = line_height;
+
+                    // At direction boundaries, the caret is split in two halves
+                    let carets = match internal.editor.split_caret() {
+                        Some(split_position) => vec![
+                            Rectangle::new(position + translation, Size::new(width, height / 2.0)),
+                            Rectangle::new(
+                                split_position + translation + Vector::new(0.0, height / 2.0),
+                                Size::new(width, height / 2.0),
+                            ),
+                        ],
+                        None => vec![Rectangle::new(
+                            position + translation,
+                            Size::new(width, height),
+                        )],
+                    };
+
+                    for cursor in carets
+                        .into_iter()
+                        .filter_map(|cursor| text_bounds.intersection(&cursor))
+                    {
                         renderer.fill_quad(
                             renderer::Quad {
-                                bounds: clipped_cursor,
+                                bounds: cursor,
                                 ..renderer::Quad::default()
                             },
                             style.value,
@@ -993,6 +1142,61 @@ where
                 Selection::Caret(_) => {}
             }
         }
+
+        if let Some((preedit, focus)) = preedit.zip(state.focus.as_ref()) {
+            let paragraph = &state.preedit_paragraph;
+            let position = composition_origin(&internal.editor) + translation;
+
+            let size = self.text_size.unwrap_or_else(|| renderer.default_size());
+            let height = line_height;
+            let thickness = (f32::from(size) / 14.0).max(1.0);
+
+            // Draw the composition above the selection of the editor
+            renderer.with_layer(text_bounds, |renderer| {
+                let mut fill = |bounds: Rectangle, background: Background| {
+                    if let Some(bounds) = text_bounds.intersection(&bounds) {
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds,
+                                ..renderer::Quad::default()
+                            },
+                            background,
+                        );
+                    }
+                };
+
+                for (range, underline) in preedit.segments() {
+                    let start = preedit_offset(paragraph, range.start);
+                    let end = preedit_offset(paragraph, range.end);
+
+                    let thickness = match underline {
+                        input_method::Underline::Thin => thickness,
+                        input_method::Underline::Thick => thickness * 2.0,
+                    };
+
+                    // Leave a small gap between clauses
+                    fill(
+                        Rectangle::new(
+                            Point::new(position.x + start + 1.0, position.y + height - thickness),
+                            Size::new((end - start - 2.0).max(1.0), thickness),
+                        ),
+                        Background::Color(style.value),
+                    );
+                }
+
+                if let Some(caret) = preedit.caret().filter(|_| focus.is_cursor_visible()) {
+                    fill(
+                        Rectangle::new(
+                            Point::new(position.x + preedit_offset(paragraph, caret), position.y),
+                            Size::new(1.0, height),
+                        ),
+                        Background::Color(style.value),
+                    );
+                }
+
+                renderer.fill_paragraph(paragraph, position, style.value, text_bounds);
+            });
+        }
     }
 
     fn mouse_interaction(
@@ -1023,10 +1227,71 @@ where
         _renderer: &Renderer,
         operation: &mut dyn widget::Operation,
     ) {
-        let state = tree.state.downcast_mut::<State<Highlighter>>();
+        #[cfg(feature = "accessibility")]
+        if let Some(info) =
+            <Self as Widget<Message, Theme, Renderer>>::accessibility(self, tree, layout)
+        {
+            operation.accessibility(self.id.as_ref(), layout.bounds(), info);
+        }
+
+        let state = tree
+            .state
+            .downcast_mut::<State<Highlighter, Renderer::Paragraph>>();
 
         operation.focusable(self.id.as_ref(), layout.bounds(), state);
     }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility(
+        &self,
+        _tree: &widget::Tree,
+        layout: Layout<'_>,
+    ) -> Option<crate::core::accessibility::WidgetInfo> {
+        use crate::core::accessibility::WidgetInfo;
+
+        let mut value = String::new();
+        let mut line_starts = Vec::new();
+        let mut lines = self.content.lines().peekable();
+
+        // Build the value and the character offset of every line, so the
+        // cursor can be mapped to a character index.
+        while let Some(line) = lines.next() {
+            line_starts.push((value.chars().count(), line.text.clone()));
+            value.push_str(&line.text);
+
+            if lines.peek().is_some() {
+                value.push_str(if line.ending == LineEnding::None {
+                    LineEnding::default().as_str()
+                } else {
+                    line.ending.as_str()
+                });
+            }
+        }
+
+        let character_index = |position: Position| {
+            line_starts.get(position.line).map_or(0, |(start, text)| {
+                start
+                    + text
+                        .get(..position.column)
+                        .map_or(position.column, |text| text.chars().count())
+            })
+        };
+
+        let cursor = self.content.cursor();
+        let end = character_index(cursor.position);
+        let start = cursor.selection.map_or(end, character_index);
+
+        let mut info = WidgetInfo::text_editor(value)
+            .with_bounds(layout.bounds())
+            .with_enabled(self.on_edit.is_some())
+            .with_selection(start, end);
+
+        if let Some(placeholder) = &self.placeholder {
+            info = info.with_placeholder(placeholder.as_ref());
+        }
+
+        Some(info)
+    }
 }
 
 impl<'a, Highlighter, Message, Theme, Renderer>
@@ -1197,9 +1462,9 @@ enum Ime {
 }
 
 impl<Message> Update<Message> {
-    fn from_event<H: Highlighter>(
+    fn from_event<H: Highlighter, P: text::Paragraph>(
         event: &Event,
-        state: &State<H>,
+        state: &State<H, P>,
         bounds: Rectangle,
         padding: Padding,
         cursor: mouse::Cursor,
@@ -1280,6 +1545,15 @@ impl<Message> Update<Message> {
                 text,
                 ..
             }) => {
+                // Keys belong to the input method while it is composing
+                if state
+                    .preedit
+                    .as_ref()
+                    .is_some_and(|preedit| !preedit.content.is_empty())
+                {
+                    return None;
+                }
+
                 let status = if state.focus.is_some() {
                     Status::Focused {
                         is_hovered: cursor.is_over(bounds),
@@ -49,7 +49,7 @@ use crate::core::layout;
 use crate::core::mouse::{self, click};
 use crate::core::renderer;
 use crate::core::text::paragraph::{self, Paragraph as _};
-use crate::core::text::{self, Text};
+use crate::core::text::{self, Text, bidi};
 use crate::core::time::{Duration, Instant};
 use crate::core::touch;
 use crate::core::widget;
@@ -358,6 +358,7 @@ where
             align_y: alignment::Vertical::Center,
             shaping: text::Shaping::Advanced,
             wrapping: text::Wrapping::default(),
+            truncation: text::Truncation::None,
             hint_factor: renderer.scale_factor(),
         };
 
@@ -384,6 +385,7 @@ where
                 align_y: alignment::Vertical::Center,
                 shaping: text::Shaping::Advanced,
                 wrapping: text::Wrapping::default(),
+                truncation: text::Truncation::None,
                 hint_factor: renderer.scale_factor(),
             };
 
@@ -555,22 +557,37 @@ where
                             .is_multiple_of(2);
 
                     let cursor = if is_cursor_visible {
-                        Some((
-                            renderer::Quad {
-                                bounds: Rectangle {
-                                    x: text_bounds.x + text_value_width,
-                                    y: text_bounds.y,
-                                    width: if renderer::CRISP {
-                                        (1.0 / renderer.scale_factor().unwrap_or(1.0)).max(1.0)
-                                    } else {
-                                        1.0
-                                    },
-                                    height: text_bounds.height,
+                        let width = if renderer::CRISP {
+                            (1.0 / renderer.scale_factor().unwrap_or(1.0)).max(1.0)
+                        } else {
+                            1.0
+                        };
+
+                        let caret = Rectangle {
+                            x: text_bounds.x + text_value_width,
+                            y: text_bounds.y,
+                            width,
+                            height: text_bounds.height,
+                        };
+
+                        // At direction boundaries, the caret is split in two halves
+                        let carets = match measure_split_caret(state.value.raw(), position) {
+                            Some(split_caret) => vec![
+                                Rectangle {
+                                    height: caret.height / 2.0,
+                                    ..caret
+                                },
+                                Rectangle {
+                                    x: text_bounds.x + split_caret,
+                                    y: caret.y + caret.height / 2.0,
+                                    height: caret.height / 2.0,
+                                    ..caret
                                 },
-                                ..renderer::Quad::default()
-                            },
-                            style.value,
-                        ))
+                            ],
+                            None => vec![caret],
+                        };
+
+                        Some((carets, style.value))
                     } else {
                         None
                     };
@@ -587,19 +604,26 @@ where
                     let (right_position, right_offset) =
                         measure_cursor_and_scroll_offset(state.value.raw(), text_bounds, right);
 
-                    let width = right_position - left_position;
+                    let clusters = state.value.raw().visual_clusters(0);
+
+                    // Mixed-direction text may be selected in disjoint runs
+                    let spans = if bidi::is_mixed(&clusters) {
+                        bidi::selection_spans(&clusters, left..right)
+                    } else {
+                        vec![(left_position, right_position - left_position)]
+                    };
 
                     (
                         Some((
-                            renderer::Quad {
-                                bounds: Rectangle {
-                                    x: text_bounds.x + left_position,
+                            spans
+                                .into_iter()
+                                .map(|(x, width)| Rectangle {
+                                    x: text_bounds.x + x,
                                     y: text_bounds.y,
                                     width,
                                     height: text_bounds.height,
-                                },
-                                ..renderer::Quad::default()
-                            },
+                                })
+                                .collect(),
                             style.selection,
                         )),
                         if end == right {
@@ -640,11 +664,19 @@ where
                 is_rtl,
             );
 
-            if let Some((cursor, color)) = cursor {
+            if let Some((bounds, color)) = &cursor {
                 renderer.with_translation(
                     Vector::new(alignment_offset - offset, 0.0),
                     |renderer| {
-                        renderer.fill_quad(cursor, color);
+                        for bounds in bounds {
+                            renderer.fill_quad(
+                                renderer::Quad {
+                                    bounds: *bounds,
+                                    ..renderer::Quad::default()
+                                },
+                                *color,
+                            );
+                        }
                     },
                 );
             } else {
@@ -1188,10 +1220,18 @@ where
                                 } else {
                                     state.cursor.move_left_by_words(&self.value);
                                 }
-                            } else if modifiers.shift() {
-                                state.cursor.select_left(&self.value);
-                            } else {
-                                state.cursor.move_left(&self.value);
+                            } else if !move_visually(
+                                &mut state.cursor,
+                                state.value.raw(),
+                                &self.value,
+                                text::editor::Direction::Left,
+                                modifiers.shift(),
+                            ) {
+                                if modifiers.shift() {
+                                    state.cursor.select_left(&self.value);
+                                } else {
+                                    state.cursor.move_left(&self.value);
+                                }
                             }
 
                             if cursor_before != state.cursor {
@@ -1220,10 +1260,18 @@ where
                                 } else {
                                     state.cursor.move_right_by_words(&self.value);
                                 }
-                            } else if modifiers.shift() {
-                                state.cursor.select_right(&self.value);
-                            } else {
-                                state.cursor.move_right(&self.value);
+                            } else if !move_visually(
+                                &mut state.cursor,
+                                state.value.raw(),
+                                &self.value,
+                                text::editor::Direction::Right,
+                                modifiers.shift(),
+                            ) {
+                                if modifiers.shift() {
+                                    state.cursor.select_right(&self.value);
+                                } else {
+                                    state.cursor.move_right(&self.value);
+                                }
                             }
 
                             if cursor_before != state.cursor {
@@ -1802,6 +1850,48 @@ fn measure_cursor_and_scroll_offset(
     (grapheme_position.x, offset)
 }
 
+/// Moves the [`Cursor`] one visual step in the given direction, if the
+/// paragraph contains right-to-left text.
+///
+/// Returns `false` if the [`Cursor`] must be moved logically instead.
+fn move_visually(
+    cursor: &mut Cursor,
+    paragraph: &impl text::Paragraph,
+    value: &Value,
+    direction: text::editor::Direction,
+    select: bool,
+) -> bool {
+    let clusters = paragraph.visual_clusters(0);
+
+    if !bidi::is_mixed(&clusters) {
+        return false;
+    }
+
+    // Collapsing a selection is performed logically
+    if !select && cursor.selection(value).is_some() {
+        return false;
+    }
+
+    let end = cursor.end(value);
+
+    if let Some(target) = bidi::move_visually(&clusters, end, direction) {
+        if select {
+            cursor.select_range(cursor.start(value), target);
+        } else {
+            cursor.move_to(target);
+        }
+    }
+
+    true
+}
+
+/// Returns the position of the secondary caret at the given index of a
+/// paragraph, if the index sits at a direction boundary.
+fn measure_split_caret(paragraph: &impl text::Paragraph, cursor_index: usize) -> Option<f32> {
+    bidi::caret_positions(&paragraph.visual_clusters(0), cursor_index)
+        .and_then(|(_, split_caret)| split_caret)
+}
+
 /// Computes the position of the text cursor at the given X coordinate of
 /// a [`TextInput`].
 fn find_cursor_position<P: text::Paragraph>(
@@ -1855,6 +1945,7 @@ fn replace_paragraph<Renderer>(
         align_y: alignment::Vertical::Center,
         shaping: text::Shaping::Advanced,
         wrapping: text::Wrapping::default(),
+        truncation: text::Truncation::None,
         hint_factor: renderer.scale_factor(),
     });
 }
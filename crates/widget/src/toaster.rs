@@ -150,6 +150,9 @@ pub struct Toast<Message> {
     pub duration: Duration,
     /// Custom style function for this toast.
     pub style: StyleFn,
+    /// Whether screen readers should interrupt their current speech to
+    /// announce this toast.
+    pub is_urgent: bool,
 }
 
 impl<Message> std::fmt::Debug for Toast<Message> {
@@ -158,6 +161,7 @@ impl<Message> std::fmt::Debug for Toast<Message> {
             .field("message", &self.message)
             .field("action", &self.action)
             .field("duration", &self.duration)
+            .field("is_urgent", &self.is_urgent)
             .finish()
     }
 }
@@ -169,6 +173,7 @@ impl<Message: Clone> Clone for Toast<Message> {
             action: self.action.clone(),
             duration: self.duration,
             style: self.style,
+            is_urgent: self.is_urgent,
         }
     }
 }
@@ -181,6 +186,7 @@ impl<Message> Toast<Message> {
             action: None,
             duration: Duration::default(),
             style: default_style,
+            is_urgent: false,
         }
     }
 
@@ -221,6 +227,17 @@ impl<Message> Toast<Message> {
         self.style = style;
         self
     }
+
+    /// Marks the toast as urgent, like an error notification.
+    ///
+    /// Toasts are announced by screen readers when they appear. By default,
+    /// the announcement waits until the screen reader finishes speaking;
+    /// urgent toasts interrupt it instead.
+    #[must_use]
+    pub fn urgent(mut self) -> Self {
+        self.is_urgent = true;
+        self
+    }
 }
 
 // ============================================================================
@@ -355,6 +372,17 @@ where
     content: Element<'a, Message, Theme, Renderer>,
     toasts_element: Element<'a, Message, Theme, Renderer>,
     is_empty: bool,
+    #[cfg(feature = "accessibility")]
+    announcements: Vec<Announcement>,
+}
+
+/// The live region of a toast, announced by screen readers when it appears.
+#[cfg(feature = "accessibility")]
+#[derive(Debug, Clone)]
+struct Announcement {
+    id: crate::core::widget::Id,
+    message: String,
+    is_urgent: bool,
 }
 
 impl<'a, Message: Clone + 'static> Toaster<'a, Message, crate::Theme, crate::Renderer> {
@@ -368,6 +396,20 @@ impl<'a, Message: Clone + 'static> Toaster<'a, Message, crate::Theme, crate::Ren
             content: content.into(),
             toasts_element,
             is_empty: toasts.is_empty(),
+            #[cfg(feature = "accessibility")]
+            announcements: toasts
+                .iter()
+                .map(|(id, toast)| Announcement {
+                    // Keep the node stable while the toast is displayed, so it
+                    // is only announced once.
+                    id: crate::core::widget::Id::from(format!(
+                        "toaster.toast.{}",
+                        slotmap::Key::data(&id).as_ffi()
+                    )),
+                    message: toast.message.clone(),
+                    is_urgent: toast.is_urgent,
+                })
+                .collect(),
         }
     }
 }
@@ -450,6 +492,7 @@ pub fn default_style(theme: &crate::Theme) -> container::Style {
                 .scale_alpha(if theme.is_dark { 0.25 } else { 0.15 }),
             offset: Vector::new(0.0, 4.0),
             blur_radius: spacing.xs as f32,
+            ..Shadow::default()
         },
         ..container::Style::default()
     }
@@ -472,6 +515,7 @@ pub fn success_style(theme: &crate::Theme) -> container::Style {
             color: theme.palette.bright_green.scale_alpha(0.2),
             offset: Vector::new(0.0, 4.0),
             blur_radius: spacing.xs as f32,
+            ..Shadow::default()
         },
         ..container::Style::default()
     }
@@ -494,6 +538,7 @@ pub fn warning_style(theme: &crate::Theme) -> container::Style {
             color: theme.palette.bright_orange.scale_alpha(0.2),
             offset: Vector::new(0.0, 4.0),
             blur_radius: spacing.xs as f32,
+            ..Shadow::default()
         },
         ..container::Style::default()
     }
@@ -516,6 +561,7 @@ pub fn danger_style(theme: &crate::Theme) -> container::Style {
             color: theme.palette.bright_red.scale_alpha(0.2),
             offset: Vector::new(0.0, 4.0),
             blur_radius: spacing.xs as f32,
+            ..Shadow::default()
         },
         ..container::Style::default()
     }
@@ -538,6 +584,7 @@ pub fn info_style(theme: &crate::Theme) -> container::Style {
             color: theme.palette.accent_blue.scale_alpha(0.2),
             offset: Vector::new(0.0, 4.0),
             blur_radius: spacing.xs as f32,
+            ..Shadow::default()
         },
         ..container::Style::default()
     }
@@ -675,6 +722,8 @@ where
             Some(overlay::Element::new(Box::new(ToasterOverlay {
                 state: &mut state.children[1],
                 element: &mut self.toasts_element,
+                #[cfg(feature = "accessibility")]
+                announcements: &self.announcements,
             })))
         }
     }
@@ -699,6 +748,8 @@ where
 struct ToasterOverlay<'a, 'b, Message, Theme, Renderer> {
     state: &'b mut Tree,
     element: &'b mut Element<'a, Message, Theme, Renderer>,
+    #[cfg(feature = "accessibility")]
+    announcements: &'b [Announcement],
 }
 
 impl<Message, Theme, Renderer> overlay::Overlay<Message, Theme, Renderer>
@@ -738,6 +789,29 @@ where
             .draw(self.state, renderer, theme, style, layout, cursor, &bounds);
     }
 
+    fn operate(&mut self, layout: Layout<'_>, renderer: &Renderer, operation: &mut dyn Operation) {
+        #[cfg(feature = "accessibility")]
+        for (announcement, layout) in self.announcements.iter().zip(layout.children()) {
+            use crate::core::accessibility::WidgetInfo;
+
+            let info = if announcement.is_urgent {
+                WidgetInfo::alert(announcement.message.as_str())
+            } else {
+                WidgetInfo::status(announcement.message.as_str())
+            };
+
+            operation.accessibility(
+                Some(&announcement.id),
+                layout.bounds(),
+                info.with_bounds(layout.bounds()),
+            );
+        }
+
+        self.element
+            .as_widget_mut()
+            .operate(self.state, layout, renderer, operation);
+    }
+
     fn update(
         &mut self,
         event: &Event,
@@ -788,3 +862,77 @@ where
         )
     }
 }
+
+#[cfg(all(test, feature = "accessibility"))]
+mod tests {
+    use super::*;
+    use crate::core::accessibility::{Live, Role, node_id_from_widget_id};
+    use crate::core::widget::Id;
+    use crate::core::widget::operation::{self, Outcome, accessibility};
+    use crate::core::{Element, overlay::Overlay as _};
+
+    #[test]
+    fn toasts_are_live_regions() {
+        let announcements = [
+            Announcement {
+                id: Id::new("saved"),
+                message: String::from("File saved"),
+                is_urgent: false,
+            },
+            Announcement {
+                id: Id::new("failed"),
+                message: String::from("Upload failed"),
+                is_urgent: true,
+            },
+        ];
+
+        let mut element: Element<'_, (), crate::Theme, ()> = Column::with_children(
+            announcements
+                .iter()
+                .map(|_| crate::Space::new().width(100).height(20).into()),
+        )
+        .into();
+
+        let mut state = Tree::new(&element);
+
+        let mut overlay = ToasterOverlay {
+            state: &mut state,
+            element: &mut element,
+            announcements: &announcements,
+        };
+
+        let node = overlay.layout(&(), Size::new(400.0, 300.0));
+        let mut operation = accessibility::collect();
+
+        overlay.operate(
+            Layout::new(&node),
+            &(),
+            &mut operation::black_box(&mut operation),
+        );
+
+        let Outcome::Some(tree) = operation.finish() else {
+            panic!("collect should produce a tree");
+        };
+
+        let find = |id: &'static str| {
+            let id = node_id_from_widget_id(&Id::new(id));
+
+            tree.nodes
+                .iter()
+                .find(|(node, _)| *node == id)
+                .map(|(_, node)| node)
+                .expect("toast node")
+        };
+
+        let saved = find("saved");
+        let failed = find("failed");
+
+        assert_eq!(saved.role(), Role::Status);
+        assert_eq!(saved.live(), Some(Live::Polite));
+        assert_eq!(saved.label(), Some("File saved"));
+
+        assert_eq!(failed.role(), Role::Alert);
+        assert_eq!(failed.live(), Some(Live::Assertive));
+        assert_eq!(failed.label(), Some("Upload failed"));
+    }
+}
@@ -72,6 +72,7 @@ where
     padding: f32,
     snap_within_viewport: bool,
     delay: Duration,
+    when_truncated: bool,
     class: Theme::Class<'a>,
 }
 
@@ -99,6 +100,7 @@ where
             padding: Self::DEFAULT_PADDING,
             snap_within_viewport: true,
             delay: Duration::ZERO,
+            when_truncated: false,
             class: Theme::default(),
         }
     }
@@ -129,6 +131,15 @@ where
         self
     }
 
+    /// Sets whether the [`Tooltip`] is only shown while its content is a
+    /// [`Text`] that does not fit its bounds and has been truncated.
+    ///
+    /// [`Text`]: crate::Text
+    pub fn when_truncated(mut self, when_truncated: bool) -> Self {
+        self.when_truncated = when_truncated;
+        self
+    }
+
     /// Sets the style of the [`Tooltip`].
     #[must_use]
     pub fn style(mut self, style: impl Fn(&Theme) -> container::Style + 'a) -> Self
@@ -166,7 +177,7 @@ where
     }
 
     fn state(&self) -> widget::tree::State {
-        widget::tree::State::new(State::default())
+        widget::tree::State::new(State::new())
     }
 
     fn tag(&self) -> widget::tree::Tag {
@@ -206,49 +217,51 @@ where
         if let Event::Mouse(_) | Event::Window(window::Event::RedrawRequested(_)) = event {
             let state = tree.state.downcast_mut::<State>();
             let now = Instant::now();
-            let cursor_position = cursor.position_over(layout.bounds());
+            let cursor_position = cursor
+                .position_over(layout.bounds())
+                .filter(|_| !self.when_truncated || is_truncated::<Renderer>(&tree.children[0]));
 
-            match (*state, cursor_position) {
-                (State::Idle, Some(cursor_position)) => {
+            match (state.status, cursor_position) {
+                (Status::Idle, Some(cursor_position)) => {
                     if self.delay == Duration::ZERO {
-                        *state = State::Open { cursor_position };
+                        state.status = Status::Open { cursor_position };
                         shell.invalidate_layout();
                     } else {
-                        *state = State::Hovered { at: now };
+                        state.status = Status::Hovered { at: now };
                     }
 
                     shell.request_redraw_at(now + self.delay);
                 }
-                (State::Hovered { .. }, None) => {
-                    *state = State::Idle;
+                (Status::Hovered { .. }, None) => {
+                    state.status = Status::Idle;
                 }
-                (State::Hovered { at, .. }, _) if at.elapsed() < self.delay => {
+                (Status::Hovered { at, .. }, _) if at.elapsed() < self.delay => {
                     shell.request_redraw_at(now + self.delay - at.elapsed());
                 }
-                (State::Hovered { .. }, Some(cursor_position)) => {
-                    *state = State::Open { cursor_position };
+                (Status::Hovered { .. }, Some(cursor_position)) => {
+                    state.status = Status::Open { cursor_position };
                     shell.invalidate_layout();
                 }
                 (
-                    State::Open {
+                    Status::Open {
                         cursor_position: last_position,
                     },
                     Some(cursor_position),
                 ) if self.position == Position::FollowCursor
                     && last_position != cursor_position =>
                 {
-                    *state = State::Open { cursor_position };
+                    state.status = Status::Open { cursor_position };
                     shell.request_redraw();
                 }
-                (State::Open { .. }, None) => {
-                    *state = State::Idle;
+                (Status::Open { .. }, None) => {
+                    state.status = Status::Idle;
                     shell.invalidate_layout();
 
                     if !matches!(event, Event::Window(window::Event::RedrawRequested(_)),) {
                         shell.request_redraw();
                     }
                 }
-                (State::Open { .. }, Some(_)) | (State::Idle, None) => (),
+                (Status::Open { .. }, Some(_)) | (Status::Idle, None) => (),
             }
         }
 
@@ -322,7 +335,7 @@ where
             translation,
         );
 
-        let tooltip = if let State::Open { cursor_position } = *state {
+        let tooltip = if let Status::Open { cursor_position } = state.status {
             Some(overlay::Element::new(Box::new(Overlay {
                 position: layout.position() + translation,
                 tooltip: &mut self.tooltip,
@@ -356,8 +369,51 @@ where
         renderer: &Renderer,
         operation: &mut dyn widget::Operation,
     ) {
+        #[cfg(feature = "accessibility")]
+        let description = {
+            use crate::core::accessibility::{WidgetInfo, node_id_from_widget_id};
+
+            let state = tree.state.downcast_ref::<State>();
+
+            let is_shown = !self.when_truncated || is_truncated::<Renderer>(&tree.children[0]);
+
+            let label = self
+                .tooltip
+                .as_widget()
+                .accessibility_label()
+                .filter(|_| is_shown);
+
+            label.map(|label| {
+                let label = label.into_owned();
+
+                operation.accessibility(
+                    Some(&state.id),
+                    layout.bounds(),
+                    WidgetInfo::tooltip(label.clone()).with_bounds(layout.bounds()),
+                );
+
+                (node_id_from_widget_id(&state.id), label)
+            })
+        };
+
         operation.container(None, layout.bounds());
         operation.traverse(&mut |operation| {
+            #[cfg(feature = "accessibility")]
+            if let Some((node, label)) = &description {
+                self.content.as_widget_mut().operate(
+                    &mut tree.children[0],
+                    layout,
+                    renderer,
+                    &mut widget::operation::accessibility::described_by(
+                        operation,
+                        *node,
+                        Some(label.as_str()),
+                    ),
+                );
+
+                return;
+            }
+
             self.content.as_widget_mut().operate(
                 &mut tree.children[0],
                 layout,
@@ -367,6 +423,11 @@ where
         });
         operation.leave_container();
     }
+
+    #[cfg(feature = "accessibility")]
+    fn accessibility_label(&self) -> Option<std::borrow::Cow<'_, str>> {
+        self.content.as_widget().accessibility_label()
+    }
 }
 
 impl<'a, Message, Theme, Renderer> From<Tooltip<'a, Message, Theme, Renderer>>
@@ -399,16 +460,47 @@ pub enum Position {
     FollowCursor,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
-enum State {
-    #[default]
+/// Returns true if the given [`widget::Tree`] belongs to a [`Text`] that
+/// has been truncated.
+///
+/// [`Text`]: crate::Text
+fn is_truncated<Renderer>(tree: &widget::Tree) -> bool
+where
+    Renderer: text::Renderer,
+{
+    type Paragraph<R> = widget::text::State<<R as text::Renderer>::Paragraph>;
+
+    tree.tag == widget::tree::Tag::of::<Paragraph<Renderer>>()
+        && tree
+            .state
+            .downcast_ref::<Paragraph<Renderer>>()
+            .is_truncated()
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    status: Status,
+    /// The identifier of the accessibility node of the tooltip, which
+    /// describes the content.
+    #[cfg(feature = "accessibility")]
+    id: widget::Id,
+}
+
+impl State {
+    fn new() -> Self {
+        Self {
+            status: Status::Idle,
+            #[cfg(feature = "accessibility")]
+            id: widget::Id::unique(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Status {
     Idle,
-    Hovered {
-        at: Instant,
-    },
-    Open {
-        cursor_position: Point,
-    },
+    Hovered { at: Instant },
+    Open { cursor_position: Point },
 }
 
 struct Overlay<'a, 'b, Message, Theme, Renderer>
@@ -522,20 +614,168 @@ where
     ) {
         let style = theme.style(self.class);
 
-        container::draw_background(renderer, &style, layout.bounds());
-
         let defaults = renderer::Style {
             text_color: style.text_color.unwrap_or(inherited_style.text_color),
         };
 
-        self.tooltip.as_widget().draw(
-            self.tree,
-            renderer,
-            theme,
-            &defaults,
-            layout.children().next().unwrap(),
-            cursor_position,
+        let viewport = Rectangle::with_size(Size::INFINITE);
+
+        container::draw_with_backdrop(renderer, &style, layout.bounds(), &viewport, |renderer| {
+            self.tooltip.as_widget().draw(
+                self.tree,
+                renderer,
+                theme,
+                &defaults,
+                layout.children().next().unwrap(),
+                cursor_position,
+                &viewport,
+            );
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::core::clipboard;
+    use crate::core::text::Truncation;
+
+    type Tooltip<'a> = super::Tooltip<'a, (), crate::Theme, ()>;
+
+    /// Lays out the given [`Tooltip`] and collects its accessibility tree.
+    #[cfg(feature = "accessibility")]
+    fn collect(
+        mut tooltip: Tooltip<'_>,
+    ) -> crate::core::widget::operation::accessibility::AccessibilityTree {
+        use crate::core::widget::operation::{self, Operation, Outcome, accessibility};
+
+        let mut tree = widget::Tree::new(&tooltip as &dyn Widget<(), crate::Theme, ()>);
+        let node = Widget::<(), crate::Theme, ()>::layout(
+            &mut tooltip,
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 20.0)),
+        );
+
+        let mut operation = accessibility::collect();
+
+        tooltip.operate(
+            &mut tree,
+            Layout::new(&node),
+            &(),
+            &mut operation::black_box(&mut operation),
+        );
+
+        let Outcome::Some(tree) = operation.finish() else {
+            panic!("collect should produce a tree");
+        };
+
+        tree
+    }
+
+    /// The `()` renderer measures text as empty, so contents fill the bounds
+    /// to be hovered.
+    fn content<'a>(content: &'a str) -> crate::Text<'a, crate::Theme, ()> {
+        crate::Text::new(content)
+            .width(Length::Fill)
+            .height(Length::Fill)
+    }
+
+    /// Hovers the given [`Tooltip`] and returns its [`Status`].
+    fn hover(mut tooltip: Tooltip<'_>) -> Status {
+        let mut tree = widget::Tree::new(&tooltip as &dyn Widget<(), crate::Theme, ()>);
+        let node = Widget::<(), crate::Theme, ()>::layout(
+            &mut tooltip,
+            &mut tree,
+            &(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, 20.0)),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        tooltip.update(
+            &mut tree,
+            &Event::Mouse(mouse::Event::CursorMoved {
+                position: Point::ORIGIN,
+                modifiers: crate::core::keyboard::Modifiers::default(),
+            }),
+            Layout::new(&node),
+            mouse::Cursor::Available(Point::ORIGIN),
+            &(),
+            &mut clipboard::Null,
+            &mut shell,
             &Rectangle::with_size(Size::INFINITE),
         );
+
+        tree.state.downcast_ref::<State>().status
+    }
+
+    #[test]
+    fn tooltip_opens_when_hovered() {
+        let tooltip = Tooltip::new(content("Content"), "Tooltip", Position::Top);
+
+        assert!(matches!(hover(tooltip), Status::Open { .. }));
+    }
+
+    #[test]
+    fn tooltip_when_truncated_stays_closed_while_the_text_fits() {
+        let tooltip = Tooltip::new(
+            content("Content").truncation(Truncation::End),
+            "Content",
+            Position::Top,
+        )
+        .when_truncated(true);
+
+        assert_eq!(hover(tooltip), Status::Idle);
+    }
+
+    #[cfg(feature = "accessibility")]
+    #[test]
+    fn tooltip_describes_its_content() {
+        use crate::core::accessibility::Role;
+
+        let tree = collect(Tooltip::new(
+            crate::Button::new(content("Save")),
+            "Saves the file",
+            Position::Top,
+        ));
+
+        let (tooltip, _) = tree
+            .nodes
+            .iter()
+            .find(|(_, node)| node.role() == Role::Tooltip)
+            .expect("tooltip node");
+
+        let (_, content) = tree
+            .nodes
+            .iter()
+            .find(|(_, node)| node.role() != Role::Tooltip)
+            .expect("content node");
+
+        assert_eq!(content.described_by(), [*tooltip]);
+        assert_eq!(content.description(), Some("Saves the file"));
+    }
+
+    #[cfg(feature = "accessibility")]
+    #[test]
+    fn tooltip_when_truncated_does_not_describe_fitting_text() {
+        use crate::core::accessibility::Role;
+
+        let tree = collect(
+            Tooltip::new(
+                content("Save").truncation(Truncation::End),
+                "Save",
+                Position::Top,
+            )
+            .when_truncated(true),
+        );
+
+        assert!(
+            tree.nodes
+                .iter()
+                .all(|(_, node)| node.role() != Role::Tooltip && node.described_by().is_empty())
+        );
     }
 }